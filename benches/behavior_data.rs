@@ -0,0 +1,117 @@
+//! Benchmarks of [`BehaviorData`] port access
+// Copyright © 2025 Stephan Kunz
+
+#![allow(missing_docs)]
+#![allow(clippy::unwrap_used)]
+
+use behaviortree::prelude::*;
+use criterion::{Criterion, criterion_group, criterion_main};
+use std::time::Duration;
+
+const SAMPLES: usize = 10;
+const ITERATIONS: usize = 10;
+const DURATION: Duration = Duration::from_secs(5);
+/// Number of reads respectively writes of the same port per tick
+const READS: usize = 100;
+
+const TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<SubTree ID="Sub" value="{value}"/>
+	</BehaviorTree>
+	<BehaviorTree ID="Sub">
+		<Sequence>
+			<Reader value="{value}" first="1" second="2" third="3"/>
+			<Writer value="{value}" first="1" second="2" third="3"/>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+/// Action reading the same port in a tight loop
+#[derive(Action, Debug, Default)]
+struct Reader;
+
+#[async_trait::async_trait]
+impl Behavior for Reader {
+	async fn tick(
+		&mut self,
+		behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		for _ in 0..READS {
+			std::hint::black_box(behavior.get::<i64>("value")?);
+		}
+		Ok(BehaviorState::Success)
+	}
+
+	fn provided_ports() -> PortList {
+		port_list![
+			input_port!(i64, "first"),
+			input_port!(i64, "second"),
+			input_port!(i64, "third"),
+			input_port!(i64, "value"),
+		]
+	}
+}
+
+/// Action writing the same port in a tight loop
+#[derive(Action, Debug, Default)]
+struct Writer;
+
+#[async_trait::async_trait]
+impl Behavior for Writer {
+	async fn tick(
+		&mut self,
+		behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		for value in (0_i64..).take(READS) {
+			behavior.set("value", value)?;
+		}
+		Ok(BehaviorState::Success)
+	}
+
+	fn provided_ports() -> PortList {
+		port_list![
+			input_port!(i64, "first"),
+			input_port!(i64, "second"),
+			input_port!(i64, "third"),
+			output_port!(i64, "value"),
+		]
+	}
+}
+
+fn port_access(c: &mut Criterion) {
+	let runtime = tokio::runtime::Builder::new_current_thread()
+		.build()
+		.unwrap();
+
+	let mut group = c.benchmark_group("port access");
+	group
+		.measurement_time(DURATION)
+		.sample_size(SAMPLES);
+
+	let mut factory = BehaviorTreeFactory::new().unwrap();
+	register_behavior!(factory, Reader, "Reader").unwrap();
+	register_behavior!(factory, Writer, "Writer").unwrap();
+	let mut tree = factory.create_from_text(TREE).unwrap();
+	tree.blackboard().set("value", 42_i64).unwrap();
+	group.bench_function("remapped ports", |b| {
+		b.iter(|| {
+			runtime.block_on(async {
+				for _ in 1..=ITERATIONS {
+					tree.reset().unwrap();
+					tree.tick_once().await.unwrap();
+					std::hint::black_box(());
+				}
+			});
+		});
+	});
+}
+
+criterion_group!(benches, port_access);
+
+criterion_main!(benches);
@@ -0,0 +1,49 @@
+//! Benchmarks of compiled versus re-parsed script execution
+// Copyright © 2025 Stephan Kunz
+
+#![allow(missing_docs)]
+#![allow(clippy::unwrap_used)]
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use std::time::Duration;
+use tinyscript::{DefaultEnvironment, Environment, Runtime, ScriptingValue};
+
+const SAMPLES: usize = 10;
+const ITERATIONS: usize = 100;
+const DURATION: Duration = Duration::from_secs(5);
+
+/// A typical condition script of a reactive behavior
+const CODE: &str = "value > 40 && value < 50 && (value * 2 - 4) / 2 == 40";
+
+fn script(c: &mut Criterion) {
+	let mut group = c.benchmark_group("script");
+	group
+		.measurement_time(DURATION)
+		.sample_size(SAMPLES);
+
+	let mut runtime = Runtime::default();
+	let mut env = DefaultEnvironment::default();
+	env.define_env("value", ScriptingValue::Int64(42))
+		.unwrap();
+
+	group.bench_function("re-parsed", |b| {
+		b.iter(|| {
+			for _ in 1..=ITERATIONS {
+				std::hint::black_box(runtime.run(CODE, &mut env).unwrap());
+			}
+		});
+	});
+
+	let chunk = runtime.parse(CODE).unwrap();
+	group.bench_function("compiled", |b| {
+		b.iter(|| {
+			for _ in 1..=ITERATIONS {
+				std::hint::black_box(runtime.execute(&chunk, &mut env).unwrap());
+			}
+		});
+	});
+}
+
+criterion_group!(benches, script);
+
+criterion_main!(benches);
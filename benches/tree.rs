@@ -5,13 +5,14 @@
 #![allow(clippy::unwrap_used)]
 
 use behaviortree::{BehaviorTreeObserver, Groot2Connector, prelude::*};
-use criterion::{Criterion, criterion_group, criterion_main};
+use criterion::{Criterion, Throughput, criterion_group, criterion_main};
 use std::time::Duration;
 use tokio::try_join;
 
 const SAMPLES: usize = 10;
 const ITERATIONS: usize = 10;
 const DURATION: Duration = Duration::from_secs(5);
+const BULK_TICKS: usize = 1000;
 
 const TREE: &str = r#"
 <root BTCPP_format="4"
@@ -279,6 +280,28 @@ fn trees(c: &mut Criterion) {
 	});
 }
 
-criterion_group!(benches, trees);
+fn tick_bulk(c: &mut Criterion) {
+	let runtime = tokio::runtime::Builder::new_current_thread()
+		.build()
+		.unwrap();
+
+	let mut group = c.benchmark_group("tick bulk");
+	group
+		.measurement_time(DURATION)
+		.sample_size(SAMPLES)
+		.throughput(Throughput::Elements(BULK_TICKS as u64));
+
+	let mut factory = create_factory().unwrap();
+	let mut tree = factory.create_tree("MainTree").unwrap();
+	group.bench_function("ticks", |b| {
+		b.iter(|| {
+			runtime.block_on(async {
+				std::hint::black_box(tree.tick_bulk(BULK_TICKS, true).await.unwrap());
+			});
+		});
+	});
+}
+
+criterion_group!(benches, trees, tick_bulk);
 
 criterion_main!(benches);
@@ -32,7 +32,17 @@ pub fn derive_behavior_struct(input: &DeriveInput, kind: super::Kind) -> TokenSt
 		crate::Kind::Decorator => quote! { behaviortree::behavior::BehaviorKind::Decorator },
 	};
 
+	// static assertion, that the type is usable as behavior, not possible for generic types
+	let assertion = if input.generics.params.is_empty() {
+		quote! {
+			const _: () = behaviortree::behavior::assert_behavior::<#ident>();
+		}
+	} else {
+		TokenStream::new()
+	};
+
 	quote! {
+		#assertion
 		#derived
 		#diagnostic
 		impl #impl_generics behaviortree::behavior::BehaviorExecution for #ident #type_generics #where_clause {
@@ -46,7 +56,7 @@ pub fn derive_behavior_struct(input: &DeriveInput, kind: super::Kind) -> TokenSt
 			#[inline]
 			fn kind() -> behaviortree::behavior::BehaviorKind { #kind_ }
 			#[inline]
-			fn static_provided_ports(&self) -> behaviortree::port::PortList { Self::provided_ports() }
+			fn static_provided_ports(&self) -> behaviortree::port::PortList { <Self as behaviortree::behavior::Behavior>::provided_ports() }
 		}
 	}
 }
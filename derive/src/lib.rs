@@ -52,7 +52,7 @@
 //! impl behaviortree::behavior::BehaviorExecution for MyAction {
 //!     fn as_any(&self) -> &dyn core::any::Any { self }
 //!     fn as_any_mut(&mut self) -> &mut dyn core::any::Any { self }
-//!     fn static_provided_ports(&self) -> behaviortree::port::PortList { Self::provided_ports() }
+//!     fn static_provided_ports(&self) -> behaviortree::port::PortList { <Self as behaviortree::behavior::Behavior>::provided_ports() }
 //! }
 //! ```
 //!
@@ -72,4 +72,20 @@ mod tests {
 		tree.tick_while_running().await?;
 		Ok(())
 	}
+
+	#[test]
+	async fn depth_limit() -> Result<(), Error> {
+		let mut factory = BehaviorTreeFactory::new()?;
+		factory.register_behavior_tree_from_text(XML)?;
+
+		// the root, 11 levels of controls and the leafs
+		factory.set_max_tree_depth(12);
+		assert!(factory.create_main_tree().is_err());
+		factory.set_max_tree_depth(13);
+		let mut tree = factory.create_main_tree()?;
+		drop(factory);
+
+		tree.tick_while_running().await?;
+		Ok(())
+	}
 }
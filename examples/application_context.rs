@@ -0,0 +1,138 @@
+// Copyright © 2025 Stephan Kunz
+//! Passing an application context into behaviors.
+//!
+//! Instead of reaching for a global like `static mut GLOBAL_BLACKBOARD`,
+//! a handle to e.g. a device driver is injected into the tree with
+//! [`BehaviorTree::set_context`] and fetched in `tick` with [`BehaviorData::context`].
+
+use std::sync::{
+	Arc,
+	atomic::{AtomicBool, Ordering},
+};
+
+use behaviortree::prelude::*;
+
+const XML: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence>
+			<MotorUp/>
+			<IsMotorRunning/>
+			<MotorStop/>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+/// A stand-in for a device driver handle.
+#[derive(Debug, Default)]
+struct MotorDriver {
+	running: AtomicBool,
+}
+
+/// Fetches the [`MotorDriver`] from the trees context.
+fn motor(behavior: &BehaviorData) -> Result<&MotorDriver, BehaviorError> {
+	behavior
+		.context::<Arc<MotorDriver>>()
+		.map(AsRef::as_ref)
+		.ok_or_else(|| BehaviorError::Composition {
+			txt: "no MotorDriver context set".into(),
+		})
+}
+
+/// Action `MotorUp`
+#[derive(Action, Debug, Default)]
+struct MotorUp;
+
+#[async_trait::async_trait]
+impl Behavior for MotorUp {
+	async fn tick(
+		&mut self,
+		behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		motor(behavior)?
+			.running
+			.store(true, Ordering::Relaxed);
+		println!("[ MotorUp: motor started ]");
+		Ok(BehaviorState::Success)
+	}
+}
+
+/// Action `MotorStop`
+#[derive(Action, Debug, Default)]
+struct MotorStop;
+
+#[async_trait::async_trait]
+impl Behavior for MotorStop {
+	async fn tick(
+		&mut self,
+		behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		motor(behavior)?
+			.running
+			.store(false, Ordering::Relaxed);
+		println!("[ MotorStop: motor stopped ]");
+		Ok(BehaviorState::Success)
+	}
+}
+
+/// Condition `IsMotorRunning`
+#[derive(Condition, Debug, Default)]
+struct IsMotorRunning;
+
+#[async_trait::async_trait]
+impl Behavior for IsMotorRunning {
+	async fn tick(
+		&mut self,
+		behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		if motor(behavior)?.running.load(Ordering::Relaxed) {
+			Ok(BehaviorState::Success)
+		} else {
+			Ok(BehaviorState::Failure)
+		}
+	}
+}
+
+async fn example() -> BehaviorTreeResult {
+	// the driver is owned by the application and shared with the tree
+	let driver = Arc::new(MotorDriver::default());
+
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, MotorUp, "MotorUp")?;
+	register_behavior!(factory, MotorStop, "MotorStop")?;
+	register_behavior!(factory, IsMotorRunning, "IsMotorRunning")?;
+
+	let mut tree = factory.create_from_text(XML)?;
+	drop(factory);
+
+	tree.set_context(driver.clone());
+
+	let result = tree.tick_while_running().await?;
+	assert!(!driver.running.load(Ordering::Relaxed));
+	Ok(result)
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Error> {
+	example().await?;
+	Ok(())
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[tokio::test]
+	async fn application_context() -> Result<(), Error> {
+		let result = example().await?;
+		assert_eq!(result, BehaviorState::Success);
+		Ok(())
+	}
+}
@@ -0,0 +1,92 @@
+// Copyright © 2025 Stephan Kunz
+//! [`ConvertQueue`] [`Action`] implementation.
+
+// region:      --- modules
+use crate::SharedRuntime;
+use crate::{
+	self as behaviortree, Action, EMPTY_STR, REASON,
+	behavior::{BehaviorData, BehaviorResult, BehaviorState, InlineBehavior, shared_queue::SharedQueue},
+	inout_port, input_port, output_port,
+	port::PortList,
+	port_list,
+	tree::BehaviorTreeElementList,
+};
+use alloc::string::{String, ToString};
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::str::FromStr;
+// endregion:   --- modules
+
+// region:		--- globals
+/// Port name literals
+const INPUT_QUEUE: &str = "input_queue";
+const OUTPUT_QUEUE: &str = "output_queue";
+// endregion:	--- globals
+
+// region:      --- ConvertQueue
+/// The [`ConvertQueue`] behavior converts the elements of a [`SharedQueue<F>`] into a [`SharedQueue<T>`].
+///
+/// The behavior drains the queue in port `input_queue` and appends the elements converted
+/// with [`TryFrom`] to the queue in port `output_queue`.
+/// If there is no output queue yet, a new one is created.
+///
+/// If an element cannot be converted, the behavior returns Failure and the output port `reason` names the element.
+/// The failed element and all following elements stay in the input queue.
+///
+/// The behavior is intended for numeric types, e.g. `ConvertQueue<i32, f64>`,
+/// and has to be registered for each pair of types, e.g.
+/// `register_behavior!(factory, ConvertQueue<i32, f64>, "IntToDouble")`.
+///
+/// The behavior is gated behind feature `convert_queue`.
+#[derive(Action, Debug, Default)]
+pub struct ConvertQueue<F, T>
+where
+	F: Clone + Debug + Default + FromStr + ToString + Send + Sync + 'static,
+	T: Clone + Debug + Default + FromStr + ToString + TryFrom<F> + Send + Sync + 'static,
+{
+	/// The converted types
+	_marker: PhantomData<(F, T)>,
+}
+
+impl<F, T> InlineBehavior for ConvertQueue<F, T>
+where
+	F: Clone + Debug + Default + FromStr + ToString + Send + Sync,
+	T: Clone + Debug + Default + FromStr + ToString + TryFrom<F> + Send + Sync,
+{
+	async fn tick(
+		&mut self,
+		behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		let input = behavior.get::<SharedQueue<F>>(INPUT_QUEUE)?;
+		let output = if let Ok(queue) = behavior.get::<SharedQueue<T>>(OUTPUT_QUEUE) {
+			queue
+		} else {
+			let queue = SharedQueue::<T>::default();
+			behavior.set(OUTPUT_QUEUE, queue.clone())?;
+			queue
+		};
+
+		while let Some(value) = input.pop_front() {
+			if let Ok(converted) = T::try_from(value.clone()) {
+				output.push_back(converted);
+			} else {
+				let reason = String::from("value '") + &value.to_string() + "' cannot be converted";
+				input.push_front(value);
+				behavior.set_reason(&reason)?;
+				return Ok(BehaviorState::Failure);
+			}
+		}
+		Ok(BehaviorState::Success)
+	}
+
+	fn provided_ports() -> PortList {
+		port_list![
+			input_port!(SharedQueue<F>, INPUT_QUEUE),
+			inout_port!(SharedQueue<T>, OUTPUT_QUEUE),
+			output_port!(String, REASON, EMPTY_STR, "Explanation of a failure."),
+		]
+	}
+}
+// endregion:   --- ConvertQueue
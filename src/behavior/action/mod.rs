@@ -1,11 +1,13 @@
 // Copyright © 2025 Stephan Kunz
 //! Built-in `Action` behaviors of [`behaviortree`](crate).
 
+#[cfg(feature = "convert_queue")]
+mod convert_queue;
 #[cfg(feature = "pop_from_queue")]
 mod pop_from_queue;
 #[cfg(feature = "script")]
 mod script;
-#[cfg(feature = "set_blackboard")]
+#[cfg(feature = "set_blackboards")]
 mod set_blackboard;
 #[cfg(feature = "sleep")]
 mod sleep;
@@ -13,12 +15,16 @@ mod sleep;
 mod unset_blackboard;
 
 // flatten
+#[cfg(feature = "convert_queue")]
+pub use convert_queue::ConvertQueue;
 #[cfg(feature = "pop_from_queue")]
 pub use pop_from_queue::PopFromQueue;
 #[cfg(feature = "script")]
 pub use script::Script;
 #[cfg(feature = "set_blackboard")]
 pub use set_blackboard::SetBlackboard;
+#[cfg(feature = "set_typed_blackboard")]
+pub use set_blackboard::SetTypedBlackboard;
 #[cfg(feature = "sleep")]
 pub use sleep::Sleep;
 #[cfg(feature = "unset_blackboard")]
@@ -2,18 +2,18 @@
 //! [`PopFromQueue`] [`Action`] implementation.
 
 // region:      --- modules
+use crate::SharedRuntime;
 use crate::{
 	self as behaviortree, Action,
-	behavior::{Behavior, BehaviorData, BehaviorResult, BehaviorState, shared_queue::SharedQueue},
+	behavior::{BehaviorData, BehaviorResult, BehaviorState, InlineBehavior, shared_queue::SharedQueue},
 	input_port, output_port,
 	port::PortList,
 	port_list,
 	tree::BehaviorTreeElementList,
 };
-use alloc::{boxed::Box, string::ToString};
+use alloc::string::ToString;
 use core::fmt::Debug;
 use core::str::FromStr;
-use tinyscript::SharedRuntime;
 // endregion:   --- modules
 
 // region:		--- globals
@@ -43,8 +43,7 @@ where
 	tmp_queue: Option<SharedQueue<T>>,
 }
 
-#[async_trait::async_trait]
-impl<T> Behavior for PopFromQueue<T>
+impl<T> InlineBehavior for PopFromQueue<T>
 where
 	T: Clone + Debug + Default + FromStr + ToString + Send + Sync,
 {
@@ -62,15 +61,17 @@ where
 				Ok(q) => q.pop_front(),
 				#[allow(clippy::collapsible_match)]
 				Err(err) => match &err {
-					crate::port::error::Error::Databoard { source } => match source {
-						databoard::Error::Assignment { key: _, value } => {
-							let q = SharedQueue::from_str(value)?;
-							let first = q.pop_front();
-							self.tmp_queue = Some(q);
-							first
+					crate::port::error::Error::Databoard { source } | crate::port::error::Error::Access { source, .. } => {
+						match source {
+							databoard::Error::Assignment { key: _, value } => {
+								let q = SharedQueue::from_str(value)?;
+								let first = q.pop_front();
+								self.tmp_queue = Some(q);
+								first
+							}
+							_ => return Err(err.into()),
 						}
-						_ => return Err(err.into()),
-					},
+					}
 					_ => return Err(err.into()),
 				},
 			}
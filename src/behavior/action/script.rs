@@ -2,19 +2,16 @@
 //! [`Script`] [`Action`] implementation.
 
 // region:      --- modules
+use crate::SharedRuntime;
 use crate::{
 	self as behaviortree, Action, EMPTY_STR,
-	behavior::{Behavior, BehaviorData, BehaviorResult, BehaviorState},
+	behavior::{BehaviorData, BehaviorError, BehaviorResult, BehaviorState, CompiledScript, InlineBehavior},
 	input_port,
 	port::PortList,
 	port_list,
 	tree::BehaviorTreeElementList,
 };
-use alloc::{
-	boxed::Box,
-	string::{String, ToString},
-};
-use tinyscript::SharedRuntime;
+use alloc::string::{String, ToString};
 //endregion:    --- modules
 
 // region:		--- globals
@@ -25,11 +22,21 @@ const CODE: &str = "code";
 /// The `Script` behavior returns Success or Failure depending on the result of the scripted code.
 ///
 /// The behavior is gated behind feature `script`.
+///
+/// The script is compiled at the creation of the tree and only compiled again, if the code changes.
 #[derive(Action, Default)]
-pub struct Script;
+pub struct Script {
+	/// The compiled `code`.
+	script: CompiledScript,
+}
+
+impl InlineBehavior for Script {
+	fn on_creation(&mut self, behavior: &mut BehaviorData, runtime: &SharedRuntime) -> Result<(), BehaviorError> {
+		self.script
+			.compile_port(&mut runtime.lock(), behavior, CODE)?;
+		Ok(())
+	}
 
-#[async_trait::async_trait]
-impl Behavior for Script {
 	async fn tick(
 		&mut self,
 		behavior: &mut BehaviorData,
@@ -37,7 +44,9 @@ impl Behavior for Script {
 		runtime: &SharedRuntime,
 	) -> BehaviorResult {
 		let code = behavior.get::<String>(CODE)?;
-		let value = runtime.lock().run(&code, behavior)?;
+		let value = self
+			.script
+			.run(&mut runtime.lock(), &code, behavior)?;
 
 		let state = if value.is_bool() {
 			let val = bool::try_from(value)?;
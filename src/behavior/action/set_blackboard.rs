@@ -1,32 +1,61 @@
 // Copyright © 2025 Stephan Kunz
-//! [`SetBlackboard`] [`Action`] implementation.
+//! [`SetBlackboard`] & [`SetTypedBlackboard`] [`Action`] implementations.
 
 // region:      --- modules
+use crate::SharedRuntime;
 use crate::{
 	self as behaviortree, Action, EMPTY_STR,
-	behavior::{Behavior, BehaviorData, BehaviorResult, BehaviorState},
+	behavior::{BehaviorData, BehaviorError, BehaviorResult, BehaviorState, InlineBehavior},
 	inout_port, input_port,
 	port::PortList,
 	port_list,
 	tree::BehaviorTreeElementList,
 };
-use alloc::{boxed::Box, string::String, string::ToString};
+use alloc::{string::String, string::ToString};
 use core::{fmt::Debug, marker::PhantomData, str::FromStr};
 use databoard::check_board_pointer;
-use tinyscript::SharedRuntime;
 // endregion:   --- modules
 
 // region:		--- globals
 /// Port name literals
 const OUTPUT_KEY: &str = "output_key";
+const TYPE: &str = "type";
 const VALUE: &str = "value";
 // endregion:	--- globals
 
+// region:		--- helper
+/// Writes the value to the entry given by `key`, which may be a blackboard pointer.
+fn write_value<T>(behavior: &mut BehaviorData, key: &str, value: T) -> Result<(), BehaviorError>
+where
+	T: Clone + Debug + FromStr + ToString + Send + Sync + 'static,
+{
+	match check_board_pointer(key) {
+		Ok(stripped_key) => behavior.set(stripped_key, value)?,
+		Err(original_key) => behavior.set(original_key, value)?,
+	};
+	Ok(())
+}
+
+/// Parses the value into `T`.
+fn parse_value<T: FromStr>(value: &str, typ: &str) -> Result<T, BehaviorError> {
+	T::from_str(value).map_err(|_| BehaviorError::ParsePortValue {
+		port: VALUE.into(),
+		typ: typ.into(),
+	})
+}
+// endregion:	--- helper
+
 // region:      --- SetBlackboard
 /// The [`SetBlackboard`] behavior is used to store a value of type T
 /// into an entry of the Blackboard specified via port `output_key`.
 ///
 /// The behavior is gated behind feature `set_blackboard`.
+///
+/// Example:
+///
+/// ```xml
+/// <SetBlackboard output_key="message" value="hello world"/>
+/// ```
 #[derive(Action, Default)]
 pub struct SetBlackboard<T: Debug>
 where
@@ -35,8 +64,7 @@ where
 	_marker: PhantomData<T>,
 }
 
-#[async_trait::async_trait]
-impl<T> Behavior for SetBlackboard<T>
+impl<T> InlineBehavior for SetBlackboard<T>
 where
 	T: Clone + Debug + Default + FromStr + ToString + Send + Sync,
 {
@@ -48,10 +76,7 @@ where
 	) -> BehaviorResult {
 		let value = behavior.get::<T>(VALUE)?;
 		let key = behavior.get::<String>(OUTPUT_KEY)?;
-		match check_board_pointer(&key) {
-			Ok(stripped_key) => behavior.set(stripped_key, value)?,
-			Err(original_key) => behavior.set(original_key, value)?,
-		};
+		write_value(behavior, &key, value)?;
 
 		Ok(BehaviorState::Success)
 	}
@@ -69,3 +94,71 @@ where
 	}
 }
 // endregion:   --- SetBlackboard
+
+// region:      --- SetTypedBlackboard
+/// The [`SetTypedBlackboard`] behavior is used to store a value into an entry of the Blackboard
+/// specified via port `output_key`. The type of the stored value is given by the port `type`:
+/// - `int`: stored as `i32`
+/// - `double`: stored as `f64`
+/// - `bool`: stored as `bool`
+/// - `string`: stored as `String`, which is the default
+///
+/// This avoids registering a [`SetBlackboard`] for each needed type.
+///
+/// The behavior is gated behind feature `set_typed_blackboard`.
+///
+/// Example:
+///
+/// ```xml
+/// <SetTypedBlackboard output_key="counter" type="int" value="42"/>
+/// ```
+#[derive(Action, Debug, Default)]
+pub struct SetTypedBlackboard;
+
+impl InlineBehavior for SetTypedBlackboard {
+	async fn tick(
+		&mut self,
+		behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		let typ = behavior
+			.get::<String>(TYPE)
+			.unwrap_or_else(|_| String::from("string"));
+		let value = behavior.get::<String>(VALUE)?;
+		let key = behavior.get::<String>(OUTPUT_KEY)?;
+		match typ.to_ascii_lowercase().as_ref() {
+			"int" => write_value(behavior, &key, parse_value::<i32>(&value, "int")?)?,
+			"double" => write_value(behavior, &key, parse_value::<f64>(&value, "double")?)?,
+			"bool" => write_value(behavior, &key, parse_value::<bool>(&value, "bool")?)?,
+			"string" => write_value(behavior, &key, value)?,
+			_ => {
+				return Err(BehaviorError::ParseError {
+					value: typ.into(),
+					src: "SetTypedBlackboard port 'type'".into(),
+				});
+			}
+		}
+
+		Ok(BehaviorState::Success)
+	}
+
+	fn provided_ports() -> PortList {
+		port_list![
+			input_port!(
+				String,
+				TYPE,
+				"string",
+				"Type of the value: 'int', 'double', 'bool' or 'string'"
+			),
+			input_port!(String, VALUE, EMPTY_STR, "Value to be written into the output_key"),
+			inout_port!(
+				String,
+				OUTPUT_KEY,
+				EMPTY_STR,
+				"Name of the blackboard entry where the value should be written"
+			),
+		]
+	}
+}
+// endregion:   --- SetTypedBlackboard
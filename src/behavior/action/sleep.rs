@@ -5,21 +5,19 @@
 extern crate std;
 
 // region:      --- modules
+use crate::SharedRuntime;
 use crate::{
 	self as behaviortree, Action, EMPTY_STR,
-	behavior::{Behavior, BehaviorData, BehaviorError, BehaviorResult, BehaviorState},
+	behavior::{BehaviorData, BehaviorError, BehaviorResult, BehaviorState, InlineBehavior},
 	input_port,
 	port::PortList,
 	port_list,
 	tree::BehaviorTreeElementList,
 };
-use alloc::{boxed::Box, string::ToString};
-use tinyscript::SharedRuntime;
+use alloc::string::ToString;
 
 #[cfg(feature = "std")]
 use core::time::Duration;
-#[cfg(feature = "std")]
-use std::time::Instant;
 //endregion:    --- modules
 
 // region:		--- globals
@@ -35,11 +33,10 @@ const MSEC: &str = "msec";
 #[derive(Action, Debug, Default)]
 pub struct Sleep {
 	#[cfg(feature = "std")]
-	start_time: Option<Instant>,
+	start_time: Option<Duration>,
 }
 
-#[async_trait::async_trait]
-impl Behavior for Sleep {
+impl InlineBehavior for Sleep {
 	#[inline]
 	fn on_halt(&mut self) -> Result<(), BehaviorError> {
 		#[cfg(feature = "std")]
@@ -57,7 +54,7 @@ impl Behavior for Sleep {
 	) -> Result<(), BehaviorError> {
 		#[cfg(feature = "std")]
 		{
-			self.start_time = Some(Instant::now());
+			self.start_time = Some(behavior.now());
 		}
 		behavior.set_state(BehaviorState::Running);
 		Ok(())
@@ -72,7 +69,7 @@ impl Behavior for Sleep {
 		let millis: u64 = behavior.get(MSEC)?;
 		#[cfg(feature = "std")]
 		if let Some(start) = &self.start_time {
-			if Instant::now().duration_since(*start) > Duration::from_millis(millis) {
+			if behavior.now().saturating_sub(*start) > Duration::from_millis(millis) {
 				self.start_time = None;
 				Ok(BehaviorState::Success)
 			} else {
@@ -2,19 +2,21 @@
 //! [`UnsetBlackboard`] [`Action`] implementation.
 
 // region:      --- modules
+use crate::SharedRuntime;
 use crate::{
 	self as behaviortree, Action, EMPTY_STR,
-	behavior::{Behavior, BehaviorData, BehaviorResult, BehaviorState},
+	behavior::{BehaviorData, BehaviorResult, BehaviorState, InlineBehavior},
 	input_port,
 	port::PortList,
 	port_list,
 	tree::BehaviorTreeElementList,
 };
 use alloc::string::String;
-use alloc::{boxed::Box, string::ToString};
+use alloc::string::ToString;
+use core::any::TypeId;
+use core::fmt::Debug;
 use core::marker::PhantomData;
 use core::str::FromStr;
-use tinyscript::SharedRuntime;
 // endregion:   --- modules
 
 // region:		--- globals
@@ -27,19 +29,23 @@ const KEY: &str = "key";
 /// from the Blackboard specified via port `key`.
 /// Will return Success whether the entry exists or not.
 ///
+/// Entries of the types created by scripts (`bool`, `f64`, `i64` and `String`)
+/// are deleted regardless of T.
+/// A key with prefix `@` like `<UnsetBlackboard key="@value"/>` deletes the entry
+/// from the root blackboard, also from within a subtree.
+///
 /// The behavior is gated behind feature `unset_blackboard`.
 #[derive(Action, Default)]
 pub struct UnsetBlackboard<T>
 where
-	T: Clone + Default + FromStr + ToString + Send + Sync + 'static,
+	T: Clone + Debug + Default + FromStr + ToString + Send + Sync + 'static,
 {
 	_marker: PhantomData<T>,
 }
 
-#[async_trait::async_trait]
-impl<T> Behavior for UnsetBlackboard<T>
+impl<T> InlineBehavior for UnsetBlackboard<T>
 where
-	T: Clone + Default + FromStr + ToString + Send + Sync,
+	T: Clone + Debug + Default + FromStr + ToString + Send + Sync,
 {
 	async fn tick(
 		&mut self,
@@ -48,7 +54,23 @@ where
 		_runtime: &SharedRuntime,
 	) -> BehaviorResult {
 		let key = behavior.get::<String>(KEY)?;
-		behavior.delete::<String>(&key)?;
+		let type_id = match behavior.blackboard().entry(&key) {
+			Ok(entry) => entry.read().data().as_ref().type_id(),
+			// nothing to delete
+			Err(_) => return Ok(BehaviorState::Success),
+		};
+
+		if type_id == TypeId::of::<bool>() {
+			behavior.delete::<bool>(&key)?;
+		} else if type_id == TypeId::of::<f64>() {
+			behavior.delete::<f64>(&key)?;
+		} else if type_id == TypeId::of::<i64>() {
+			behavior.delete::<i64>(&key)?;
+		} else if type_id == TypeId::of::<String>() {
+			behavior.delete::<String>(&key)?;
+		} else {
+			behavior.delete::<T>(&key)?;
+		}
 
 		Ok(BehaviorState::Success)
 	}
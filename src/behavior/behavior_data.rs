@@ -1,17 +1,24 @@
 // Copyright © 2025 Stephan Kunz
 //! Built-In behaviors of [`behaviortree`](crate).
 
+#[cfg(any(feature = "std", feature = "embassy"))]
+use crate::clock::{Clock, DefaultClock};
 use crate::{
-	BehaviorState, ConstString,
+	BehaviorState, ConstString, Mutex, REASON, TreeOptions,
 	behavior::{BehaviorDataCollection, BehaviorTickCallback, behavior_description::BehaviorDescription},
-	port::error::Error,
+	port::{PortDirection, error::Error},
 };
+#[cfg(feature = "scripting")]
+use alloc::borrow::ToOwned;
 use alloc::{
-	borrow::ToOwned,
 	boxed::Box,
+	collections::btree_map::BTreeMap,
 	string::{String, ToString},
+	sync::Arc,
 	vec::Vec,
 };
+#[cfg(any(feature = "std", feature = "embassy"))]
+use core::time::Duration;
 use core::{
 	any::{Any, TypeId},
 	fmt::Debug,
@@ -20,6 +27,7 @@ use core::{
 use databoard::{
 	Databoard, EntryReadGuard, EntryWriteGuard, Remappings, check_board_pointer, is_const_assignment, strip_board_pointer,
 };
+#[cfg(feature = "scripting")]
 use tinyscript::{Environment, ScriptingValue};
 
 // region:		--- helpers
@@ -30,8 +38,139 @@ fn strip_curly_brackets(key: &str) -> &str {
 	let key = key.strip_prefix('{').unwrap_or(key);
 	key.strip_suffix('}').unwrap_or(key)
 }
+
+/// Converts a constant assignment into the best matching [`ScriptingValue`].
+#[cfg(feature = "scripting")]
+fn scripting_value_from_str(value: &str) -> ScriptingValue {
+	i64::from_str(value).map_or_else(
+		|_| {
+			f64::from_str(value).map_or_else(
+				|_| {
+					bool::from_str(value).map_or_else(|_| ScriptingValue::String(value.to_string()), ScriptingValue::Boolean)
+				},
+				ScriptingValue::Float64,
+			)
+		},
+		ScriptingValue::Int64,
+	)
+}
+
+/// Parses a `bool` from `true`/`false`, `yes`/`no` or `1`/`0`, ignoring the case.
+fn parse_lenient_bool(value: &str) -> Option<bool> {
+	let value = value.trim();
+	if value == "1" || value.eq_ignore_ascii_case("true") || value.eq_ignore_ascii_case("yes") {
+		Some(true)
+	} else if value == "0" || value.eq_ignore_ascii_case("false") || value.eq_ignore_ascii_case("no") {
+		Some(false)
+	} else {
+		None
+	}
+}
+
+/// Creates an [`Error::Access`] for the port `key` and the requested type `T`.
+fn access_error<T>(key: &str, source: databoard::Error) -> Error {
+	Error::Access {
+		port: key.into(),
+		type_name: core::any::type_name::<T>().into(),
+		source,
+	}
+}
+
+/// Names of the types stored via [`BehaviorData`], as a blackboard entry only provides the [`TypeId`] of its value.
+static TYPE_NAMES: Mutex<BTreeMap<TypeId, &'static str>> = Mutex::new(BTreeMap::new());
+
+/// Remembers the name of `T` for a newly created blackboard entry.
+fn remember_type_name<T: Any>() {
+	TYPE_NAMES
+		.lock()
+		.entry(TypeId::of::<T>())
+		.or_insert_with(core::any::type_name::<T>);
+}
+
+/// Returns the name of the stored type.
+/// Types not known to scripting are only known, if an entry of that type was created via [`BehaviorData`].
+fn stored_type_name(type_id: TypeId) -> &'static str {
+	if type_id == TypeId::of::<String>() {
+		"String"
+	} else if type_id == TypeId::of::<bool>() {
+		"bool"
+	} else if type_id == TypeId::of::<f64>() {
+		"f64"
+	} else if type_id == TypeId::of::<f32>() {
+		"f32"
+	} else if type_id == TypeId::of::<i64>() {
+		"i64"
+	} else if type_id == TypeId::of::<i32>() {
+		"i32"
+	} else if type_id == TypeId::of::<u32>() {
+		"u32"
+	} else if type_id == TypeId::of::<i16>() {
+		"i16"
+	} else if type_id == TypeId::of::<u16>() {
+		"u16"
+	} else if type_id == TypeId::of::<u8>() {
+		"u8"
+	} else if type_id == TypeId::of::<i8>() {
+		"i8"
+	} else {
+		TYPE_NAMES
+			.lock()
+			.get(&type_id)
+			.copied()
+			.unwrap_or("unknown")
+	}
+}
+
+/// Creates an [`Error::TypeMismatch`] for the port `key`, the requested type `T` and the stored type.
+fn type_mismatch<T>(key: &str, type_id: TypeId) -> Error {
+	Error::TypeMismatch {
+		key: key.into(),
+		expected: core::any::type_name::<T>().into(),
+		actual: stored_type_name(type_id).into(),
+	}
+}
 // endregion:	--- helpers
 
+// region:		--- BlackboardBatch
+/// A deferred write of a [`BlackboardBatch`].
+type BatchWrite = Box<dyn FnOnce(&mut BehaviorData) -> Result<(), Error> + Send + Sync>;
+/// A type check of a deferred write of a [`BlackboardBatch`].
+type BatchCheck = Box<dyn Fn(&BehaviorData) -> Result<(), Error> + Send + Sync>;
+
+/// Collects blackboard writes, which are applied together by [`BehaviorData::with_batch`].
+#[derive(Default)]
+pub struct BlackboardBatch {
+	writes: Vec<(BatchCheck, BatchWrite)>,
+}
+
+impl BlackboardBatch {
+	/// Adds the write of a value of type `T` to the batch.
+	pub fn set<T>(&mut self, key: &str, value: T)
+	where
+		T: Any + Debug + FromStr + ToString + Send + Sync,
+	{
+		let check_key: ConstString = key.into();
+		let write_key = check_key.clone();
+		self.writes.push((
+			Box::new(move |behavior| behavior.check_writable::<T>(&check_key)),
+			Box::new(move |behavior| behavior.set(&write_key, value).map(|_| ())),
+		));
+	}
+
+	/// Returns the number of collected writes.
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.writes.len()
+	}
+
+	/// Returns `true` if no writes are collected.
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.writes.is_empty()
+	}
+}
+// endregion:	--- BlackboardBatch
+
 // region:      --- BehaviorData
 /// Structure for implementing behaviors.
 #[derive(Default)]
@@ -41,6 +180,9 @@ pub struct BehaviorData {
 	/// The ordering of the uid is following the creation order by the [`XmlParser`](crate::factory::xml_parser::XmlParser).
 	/// This should end up in a depth first ordering.
 	uid: u16,
+	/// Optional id derived from the elements position by name within the tree,
+	/// which does not depend on the order of siblings.
+	stable_id: Option<u64>,
 	/// Current state of the behavior.
 	state: BehaviorState,
 	/// List of internal [`Remappings`] including
@@ -54,6 +196,18 @@ pub struct BehaviorData {
 	pre_state_change_hooks: Vec<(ConstString, Box<BehaviorTickCallback>)>,
 	/// Description of the Behavior.
 	description: BehaviorDescription,
+	/// The tick count of the [`BehaviorTree`](crate::tree::BehaviorTree), shared by all its elements.
+	tick_count: Arc<Mutex<u64>>,
+	/// The seed derived from the seed of the [`BehaviorTree`](crate::tree::BehaviorTree) and the uid.
+	seed: Option<u64>,
+	/// The options of the [`BehaviorTree`](crate::tree::BehaviorTree), relevant are strict port directions and lenient bools.
+	options: TreeOptions,
+	/// The clock of the [`BehaviorTree`](crate::tree::BehaviorTree), shared by all its elements,
+	/// `None` for the default clock.
+	#[cfg(any(feature = "std", feature = "embassy"))]
+	clock: Option<Arc<dyn Clock>>,
+	/// The application context of the [`BehaviorTree`](crate::tree::BehaviorTree), shared by all its elements.
+	context: Option<Arc<dyn Any + Send + Sync>>,
 }
 
 impl BehaviorData {
@@ -62,11 +216,18 @@ impl BehaviorData {
 	pub(crate) fn new(data: &BehaviorDataCollection) -> Self {
 		Self {
 			uid: data.uid,
+			stable_id: data.stable_id,
 			state: BehaviorState::default(),
 			remappings: data.remappings.clone(),
 			blackboard: data.blackboard.clone(),
 			pre_state_change_hooks: Vec::default(),
 			description: data.bhvr_desc.clone(),
+			tick_count: Arc::default(),
+			seed: None,
+			options: TreeOptions::empty(),
+			#[cfg(any(feature = "std", feature = "embassy"))]
+			clock: None,
+			context: None,
 		}
 	}
 
@@ -80,13 +241,22 @@ impl BehaviorData {
 	}
 
 	/// Returns `true` if a <T> with the `key` is available, otherwise `false`.
+	/// An entry of a different type results in `false`.
 	/// # Errors
-	/// - if type is wrong
-	pub fn contains<T>(&self, _key: &str) -> Result<bool, databoard::Error>
+	/// - if the entry cannot be accessed
+	pub fn contains<T>(&self, key: &str) -> Result<bool, databoard::Error>
 	where
 		T: Any + Debug + FromStr + ToString + Send + Sync,
 	{
-		todo!()
+		let key = strip_curly_brackets(key);
+		let remapped_key = self.remappings.remap(key);
+		let key = strip_curly_brackets(&remapped_key);
+		if !self.blackboard.contains_key(key) {
+			return Ok(false);
+		}
+		let entry = self.blackboard.entry(key)?;
+		let en = &*entry.read();
+		Ok(en.data().downcast_ref::<T>().is_some())
 	}
 
 	/// Delete an entry of type `T` from Blackboard.
@@ -107,8 +277,20 @@ impl BehaviorData {
 	/// Get a value of type `T` from Blackboard.
 	/// # Errors
 	/// - if value is not found
-	#[allow(clippy::too_many_lines)]
+	/// - [`Error::TypeMismatch`] if the stored value has an incompatible type
+	/// - [`Error::CouldNotConvert`] if a stored string can not be parsed into `T`
+	/// - [`Error::NotReadable`] if `key` is an output port and port directions are strict
 	pub fn get<T>(&self, key: &str) -> Result<T, Error>
+	where
+		T: Any + Clone + Debug + FromStr + ToString + Send + Sync,
+	{
+		self.check_readable(key)?;
+		self.read(key)
+	}
+
+	/// Get a value of type `T` from Blackboard without checking the port direction.
+	#[allow(clippy::too_many_lines, clippy::option_if_let_else, clippy::single_match_else)]
+	fn read<T>(&self, key: &str) -> Result<T, Error>
 	where
 		T: Any + Clone + Debug + FromStr + ToString + Send + Sync,
 	{
@@ -125,32 +307,10 @@ impl BehaviorData {
 						data.downcast_ref::<T>().map_or_else(
 							|| {
 								data.downcast_ref::<String>().map_or_else(
-									|| {
-										self.get_env(remapped_key).map_or_else(
-											|_| Err(Error::NotFound { key: remapped.clone() }),
-											|val| {
-												let s = match val {
-													ScriptingValue::Nil() => unreachable!(),
-													ScriptingValue::Boolean(b) => b.to_string(),
-													ScriptingValue::Float64(f) => f.to_string(),
-													ScriptingValue::Int64(i) => i.to_string(),
-													ScriptingValue::String(s) => s,
-												};
-												T::from_str(&s).map_or_else(
-													|_| {
-														Err(Error::CouldNotConvert {
-															value: remapped_key.into(),
-															port: key.into(),
-														})
-													},
-													|val| Ok(val),
-												)
-											},
-										)
-									},
+									|| self.get_scripting_value(key, remapped_key, data.type_id()),
 									|val| {
-										T::from_str(val).map_or_else(
-											|_| {
+										self.parse::<T>(val).map_or_else(
+											|| {
 												Err(Error::CouldNotConvert {
 													value: remapped_key.into(),
 													port: key.into(),
@@ -167,8 +327,8 @@ impl BehaviorData {
 					Err(err) => {
 						// std::dbg!("remapped4");
 						match err {
-							databoard::Error::Assignment { key: _, value } => T::from_str(&value).map_or_else(
-								|_| {
+							databoard::Error::Assignment { key: _, value } => self.parse::<T>(&value).map_or_else(
+								|| {
 									Err(Error::CouldNotConvert {
 										value: remapped_key.into(),
 										port: key.into(),
@@ -182,9 +342,9 @@ impl BehaviorData {
 				},
 				None => {
 					// std::dbg!("remapped5");
-					match T::from_str(&remapped) {
-						Ok(res) => Ok(res),
-						Err(_err) => Err(Error::CouldNotConvert {
+					match self.parse::<T>(&remapped) {
+						Some(res) => Ok(res),
+						None => Err(Error::CouldNotConvert {
 							value: remapped,
 							port: key.into(),
 						}),
@@ -196,14 +356,15 @@ impl BehaviorData {
 			match check_board_pointer(key) {
 				Ok(board_ptr) => match self.blackboard.get::<T>(board_ptr) {
 					Ok(value) => Ok(value),
-					Err(err) => {
+					Err(_) => {
+						// an existing entry is neither a `T` nor a `String`
 						let entry = self.blackboard.entry(key)?;
 						let en = &*entry.read();
 						en.data().downcast_ref::<String>().map_or_else(
-							|| Err(err.into()),
+							|| Err(type_mismatch::<T>(key, en.data().as_ref().type_id())),
 							|val| {
-								T::from_str(val).map_or_else(
-									|_| {
+								self.parse::<T>(val).map_or_else(
+									|| {
 										Err(Error::CouldNotConvert {
 											value: key.into(),
 											port: key.into(),
@@ -217,14 +378,15 @@ impl BehaviorData {
 				},
 				Err(original_key) => match self.blackboard.get::<T>(original_key) {
 					Ok(value) => Ok(value),
-					Err(err) => {
+					Err(_) => {
+						// an existing entry is neither a `T` nor a `String`
 						let entry = self.blackboard.entry(key)?;
 						let en = &*entry.read();
 						en.data().downcast_ref::<String>().map_or_else(
-							|| Err(err.into()),
+							|| Err(type_mismatch::<T>(key, en.data().as_ref().type_id())),
 							|val| {
-								T::from_str(val).map_or_else(
-									|_| {
+								self.parse::<T>(val).map_or_else(
+									|| {
 										Err(Error::CouldNotConvert {
 											value: key.into(),
 											port: key.into(),
@@ -242,25 +404,32 @@ impl BehaviorData {
 
 	/// Returns a reference to value of type `T` from Blackboard.
 	/// # Errors
-	/// - if value is not found
+	/// - if value is not found or has a different type, the error names the port and the type
+	/// - [`Error::NotReadable`] if `key` is an output port and port directions are strict
 	pub fn get_ref<T>(&self, key: &str) -> Result<EntryReadGuard<T>, Error>
 	where
 		T: Any + Debug + FromStr + ToString + Send + Sync,
 	{
+		self.check_readable(key)?;
 		let remapped_key = self.remappings.remap(key);
 		match check_board_pointer(&remapped_key) {
-			Ok(board_pointer) => Ok(self.blackboard.get_ref::<T>(board_pointer)?),
+			Ok(board_pointer) => self
+				.blackboard
+				.get_ref::<T>(board_pointer)
+				.map_err(|err| access_error::<T>(key, err)),
 			Err(original_key) => match self.blackboard.get_ref::<T>(original_key) {
 				Ok(value) => Ok(value),
 				Err(err) => {
 					if is_const_assignment(original_key) {
-						Err(databoard::Error::Assignment {
-							key: key.into(),
-							value: remapped_key,
-						}
-						.into())
+						Err(access_error::<T>(
+							key,
+							databoard::Error::Assignment {
+								key: key.into(),
+								value: remapped_key,
+							},
+						))
 					} else {
-						Err(err.into())
+						Err(access_error::<T>(key, err))
 					}
 				}
 			},
@@ -268,46 +437,230 @@ impl BehaviorData {
 	}
 
 	/// Returns a mutable reference to value of type `T` from Blackboard.
+	///
+	/// If the blackboard entry of an inout port does not yet exist,
+	/// it is created from the ports default value, if there is one.
 	/// # Errors
-	/// - if value is not found
+	/// - if value is not found or has a different type, the error names the port and the type
+	/// - if the default value can not be converted into `T`
+	/// - [`Error::NotWritable`] if `key` is an input port and port directions are strict
 	pub fn get_mut_ref<T>(&self, key: &str) -> Result<EntryWriteGuard<T>, Error>
 	where
 		T: Any + Debug + FromStr + ToString + Send + Sync,
 	{
+		self.check_direction_writable(key)?;
 		let remapped_key = self.remappings.remap(key);
 		match check_board_pointer(&remapped_key) {
-			Ok(board_pointer) => Ok(self.blackboard.get_mut_ref::<T>(board_pointer)?),
+			Ok(board_pointer) => {
+				if !self.blackboard.contains_key(board_pointer) {
+					self.init_from_default::<T>(key, board_pointer)?;
+				}
+				self.blackboard
+					.get_mut_ref::<T>(board_pointer)
+					.map_err(|err| access_error::<T>(key, err))
+			}
 			Err(original_key) => match self.blackboard.get_mut_ref::<T>(original_key) {
 				Ok(value) => Ok(value),
 				Err(err) => {
 					if is_const_assignment(original_key) {
-						Err(databoard::Error::Assignment {
-							key: key.into(),
-							value: remapped_key,
-						}
-						.into())
+						Err(access_error::<T>(
+							key,
+							databoard::Error::Assignment {
+								key: key.into(),
+								value: remapped_key,
+							},
+						))
 					} else {
-						Err(err.into())
+						Err(access_error::<T>(key, err))
 					}
 				}
 			},
 		}
 	}
 
+	/// Parses `value` into a `T`.
+	/// With lenient bools a `bool` is also parsed from `1`/`0` and `yes`/`no`, ignoring the case.
+	fn parse<T: Any + FromStr>(&self, value: &str) -> Option<T> {
+		if self.options.contains(TreeOptions::LENIENT_BOOLS) && TypeId::of::<T>() == TypeId::of::<bool>() {
+			let mut flag = parse_lenient_bool(value);
+			return (&mut flag as &mut dyn Any)
+				.downcast_mut::<Option<T>>()
+				.and_then(Option::take);
+		}
+		T::from_str(value).ok()
+	}
+
+	/// Creates the blackboard entry `board_key` from the default value of the inout port `key`.
+	/// Nothing is done for other ports, without a default value or with a blackboard pointer as default value.
+	/// # Errors
+	/// - if the default value can not be converted into `T`
+	fn init_from_default<T>(&self, key: &str, board_key: &str) -> Result<(), Error>
+	where
+		T: Any + Debug + FromStr + ToString + Send + Sync,
+	{
+		if let Some(port) = self.description.ports().find(key)
+			&& *port.direction() == PortDirection::InOut
+			&& let Some(default) = port.default_value()
+			&& check_board_pointer(default).is_err()
+		{
+			let value = self
+				.parse::<T>(default)
+				.ok_or_else(|| Error::CouldNotConvert {
+					value: default.clone(),
+					port: key.into(),
+				})?;
+			remember_type_name::<T>();
+			self.blackboard.set::<T>(board_key, value)?;
+		}
+		Ok(())
+	}
+
 	/// Sets a value of type `T` into Blackboard.
 	/// Returns old value if any.
 	/// # Errors
 	/// - if value can not be set
+	/// - [`Error::NotWritable`] if `key` is an input port and port directions are strict
 	pub fn set<T>(&mut self, key: &str, value: T) -> Result<Option<T>, Error>
 	where
 		T: Any + Debug + FromStr + ToString + Send + Sync,
 	{
+		self.check_direction_writable(key)?;
 		let remapped_key = self.remappings.remap(key);
 		let board_key = match check_board_pointer(&remapped_key) {
 			Ok(board_pointer) => board_pointer,
 			Err(original_key) => original_key,
 		};
-		Ok(self.blackboard.set::<T>(board_key, value)?)
+		let old = self.blackboard.set::<T>(board_key, value)?;
+		if old.is_none() {
+			remember_type_name::<T>();
+		}
+		Ok(old)
+	}
+
+	/// Collects several writes with `f` and applies them together.
+	///
+	/// All keys and types are validated before the first write. Nothing is written, if `f` fails,
+	/// if a key can not be resolved to an entry or if any collected value does not match the type of
+	/// an existing entry. So an observer of the entries sees either all or none of the new values,
+	/// never a partially updated set.
+	///
+	/// Only if another thread changes one of the entries between the validation and the writes,
+	/// a write may still fail and the values written before it are kept.
+	/// # Errors
+	/// - if `f` fails
+	/// - if a key can not be resolved to an entry, e.g. because it is assigned a constant value
+	/// - if a value does not match the type of an existing entry
+	/// - if a value can not be set
+	pub fn with_batch<F>(&mut self, f: F) -> Result<(), Error>
+	where
+		F: FnOnce(&mut BlackboardBatch) -> Result<(), Error>,
+	{
+		let mut batch = BlackboardBatch::default();
+		f(&mut batch)?;
+		for (check, _) in &batch.writes {
+			check(self)?;
+		}
+		for (_, write) in batch.writes {
+			write(self)?;
+		}
+		Ok(())
+	}
+
+	/// Checks that `key` is not an output port, if port directions are strict.
+	fn check_readable(&self, key: &str) -> Result<(), Error> {
+		if self
+			.options
+			.contains(TreeOptions::STRICT_PORT_DIRECTIONS)
+			&& let Some(port) = self.description.ports().find(key)
+			&& *port.direction() == PortDirection::Out
+		{
+			return Err(Error::NotReadable { port: key.into() });
+		}
+		Ok(())
+	}
+
+	/// Checks that `key` is not an input port, if port directions are strict.
+	fn check_direction_writable(&self, key: &str) -> Result<(), Error> {
+		if self
+			.options
+			.contains(TreeOptions::STRICT_PORT_DIRECTIONS)
+			&& let Some(port) = self.description.ports().find(key)
+			&& *port.direction() == PortDirection::In
+		{
+			return Err(Error::NotWritable { port: key.into() });
+		}
+		Ok(())
+	}
+
+	/// Checks that a value of type `T` can be written to `key`,
+	/// i.e. the key can be resolved to an entry and an existing entry has type `T`.
+	fn check_writable<T: Any>(&self, key: &str) -> Result<(), Error> {
+		self.check_direction_writable(key)?;
+		let remapped_key = self.remappings.remap(key);
+		let board_key = match check_board_pointer(&remapped_key) {
+			Ok(board_pointer) => board_pointer,
+			Err(original_key) => original_key,
+		};
+		match self.blackboard.entry(board_key) {
+			Ok(entry) => {
+				let type_id = entry.read().data().as_ref().type_id();
+				if type_id != TypeId::of::<T>() {
+					return Err(type_mismatch::<T>(key, type_id));
+				}
+			}
+			// a missing entry is created by the write
+			Err(databoard::Error::NotFound { .. }) => {}
+			// e.g. the key is assigned a constant value by a subtree
+			Err(error) => return Err(error.into()),
+		}
+		Ok(())
+	}
+
+	/// Sets a value of type `T` into Blackboard, but only if it differs from the current value.
+	/// This avoids bumping the sequence ID of the entry, when nothing changes.
+	/// Returns whether the value has been written.
+	/// # Errors
+	/// - if value can not be set
+	pub fn set_if_changed<T>(&mut self, key: &str, value: T) -> Result<bool, Error>
+	where
+		T: Any + Clone + Debug + FromStr + PartialEq + ToString + Send + Sync,
+	{
+		if let Ok(current) = self.read::<T>(key)
+			&& current == value
+		{
+			return Ok(false);
+		}
+		self.set(key, value)?;
+		Ok(true)
+	}
+
+	/// Writes an explanation into the optional output port [`REASON`](crate::REASON).
+	///
+	/// By convention a [`Condition`](crate::behavior::BehaviorKind::Condition) provides a port `reason`
+	/// and explains with this method, why it returns [`BehaviorState::Failure`].
+	/// If the port is not connected to a blackboard entry, nothing is written.
+	/// # Errors
+	/// - if the reason can not be set
+	pub fn set_reason(&mut self, reason: &str) -> Result<(), Error> {
+		if let Some(remapped) = self.remappings.find(REASON)
+			&& strip_board_pointer(&remapped).is_some()
+		{
+			self.set(REASON, String::from(reason))?;
+		}
+		Ok(())
+	}
+
+	/// Reads the explanation written with [`set_reason`](Self::set_reason).
+	///
+	/// As [`REASON`](crate::REASON) is an output port, the port direction is not checked.
+	/// Returns `None` if the port is not connected to a blackboard entry or the reason is empty.
+	pub(crate) fn reason(&self) -> Option<ConstString> {
+		self.remappings
+			.find(REASON)
+			.filter(|remapped| strip_board_pointer(remapped).is_some())
+			.and_then(|_| self.read::<String>(REASON).ok())
+			.filter(|reason| !reason.is_empty())
+			.map(ConstString::from)
 	}
 
 	/// Returns the sequence ID of a Blackboard entry.
@@ -354,6 +707,81 @@ impl BehaviorData {
 		self.uid
 	}
 
+	/// Returns the full 'path'.
+	#[must_use]
+	pub const fn path(&self) -> &ConstString {
+		self.description.path()
+	}
+
+	/// Returns the Groot2 style 'path'.
+	#[must_use]
+	pub const fn groot2_path(&self) -> &ConstString {
+		self.description.groot2_path()
+	}
+
+	/// Returns the index of the current tick of the [`BehaviorTree`](crate::tree::BehaviorTree),
+	/// starting with 1 for the first tick and reset to 0 when the tree is reset.
+	/// This allows using logical time instead of wall clock time.
+	#[must_use]
+	pub fn tick_index(&self) -> u64 {
+		*self.tick_count.lock()
+	}
+
+	/// Sets the tick count shared with the [`BehaviorTree`](crate::tree::BehaviorTree).
+	pub(crate) fn set_tick_count(&mut self, tick_count: Arc<Mutex<u64>>) {
+		self.tick_count = tick_count;
+	}
+
+	/// Returns the current time of the [`Clock`] set in the [`BehaviorTreeFactory`](crate::factory::BehaviorTreeFactory).
+	/// Time based behaviors use this instead of the system time, so that they can be tested with a logical clock.
+	#[cfg(any(feature = "std", feature = "embassy"))]
+	#[must_use]
+	pub fn now(&self) -> Duration {
+		self.clock
+			.as_ref()
+			.map_or_else(|| DefaultClock::default().now(), |clock| clock.now())
+	}
+
+	/// Sets the clock shared with the [`BehaviorTree`](crate::tree::BehaviorTree).
+	#[cfg(any(feature = "std", feature = "embassy"))]
+	pub(crate) fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+		self.clock = Some(clock);
+	}
+
+	/// Returns the application context of type `T` set with
+	/// [`BehaviorTree::set_context`](crate::tree::BehaviorTree::set_context).
+	/// Returns `None` if no context is set or the context is not of type `T`.
+	#[must_use]
+	pub fn context<T: Any + Send + Sync>(&self) -> Option<&T> {
+		self.context
+			.as_deref()
+			.and_then(|context| context.downcast_ref::<T>())
+	}
+
+	/// Sets the application context shared with the [`BehaviorTree`](crate::tree::BehaviorTree).
+	pub(crate) fn set_context(&mut self, context: Option<Arc<dyn Any + Send + Sync>>) {
+		self.context = context;
+	}
+
+	/// Returns the seed for random decisions of the behavior,
+	/// if a seed is set in the [`BehaviorTreeFactory`](crate::factory::BehaviorTreeFactory).
+	/// It is derived from the trees seed and the uid, so each behavior gets an independent but reproducible stream.
+	#[must_use]
+	pub const fn seed(&self) -> Option<u64> {
+		self.seed
+	}
+
+	/// Sets the seed derived by the [`BehaviorTree`](crate::tree::BehaviorTree).
+	pub(crate) const fn set_seed(&mut self, seed: Option<u64>) {
+		self.seed = seed;
+	}
+
+	/// Returns the stable id, if stable ids are enabled in the [`BehaviorTreeFactory`](crate::factory::BehaviorTreeFactory).
+	#[must_use]
+	pub const fn stable_id(&self) -> Option<u64> {
+		self.stable_id
+	}
+
 	/// Method to get the state.
 	#[must_use]
 	pub const fn state(&self) -> BehaviorState {
@@ -384,17 +812,19 @@ impl BehaviorData {
 
 	/// Remove any pre state change callback with the given name.
 	pub fn remove_pre_state_change_callback(&mut self, name: &ConstString) {
-		// first collect all subscriber with that name ...
-		let mut indices = Vec::new();
-		for (index, (cb_name, _)) in self.pre_state_change_hooks.iter().enumerate() {
-			if cb_name == name {
-				indices.push(index);
-			}
-		}
-		// ... then remove them from vec
-		for index in indices {
-			let _ = self.pre_state_change_hooks.remove(index);
-		}
+		self.pre_state_change_hooks
+			.retain(|(cb_name, _)| cb_name != name);
+	}
+
+	/// Sets the remapping of the port `key` to `target`,
+	/// which is either a blackboard pointer like `{entry}` or a constant value.
+	pub fn set_remapping(&mut self, key: &str, target: &str) {
+		self.remappings.overwrite(key, target);
+	}
+
+	/// Sets the options of the [`BehaviorTree`](crate::tree::BehaviorTree).
+	pub(crate) const fn set_options(&mut self, options: TreeOptions) {
+		self.options = options;
 	}
 
 	/// Returns a reference to the remappings
@@ -408,10 +838,63 @@ impl BehaviorData {
 	pub const fn full_path(&self) -> &ConstString {
 		self.description.groot2_path()
 	}
+
+	/// Returns the sorted blackboard keys in the local scope, which are referenced by the ports.
+	#[must_use]
+	pub fn local_keys(&self) -> Vec<ConstString> {
+		self.scoped_keys(false)
+	}
+
+	/// Returns the sorted blackboard keys in the global scope, which are referenced by the ports.
+	/// The keys include the `@` prefix.
+	#[must_use]
+	pub fn global_keys(&self) -> Vec<ConstString> {
+		self.scoped_keys(true)
+	}
+
+	/// Converts a stored value of another type via its scripting value.
+	#[cfg(feature = "scripting")]
+	fn get_scripting_value<T: FromStr>(&self, key: &str, remapped_key: &str, type_id: TypeId) -> Result<T, Error> {
+		self.get_env(remapped_key).map_or_else(
+			|_| Err(type_mismatch::<T>(key, type_id)),
+			|val| {
+				let s = match val {
+					ScriptingValue::Nil() => unreachable!(),
+					ScriptingValue::Boolean(b) => b.to_string(),
+					ScriptingValue::Float64(f) => f.to_string(),
+					ScriptingValue::Int64(i) => i.to_string(),
+					ScriptingValue::String(s) => s,
+				};
+				T::from_str(&s).map_or_else(|_| Err(type_mismatch::<T>(key, type_id)), |val| Ok(val))
+			},
+		)
+	}
+
+	/// Without scripting a stored value of another type is a type mismatch.
+	#[cfg(not(feature = "scripting"))]
+	#[allow(clippy::unused_self)]
+	fn get_scripting_value<T>(&self, key: &str, _remapped_key: &str, type_id: TypeId) -> Result<T, Error> {
+		Err(type_mismatch::<T>(key, type_id))
+	}
+
+	/// Collects the board pointer keys of the remappings, which are (not) global.
+	fn scoped_keys(&self, global: bool) -> Vec<ConstString> {
+		let mut keys: Vec<ConstString> = self
+			.remappings
+			.iter()
+			.filter_map(|remapping| strip_board_pointer(&remapping.1))
+			.filter(|key| key.starts_with('@') == global)
+			.map(Into::into)
+			.collect();
+		keys.sort_unstable();
+		keys.dedup();
+		keys
+	}
 }
 // endregion:	--- BehaviorData
 
 // region:		--- impl Environment
+#[cfg(feature = "scripting")]
 impl Environment for BehaviorData {
 	fn define_env(&mut self, key: &str, value: ScriptingValue) -> Result<(), tinyscript::environment::Error> {
 		if self.contains_key(key) {
@@ -469,20 +952,7 @@ impl Environment for BehaviorData {
 			|err| {
 				// std::dbg!(&err);
 				match err {
-					databoard::Error::Assignment { key: _, value } => i64::from_str(&value).map_or_else(
-						|_| {
-							f64::from_str(&value).map_or_else(
-								|_| {
-									bool::from_str(&value).map_or_else(
-										|_| Ok(ScriptingValue::String(value.to_string())),
-										|b| Ok(ScriptingValue::Boolean(b)),
-									)
-								},
-								|f| Ok(ScriptingValue::Float64(f)),
-							)
-						},
-						|i| Ok(ScriptingValue::Int64(i)),
-					),
+					databoard::Error::Assignment { key: _, value } => Ok(scripting_value_from_str(&value)),
 					_ => Err(tinyscript::environment::Error::EnvVarNotDefined { name: name.into() }),
 				}
 			},
@@ -498,6 +968,14 @@ impl Environment for BehaviorData {
 								var_type: "String".into(),
 							})?;
 					Ok(ScriptingValue::String(s.to_owned()))
+				} else if type_id == TypeId::of::<bool>() {
+					let b = entry
+						.downcast_ref::<bool>()
+						.ok_or_else(|| tinyscript::environment::Error::EnvVarTypeCast {
+							name: name.into(),
+							var_type: "bool".into(),
+						})?;
+					Ok(ScriptingValue::Boolean(b.to_owned()))
 				} else if type_id == TypeId::of::<f64>() {
 					let f = entry
 						.downcast_ref::<f64>()
@@ -748,4 +1226,56 @@ impl Environment for BehaviorData {
 		Ok(())
 	}
 }
+
+/// An [`Environment`] which resolves identifiers through the port remappings of another behavior,
+/// before accessing the blackboard of the [`BehaviorData`].
+/// A port remapped to a blackboard pointer resolves to the pointed entry,
+/// a port with a constant assignment resolves to the constant.
+/// All other identifiers are used unchanged.
+#[cfg(feature = "precondition")]
+pub(crate) struct RemappedEnvironment<'a> {
+	/// The data to access
+	data: &'a mut BehaviorData,
+	/// The remappings used for resolution
+	remappings: &'a Remappings,
+}
+
+#[cfg(feature = "precondition")]
+impl<'a> RemappedEnvironment<'a> {
+	/// Constructor
+	pub(crate) const fn new(data: &'a mut BehaviorData, remappings: &'a Remappings) -> Self {
+		Self { data, remappings }
+	}
+
+	/// Returns the blackboard key for the `name`.
+	fn resolve(&self, name: &str) -> ConstString {
+		self.remappings
+			.find(name)
+			.and_then(|remapped| strip_board_pointer(&remapped).map(ConstString::from))
+			.unwrap_or_else(|| name.into())
+	}
+}
+
+#[cfg(feature = "precondition")]
+impl Environment for RemappedEnvironment<'_> {
+	fn define_env(&mut self, key: &str, value: ScriptingValue) -> Result<(), tinyscript::environment::Error> {
+		let key = self.resolve(key);
+		self.data.define_env(&key, value)
+	}
+
+	fn get_env(&self, name: &str) -> Result<ScriptingValue, tinyscript::environment::Error> {
+		self.remappings.find(name).map_or_else(
+			|| self.data.get_env(name),
+			|remapped| {
+				strip_board_pointer(&remapped)
+					.map_or_else(|| Ok(scripting_value_from_str(&remapped)), |key| self.data.get_env(key))
+			},
+		)
+	}
+
+	fn set_env(&mut self, name: &str, value: ScriptingValue) -> Result<(), tinyscript::environment::Error> {
+		let name = self.resolve(name);
+		self.data.set_env(&name, value)
+	}
+}
 // endregion:	--- impl Environment
@@ -2,6 +2,7 @@
 //! [`BehaviorDescription`] implementation.
 
 use crate::{BehaviorKind, ConstString, EMPTY_STR, port::PortList};
+use alloc::vec::Vec;
 
 /// Description of a Behavior, used in xml parsing and creating.
 #[derive(Clone, Debug, Default)]
@@ -22,6 +23,17 @@ pub struct BehaviorDescription {
 	groot2: bool,
 	/// Path for Groot2
 	groot2_path: ConstString,
+	/// Optional metadata, e.g. a preserved XML comment.
+	metadata: Option<ConstString>,
+	/// Unknown `_` prefixed XML attributes, e.g. added by an editor.
+	metadata_attributes: Vec<(ConstString, ConstString)>,
+	/// Names of the ports, whose value is not given as XML attribute but taken from
+	/// the default value of the port definition or from a `TreeNodesModel`.
+	defaulted_ports: Vec<ConstString>,
+	/// Prefix applied to the blackboard keys of the ports, including the prefixes of enclosing subtrees.
+	prefix: ConstString,
+	/// Optional debug string of the arguments given at registration time.
+	arguments: Option<ConstString>,
 }
 
 impl BehaviorDescription {
@@ -36,6 +48,11 @@ impl BehaviorDescription {
 			ports,
 			groot2_path: EMPTY_STR.into(),
 			groot2,
+			metadata: None,
+			metadata_attributes: Vec::new(),
+			defaulted_ports: Vec::new(),
+			prefix: EMPTY_STR.into(),
+			arguments: None,
 		}
 	}
 
@@ -101,4 +118,61 @@ impl BehaviorDescription {
 	pub fn set_groot2_path(&mut self, groot2_path: ConstString) {
 		self.groot2_path = groot2_path;
 	}
+
+	/// Get the metadata.
+	#[must_use]
+	pub const fn metadata(&self) -> Option<&ConstString> {
+		self.metadata.as_ref()
+	}
+
+	/// Set the metadata.
+	pub fn set_metadata(&mut self, metadata: Option<ConstString>) {
+		self.metadata = metadata;
+	}
+
+	/// Get the unknown `_` prefixed attributes kept as metadata.
+	#[must_use]
+	pub fn metadata_attributes(&self) -> &[(ConstString, ConstString)] {
+		&self.metadata_attributes
+	}
+
+	/// Get the names of the ports, whose value is not given as XML attribute but taken from
+	/// the default value of the port definition or from a `TreeNodesModel`.
+	#[must_use]
+	pub fn defaulted_ports(&self) -> &[ConstString] {
+		&self.defaulted_ports
+	}
+
+	/// Get the prefix applied to the blackboard keys of the ports.
+	#[must_use]
+	pub const fn prefix(&self) -> &ConstString {
+		&self.prefix
+	}
+
+	/// Set the prefix applied to the blackboard keys of the ports.
+	pub fn set_prefix(&mut self, prefix: &str) {
+		self.prefix = prefix.into();
+	}
+
+	/// Get the debug string of the arguments given at registration time.
+	#[must_use]
+	pub const fn arguments(&self) -> Option<&ConstString> {
+		self.arguments.as_ref()
+	}
+
+	/// Set the debug string of the arguments given at registration time.
+	pub fn set_arguments(&mut self, arguments: Option<ConstString>) {
+		self.arguments = arguments;
+	}
+
+	/// Add an unknown `_` prefixed attribute to the metadata.
+	pub fn add_metadata_attribute(&mut self, key: &str, value: &str) {
+		self.metadata_attributes
+			.push((key.into(), value.into()));
+	}
+
+	/// Set the names of the ports, whose value is taken from a default.
+	pub(crate) fn set_defaulted_ports(&mut self, ports: Vec<ConstString>) {
+		self.defaulted_ports = ports;
+	}
 }
@@ -0,0 +1,81 @@
+// Copyright © 2025 Stephan Kunz
+//! [`CompiledScript`] implementation.
+
+// region:      --- modules
+use crate::{ConstString, behavior::BehaviorData};
+use alloc::string::String;
+use databoard::strip_board_pointer;
+use tinyscript::{Chunk, Environment, Error, Runtime, ScriptingValue};
+// endregion:   --- modules
+
+// region:		--- helper
+/// Returns the constant value assigned to the port `key` of a behavior,
+/// `None` if the port is remapped to a blackboard entry or has no value.
+#[must_use]
+pub fn constant_port_value(behavior: &BehaviorData, key: &str) -> Option<ConstString> {
+	behavior
+		.remappings()
+		.find(key)
+		.filter(|value| strip_board_pointer(value).is_none())
+}
+// endregion:	--- helper
+
+// region:      --- CompiledScript
+/// A script, which is compiled once and then executed in its compiled form.
+///
+/// A script given as constant port value is compiled at the creation of the tree,
+/// see [`compile_port`](Self::compile_port), so syntax errors let the creation fail.
+/// The script is only compiled again, if its source changes,
+/// which may happen when the source is read from a remapped port.
+#[derive(Default)]
+pub struct CompiledScript {
+	/// Source of the compiled script.
+	source: String,
+	/// Compiled form of `source`.
+	chunk: Option<Chunk>,
+}
+
+impl CompiledScript {
+	/// Compiles `source` in advance of its execution.
+	/// # Errors
+	/// - if the script cannot be compiled
+	pub fn compile(&mut self, runtime: &mut Runtime, source: &str) -> Result<(), Error> {
+		self.chunk = Some(runtime.parse(source)?);
+		self.source = source.into();
+		Ok(())
+	}
+
+	/// Compiles the constant value of the port `key` in advance of its execution.
+	/// A port remapped to a blackboard entry is compiled on its first execution, as its value is not yet known.
+	/// # Errors
+	/// - if the script cannot be compiled
+	pub fn compile_port(&mut self, runtime: &mut Runtime, behavior: &BehaviorData, key: &str) -> Result<(), Error> {
+		constant_port_value(behavior, key).map_or(Ok(()), |source| self.compile(runtime, &source))
+	}
+
+	/// Executes `source`, compiling it only if it differs from the previously executed source.
+	/// Like [`Runtime::run`] the stdout of the runtime is cleared before execution.
+	/// # Errors
+	/// - if the script cannot be compiled
+	/// - if the script cannot be executed
+	pub fn run(
+		&mut self,
+		runtime: &mut Runtime,
+		source: &str,
+		globals: &mut dyn Environment,
+	) -> Result<ScriptingValue, Error> {
+		let chunk = match self.chunk.take() {
+			Some(chunk) if self.source == source => chunk,
+			_ => {
+				let chunk = runtime.parse(source)?;
+				self.source = source.into();
+				chunk
+			}
+		};
+		runtime.clear();
+		let result = runtime.execute(&chunk, globals);
+		self.chunk = Some(chunk);
+		result
+	}
+}
+// endregion:   --- CompiledScript
@@ -0,0 +1,94 @@
+// Copyright © 2025 Stephan Kunz
+//! [`HasEntry`] [`Condition`] implementation.
+
+// region:      --- modules
+use crate::SharedRuntime;
+use crate::{
+	self as behaviortree, Condition, EMPTY_STR,
+	behavior::{BehaviorData, BehaviorError, BehaviorResult, BehaviorState, InlineBehavior},
+	input_port,
+	port::PortList,
+	port_list,
+	tree::BehaviorTreeElementList,
+};
+use alloc::string::{String, ToString};
+// endregion:   --- modules
+
+// region:		--- globals
+/// Port name literals
+const ENTRY: &str = "entry";
+const TYPE: &str = "type";
+// endregion:	--- globals
+
+// region:      --- HasEntry
+/// The [`HasEntry`] condition returns Success if the blackboard entry given via port `entry` exists,
+/// otherwise Failure. Remappings and board pointers like `@global` are respected.
+///
+/// With the optional port `type` the entry must additionally be of the given type:
+/// - `int`: `i32`
+/// - `double`: `f64`
+/// - `bool`: `bool`
+/// - `string`: `String`
+///
+/// # Errors
+/// - if port `entry` is not declared
+/// - if the given `type` is unknown
+///
+/// The behavior is gated behind feature `has_entry`.
+///
+/// Example:
+///
+/// ```xml
+/// <HasEntry entry="@counter" type="int"/>
+/// ```
+#[derive(Condition, Debug, Default)]
+pub struct HasEntry;
+
+impl InlineBehavior for HasEntry {
+	async fn tick(
+		&mut self,
+		behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		let Some(key) = behavior.remappings().find(ENTRY) else {
+			return Err(BehaviorError::PortNotDeclared {
+				port: ENTRY.into(),
+				behavior: behavior.name().clone(),
+			});
+		};
+		let typ = behavior.get::<String>(TYPE).unwrap_or_default();
+		let found = match typ.to_ascii_lowercase().as_str() {
+			EMPTY_STR => behavior.contains_key(&key),
+			"int" => behavior.contains::<i32>(&key)?,
+			"double" => behavior.contains::<f64>(&key)?,
+			"bool" => behavior.contains::<bool>(&key)?,
+			"string" => behavior.contains::<String>(&key)?,
+			_ => {
+				return Err(BehaviorError::ParseError {
+					value: typ.into(),
+					src: "HasEntry port 'type'".into(),
+				});
+			}
+		};
+
+		if found {
+			Ok(BehaviorState::Success)
+		} else {
+			Ok(BehaviorState::Failure)
+		}
+	}
+
+	fn provided_ports() -> PortList {
+		port_list![
+			input_port!(String, ENTRY, EMPTY_STR, "The blackboard entry to check."),
+			input_port!(
+				String,
+				TYPE,
+				EMPTY_STR,
+				"Optional type of the entry: 'int', 'double', 'bool' or 'string'"
+			),
+		]
+	}
+}
+// endregion:   --- HasEntry
@@ -1,12 +1,16 @@
 // Copyright © 2025 Stephan Kunz
 //! Built-in `Condition` behaviors of [`behaviortree`](crate).
 
+#[cfg(feature = "has_entry")]
+mod has_entry;
 #[cfg(feature = "script_condition")]
 mod script_condition;
 #[cfg(feature = "was_entry_updated")]
 mod was_entry_updated;
 
 // flatten
+#[cfg(feature = "has_entry")]
+pub use has_entry::HasEntry;
 #[cfg(feature = "script_condition")]
 pub use script_condition::ScriptCondition;
 #[cfg(feature = "was_entry_updated")]
@@ -2,16 +2,13 @@
 //! [`ScriptCondition`] [`Condition`] implementation.
 
 // region:      --- modules
-use alloc::{
-	boxed::Box,
-	string::{String, ToString},
-};
-use tinyscript::SharedRuntime;
+use crate::SharedRuntime;
+use alloc::string::{String, ToString};
 
 use crate::{
-	self as behaviortree, Condition, EMPTY_STR,
-	behavior::{Behavior, BehaviorData, BehaviorResult, BehaviorState},
-	input_port,
+	self as behaviortree, Condition, EMPTY_STR, REASON,
+	behavior::{BehaviorData, BehaviorError, BehaviorResult, BehaviorState, CompiledScript, InlineBehavior},
+	input_port, output_port,
 	port::PortList,
 	port_list,
 	tree::BehaviorTreeElementList,
@@ -24,13 +21,24 @@ const CODE: &str = "code";
 // endregion:	--- globals
 
 /// The `ScriptCondition` behavior returns Success or Failure depending on the result of the scripted code.
+/// On Failure the optional output port `reason` explains the failure.
 ///
 /// The behavior is gated behind feature `script_condition`.
+///
+/// The script is compiled at the creation of the tree and only compiled again, if the code changes.
 #[derive(Condition, Default)]
-pub struct ScriptCondition;
+pub struct ScriptCondition {
+	/// The compiled `code`.
+	script: CompiledScript,
+}
+
+impl InlineBehavior for ScriptCondition {
+	fn on_creation(&mut self, behavior: &mut BehaviorData, runtime: &SharedRuntime) -> Result<(), BehaviorError> {
+		self.script
+			.compile_port(&mut runtime.lock(), behavior, CODE)?;
+		Ok(())
+	}
 
-#[async_trait::async_trait]
-impl Behavior for ScriptCondition {
 	async fn tick(
 		&mut self,
 		behavior: &mut BehaviorData,
@@ -38,24 +46,31 @@ impl Behavior for ScriptCondition {
 		runtime: &SharedRuntime,
 	) -> BehaviorResult {
 		let code = behavior.get::<String>(CODE)?;
-		let value = runtime.lock().run(&code, behavior)?;
+		let value = self
+			.script
+			.run(&mut runtime.lock(), &code, behavior)?;
 
-		let state = if value.is_bool() {
-			let val = bool::try_from(value)?;
-			if val { BehaviorState::Success } else { BehaviorState::Failure }
+		if !value.is_bool() {
+			behavior.set_reason(&(String::from("script '") + &code + "' did not return a boolean"))?;
+			return Ok(BehaviorState::Failure);
+		}
+		if bool::try_from(value)? {
+			Ok(BehaviorState::Success)
 		} else {
-			BehaviorState::Failure
-		};
-
-		Ok(state)
+			behavior.set_reason(&(String::from("script '") + &code + "' returned false"))?;
+			Ok(BehaviorState::Failure)
+		}
 	}
 
 	fn provided_ports() -> PortList {
-		port_list![input_port!(
-			String,
-			CODE,
-			EMPTY_STR,
-			"Piece of code that can be parsed. Must return false or true."
-		)]
+		port_list![
+			input_port!(
+				String,
+				CODE,
+				EMPTY_STR,
+				"Piece of code that can be parsed. Must return false or true."
+			),
+			output_port!(String, REASON, EMPTY_STR, "Explanation of a failure.")
+		]
 	}
 }
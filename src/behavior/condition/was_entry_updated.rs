@@ -2,19 +2,16 @@
 //! [`WasEntryUpdated`] [`Condition`] implementation.
 
 // region:      --- modules
+use crate::SharedRuntime;
 use crate::{
-	self as behaviortree, Condition, ConstString, EMPTY_STR,
-	behavior::{Behavior, BehaviorData, BehaviorError, BehaviorResult, BehaviorState},
-	input_port,
+	self as behaviortree, Condition, ConstString, EMPTY_STR, REASON,
+	behavior::{BehaviorData, BehaviorError, BehaviorResult, BehaviorState, InlineBehavior},
+	input_port, output_port,
 	port::PortList,
 	port_list,
 	tree::BehaviorTreeElementList,
 };
-use alloc::{
-	boxed::Box,
-	string::{String, ToString},
-};
-use tinyscript::SharedRuntime;
+use alloc::string::{String, ToString};
 // endregion:   --- modules
 
 // region:		--- globals
@@ -24,6 +21,7 @@ const ENTRY: &str = "entry";
 
 // region:      --- WasEntryUpdated
 /// The `WasEntryUpdated` condition returns Success if a blackboard entry was updated otherwise Failure.
+/// On Failure the optional output port `reason` explains the failure.
 /// # Errors
 /// - if the entry does not exist
 ///
@@ -36,8 +34,7 @@ pub struct WasEntryUpdated {
 	entry_key: ConstString,
 }
 
-#[async_trait::async_trait]
-impl Behavior for WasEntryUpdated {
+impl InlineBehavior for WasEntryUpdated {
 	fn on_start(
 		&mut self,
 		behavior: &mut BehaviorData,
@@ -68,6 +65,7 @@ impl Behavior for WasEntryUpdated {
 	) -> BehaviorResult {
 		let sequence_id = behavior.sequence_id(&self.entry_key)?;
 		if sequence_id == self.sequence_id {
+			behavior.set_reason(&(String::from("entry '") + &self.entry_key + "' was not updated"))?;
 			Ok(BehaviorState::Failure)
 		} else {
 			self.sequence_id = sequence_id;
@@ -76,12 +74,10 @@ impl Behavior for WasEntryUpdated {
 	}
 
 	fn provided_ports() -> PortList {
-		port_list![input_port!(
-			String,
-			ENTRY,
-			EMPTY_STR,
-			"The blackboard entry to check."
-		)]
+		port_list![
+			input_port!(String, ENTRY, EMPTY_STR, "The blackboard entry to check."),
+			output_port!(String, REASON, EMPTY_STR, "Explanation of a failure.")
+		]
 	}
 }
 // endregion:   --- WasEntryUpdated
@@ -0,0 +1,133 @@
+// Copyright © 2025 Stephan Kunz
+//! [`And`] & [`Or`] [`Control`] implementations.
+
+use crate::SharedRuntime;
+use crate::{
+	self as behaviortree, Control,
+	behavior::{BehaviorData, BehaviorError, BehaviorResult, BehaviorState, InlineBehavior},
+	tree::BehaviorTreeElementList,
+};
+
+/// Ticks the `children` in order until one returns `decisive` and returns that state.
+/// If no child returns `decisive`, the opposite state is returned, or [`BehaviorState::Skipped`]
+/// if all children have been skipped.
+/// The children are reset afterwards, also if a child fails with an error.
+async fn evaluate(
+	name: &str,
+	decisive: BehaviorState,
+	children: &mut BehaviorTreeElementList,
+	runtime: &SharedRuntime,
+) -> BehaviorResult {
+	let mut skipped = 0;
+	let mut result = Ok(None);
+	for child in children.iter_mut() {
+		match child.tick(runtime).await {
+			Ok(BehaviorState::Skipped) => skipped += 1,
+			Ok(state @ (BehaviorState::Failure | BehaviorState::Success)) => {
+				if state == decisive {
+					result = Ok(Some(state));
+					break;
+				}
+			}
+			Ok(state @ (BehaviorState::Idle | BehaviorState::Running)) => {
+				result = Err(BehaviorError::State {
+					behavior: name.into(),
+					state,
+				});
+				break;
+			}
+			Err(error) => {
+				result = Err(error);
+				break;
+			}
+		}
+	}
+	// the error of the evaluation is more relevant than an eventual error during the reset
+	let reset = children.reset(runtime);
+	let result = result?;
+	reset?;
+
+	match result {
+		Some(state) => Ok(state),
+		None if skipped == children.len() => Ok(BehaviorState::Skipped),
+		None if decisive == BehaviorState::Failure => Ok(BehaviorState::Success),
+		None => Ok(BehaviorState::Failure),
+	}
+}
+
+/// An `And` evaluates its children, which must be conditions or other boolean controls,
+/// from first to last within one tick and never returns [`BehaviorState::Running`].
+/// - If any child returns [`BehaviorState::Failure`] the evaluation stops and `And` returns [`BehaviorState::Failure`].
+/// - Otherwise `And` returns [`BehaviorState::Success`].
+/// - Skipped children are ignored, if all children are skipped `And` returns [`BehaviorState::Skipped`].
+///
+/// Other than a [`Sequence`](crate::behavior::control::Sequence) it is meant for pure boolean logic,
+/// so the tree creation fails if a child may return [`BehaviorState::Running`].
+///
+/// The behavior is gated behind feature `and`.
+///
+/// Example:
+/// ```xml
+/// <And>
+///    <Condition1/>
+///    <Or>
+///       <Condition2/>
+///       <Condition3/>
+///    </Or>
+/// </And>
+/// ```
+#[derive(Control, Debug, Default)]
+pub struct And;
+
+impl InlineBehavior for And {
+	async fn tick(
+		&mut self,
+		_behavior: &mut BehaviorData,
+		children: &mut BehaviorTreeElementList,
+		runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		evaluate("And", BehaviorState::Failure, children, runtime).await
+	}
+
+	#[inline]
+	fn is_boolean(&self) -> bool {
+		true
+	}
+}
+
+/// An `Or` evaluates its children, which must be conditions or other boolean controls,
+/// from first to last within one tick and never returns [`BehaviorState::Running`].
+/// - If any child returns [`BehaviorState::Success`] the evaluation stops and `Or` returns [`BehaviorState::Success`].
+/// - Otherwise `Or` returns [`BehaviorState::Failure`].
+/// - Skipped children are ignored, if all children are skipped `Or` returns [`BehaviorState::Skipped`].
+///
+/// Other than a [`Fallback`](crate::behavior::control::Fallback) it is meant for pure boolean logic,
+/// so the tree creation fails if a child may return [`BehaviorState::Running`].
+///
+/// The behavior is gated behind feature `or`.
+///
+/// Example:
+/// ```xml
+/// <Or>
+///    <Condition1/>
+///    <Condition2/>
+/// </Or>
+/// ```
+#[derive(Control, Debug, Default)]
+pub struct Or;
+
+impl InlineBehavior for Or {
+	async fn tick(
+		&mut self,
+		_behavior: &mut BehaviorData,
+		children: &mut BehaviorTreeElementList,
+		runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		evaluate("Or", BehaviorState::Success, children, runtime).await
+	}
+
+	#[inline]
+	fn is_boolean(&self) -> bool {
+		true
+	}
+}
@@ -1,13 +1,12 @@
 // Copyright © 2025 Stephan Kunz
 //! [`Fallback`] & `AsyncFallback` [`Control`] implementations.
 
+use crate::SharedRuntime;
 use crate::{
 	self as behaviortree, Control,
-	behavior::{Behavior, BehaviorData, BehaviorError, BehaviorResult, BehaviorState},
+	behavior::{BehaviorData, BehaviorError, BehaviorResult, BehaviorState, InlineBehavior},
 	tree::BehaviorTreeElementList,
 };
-use alloc::boxed::Box;
-use tinyscript::SharedRuntime;
 
 /// The `Fallback` behavior is used to try different strategies until one succeeds.
 /// If any child returns [`BehaviorState::Running`], previous children will NOT be ticked again.
@@ -54,8 +53,13 @@ pub struct Fallback {
 	asynch: bool,
 }
 
-#[async_trait::async_trait]
-impl Behavior for Fallback {
+impl InlineBehavior for Fallback {
+	fn on_halt(&mut self) -> Result<(), BehaviorError> {
+		self.child_idx = 0;
+		self.skipped = 0;
+		Ok(())
+	}
+
 	async fn tick(
 		&mut self,
 		behavior: &mut BehaviorData,
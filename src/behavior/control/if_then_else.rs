@@ -2,13 +2,12 @@
 //! [`IfThenElse`] [`Control`] implementation.
 
 // region:      --- modules
+use crate::SharedRuntime;
 use crate::{
 	self as behaviortree, Control,
-	behavior::{Behavior, BehaviorData, BehaviorError, BehaviorResult, BehaviorState},
+	behavior::{BehaviorData, BehaviorError, BehaviorResult, BehaviorState, InlineBehavior},
 	tree::BehaviorTreeElementList,
 };
-use alloc::boxed::Box;
-use tinyscript::SharedRuntime;
 // endregion:   --- modules
 
 // region:      --- IfThenElse
@@ -39,8 +38,7 @@ pub struct IfThenElse {
 	child_index: usize,
 }
 
-#[async_trait::async_trait]
-impl Behavior for IfThenElse {
+impl InlineBehavior for IfThenElse {
 	#[inline]
 	fn on_halt(&mut self) -> Result<(), BehaviorError> {
 		self.child_index = 0;
@@ -1,6 +1,15 @@
 // Copyright © 2025 Stephan Kunz
 //! Built-in `Control` behaviors of [`behaviortree`](crate).
 
+#[cfg(any(feature = "parallel", feature = "parallel_timeout"))]
+use crate::behavior::BehaviorState;
+#[cfg(any(feature = "parallel", feature = "parallel_all", feature = "parallel_timeout"))]
+use crate::behavior::{BehaviorData, BehaviorError};
+#[cfg(any(feature = "parallel", feature = "parallel_all"))]
+use crate::tree::BehaviorTreeElementList;
+
+#[cfg(feature = "boolean")]
+mod boolean;
 #[cfg(feature = "fallbacks")]
 mod fallbacks;
 #[cfg(feature = "if_then_else")]
@@ -9,10 +18,14 @@ mod if_then_else;
 mod parallel;
 #[cfg(feature = "parallel_all")]
 mod parallel_all;
+#[cfg(feature = "parallel_timeout")]
+mod parallel_timeout;
 #[cfg(feature = "reactive_fallback")]
 mod reactive_fallback;
 #[cfg(feature = "reactive_sequence")]
 mod reactive_sequence;
+#[cfg(feature = "retry_fallback")]
+mod retry_fallback;
 #[cfg(feature = "sequence_with_memory")]
 mod sequence_with_memory;
 #[cfg(feature = "sequences")]
@@ -23,6 +36,8 @@ mod switch;
 mod while_do_else;
 
 // flatten
+#[cfg(feature = "boolean")]
+pub use boolean::{And, Or};
 #[cfg(feature = "fallbacks")]
 pub use fallbacks::Fallback;
 #[cfg(feature = "if_then_else")]
@@ -31,10 +46,14 @@ pub use if_then_else::IfThenElse;
 pub use parallel::Parallel;
 #[cfg(feature = "parallel_all")]
 pub use parallel_all::ParallelAll;
+#[cfg(feature = "parallel_timeout")]
+pub use parallel_timeout::ParallelTimeout;
 #[cfg(feature = "reactive_fallback")]
 pub use reactive_fallback::ReactiveFallback;
 #[cfg(feature = "reactive_sequence")]
 pub use reactive_sequence::ReactiveSequence;
+#[cfg(feature = "retry_fallback")]
+pub use retry_fallback::RetryFallback;
 #[cfg(feature = "sequence_with_memory")]
 pub use sequence_with_memory::SequenceWithMemory;
 #[cfg(feature = "sequences")]
@@ -43,3 +62,82 @@ pub use sequences::Sequence;
 pub use switch::Switch;
 #[cfg(feature = "while_do_else")]
 pub use while_do_else::WhileDoElse;
+
+// region:		--- helper
+/// Port name literal of the children states output port
+#[cfg(any(feature = "parallel", feature = "parallel_all"))]
+const CHILD_STATES: &str = "child_states";
+
+/// Writes the children states into port `child_states`, if the port is mapped.
+#[cfg(any(feature = "parallel", feature = "parallel_all"))]
+fn write_child_states(behavior: &mut BehaviorData, children: &BehaviorTreeElementList) -> Result<(), BehaviorError> {
+	if behavior.remappings().find(CHILD_STATES).is_some() {
+		behavior.set(CHILD_STATES, children.states())?;
+	}
+	Ok(())
+}
+
+/// Port name literals of the thresholds of [`Parallel`] and [`ParallelTimeout`]
+#[cfg(any(feature = "parallel", feature = "parallel_timeout"))]
+const SUCCESS_COUNT: &str = "success_count";
+#[cfg(any(feature = "parallel", feature = "parallel_timeout"))]
+const FAILURE_COUNT: &str = "failure_count";
+
+/// Reads the success and the failure threshold.
+/// Both default to `-1`, which means any number.
+#[cfg(any(feature = "parallel", feature = "parallel_timeout"))]
+fn parallel_thresholds(behavior: &BehaviorData) -> (i32, i32) {
+	(
+		behavior.get(SUCCESS_COUNT).unwrap_or(-1),
+		behavior.get(FAILURE_COUNT).unwrap_or(-1),
+	)
+}
+
+/// Checks that the thresholds can be reached with `children_count` children.
+#[cfg(any(feature = "parallel", feature = "parallel_timeout"))]
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_possible_wrap)]
+fn check_parallel_thresholds(behavior: &BehaviorData, children_count: usize) -> Result<(), BehaviorError> {
+	let (success_threshold, failure_threshold) = parallel_thresholds(behavior);
+	if (children_count as i32) < success_threshold {
+		return Err(BehaviorError::Composition {
+			txt: "Number of children is less than the threshold. Can never succeed.".into(),
+		});
+	}
+	if (children_count as i32) < failure_threshold {
+		return Err(BehaviorError::Composition {
+			txt: "Number of children is less than the threshold. Can never fail.".into(),
+		});
+	}
+	Ok(())
+}
+
+/// Determines the resulting state, once all `children_count` children are completed or skipped.
+#[cfg(any(feature = "parallel", feature = "parallel_timeout"))]
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_possible_wrap)]
+fn parallel_state(
+	behavior: &BehaviorData,
+	success_count: i32,
+	failure_count: i32,
+	skipped_count: i32,
+	children_count: usize,
+) -> BehaviorState {
+	let (success_threshold, failure_threshold) = parallel_thresholds(behavior);
+	if skipped_count == children_count as i32 {
+		BehaviorState::Skipped
+	} else if failure_threshold <= 0 && success_threshold <= 0 {
+		BehaviorState::Success
+	} else if failure_threshold <= 0 {
+		if success_count >= success_threshold {
+			BehaviorState::Success
+		} else {
+			BehaviorState::Failure
+		}
+	} else if (failure_count > failure_threshold) || (success_count < success_threshold) {
+		BehaviorState::Failure
+	} else {
+		BehaviorState::Success
+	}
+}
+// endregion:	--- helper
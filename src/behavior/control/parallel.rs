@@ -2,17 +2,18 @@
 //! [`Parallel`] [`Control`] implementation.
 
 // region:      --- modules
+use super::{CHILD_STATES, FAILURE_COUNT, SUCCESS_COUNT, check_parallel_thresholds, parallel_state, write_child_states};
+use crate::SharedRuntime;
 use crate::{
 	self as behaviortree, Control,
-	behavior::{Behavior, BehaviorData, BehaviorError, BehaviorResult, BehaviorState},
-	input_port,
+	behavior::{BehaviorData, BehaviorError, BehaviorResult, BehaviorState, InlineBehavior},
+	input_port, output_port,
 	port::PortList,
 	port_list,
 	tree::BehaviorTreeElementList,
 };
-use alloc::boxed::Box;
 use alloc::collections::btree_set::BTreeSet;
-use tinyscript::SharedRuntime;
+use alloc::string::String;
 // endregion:   --- modules
 
 // region:      --- Parallel
@@ -24,6 +25,12 @@ use tinyscript::SharedRuntime;
 /// This differs from the [`ParallelAll`](crate::behavior::control::parallel_all::ParallelAll) behavior.
 /// Both ports default to `-1` which means any number or don't care.
 ///
+/// [`BehaviorState::Skipped`] children count neither as success nor as failure.
+/// If all children are skipped, the [`Parallel`] returns [`BehaviorState::Skipped`].
+///
+/// The optional output port `child_states` receives the `;` separated states of all children
+/// after each tick, e.g. `Success;Running;Failure`. If the port is not mapped, nothing is written.
+///
 /// The behavior is gated behind feature `parallel`.
 ///
 /// Example:
@@ -45,12 +52,7 @@ pub struct Parallel {
 	completed_list: BTreeSet<usize>,
 }
 
-/// The port names
-const SUCCESS_COUNT: &str = "success_count";
-const FAILURE_COUNT: &str = "failure_count";
-
-#[async_trait::async_trait]
-impl Behavior for Parallel {
+impl InlineBehavior for Parallel {
 	fn on_halt(&mut self) -> Result<(), BehaviorError> {
 		self.completed_list.clear();
 		self.success_count = 0;
@@ -58,8 +60,6 @@ impl Behavior for Parallel {
 		Ok(())
 	}
 
-	#[allow(clippy::cast_possible_truncation)]
-	#[allow(clippy::cast_possible_wrap)]
 	fn on_start(
 		&mut self,
 		behavior: &mut BehaviorData,
@@ -67,26 +67,7 @@ impl Behavior for Parallel {
 		_runtime: &SharedRuntime,
 	) -> Result<(), BehaviorError> {
 		// check composition only once at start
-		// The minimum needed Successes to return a Success.
-		// "-1" signals any number.
-		let success_threshold = behavior.get(SUCCESS_COUNT).unwrap_or(-1);
-		// The maximum allowed failures.
-		// "-1" signals any number.
-		let failure_threshold = behavior.get(FAILURE_COUNT).unwrap_or(-1);
-
-		let children_count = children.len();
-
-		if (children_count as i32) < success_threshold {
-			return Err(BehaviorError::Composition {
-				txt: "Number of children is less than the threshold. Can never succeed.".into(),
-			});
-		}
-
-		if (children_count as i32) < failure_threshold {
-			return Err(BehaviorError::Composition {
-				txt: "Number of children is less than the threshold. Can never fail.".into(),
-			});
-		}
+		check_parallel_thresholds(behavior, children.len())?;
 		behavior.set_state(BehaviorState::Running);
 		Ok(())
 	}
@@ -100,12 +81,6 @@ impl Behavior for Parallel {
 		children: &mut BehaviorTreeElementList,
 		runtime: &SharedRuntime,
 	) -> BehaviorResult {
-		// The minimum needed Successes to return a Success.
-		// "-1" signals any number.
-		let success_threshold = behavior.get(SUCCESS_COUNT).unwrap_or(-1);
-		// The maximum allowed failures.
-		// "-1" signals any number.
-		let failure_threshold = behavior.get(FAILURE_COUNT).unwrap_or(-1);
 		let children_count = children.len();
 
 		let mut skipped_count = 0;
@@ -137,38 +112,33 @@ impl Behavior for Parallel {
 
 			let sum = self.failure_count + self.success_count + skipped_count;
 			if sum >= children_count as i32 {
-				let state = if skipped_count == children_count as i32 {
-					BehaviorState::Skipped
-				} else if failure_threshold <= 0 && success_threshold <= 0 {
-					BehaviorState::Success
-				} else if failure_threshold <= 0 {
-					if self.success_count >= success_threshold {
-						BehaviorState::Success
-					} else {
-						BehaviorState::Failure
-					}
-				} else if (self.failure_count > failure_threshold) || (self.success_count < success_threshold) {
-					BehaviorState::Failure
-				} else {
-					BehaviorState::Success
-				};
+				let state = parallel_state(
+					behavior,
+					self.success_count,
+					self.failure_count,
+					skipped_count,
+					children_count,
+				);
 
 				self.completed_list.clear();
 				self.success_count = 0;
 				self.failure_count = 0;
+				write_child_states(behavior, children)?;
 				children.halt(runtime)?;
 
 				return Ok(state);
 			}
 		}
 
+		write_child_states(behavior, children)?;
 		Ok(BehaviorState::Running)
 	}
 
 	fn provided_ports() -> PortList {
 		port_list![
 			input_port!(i32, SUCCESS_COUNT),
-			input_port!(i32, FAILURE_COUNT)
+			input_port!(i32, FAILURE_COUNT),
+			output_port!(String, CHILD_STATES)
 		]
 	}
 }
@@ -2,17 +2,18 @@
 //! [`ParallelAll`] [`Control`] implementation.
 
 // region:      --- modules
+use super::{CHILD_STATES, write_child_states};
+use crate::SharedRuntime;
 use crate::{
 	self as behaviortree, Control,
-	behavior::{Behavior, BehaviorData, BehaviorError, BehaviorResult, BehaviorState},
-	input_port,
+	behavior::{BehaviorData, BehaviorError, BehaviorResult, BehaviorState, InlineBehavior},
+	input_port, output_port,
 	port::PortList,
 	port_list,
 	tree::BehaviorTreeElementList,
 };
-use alloc::boxed::Box;
 use alloc::collections::btree_set::BTreeSet;
-use tinyscript::SharedRuntime;
+use alloc::string::String;
 // endregion:   --- modules
 
 // region:		--- globals
@@ -31,6 +32,12 @@ const MAX_FAILURES: &str = "max_failures";
 /// the [`ParallelAll`] becoming a [`BehaviorState::Failure`]. it defaults to `-1`
 /// which means any number or don't care.
 ///
+/// [`BehaviorState::Skipped`] children do not count as failures.
+/// If all children are skipped, the [`ParallelAll`] returns [`BehaviorState::Skipped`].
+///
+/// The optional output port `child_states` receives the `;` separated states of all children
+/// after each tick, e.g. `Success;Running;Failure`. If the port is not mapped, nothing is written.
+///
 /// The behavior is gated behind feature `parallel_all`.
 ///
 /// Example:
@@ -50,8 +57,7 @@ pub struct ParallelAll {
 	completed_list: BTreeSet<usize>,
 }
 
-#[async_trait::async_trait]
-impl Behavior for ParallelAll {
+impl InlineBehavior for ParallelAll {
 	fn on_halt(&mut self) -> Result<(), BehaviorError> {
 		self.failure_count = 0;
 		self.completed_list.clear();
@@ -134,17 +140,23 @@ impl Behavior for ParallelAll {
 			};
 
 			// Done!
+			write_child_states(behavior, children)?;
 			children.halt(runtime)?;
 			self.completed_list.clear();
+			self.failure_count = 0;
 
 			return Ok(state);
 		}
 
+		write_child_states(behavior, children)?;
 		Ok(BehaviorState::Running)
 	}
 
 	fn provided_ports() -> PortList {
-		port_list![input_port!(i32, MAX_FAILURES)]
+		port_list![
+			input_port!(i32, MAX_FAILURES),
+			output_port!(String, CHILD_STATES)
+		]
 	}
 }
 // endregion:   --- ParallelAll
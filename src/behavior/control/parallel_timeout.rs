@@ -0,0 +1,230 @@
+// Copyright © 2025 Stephan Kunz
+//! [`ParallelTimeout`] [`Control`] implementation.
+
+#[cfg(feature = "std")]
+extern crate std;
+
+// region:      --- modules
+use super::{FAILURE_COUNT, SUCCESS_COUNT, check_parallel_thresholds, parallel_state};
+use crate::SharedRuntime;
+use crate::{
+	self as behaviortree, Control,
+	behavior::{BehaviorData, BehaviorError, BehaviorResult, BehaviorState, InlineBehavior},
+	input_port,
+	port::PortList,
+	port_list,
+	tree::BehaviorTreeElementList,
+};
+use alloc::collections::btree_set::BTreeSet;
+use alloc::{string::String, vec::Vec};
+
+#[cfg(feature = "std")]
+use core::time::Duration;
+// endregion:   --- modules
+
+// region:		--- globals
+/// Port name literals
+const TIMEOUT: &str = "timeout";
+const TIMEOUTS: &str = "timeouts";
+// endregion:	--- globals
+
+// region:		--- helper
+/// Creates the list of timeouts in milliseconds, one for each child.
+/// Entries of `timeouts` take precedence, missing or empty entries fall back to `timeout`.
+fn create_timeouts(behavior: &BehaviorData, children_count: usize) -> Result<Vec<Option<u64>>, BehaviorError> {
+	let default = behavior.get::<u64>(TIMEOUT).ok();
+	let mut timeouts = Vec::with_capacity(children_count);
+	if let Ok(list) = behavior.get::<String>(TIMEOUTS) {
+		for entry in list.split(';').map(str::trim) {
+			if entry.is_empty() {
+				timeouts.push(default);
+			} else {
+				let millis = entry
+					.parse::<u64>()
+					.map_err(|_| BehaviorError::ParseError {
+						value: entry.into(),
+						src: TIMEOUTS.into(),
+					})?;
+				timeouts.push(Some(millis));
+			}
+		}
+	}
+
+	if timeouts.len() > children_count {
+		return Err(BehaviorError::Composition {
+			txt: "ParallelTimeout has more timeouts than children.".into(),
+		});
+	}
+	timeouts.resize(children_count, default);
+	Ok(timeouts)
+}
+// endregion:	--- helper
+
+// region:      --- ParallelTimeout
+/// A [`ParallelTimeout`] executes its children __concurrently__ in one thread,
+/// each child with its own timeout.
+///
+/// A child exceeding its timeout is halted and counted as a failure, while the other children continue.
+/// The timeouts in milliseconds are given either per child with the port `timeouts` as a `;` separated
+/// list aligned to the order of the children, or for all children with the port `timeout`.
+/// Children without a timeout run without limit.
+///
+/// Apart from the timeouts the behavior is the same as a [`Parallel`](crate::behavior::control::Parallel)
+/// and uses the ports `success_count` and `failure_count` the same way.
+///
+/// Timeouts are only checked with feature `std`, without it the ports `timeout` and `timeouts`
+/// are validated but ignored, so the behavior is the same as a [`Parallel`](crate::behavior::control::Parallel).
+///
+/// The behavior is gated behind feature `parallel_timeout`.
+///
+/// Example:
+///
+/// ```xml
+/// <ParallelTimeout success_count="1" timeouts="500;;2000">
+///    <Behavior1/>
+///    <Behavior2/>
+///    <Behavior3/>
+/// </ParallelTimeout>
+/// ```
+#[derive(Control, Debug, Default)]
+pub struct ParallelTimeout {
+	/// The amount of completed sub behaviors that succeeded.
+	success_count: i32,
+	/// The amount of completed sub behaviors that failed, including the timed out ones.
+	failure_count: i32,
+	/// The list of completed sub behaviors
+	completed_list: BTreeSet<usize>,
+	/// The timeout for each sub behavior in milliseconds.
+	timeouts: Vec<Option<u64>>,
+	/// The time when the children were started.
+	#[cfg(feature = "std")]
+	start_time: Option<Duration>,
+}
+
+impl ParallelTimeout {
+	/// Returns whether the child at `index` exceeded its timeout.
+	#[cfg(feature = "std")]
+	fn is_timed_out(&self, index: usize, behavior: &BehaviorData) -> bool {
+		match (self.start_time, self.timeouts.get(index).copied().flatten()) {
+			(Some(start), Some(millis)) => behavior.now().saturating_sub(start) > Duration::from_millis(millis),
+			_ => false,
+		}
+	}
+
+	/// Without feature `std` there is no clock, so a child never exceeds its timeout.
+	#[cfg(not(feature = "std"))]
+	#[allow(clippy::unused_self)]
+	const fn is_timed_out(&self, _index: usize, _behavior: &BehaviorData) -> bool {
+		false
+	}
+
+	/// Resets the progress of the children.
+	fn reset(&mut self) {
+		self.completed_list.clear();
+		self.success_count = 0;
+		self.failure_count = 0;
+		#[cfg(feature = "std")]
+		{
+			self.start_time = None;
+		}
+	}
+}
+
+impl InlineBehavior for ParallelTimeout {
+	fn on_halt(&mut self) -> Result<(), BehaviorError> {
+		self.reset();
+		Ok(())
+	}
+
+	fn on_start(
+		&mut self,
+		behavior: &mut BehaviorData,
+		children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> Result<(), BehaviorError> {
+		// check composition only once at start
+		let children_count = children.len();
+		check_parallel_thresholds(behavior, children_count)?;
+
+		self.timeouts = create_timeouts(behavior, children_count)?;
+		#[cfg(feature = "std")]
+		{
+			self.start_time = Some(behavior.now());
+		}
+		behavior.set_state(BehaviorState::Running);
+		Ok(())
+	}
+
+	#[allow(clippy::cast_possible_truncation)]
+	#[allow(clippy::cast_possible_wrap)]
+	#[allow(clippy::set_contains_or_insert)]
+	async fn tick(
+		&mut self,
+		behavior: &mut BehaviorData,
+		children: &mut BehaviorTreeElementList,
+		runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		let children_count = children.len();
+
+		let mut skipped_count = 0;
+
+		for i in 0..children_count {
+			// Skip completed node
+			if !self.completed_list.contains(&i) {
+				match children[i].tick(runtime).await? {
+					BehaviorState::Skipped => skipped_count += 1,
+					BehaviorState::Success => {
+						self.completed_list.insert(i);
+						self.success_count += 1;
+					}
+					BehaviorState::Failure => {
+						self.completed_list.insert(i);
+						self.failure_count += 1;
+					}
+					BehaviorState::Running => {
+						if self.is_timed_out(i, behavior) {
+							children.halt_at(i, runtime)?;
+							self.completed_list.insert(i);
+							self.failure_count += 1;
+						}
+					}
+					// Throw error, should never happen
+					BehaviorState::Idle => {
+						return Err(BehaviorError::State {
+							behavior: "ParallelTimeout".into(),
+							state: BehaviorState::Idle,
+						});
+					}
+				}
+			}
+
+			let sum = self.failure_count + self.success_count + skipped_count;
+			if sum >= children_count as i32 {
+				let state = parallel_state(
+					behavior,
+					self.success_count,
+					self.failure_count,
+					skipped_count,
+					children_count,
+				);
+
+				self.reset();
+				children.halt(runtime)?;
+
+				return Ok(state);
+			}
+		}
+
+		Ok(BehaviorState::Running)
+	}
+
+	fn provided_ports() -> PortList {
+		port_list![
+			input_port!(i32, SUCCESS_COUNT),
+			input_port!(i32, FAILURE_COUNT),
+			input_port!(u64, TIMEOUT),
+			input_port!(String, TIMEOUTS)
+		]
+	}
+}
+// endregion:   --- ParallelTimeout
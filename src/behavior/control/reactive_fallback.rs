@@ -2,13 +2,12 @@
 //! [`ReactiveFallback`] [`Control`] implementation.
 
 // region:      --- modules
+use crate::SharedRuntime;
 use crate::{
 	self as behaviortree, Control,
-	behavior::{Behavior, BehaviorData, BehaviorError, BehaviorResult, BehaviorState},
+	behavior::{BehaviorData, BehaviorError, BehaviorResult, BehaviorState, InlineBehavior},
 	tree::BehaviorTreeElementList,
 };
-use alloc::boxed::Box;
-use tinyscript::SharedRuntime;
 // endregion:   --- modules
 
 // region:      --- ReactiveFallback
@@ -47,8 +46,12 @@ impl Default for ReactiveFallback {
 	}
 }
 
-#[async_trait::async_trait]
-impl Behavior for ReactiveFallback {
+impl InlineBehavior for ReactiveFallback {
+	#[inline]
+	fn is_reactive(&self) -> bool {
+		true
+	}
+
 	#[inline]
 	fn on_halt(&mut self) -> Result<(), BehaviorError> {
 		self.running_child_idx = -1;
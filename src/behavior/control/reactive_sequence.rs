@@ -2,15 +2,23 @@
 //! [`ReactiveSequence`] [`Control`] implementation.
 
 // region:      --- modules
+use crate::SharedRuntime;
 use crate::{
 	self as behaviortree, Control,
-	behavior::{Behavior, BehaviorData, BehaviorError, BehaviorResult, BehaviorState},
+	behavior::{BehaviorData, BehaviorError, BehaviorResult, BehaviorState, InlineBehavior},
+	input_port,
+	port::PortList,
+	port_list,
 	tree::BehaviorTreeElementList,
 };
-use alloc::boxed::Box;
-use tinyscript::SharedRuntime;
+use alloc::string::ToString;
 // endregion:   --- modules
 
+// region:		--- globals
+/// Port name literals
+const PRESERVE_RUNNING_TAIL: &str = "preserve_running_tail";
+// endregion:	--- globals
+
 // region:      --- ReactiveSequence
 /// A [`ReactiveSequence`] ticks its children in an ordered sequence from first to last at every tick.
 /// - If a child returns [`BehaviorState::Failure`] the sequence returns [`BehaviorState::Failure`].
@@ -21,6 +29,11 @@ use tinyscript::SharedRuntime;
 ///
 /// If all the children return [`BehaviorState::Success`], this node returns [`BehaviorState::Success`].
 ///
+/// With the optional port `preserve_running_tail` set to `true`, a later child that is
+/// [`BehaviorState::Running`] is not halted when an earlier child returns [`BehaviorState::Running`].
+/// It is resumed as soon as the earlier children succeed again.
+/// A [`BehaviorState::Failure`] of an earlier child still halts all children. Defaults to `false`.
+///
 /// IMPORTANT: Having asynchronous children (aka children that return [`BehaviorState::Running`]) makes
 /// this behavior difficult to predict. Avoid having more than one asynchronous children!
 ///
@@ -47,8 +60,12 @@ impl Default for ReactiveSequence {
 	}
 }
 
-#[async_trait::async_trait]
-impl Behavior for ReactiveSequence {
+impl InlineBehavior for ReactiveSequence {
+	#[inline]
+	fn is_reactive(&self) -> bool {
+		true
+	}
+
 	#[inline]
 	fn on_halt(&mut self) -> Result<(), BehaviorError> {
 		self.running_child_idx = -1;
@@ -71,10 +88,13 @@ impl Behavior for ReactiveSequence {
 	#[allow(clippy::cast_sign_loss)]
 	async fn tick(
 		&mut self,
-		_behavior: &mut BehaviorData,
+		behavior: &mut BehaviorData,
 		children: &mut BehaviorTreeElementList,
 		runtime: &SharedRuntime,
 	) -> BehaviorResult {
+		let preserve_running_tail = behavior
+			.get::<bool>(PRESERVE_RUNNING_TAIL)
+			.unwrap_or(false);
 		let mut all_skipped = true;
 
 		let children_count = children.len();
@@ -99,7 +119,11 @@ impl Behavior for ReactiveSequence {
 				BehaviorState::Running => {
 					// halt previously running child
 					if self.running_child_idx != (child_idx as i32) && self.running_child_idx != -1 {
-						children[self.running_child_idx as usize].halt_children(runtime)?;
+						if preserve_running_tail && self.running_child_idx > (child_idx as i32) {
+							// keep the running later child alive
+							return Ok(BehaviorState::Running);
+						}
+						children[self.running_child_idx as usize].halt(runtime)?;
 						self.running_child_idx = -1;
 					}
 					if self.running_child_idx == -1 {
@@ -118,7 +142,10 @@ impl Behavior for ReactiveSequence {
 					self.running_child_idx = -1;
 				}
 				BehaviorState::Success => {
-					self.running_child_idx = -1;
+					// a preserved running later child stays registered
+					if !preserve_running_tail || self.running_child_idx <= (child_idx as i32) {
+						self.running_child_idx = -1;
+					}
 				}
 			}
 		}
@@ -132,5 +159,14 @@ impl Behavior for ReactiveSequence {
 			Ok(BehaviorState::Success)
 		}
 	}
+
+	fn provided_ports() -> PortList {
+		port_list![input_port!(
+			bool,
+			PRESERVE_RUNNING_TAIL,
+			"false",
+			"Keep a running later child alive while an earlier child is running."
+		)]
+	}
 }
 // endregion:   --- ReactiveSequence
@@ -0,0 +1,183 @@
+// Copyright © 2025 Stephan Kunz
+//! [`RetryFallback`] [`Control`] implementation.
+
+#[cfg(feature = "std")]
+extern crate std;
+
+// region:      --- modules
+use crate::SharedRuntime;
+use crate::{
+	self as behaviortree, Control,
+	behavior::{BehaviorData, BehaviorError, BehaviorResult, BehaviorState, InlineBehavior},
+	input_port,
+	port::PortList,
+	port_list,
+	tree::BehaviorTreeElementList,
+};
+use alloc::string::ToString;
+
+#[cfg(feature = "std")]
+use core::time::Duration;
+// endregion:   --- modules
+
+// region:		--- globals
+/// Port name literals
+const NUM_RETRIES: &str = "num_retries";
+const RETRY_DELAY: &str = "retry_delay";
+// endregion:	--- globals
+
+// region:      --- RetryFallback
+/// The `RetryFallback` behavior is a [`Fallback`](crate::behavior::control::Fallback),
+/// which retries all its children if all of them failed.
+/// - If a child returns [`BehaviorState::Running`], this node returns [`BehaviorState::Running`].
+/// - If a child returns [`BehaviorState::Success`] this behavior returns [`BehaviorState::Success`].
+/// - If all the children return [`BehaviorState::Failure`], the children are reset and
+///   the whole fallback is retried up to N times (N is read from port `num_retries`, -1 retries forever).
+///   Only if all retries failed this node returns [`BehaviorState::Failure`].
+///
+/// Between two passes the behavior waits `retry_delay` milliseconds, returning [`BehaviorState::Running`]
+/// while waiting. Without a delay the retries are done within the same tick.
+/// In `no_std` environments the delay is ignored.
+///
+/// The behavior is gated behind feature `retry_fallback`.
+///
+/// Example:
+///
+/// ```xml
+/// <RetryFallback num_retries="3" retry_delay="500">
+///    <Behavior1/>
+///    <Behavior2/>
+/// </RetryFallback>
+/// ```
+#[derive(Control, Debug, Default)]
+pub struct RetryFallback {
+	/// Defaults to '0'
+	child_idx: usize,
+	/// Defaults to '0'
+	skipped: usize,
+	/// Number of retries done so far.
+	retries: i32,
+	/// Point in time, when the next pass may start.
+	#[cfg(feature = "std")]
+	retry_at: Option<Duration>,
+}
+
+impl InlineBehavior for RetryFallback {
+	#[inline]
+	fn on_halt(&mut self) -> Result<(), BehaviorError> {
+		self.restart();
+		self.retries = 0;
+		Ok(())
+	}
+
+	fn on_start(
+		&mut self,
+		behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> Result<(), BehaviorError> {
+		self.restart();
+		self.retries = 0;
+		behavior.set_state(BehaviorState::Running);
+		Ok(())
+	}
+
+	async fn tick(
+		&mut self,
+		behavior: &mut BehaviorData,
+		children: &mut BehaviorTreeElementList,
+		runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		let max_retries = behavior.get::<i32>(NUM_RETRIES).unwrap_or(0);
+		let millis = behavior.get::<u64>(RETRY_DELAY).unwrap_or(0);
+
+		#[cfg(feature = "std")]
+		if let Some(retry_at) = self.retry_at {
+			if behavior.now() < retry_at {
+				return Ok(BehaviorState::Running);
+			}
+			self.retry_at = None;
+		}
+
+		let children_count = children.len();
+		loop {
+			while self.child_idx < children_count {
+				let child = &mut children[self.child_idx];
+				let child_state = child.tick(runtime).await?;
+
+				match child_state {
+					BehaviorState::Failure => self.child_idx += 1,
+					BehaviorState::Idle => {
+						return Err(BehaviorError::State {
+							behavior: "RetryFallback".into(),
+							state: child_state,
+						});
+					}
+					BehaviorState::Running => return Ok(child_state),
+					BehaviorState::Success => {
+						children.reset(runtime)?;
+						self.restart();
+						self.retries = 0;
+						return Ok(child_state);
+					}
+					BehaviorState::Skipped => {
+						self.child_idx += 1;
+						self.skipped += 1;
+					}
+				}
+			}
+
+			// pass ended without a success,
+			// so either all children failed or were skipped
+			let all_skipped = self.skipped == children_count;
+			children.reset(runtime)?;
+			self.restart();
+			if all_skipped {
+				self.retries = 0;
+				return Ok(BehaviorState::Skipped);
+			}
+			if self.retries >= max_retries && max_retries != -1 {
+				self.retries = 0;
+				return Ok(BehaviorState::Failure);
+			}
+			self.retries += 1;
+
+			#[cfg(feature = "std")]
+			if millis > 0 {
+				self.retry_at = Some(
+					behavior
+						.now()
+						.saturating_add(Duration::from_millis(millis)),
+				);
+				return Ok(BehaviorState::Running);
+			}
+			#[cfg(not(feature = "std"))]
+			let _ = millis;
+		}
+	}
+
+	fn provided_ports() -> PortList {
+		port_list![
+			input_port!(
+				i32,
+				NUM_RETRIES,
+				0,
+				"Retry the children up to N times after all failed. Use -1 to retry forever."
+			),
+			input_port!(u64, RETRY_DELAY, 0, "Milliseconds to wait before a retry.")
+		]
+	}
+}
+
+impl RetryFallback {
+	/// Prepares a new pass over the children.
+	const fn restart(&mut self) {
+		self.child_idx = 0;
+		self.skipped = 0;
+		#[cfg(feature = "std")]
+		{
+			self.retry_at = None;
+		}
+	}
+}
+// endregion:   --- RetryFallback
@@ -2,15 +2,24 @@
 //! [`SequenceWithMemory`] [`Control`] implementation.
 
 // region:      --- modules
+use crate::SharedRuntime;
 use crate::{
 	self as behaviortree, Control,
-	behavior::{Behavior, BehaviorData, BehaviorError, BehaviorResult, BehaviorState},
+	behavior::{BehaviorData, BehaviorError, BehaviorResult, BehaviorState, InlineBehavior},
+	input_port,
+	port::PortList,
+	port_list,
 	tree::BehaviorTreeElementList,
 };
-use alloc::boxed::Box;
-use tinyscript::SharedRuntime;
+use alloc::string::ToString;
 // endregion:   --- modules
 
+// region:		--- globals
+/// Port name literals
+const PERSISTENT: &str = "persistent";
+const RESET_ON_REENTRY: &str = "reset_on_reentry";
+// endregion:	--- globals
+
 // region:      --- SequenceWithMemory
 /// A [`SequenceWithMemory`] ticks its children in an ordered sequence from first to last.
 /// If any child returns [`BehaviorState::Running`], previous children are not ticked again.
@@ -18,13 +27,32 @@ use tinyscript::SharedRuntime;
 /// - If a child returns [`BehaviorState::Running`], this behavior returns [`BehaviorState::Running`].
 ///   The loop is NOT restarted, the same running child will be ticked again.
 /// - If a child returns [`BehaviorState::Failure`], the loop stops and the behavior returns [`BehaviorState::Failure`].
+///   The memory is kept, so the next tick continues with the failed child.
+///
+/// The memory is cleared, when all children succeeded. Whether a halt, which happens when the
+/// behavior is re-entered from [`BehaviorState::Idle`], also clears the memory, is controlled by the
+/// optional port `reset_on_reentry`:
+/// - `true` (default): a halted behavior restarts with the first child.
+/// - `false`: a halted behavior continues with the child that was running or failed last.
+///
+/// With the optional port `persistent` set to `true` (default `false`) the memory is never cleared,
+/// neither by a halt nor by a reset of the tree nor after completion. A completed behavior
+/// returns the result of its completion without ticking its children again, which is useful for
+/// a startup sequence that shall run only once during the whole program.
+/// `persistent` takes precedence, so with `persistent="true"` the port `reset_on_reentry` has no effect.
+///
+/// If all children are [`BehaviorState::Skipped`], this behavior returns [`BehaviorState::Skipped`].
+///
+/// In contrast, a [`Sequence`](crate::behavior::control::Sequence) restarts with the first child
+/// after a failure and a [`ReactiveSequence`](crate::behavior::control::ReactiveSequence)
+/// restarts with the first child at every tick.
 ///
 /// The behavior is gated behind feature `sequence_with_memory`.
 ///
 /// Example:
 ///
 /// ```xml
-/// <SequenceWithMemory>
+/// <SequenceWithMemory reset_on_reentry="false">
 ///    <Behavior1/>
 ///    <Behavior2/>
 ///    <Behavior3/>
@@ -34,8 +62,12 @@ use tinyscript::SharedRuntime;
 pub struct SequenceWithMemory {
 	/// Defaults to '0'
 	child_idx: usize,
-	/// Defaults to 'true'
+	/// Whether all completed children of the current run are skipped, defaults to 'true'
 	all_skipped: bool,
+	/// Value of port `reset_on_reentry`, defaults to 'true'
+	reset_on_reentry: bool,
+	/// Value of port `persistent`, defaults to 'false'
+	persistent: bool,
 }
 
 impl Default for SequenceWithMemory {
@@ -43,16 +75,19 @@ impl Default for SequenceWithMemory {
 		Self {
 			child_idx: 0,
 			all_skipped: true,
+			reset_on_reentry: true,
+			persistent: false,
 		}
 	}
 }
 
-#[async_trait::async_trait]
-impl Behavior for SequenceWithMemory {
+impl InlineBehavior for SequenceWithMemory {
 	#[inline]
 	fn on_halt(&mut self) -> Result<(), BehaviorError> {
-		self.child_idx = 0;
-		self.all_skipped = true;
+		if self.reset_on_reentry && !self.persistent {
+			self.child_idx = 0;
+			self.all_skipped = true;
+		}
 		Ok(())
 	}
 
@@ -63,6 +98,10 @@ impl Behavior for SequenceWithMemory {
 		_children: &mut BehaviorTreeElementList,
 		_runtime: &SharedRuntime,
 	) -> Result<(), BehaviorError> {
+		self.reset_on_reentry = behavior
+			.get::<bool>(RESET_ON_REENTRY)
+			.unwrap_or(true);
+		self.persistent = behavior.get::<bool>(PERSISTENT).unwrap_or(false);
 		behavior.set_state(BehaviorState::Running);
 		Ok(())
 	}
@@ -73,12 +112,15 @@ impl Behavior for SequenceWithMemory {
 		children: &mut BehaviorTreeElementList,
 		runtime: &SharedRuntime,
 	) -> BehaviorResult {
+		// a completed persistent sequence is not run again
+		if self.persistent && self.child_idx >= children.len() {
+			return Ok(self.completion_state());
+		}
+
 		while self.child_idx < children.len() {
 			let child = &mut children[self.child_idx];
 			let new_state = child.tick(runtime).await?;
 
-			self.all_skipped &= new_state == BehaviorState::Skipped;
-
 			match new_state {
 				BehaviorState::Failure => {
 					// Do NOT reset children on failure
@@ -94,22 +136,48 @@ impl Behavior for SequenceWithMemory {
 				}
 				BehaviorState::Running => return Ok(BehaviorState::Running),
 				BehaviorState::Skipped | BehaviorState::Success => {
+					self.all_skipped &= new_state == BehaviorState::Skipped;
 					self.child_idx += 1;
 				}
 			}
 		}
 
-		// All children returned Success
-		if self.child_idx >= children.len() {
-			// Reset children
-			children.halt(runtime)?;
+		// All children returned Success or Skipped
+		let state = self.completion_state();
+		// Reset children
+		children.halt(runtime)?;
+		if !self.persistent {
 			self.child_idx = 0;
+			self.all_skipped = true;
 		}
+		Ok(state)
+	}
+
+	fn provided_ports() -> PortList {
+		port_list![
+			input_port!(
+				bool,
+				PERSISTENT,
+				"false",
+				"Whether the memory survives halts, resets and completion."
+			),
+			input_port!(
+				bool,
+				RESET_ON_REENTRY,
+				"true",
+				"Whether a halt clears the memory of completed children."
+			)
+		]
+	}
+}
 
+impl SequenceWithMemory {
+	/// The state of a completed run.
+	const fn completion_state(&self) -> BehaviorState {
 		if self.all_skipped {
-			Ok(BehaviorState::Skipped)
+			BehaviorState::Skipped
 		} else {
-			Ok(BehaviorState::Success)
+			BehaviorState::Success
 		}
 	}
 }
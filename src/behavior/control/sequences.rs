@@ -1,17 +1,17 @@
 // Copyright © 2025 Stephan Kunz
 //! [`Sequence`] & `AsyncSequence` [`Control`] implementations.
 
+use crate::SharedRuntime;
 use crate::{
 	self as behaviortree, Control,
-	behavior::{Behavior, BehaviorData, BehaviorError, BehaviorResult, BehaviorState},
+	behavior::{BehaviorData, BehaviorError, BehaviorResult, BehaviorState, InlineBehavior},
 	tree::BehaviorTreeElementList,
 };
-use alloc::boxed::Box;
-use tinyscript::SharedRuntime;
 
 /// A `Sequence` ticks its children in an ordered sequence from first to last.
 /// If any child returns [`BehaviorState::Running`], previous children will NOT be ticked again.
-/// - If any child returns [`BehaviorState::Failure`] the sequence returns [`BehaviorState::Failure`].
+/// - If any child returns [`BehaviorState::Failure`] the sequence returns [`BehaviorState::Failure`]
+///   and restarts with the first child at the next tick.
 /// - If all children return [`BehaviorState::Success`] the sequence returns [`BehaviorState::Success`].
 /// - While any child returns [`BehaviorState::Running`] the sequence returns [`BehaviorState::Running`].
 ///
@@ -22,6 +22,8 @@ use tinyscript::SharedRuntime;
 ///
 /// While running, the loop is not restarted, first the running child will be ticked again.
 /// If that tick succeeds the sequence continues, children that already succeeded will not be ticked again.
+/// A halt always restarts the sequence with the first child.
+/// For keeping the progress after a failure see [`SequenceWithMemory`](crate::behavior::control::SequenceWithMemory).
 ///
 /// The variants are gated behind features `sequence` and `async_sequence` respectively.
 ///
@@ -54,8 +56,7 @@ pub struct Sequence {
 	asynch: bool,
 }
 
-#[async_trait::async_trait]
-impl Behavior for Sequence {
+impl InlineBehavior for Sequence {
 	#[inline]
 	fn on_halt(&mut self) -> Result<(), BehaviorError> {
 		self.child_idx = 0;
@@ -2,18 +2,17 @@
 //! [`Switch<T>`] [`Control`] implementation.
 
 // region:      --- modules
+use crate::SharedRuntime;
 use crate::{
 	self as behaviortree, ConstString, Control, EMPTY_STR,
-	behavior::{Behavior, BehaviorData, BehaviorError, BehaviorResult, BehaviorState},
+	behavior::{BehaviorData, BehaviorError, BehaviorResult, BehaviorState, InlineBehavior},
 	input_port,
 	port::PortList,
 	tree::BehaviorTreeElementList,
 };
-use alloc::boxed::Box;
-use alloc::string::String;
+use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use databoard::is_board_pointer;
-use tinyscript::SharedRuntime;
 // endregion:   --- modules
 
 // region:		--- globals
@@ -21,7 +20,11 @@ use tinyscript::SharedRuntime;
 const CASES: [&str; 6] = [
 	"case_1", "case_2", "case_3", "case_4", "case_5", "case_6",
 ];
+const EPSILON: &str = "epsilon";
 const VARIABLE: &str = "variable";
+
+/// Default tolerance for comparing floats.
+const DEFAULT_EPSILON: f64 = 0.000_000_000_000_002;
 // endregion:	--- globals
 
 // region:      --- Switch
@@ -47,6 +50,13 @@ const VARIABLE: &str = "variable";
 /// the "variable" will be compared to the cases and execute the correct child
 /// or the default one (last).
 ///
+/// If the "variable" and a case both are floats, they match when they differ by
+/// at most the value of the optional port "epsilon".
+///
+/// A case may also be a numeric range, either half-open like `case_1="1..5"`
+/// or inclusive like `case_1="1..=5"`, or a set of alternatives like `case_2="a|b|c"`.
+/// Plain literals are matched exactly.
+///
 /// Note: The same behaviour can be achieved with multiple `Sequences`, `Fallbacks` and `Conditions`,
 /// but switch is shorter and hence more readable.
 #[derive(Control, Debug)]
@@ -69,8 +79,7 @@ impl<const T: u8> Default for Switch<T> {
 	}
 }
 
-#[async_trait::async_trait]
-impl<const T: u8> Behavior for Switch<T> {
+impl<const T: u8> InlineBehavior for Switch<T> {
 	fn on_halt(&mut self) -> Result<(), BehaviorError> {
 		self.cases = T;
 		self.running_child_index = -1;
@@ -129,6 +138,9 @@ impl<const T: u8> Behavior for Switch<T> {
 		) -> Result<i32, BehaviorError> {
 			let mut match_index = i32::from(default_index);
 			let var = behavior.get::<String>(var)?;
+			let epsilon = behavior
+				.get::<f64>(EPSILON)
+				.map_or(DEFAULT_EPSILON, f64::abs);
 			for i in 0..default_index {
 				let case = behavior.get::<String>(CASES[i as usize])?;
 
@@ -169,11 +181,17 @@ impl<const T: u8> Behavior for Switch<T> {
 					&& let Ok(v_val) = var.parse::<f64>()
 				{
 					let delta = f64::abs(v_val - c_val);
-					if delta <= 0.000_000_000_000_002 {
+					if delta <= epsilon {
 						match_index = i32::from(i);
 						break;
 					}
 				}
+
+				// compare with a range or a set of alternatives
+				if matches_range(&var, &case) || matches_alternatives(&var, &case) {
+					match_index = i32::from(i);
+					break;
+				}
 			}
 			Ok(match_index)
 		}
@@ -216,6 +234,34 @@ impl<const T: u8> Behavior for Switch<T> {
 	}
 }
 
+/// Checks whether `var` lies within the numeric range `case`,
+/// which is either half-open `<start>..<end>` or inclusive `<start>..=<end>`.
+fn matches_range(var: &str, case: &str) -> bool {
+	let (start, end, inclusive) = if let Some((start, end)) = case.split_once("..=") {
+		(start, end, true)
+	} else if let Some((start, end)) = case.split_once("..") {
+		(start, end, false)
+	} else {
+		return false;
+	};
+	if let Ok(value) = var.trim().parse::<f64>()
+		&& let Ok(start) = start.trim().parse::<f64>()
+		&& let Ok(end) = end.trim().parse::<f64>()
+	{
+		value >= start && (value < end || (inclusive && value <= end))
+	} else {
+		false
+	}
+}
+
+/// Checks whether `var` equals one of the `|` separated alternatives in `case`.
+fn matches_alternatives(var: &str, case: &str) -> bool {
+	case.contains('|')
+		&& case
+			.split('|')
+			.any(|alternative| alternative.trim() == var)
+}
+
 #[allow(clippy::expect_used)]
 fn create_port_list(size: u8) -> PortList {
 	let mut ports = PortList(Vec::with_capacity(size as usize));
@@ -223,6 +269,15 @@ fn create_port_list(size: u8) -> PortList {
 	ports
 		.add(port)
 		.expect("providing port [variable] failed in behavior [Switch<T>]");
+	let port = input_port!(
+		f64,
+		EPSILON,
+		DEFAULT_EPSILON,
+		"Tolerance when comparing the variable and a case as floats."
+	);
+	ports
+		.add(port)
+		.expect("providing port [epsilon] failed in behavior [Switch<T>]");
 
 	for i in 0..size {
 		let port = input_port!(String, CASES[i as usize]);
@@ -2,13 +2,12 @@
 //! [`WhileDoElse`] [`Control`] implementation.
 
 // region:      --- modules
+use crate::SharedRuntime;
 use crate::{
 	self as behaviortree, Control,
-	behavior::{Behavior, BehaviorData, BehaviorError, BehaviorResult, BehaviorState},
+	behavior::{BehaviorData, BehaviorError, BehaviorResult, BehaviorState, InlineBehavior},
 	tree::BehaviorTreeElementList,
 };
-use alloc::boxed::Box;
-use tinyscript::SharedRuntime;
 // endregion:   --- modules
 
 // region:      --- WhileDoElse
@@ -43,8 +42,12 @@ use tinyscript::SharedRuntime;
 #[derive(Control, Default)]
 pub struct WhileDoElse;
 
-#[async_trait::async_trait]
-impl Behavior for WhileDoElse {
+impl InlineBehavior for WhileDoElse {
+	#[inline]
+	fn is_reactive(&self) -> bool {
+		true
+	}
+
 	fn on_start(
 		&mut self,
 		behavior: &mut BehaviorData,
@@ -5,21 +5,19 @@
 extern crate std;
 
 // region:      --- modules
+use crate::SharedRuntime;
 use crate::{
 	self as behaviortree, Decorator, EMPTY_STR,
-	behavior::{Behavior, BehaviorData, BehaviorError, BehaviorResult, BehaviorState},
+	behavior::{BehaviorData, BehaviorError, BehaviorResult, BehaviorState, InlineBehavior},
 	input_port,
 	port::PortList,
 	port_list,
 	tree::BehaviorTreeElementList,
 };
-use alloc::{boxed::Box, string::ToString};
-use tinyscript::SharedRuntime;
+use alloc::string::ToString;
 
 #[cfg(feature = "std")]
 use core::time::Duration;
-#[cfg(feature = "std")]
-use std::time::Instant;
 //endregion:    --- modules
 
 // region:		--- globals
@@ -35,11 +33,10 @@ const DELAY_MSEC: &str = "delay_msec";
 #[derive(Decorator, Debug, Default)]
 pub struct Delay {
 	#[cfg(feature = "std")]
-	start_time: Option<Instant>,
+	start_time: Option<Duration>,
 }
 
-#[async_trait::async_trait]
-impl Behavior for Delay {
+impl InlineBehavior for Delay {
 	#[inline]
 	fn on_halt(&mut self) -> Result<(), BehaviorError> {
 		#[cfg(feature = "std")]
@@ -57,7 +54,7 @@ impl Behavior for Delay {
 	) -> Result<(), BehaviorError> {
 		#[cfg(feature = "std")]
 		{
-			self.start_time = Some(Instant::now());
+			self.start_time = Some(behavior.now());
 		}
 		behavior.set_state(BehaviorState::Running);
 		Ok(())
@@ -80,7 +77,7 @@ impl Behavior for Delay {
 		}
 		#[cfg(feature = "std")]
 		if let Some(start) = &self.start_time {
-			if Instant::now().duration_since(*start) > Duration::from_millis(millis) {
+			if behavior.now().saturating_sub(*start) > Duration::from_millis(millis) {
 				let state = children[0].tick(runtime).await?;
 				if state.is_completed() {
 					children.halt(runtime)?;
@@ -2,20 +2,17 @@
 //! [`EntryUpdated`] [`Decorator`] implementation.
 
 // region:      --- modules
+use crate::SharedRuntime;
 use crate::{
 	self as behaviortree, ConstString, Decorator, EMPTY_STR,
-	behavior::{Behavior, BehaviorData, BehaviorError, BehaviorResult, BehaviorState},
+	behavior::{BehaviorData, BehaviorError, BehaviorResult, BehaviorState, InlineBehavior},
 	input_port,
 	port::PortList,
 	port_list,
 	tree::BehaviorTreeElementList,
 };
+use alloc::string::{String, ToString};
 use alloc::sync::Arc;
-use alloc::{
-	boxed::Box,
-	string::{String, ToString},
-};
-use tinyscript::SharedRuntime;
 // endregion:   --- modules
 
 // region:		--- globals
@@ -53,7 +50,8 @@ pub struct EntryUpdated {
 
 impl EntryUpdated {
 	/// Create the behavior with a non default [`BehaviorState`] to return.
-	/// The default state is [`BehaviorState::Idle`].
+	/// The default state is [`BehaviorState::Idle`], which is rejected as a tick result,
+	/// so the raw version has to be initialized with a legal state.
 	#[must_use]
 	pub fn new(state: BehaviorState) -> Self {
 		Self {
@@ -70,8 +68,7 @@ impl EntryUpdated {
 	}
 }
 
-#[async_trait::async_trait]
-impl Behavior for EntryUpdated {
+impl InlineBehavior for EntryUpdated {
 	#[inline]
 	fn on_halt(&mut self) -> Result<(), BehaviorError> {
 		self.sequence_id = usize::MIN;
@@ -115,7 +112,7 @@ impl Behavior for EntryUpdated {
 			self.sequence_id = sequence_id;
 			let state = children[0].tick(runtime).await?;
 			self.is_running = state == BehaviorState::Running;
-			return Ok(state);
+			Ok(state)
 		}
 	}
 
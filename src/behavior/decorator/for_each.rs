@@ -0,0 +1,156 @@
+// Copyright © 2025 Stephan Kunz
+//! [`ForEach`] [`Decorator`] implementation.
+
+// region:      --- modules
+use crate::SharedRuntime;
+use crate::{
+	self as behaviortree, Decorator,
+	behavior::{BehaviorData, BehaviorError, BehaviorResult, BehaviorState, InlineBehavior},
+	input_port, output_port,
+	port::PortList,
+	port_list,
+	tree::BehaviorTreeElementList,
+};
+use alloc::string::ToString;
+// endregion:   --- modules
+
+// region:		--- globals
+/// Port name literals
+const FROM: &str = "from";
+const STEP: &str = "step";
+const TO: &str = "to";
+const VALUE: &str = "value";
+// endregion:	--- globals
+
+// region:      --- ForEach
+/// The [`ForEach`] decorator executes its child once for every index of the range `from..to`.
+///
+/// The current index is written into the port `value` before the child is ticked.
+/// The range excludes `to`, a negative `step` iterates in reverse order.
+/// - If the range is empty, the behavior returns [`BehaviorState::Success`] without ticking the child.
+/// - If the child returns [`BehaviorState::Failure`], the behavior returns [`BehaviorState::Failure`].
+/// - If the child returns [`BehaviorState::Running`], the current index is kept for the next tick.
+/// - If the child returns [`BehaviorState::Skipped`], the iteration is done like on [`BehaviorState::Success`].
+/// - If the range is completed, the behavior returns [`BehaviorState::Success`],
+///   or [`BehaviorState::Skipped`] if the child skipped every iteration.
+///
+/// The behavior is gated behind feature `for_each`.
+///
+/// Example:
+///
+/// ```xml
+/// <ForEach from="10" to="0" step="-2" value="{index}">
+///     <MoveToSlot slot="{index}"/>
+/// </ForEach>
+/// ```
+#[derive(Decorator, Debug)]
+pub struct ForEach {
+	/// The index for the next child tick
+	current: i32,
+	/// The excluded end of the range
+	to: i32,
+	/// The increment per iteration
+	step: i32,
+	/// Whether the child skipped all iterations so far
+	all_skipped: bool,
+}
+
+impl Default for ForEach {
+	fn default() -> Self {
+		Self {
+			current: 0,
+			to: 0,
+			step: 1,
+			all_skipped: true,
+		}
+	}
+}
+
+impl ForEach {
+	/// Returns whether `current` is within the range.
+	const fn in_range(&self) -> bool {
+		if self.step > 0 {
+			self.current < self.to
+		} else {
+			self.current > self.to
+		}
+	}
+}
+
+impl InlineBehavior for ForEach {
+	fn on_start(
+		&mut self,
+		behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> Result<(), BehaviorError> {
+		self.current = behavior.get::<i32>(FROM)?;
+		self.to = behavior.get::<i32>(TO)?;
+		self.step = match behavior.get::<i32>(STEP) {
+			Ok(step) => step,
+			Err(error) if error.is_not_found() => 1,
+			Err(error) => return Err(error.into()),
+		};
+		self.all_skipped = true;
+		if self.step == 0 {
+			return Err(BehaviorError::Composition {
+				txt: "ForEach needs a 'step' different from 0".into(),
+			});
+		}
+		behavior.set_state(BehaviorState::Running);
+		Ok(())
+	}
+
+	async fn tick(
+		&mut self,
+		behavior: &mut BehaviorData,
+		children: &mut BehaviorTreeElementList,
+		runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		if !self.in_range() {
+			return Ok(BehaviorState::Success);
+		}
+
+		behavior.set(VALUE, self.current)?;
+		let child = &mut children[0];
+		let new_state = child.tick(runtime).await?;
+
+		match new_state {
+			BehaviorState::Failure => {
+				children.halt(runtime)?;
+				Ok(BehaviorState::Failure)
+			}
+			BehaviorState::Idle => Err(BehaviorError::State {
+				behavior: "ForEach".into(),
+				state: new_state,
+			}),
+			BehaviorState::Running => Ok(BehaviorState::Running),
+			BehaviorState::Skipped | BehaviorState::Success => {
+				children.halt(runtime)?;
+				self.all_skipped &= new_state == BehaviorState::Skipped;
+				// an overflow ends the range
+				let next = self.current.checked_add(self.step);
+				if let Some(next) = next {
+					self.current = next;
+				}
+				if next.is_some() && self.in_range() {
+					Ok(BehaviorState::Running)
+				} else if self.all_skipped {
+					Ok(BehaviorState::Skipped)
+				} else {
+					Ok(BehaviorState::Success)
+				}
+			}
+		}
+	}
+
+	fn provided_ports() -> PortList {
+		port_list![
+			input_port!(i32, FROM),
+			input_port!(i32, TO),
+			input_port!(i32, STEP, 1, "Increment per iteration, negative for a reverse range."),
+			output_port!(i32, VALUE)
+		]
+	}
+}
+// endregion:   --- ForEach
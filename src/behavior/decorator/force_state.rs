@@ -2,13 +2,12 @@
 //! [`ForceState`] [`Decorator`] implementation.
 
 // region:      --- modules
+use crate::SharedRuntime;
 use crate::{
 	self as behaviortree, Decorator,
-	behavior::{Behavior, BehaviorData, BehaviorError, BehaviorResult, BehaviorState},
+	behavior::{BehaviorData, BehaviorError, BehaviorResult, BehaviorState, InlineBehavior},
 	tree::BehaviorTreeElementList,
 };
-use alloc::boxed::Box;
-use tinyscript::SharedRuntime;
 // endregion:   --- modules
 
 // region:      --- ForceState
@@ -30,8 +29,7 @@ pub struct ForceState {
 	state: BehaviorState,
 }
 
-#[async_trait::async_trait]
-impl Behavior for ForceState {
+impl InlineBehavior for ForceState {
 	async fn tick(
 		&mut self,
 		_behavior: &mut BehaviorData,
@@ -2,13 +2,12 @@
 //! [`Inverter`] [`Decorator`] implementation.
 
 // region:      --- modules
+use crate::SharedRuntime;
 use crate::{
 	self as behaviortree, Decorator,
-	behavior::{Behavior, BehaviorData, BehaviorError, BehaviorResult, BehaviorState},
+	behavior::{BehaviorData, BehaviorError, BehaviorResult, BehaviorState, InlineBehavior},
 	tree::BehaviorTreeElementList,
 };
-use alloc::boxed::Box;
-use tinyscript::SharedRuntime;
 // endregion:   --- modules
 
 // region:      --- Inverter
@@ -21,8 +20,7 @@ use tinyscript::SharedRuntime;
 #[derive(Decorator, Default)]
 pub struct Inverter;
 
-#[async_trait::async_trait]
-impl Behavior for Inverter {
+impl InlineBehavior for Inverter {
 	async fn tick(
 		&mut self,
 		_behavior: &mut BehaviorData,
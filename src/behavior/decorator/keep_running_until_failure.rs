@@ -2,13 +2,12 @@
 //! [`KeepRunningUntilFailure`] [`Decorator`] implementation.
 
 // region:      --- modules
+use crate::SharedRuntime;
 use crate::{
 	self as behaviortree, Decorator,
-	behavior::{Behavior, BehaviorData, BehaviorError, BehaviorResult, BehaviorState},
+	behavior::{BehaviorData, BehaviorError, BehaviorResult, BehaviorState, InlineBehavior},
 	tree::BehaviorTreeElementList,
 };
-use alloc::boxed::Box;
-use tinyscript::SharedRuntime;
 // endregion:   --- modules
 
 // region:      --- KeepRunningUntilFailure
@@ -26,8 +25,7 @@ use tinyscript::SharedRuntime;
 #[derive(Decorator, Default)]
 pub struct KeepRunningUntilFailure;
 
-#[async_trait::async_trait]
-impl Behavior for KeepRunningUntilFailure {
+impl InlineBehavior for KeepRunningUntilFailure {
 	async fn tick(
 		&mut self,
 		_behavior: &mut BehaviorData,
@@ -2,22 +2,23 @@
 //! [`Loop<T>`] [`Decorator`] implementation.
 
 // region:      --- modules
+use crate::SharedRuntime;
 use crate::{
 	self as behaviortree, Decorator,
-	behavior::{Behavior, BehaviorData, BehaviorResult, BehaviorState, shared_queue::SharedQueue},
+	behavior::{BehaviorData, BehaviorError, BehaviorResult, BehaviorState, InlineBehavior, shared_queue::SharedQueue},
 	inout_port, input_port, output_port,
 	port::PortList,
 	port_list,
 	tree::BehaviorTreeElementList,
 };
-use alloc::{boxed::Box, string::ToString};
+use alloc::string::ToString;
 use core::fmt::Debug;
 use core::str::FromStr;
-use tinyscript::SharedRuntime;
 // endregion:   --- modules
 
 // region:		--- globals
 /// Port name literals
+const EAGER: &str = "eager";
 const IF_EMPTY: &str = "if_empty";
 const QUEUE: &str = "queue";
 const VALUE: &str = "value";
@@ -28,6 +29,10 @@ const VALUE: &str = "value";
 /// This element is copied into the port `value` and the child will be executed
 /// as long as there are elements in the queue.
 ///
+/// By default the child is ticked once per tick of this behavior.
+/// With port `eager` set to `true`, the queue is drained within a single tick,
+/// as long as the child returns [`BehaviorState::Success`].
+///
 /// There are the predefined behaviors
 /// - `LoopBool`: gated behind feature `loop_bool`
 /// - `LoopDouble`: gated behind feature `loop_double`
@@ -44,8 +49,7 @@ where
 	tmp_queue: Option<SharedQueue<T>>,
 }
 
-#[async_trait::async_trait]
-impl<T> Behavior for Loop<T>
+impl<T> InlineBehavior for Loop<T>
 where
 	T: Clone + Debug + Default + FromStr + ToString + Send + Sync,
 {
@@ -55,56 +59,40 @@ where
 		children: &mut BehaviorTreeElementList,
 		runtime: &SharedRuntime,
 	) -> BehaviorResult {
-		async fn inner_tick(children: &mut BehaviorTreeElementList, runtime: &SharedRuntime) -> BehaviorResult {
-			let child_state = children[0].tick(runtime).await?;
-			if child_state.is_completed() {
-				children[0].halt_children(runtime)?;
-			}
-			if child_state == BehaviorState::Failure {
-				Ok(BehaviorState::Failure)
-			} else {
-				Ok(BehaviorState::Running)
-			}
-		}
-
 		behavior.set_state(BehaviorState::Running);
+		let eager = behavior.get::<bool>(EAGER).unwrap_or(false);
 
-		// get a value
-		let value = if let Some(const_queue) = &self.tmp_queue {
-			const_queue.pop_front()
-		} else {
-			match behavior.get_mut_ref::<SharedQueue<T>>(QUEUE) {
-				Ok(q) => q.pop_front(),
-				#[allow(clippy::collapsible_match)]
-				Err(err) => match &err {
-					crate::port::error::Error::Databoard { source } => match source {
-						databoard::Error::Assignment { key: _, value } => {
-							let q = SharedQueue::from_str(value)?;
-							let first = q.pop_front();
-							self.tmp_queue = Some(q);
-							first
-						}
-						_ => return Err(err.into()),
-					},
-					_ => return Err(err.into()),
-				},
+		loop {
+			if let Some(value) = self.next_value(behavior)? {
+				behavior.set::<T>(VALUE, value)?;
+				let child_state = children[0].tick(runtime).await?;
+				if child_state.is_completed() {
+					children[0].halt_children(runtime)?;
+				}
+				if child_state == BehaviorState::Failure {
+					return Ok(BehaviorState::Failure);
+				}
+				if !eager || child_state != BehaviorState::Success {
+					return Ok(BehaviorState::Running);
+				}
+			} else {
+				self.tmp_queue = None;
+				let state = behavior
+					.get::<BehaviorState>(IF_EMPTY)
+					.unwrap_or(BehaviorState::Success);
+				return Ok(state);
 			}
-		};
-
-		if let Some(value) = value {
-			behavior.set::<T>(VALUE, value)?;
-			inner_tick(children, runtime).await
-		} else {
-			self.tmp_queue = None;
-			let state = behavior
-				.get::<BehaviorState>(IF_EMPTY)
-				.unwrap_or(BehaviorState::Success);
-			Ok(state)
 		}
 	}
 
 	fn provided_ports() -> PortList {
 		port_list![
+			input_port!(
+				bool,
+				EAGER,
+				false,
+				"Drain the queue within one tick as long as the child succeeds."
+			),
 			inout_port!(SharedQueue<T>, QUEUE),
 			input_port!(
 				BehaviorState,
@@ -116,4 +104,36 @@ where
 		]
 	}
 }
+
+impl<T> Loop<T>
+where
+	T: Clone + Debug + Default + FromStr + ToString + Send + Sync,
+{
+	/// Pops the next value from the queue.
+	fn next_value(&mut self, behavior: &BehaviorData) -> Result<Option<T>, BehaviorError> {
+		let value = if let Some(const_queue) = &self.tmp_queue {
+			const_queue.pop_front()
+		} else {
+			match behavior.get_mut_ref::<SharedQueue<T>>(QUEUE) {
+				Ok(q) => q.pop_front(),
+				#[allow(clippy::collapsible_match)]
+				Err(err) => match &err {
+					crate::port::error::Error::Databoard { source } | crate::port::error::Error::Access { source, .. } => {
+						match source {
+							databoard::Error::Assignment { key: _, value } => {
+								let q = SharedQueue::from_str(value)?;
+								let first = q.pop_front();
+								self.tmp_queue = Some(q);
+								first
+							}
+							_ => return Err(err.into()),
+						}
+					}
+					_ => return Err(err.into()),
+				},
+			}
+		};
+		Ok(value)
+	}
+}
 // endregion:   --- Loop
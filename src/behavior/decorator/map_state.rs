@@ -0,0 +1,132 @@
+// Copyright © 2025 Stephan Kunz
+//! [`MapState`] [`Decorator`] implementation.
+
+// region:      --- modules
+use crate::SharedRuntime;
+use crate::{
+	self as behaviortree, Decorator,
+	behavior::{BehaviorData, BehaviorError, BehaviorResult, BehaviorState, InlineBehavior},
+	input_port,
+	port::PortList,
+	port_list,
+	tree::BehaviorTreeElementList,
+};
+use alloc::string::String;
+// endregion:   --- modules
+
+// region:		--- globals
+/// Port name literals
+const MAPPING: &str = "mapping";
+// endregion:	--- globals
+
+// region:		--- helper
+/// Returns the index of a mappable [`BehaviorState`].
+const fn index(state: BehaviorState) -> Option<usize> {
+	match state {
+		BehaviorState::Failure => Some(0),
+		BehaviorState::Running => Some(1),
+		BehaviorState::Skipped => Some(2),
+		BehaviorState::Success => Some(3),
+		BehaviorState::Idle => None,
+	}
+}
+
+/// Parses a mapping like `success=failure,running=success`.
+fn parse_mapping(mapping: &str) -> Result<[Option<BehaviorState>; 4], BehaviorError> {
+	let mut result = [None; 4];
+	for entry in mapping
+		.split(',')
+		.map(str::trim)
+		.filter(|entry| !entry.is_empty())
+	{
+		let Some((from, to)) = entry.split_once('=') else {
+			return Err(BehaviorError::ParseError {
+				value: entry.into(),
+				src: MAPPING.into(),
+			});
+		};
+		let from = from.trim().parse::<BehaviorState>()?;
+		let to = to.trim().parse::<BehaviorState>()?;
+		match (index(from), to) {
+			(Some(idx), to) if to != BehaviorState::Idle => result[idx] = Some(to),
+			_ => {
+				return Err(BehaviorError::Composition {
+					txt: "MapState can not map from or to 'Idle'".into(),
+				});
+			}
+		}
+	}
+	Ok(result)
+}
+// endregion:	--- helper
+
+// region:      --- MapState
+/// The `MapState` behavior maps the childs outcome to a configured [`BehaviorState`].
+///
+/// The mapping is given with the port `mapping` as a `,` separated list of `child_state=result_state`,
+/// it is parsed once when the behavior starts.
+/// - A child state without mapping is returned unchanged.
+/// - `Idle` can neither be mapped nor be the result of a mapping.
+/// - If the child is completed, it is halted.
+/// - If `Running` is mapped to another state, the still running child is halted,
+///   so it will start anew with the next tick of this behavior.
+///
+/// With that the behavior can replace `Inverter`, `ForceFailure` and `ForceSuccess`.
+///
+/// The behavior is gated behind feature `map_state`.
+///
+/// Example:
+///
+/// ```xml
+/// <MapState mapping="success=failure,failure=success,running=failure">
+///    <Behavior/>
+/// </MapState>
+/// ```
+#[derive(Decorator, Debug, Default)]
+pub struct MapState {
+	/// The mapping indexed by the childs state.
+	mapping: [Option<BehaviorState>; 4],
+}
+
+impl InlineBehavior for MapState {
+	fn on_start(
+		&mut self,
+		behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> Result<(), BehaviorError> {
+		let mapping = behavior
+			.get::<String>(MAPPING)
+			.unwrap_or_default();
+		self.mapping = parse_mapping(&mapping)?;
+		behavior.set_state(BehaviorState::Running);
+		Ok(())
+	}
+
+	async fn tick(
+		&mut self,
+		_behavior: &mut BehaviorData,
+		children: &mut BehaviorTreeElementList,
+		runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		let child = &mut children[0];
+		let new_state = child.tick(runtime).await?;
+
+		let Some(idx) = index(new_state) else {
+			return Err(BehaviorError::State {
+				behavior: "MapState".into(),
+				state: new_state,
+			});
+		};
+		let state = self.mapping[idx].unwrap_or(new_state);
+		if new_state.is_completed() || (new_state == BehaviorState::Running && state != BehaviorState::Running) {
+			children.halt(runtime)?;
+		}
+		Ok(state)
+	}
+
+	fn provided_ports() -> PortList {
+		port_list![input_port!(String, MAPPING)]
+	}
+}
+// endregion:   --- MapState
@@ -5,6 +5,8 @@
 mod delay;
 #[cfg(feature = "entry_updated")]
 mod entry_updated;
+#[cfg(feature = "for_each")]
+mod for_each;
 #[cfg(feature = "force_state")]
 mod force_state;
 #[cfg(feature = "inverter")]
@@ -13,6 +15,8 @@ mod inverter;
 mod keep_running_until_failure;
 #[cfg(feature = "loop_queue")]
 mod loop_queue;
+#[cfg(feature = "map_state")]
+mod map_state;
 #[cfg(feature = "precondition")]
 mod precondition;
 #[cfg(feature = "repeat")]
@@ -29,6 +33,8 @@ mod timeout;
 pub use delay::Delay;
 #[cfg(feature = "entry_updated")]
 pub use entry_updated::EntryUpdated;
+#[cfg(feature = "for_each")]
+pub use for_each::ForEach;
 #[cfg(feature = "force_state")]
 pub use force_state::ForceState;
 #[cfg(feature = "inverter")]
@@ -37,6 +43,8 @@ pub use inverter::Inverter;
 pub use keep_running_until_failure::KeepRunningUntilFailure;
 #[cfg(feature = "loop_queue")]
 pub use loop_queue::Loop;
+#[cfg(feature = "map_state")]
+pub use map_state::MapState;
 #[cfg(feature = "precondition")]
 pub use precondition::Precondition;
 #[cfg(feature = "repeat")]
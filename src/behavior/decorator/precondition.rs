@@ -2,19 +2,19 @@
 //! [`Precondition`] [`Decorator`] implementation.
 
 // region:      --- modules
+use crate::SharedRuntime;
 use crate::{
 	self as behaviortree, Decorator, EMPTY_STR,
-	behavior::{Behavior, BehaviorData, BehaviorError, BehaviorResult, BehaviorState},
+	behavior::{
+		BehaviorData, BehaviorError, BehaviorResult, BehaviorState, CompiledScript, InlineBehavior,
+		behavior_data::RemappedEnvironment, compiled_script::constant_port_value,
+	},
 	input_port,
 	port::PortList,
 	port_list,
 	tree::BehaviorTreeElementList,
 };
-use alloc::{
-	boxed::Box,
-	string::{String, ToString},
-};
-use tinyscript::SharedRuntime;
+use alloc::string::{String, ToString};
 // endregion:   --- modules
 
 // region:		--- globals
@@ -27,12 +27,36 @@ const IF: &str = "if";
 /// The `Precondition` behavior is used to check a scripted condition before
 /// executing its child.
 ///
+/// Identifiers in the scripts are resolved through the port remappings of the child,
+/// so a script can use the port names of the child. A port of the child, which is remapped
+/// to a blackboard entry, evaluates that entry, a port with a constant assignment evaluates the constant.
+/// Identifiers, which are no ports of the child, refer directly to blackboard entries.
+///
 /// The behavior is gated behind feature `precondition`.
+///
+/// The scripts are compiled at the creation of the tree and only compiled again, if they change.
 #[derive(Decorator, Default)]
-pub struct Precondition;
+pub struct Precondition {
+	/// The compiled `if` script.
+	if_script: CompiledScript,
+	/// The compiled `else` script, if `else` is no state.
+	else_script: CompiledScript,
+}
+
+impl InlineBehavior for Precondition {
+	fn on_creation(&mut self, behavior: &mut BehaviorData, runtime: &SharedRuntime) -> Result<(), BehaviorError> {
+		let runtime = &mut runtime.lock();
+		self.if_script
+			.compile_port(runtime, behavior, IF)?;
+		if let Some(else_branch) = constant_port_value(behavior, ELSE) {
+			let else_branch = else_branch.to_uppercase();
+			if else_branch.parse::<BehaviorState>().is_err() {
+				self.else_script.compile(runtime, &else_branch)?;
+			}
+		}
+		Ok(())
+	}
 
-#[async_trait::async_trait]
-impl Behavior for Precondition {
 	async fn tick(
 		&mut self,
 		behavior: &mut BehaviorData,
@@ -40,7 +64,11 @@ impl Behavior for Precondition {
 		runtime: &SharedRuntime,
 	) -> BehaviorResult {
 		let if_branch = behavior.get::<String>(IF)?;
-		let value = runtime.lock().run(&if_branch, behavior)?;
+		let value = self.if_script.run(
+			&mut runtime.lock(),
+			&if_branch,
+			&mut RemappedEnvironment::new(behavior, children[0].data().remappings()),
+		)?;
 
 		let new_state = {
 			let val = bool::try_from(value)?;
@@ -60,7 +88,11 @@ impl Behavior for Precondition {
 					"SKIPPED" => BehaviorState::Skipped,
 					"SUCCESS" => BehaviorState::Success,
 					_ => {
-						let value = runtime.lock().run(&else_branch, behavior)?;
+						let value = self.else_script.run(
+							&mut runtime.lock(),
+							&else_branch,
+							&mut RemappedEnvironment::new(behavior, children[0].data().remappings()),
+						)?;
 						if value.is_bool() {
 							let val = bool::try_from(value)?;
 							if val { BehaviorState::Success } else { BehaviorState::Failure }
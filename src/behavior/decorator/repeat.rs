@@ -2,16 +2,16 @@
 //! [`Repeat`] [`Decorator`] implementation.
 
 // region:      --- modules
+use crate::SharedRuntime;
 use crate::{
 	self as behaviortree, Decorator,
-	behavior::{Behavior, BehaviorData, BehaviorError, BehaviorResult, BehaviorState},
+	behavior::{BehaviorData, BehaviorError, BehaviorResult, BehaviorState, InlineBehavior},
 	input_port,
 	port::PortList,
 	port_list,
 	tree::BehaviorTreeElementList,
 };
-use alloc::{boxed::Box, string::ToString};
-use tinyscript::SharedRuntime;
+use alloc::string::ToString;
 // endregion:   --- modules
 
 // region:		--- globals
@@ -37,8 +37,7 @@ pub struct Repeat {
 	repeat_count: i32,
 }
 
-#[async_trait::async_trait]
-impl Behavior for Repeat {
+impl InlineBehavior for Repeat {
 	#[inline]
 	fn on_halt(&mut self) -> Result<(), BehaviorError> {
 		self.repeat_count = 0;
@@ -62,13 +61,11 @@ impl Behavior for Repeat {
 					children.halt(runtime)?;
 					Ok(BehaviorState::Failure)
 				}
-				BehaviorState::Idle => {
-					return Err(BehaviorError::State {
-						behavior: "Repeat".into(),
-						state: new_state,
-					});
-				}
-				BehaviorState::Running => return Ok(BehaviorState::Running),
+				BehaviorState::Idle => Err(BehaviorError::State {
+					behavior: "Repeat".into(),
+					state: new_state,
+				}),
+				BehaviorState::Running => Ok(BehaviorState::Running),
 				BehaviorState::Skipped => {
 					children.halt(runtime)?;
 					Ok(BehaviorState::Skipped)
@@ -2,16 +2,16 @@
 //! [`RetryUntilSuccessful`] [`Decorator`] implementation.
 
 // region:      --- modules
+use crate::SharedRuntime;
 use crate::{
 	self as behaviortree, Decorator,
-	behavior::{Behavior, BehaviorData, BehaviorError, BehaviorResult, BehaviorState},
+	behavior::{BehaviorData, BehaviorError, BehaviorResult, BehaviorState, InlineBehavior},
 	input_port,
 	port::PortList,
 	port_list,
 	tree::BehaviorTreeElementList,
 };
-use alloc::{boxed::Box, string::ToString};
-use tinyscript::SharedRuntime;
+use alloc::string::ToString;
 // endregion:   --- modules
 
 // region:		--- globals
@@ -42,8 +42,7 @@ const NUM_ATTEMPTS: &str = "num_attempts";
 #[derive(Decorator, Debug, Default)]
 pub struct RetryUntilSuccessful;
 
-#[async_trait::async_trait]
-impl Behavior for RetryUntilSuccessful {
+impl InlineBehavior for RetryUntilSuccessful {
 	async fn tick(
 		&mut self,
 		behavior: &mut BehaviorData,
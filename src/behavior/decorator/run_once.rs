@@ -2,16 +2,16 @@
 //! [`RunOnce`] [`Decorator`] implementation.
 
 // region:      --- modules
+use crate::SharedRuntime;
 use crate::{
 	self as behaviortree, Decorator,
-	behavior::{Behavior, BehaviorData, BehaviorError, BehaviorResult, BehaviorState},
+	behavior::{BehaviorData, BehaviorError, BehaviorResult, BehaviorState, InlineBehavior},
 	input_port,
 	port::PortList,
 	port_list,
 	tree::BehaviorTreeElementList,
 };
-use alloc::{boxed::Box, string::ToString};
-use tinyscript::SharedRuntime;
+use alloc::string::ToString;
 // endregion:   --- modules
 
 // region:		--- globals
@@ -30,8 +30,7 @@ pub struct RunOnce {
 	state: BehaviorState,
 }
 
-#[async_trait::async_trait]
-impl Behavior for RunOnce {
+impl InlineBehavior for RunOnce {
 	#[inline]
 	fn on_halt(&mut self) -> Result<(), BehaviorError> {
 		self.already_ticked = false;
@@ -5,21 +5,19 @@
 extern crate std;
 
 // region:      --- modules
+use crate::SharedRuntime;
 use crate::{
 	self as behaviortree, Decorator, EMPTY_STR,
-	behavior::{Behavior, BehaviorData, BehaviorError, BehaviorResult, BehaviorState},
+	behavior::{BehaviorData, BehaviorError, BehaviorResult, BehaviorState, InlineBehavior},
 	input_port,
 	port::PortList,
 	port_list,
 	tree::BehaviorTreeElementList,
 };
-use alloc::{boxed::Box, string::ToString};
-use tinyscript::SharedRuntime;
+use alloc::string::ToString;
 
 #[cfg(feature = "std")]
 use core::time::Duration;
-#[cfg(feature = "std")]
-use std::time::Instant;
 //endregion:    --- modules
 
 // region:		--- globals
@@ -34,11 +32,10 @@ const MSEC: &str = "msec";
 #[derive(Decorator, Debug, Default)]
 pub struct Timeout {
 	#[cfg(feature = "std")]
-	start_time: Option<Instant>,
+	start_time: Option<Duration>,
 }
 
-#[async_trait::async_trait]
-impl Behavior for Timeout {
+impl InlineBehavior for Timeout {
 	#[inline]
 	fn on_halt(&mut self) -> Result<(), BehaviorError> {
 		#[cfg(feature = "std")]
@@ -56,7 +53,7 @@ impl Behavior for Timeout {
 	) -> Result<(), BehaviorError> {
 		#[cfg(feature = "std")]
 		{
-			self.start_time = Some(Instant::now());
+			self.start_time = Some(behavior.now());
 		}
 
 		behavior.set_state(BehaviorState::Running);
@@ -84,7 +81,7 @@ impl Behavior for Timeout {
 				self.start_time = None;
 				children.halt(runtime)?;
 				Ok(state)
-			} else if Instant::now().duration_since(*start) > Duration::from_millis(millis) {
+			} else if behavior.now().saturating_sub(*start) > Duration::from_millis(millis) {
 				children[0].halt_children(runtime)?;
 				self.start_time = None;
 				Ok(BehaviorState::Failure)
@@ -17,11 +17,23 @@ pub enum Error {
 		/// The textual error message.
 		txt: ConstString,
 	},
+	/// A [`Condition`](super::BehaviorKind::Condition) returned [`BehaviorState::Running`], but conditions must be instantaneous
+	ConditionReturnedRunning {
+		/// Path of the affected node
+		node: ConstString,
+	},
 	/// Pass through errors from databoard
 	Databoard {
 		/// The source error
 		source: databoard::Error,
 	},
+	/// A behavior returned a [`BehaviorState`] from a tick which is not allowed, e.g. `Idle`
+	IllegalTickResult {
+		/// Path of the affected node
+		node: ConstString,
+		/// The returned state
+		state: BehaviorState,
+	},
 	/// Pass through errors from nanoserde
 	Nanoserde {
 		/// The source error
@@ -56,6 +68,11 @@ pub enum Error {
 		/// The wanted data type
 		typ: ConstString,
 	},
+	/// An [`InlineBehavior`](super::InlineBehavior) did not complete within a single poll
+	Pending {
+		/// Path of the affected node
+		node: ConstString,
+	},
 	/// Pass through errors from `crate::port`
 	Port {
 		/// The port error
@@ -69,6 +86,7 @@ pub enum Error {
 		behavior: ConstString,
 	},
 	/// Pass through errors from tinyscript
+	#[cfg(feature = "scripting")]
 	Scripting {
 		/// The scripting eror
 		source: tinyscript::Error,
@@ -104,15 +122,19 @@ impl core::fmt::Debug for Error {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		match self {
 			Self::Composition { txt } => write!(f, "Composition({txt})"),
+			Self::ConditionReturnedRunning { node } => write!(f, "ConditionReturnedRunning(node: {node})"),
 			Self::Databoard { source } => write!(f, "Databoard({source})"),
+			Self::IllegalTickResult { node, state } => write!(f, "IllegalTickResult(node: {node}, state: {state})"),
 			Self::Nanoserde { source } => write!(f, "Nanoserde({source})"),
 			Self::NoCondition { value } => write!(f, "NoCondition(value: {value})"),
 			Self::NotABool { value } => write!(f, "NotABool(value: {value})"),
 			Self::ParseError { value, src } => write!(f, "ParseError(value: {value}, src: {src})"),
 			Self::ParseInt { source } => write!(f, "ParseInt({source})"),
 			Self::ParsePortValue { port, typ } => write!(f, "ParsePort(port: {port}, type: {typ})"),
+			Self::Pending { node } => write!(f, "Pending(node: {node})"),
 			Self::Port { source } => write!(f, "Port({source})"),
 			Self::PortNotDeclared { port, behavior } => write!(f, "PortNotDeclared(port: {port}, behavior: {behavior})"),
+			#[cfg(feature = "scripting")]
 			Self::Scripting { source } => write!(f, "Scripting({source})"),
 			Self::State { behavior, state } => write!(f, "State(behavior: {behavior}, state: {state})"),
 			Self::UnableToSetCondition { value } => write!(f, "UnableToSetCondition(value: {value})"),
@@ -124,7 +146,9 @@ impl core::fmt::Display for Error {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		match self {
 			Self::Composition { txt } => write!(f, "behavior composition error: {txt}"),
+			Self::ConditionReturnedRunning { node } => write!(f, "condition {node} returned Running, but must not"),
 			Self::Databoard { source } => write!(f, "a blackboard error occured: {source}"),
+			Self::IllegalTickResult { node, state } => write!(f, "behavior {node} returned illegal state {state} from tick"),
 			Self::Nanoserde { source } => write!(f, "a deserialization error occured: {source}"),
 			Self::NoCondition { value } => write!(f, "the attribute '{value}' is no pre or post condition"),
 			Self::NotABool { value } => write!(f, "value {value} is not a boolean type"),
@@ -133,8 +157,10 @@ impl core::fmt::Display for Error {
 			Self::ParsePortValue { port, typ } => {
 				write!(f, "could not parse value for port {port} into specified type {typ}")
 			}
+			Self::Pending { node } => write!(f, "inline behavior {node} is pending, but must complete within one poll"),
 			Self::Port { source } => write!(f, "a port error occured: {source}"),
 			Self::PortNotDeclared { port, behavior } => write!(f, "port {port} is not declared in behavior {behavior}"),
+			#[cfg(feature = "scripting")]
 			Self::Scripting { source } => write!(f, "a scripting error occured: {source}"),
 			Self::State { behavior, state } => {
 				write!(f, "child node of  {behavior} returned state {state} when not allowed")
@@ -161,6 +187,7 @@ impl From<databoard::Error> for Error {
 	}
 }
 
+#[cfg(feature = "scripting")]
 impl From<tinyscript::Error> for Error {
 	fn from(source: tinyscript::Error) -> Self {
 		Self::Scripting { source }
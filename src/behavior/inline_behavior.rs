@@ -0,0 +1,259 @@
+// Copyright © 2025 Stephan Kunz
+//! [`InlineBehavior`] implementation.
+
+#[doc(hidden)]
+extern crate alloc;
+
+// region:      --- modules
+use crate::SharedRuntime;
+use crate::{
+	behavior::{Behavior, BehaviorData, BehaviorError, BehaviorResult, BehaviorState},
+	port::PortList,
+	tree::BehaviorTreeElementList,
+};
+use alloc::boxed::Box;
+use core::{
+	pin::pin,
+	task::{Context, Poll, Waker},
+};
+// endregion:   --- modules
+
+// region:		--- InlineBehavior
+/// Companion trait to [`Behavior`] using native `-> impl Future` methods instead of `async_trait`.
+///
+/// The futures of an `InlineBehavior` are not boxed, the tree polls them in place.
+/// That avoids one heap allocation per `start()`/`tick()` of the behavior,
+/// but requires the returned futures to complete within a single poll.
+/// Long lasting operations have to return [`BehaviorState::Running`] instead of awaiting their completion.
+/// A future which is still pending after the first poll results in [`BehaviorError::Pending`].
+///
+/// The futures are only polled in place, if all behaviors below are `InlineBehavior`s as well.
+/// Otherwise the futures are boxed like those of a [`Behavior`], so that children may await.
+///
+/// [`Behavior`] is implemented for every `InlineBehavior`,
+/// so the derive macros and the registration work as for any other behavior.
+/// The trait is not part of the prelude, as with both traits in scope
+/// calls like `MyAction::provided_ports()` are ambiguous.
+///
+/// Example:
+/// ```no_test
+/// #[derive(Action, Debug, Default)]
+/// struct MyAction;
+///
+/// impl InlineBehavior for MyAction {
+///     async fn tick(
+///         &mut self,
+///         behavior: &mut BehaviorData,
+///         children: &mut BehaviorTreeElementList,
+///         runtime: &SharedRuntime,
+///     ) -> BehaviorResult {
+///         Ok(BehaviorState::Success)
+///     }
+/// }
+/// ```
+pub trait InlineBehavior: Send + Sync {
+	/// Method called during stop/cancel/halt of a behavior.
+	/// See [`Behavior::on_halt`].
+	/// # Errors
+	/// - if something prevents stopping the behavior properly
+	#[inline]
+	fn on_halt(&mut self) -> Result<(), BehaviorError> {
+		Ok(())
+	}
+
+	/// Method called once, when the behavior has been created as part of a tree.
+	/// See [`Behavior::on_creation`].
+	/// # Errors
+	/// - if the configuration of the behavior is invalid
+	#[inline]
+	fn on_creation(&mut self, _behavior: &mut BehaviorData, _runtime: &SharedRuntime) -> Result<(), BehaviorError> {
+		Ok(())
+	}
+
+	/// Method is called before starting to tick a behavior.
+	/// See [`Behavior::on_start`].
+	/// # Errors
+	/// - if something prevents starting the behavior properly
+	#[inline]
+	fn on_start(
+		&mut self,
+		_behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> Result<(), BehaviorError> {
+		Ok(())
+	}
+
+	/// Method is called on first tick of a behavior instead of `tick()`.
+	/// See [`Behavior::start`].
+	/// # Errors
+	/// - if something prevents starting the behavior properly
+	#[inline]
+	fn start(
+		&mut self,
+		behavior: &mut BehaviorData,
+		children: &mut BehaviorTreeElementList,
+		runtime: &SharedRuntime,
+	) -> impl Future<Output = BehaviorResult> + Send {
+		async move {
+			self.on_start(behavior, children, runtime)?;
+			self.tick(behavior, children, runtime).await
+		}
+	}
+
+	/// Method to tick a behavior.
+	/// # Errors
+	fn tick(
+		&mut self,
+		behavior: &mut BehaviorData,
+		children: &mut BehaviorTreeElementList,
+		runtime: &SharedRuntime,
+	) -> impl Future<Output = BehaviorResult> + Send;
+
+	/// Method to halt a behavior.
+	/// See [`Behavior::halt`].
+	/// # Errors
+	#[inline]
+	fn halt(
+		&mut self,
+		_behavior: &mut BehaviorData,
+		children: &mut BehaviorTreeElementList,
+		runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		children.halt(runtime)?;
+		self.on_halt()?;
+		Ok(BehaviorState::Idle)
+	}
+
+	/// See [`Behavior::is_reactive`].
+	#[must_use]
+	#[inline]
+	fn is_reactive(&self) -> bool {
+		false
+	}
+
+	/// See [`Behavior::is_boolean`].
+	#[must_use]
+	#[inline]
+	fn is_boolean(&self) -> bool {
+		false
+	}
+
+	/// Provide the list of defined ports.
+	/// Default implementation returns an empty list.
+	#[must_use]
+	#[inline]
+	fn provided_ports() -> PortList
+	where
+		Self: Sized,
+	{
+		PortList::default()
+	}
+}
+
+/// Polls the `future` once in place.
+fn poll_once<F: Future>(future: F) -> Option<F::Output> {
+	let mut future = pin!(future);
+	let mut context = Context::from_waker(Waker::noop());
+	match future.as_mut().poll(&mut context) {
+		Poll::Ready(output) => Some(output),
+		Poll::Pending => None,
+	}
+}
+
+#[async_trait::async_trait]
+impl<T: InlineBehavior> Behavior for T {
+	#[inline]
+	fn on_halt(&mut self) -> Result<(), BehaviorError> {
+		InlineBehavior::on_halt(self)
+	}
+
+	#[inline]
+	fn on_creation(&mut self, behavior: &mut BehaviorData, runtime: &SharedRuntime) -> Result<(), BehaviorError> {
+		InlineBehavior::on_creation(self, behavior, runtime)
+	}
+
+	#[inline]
+	fn on_start(
+		&mut self,
+		behavior: &mut BehaviorData,
+		children: &mut BehaviorTreeElementList,
+		runtime: &SharedRuntime,
+	) -> Result<(), BehaviorError> {
+		InlineBehavior::on_start(self, behavior, children, runtime)
+	}
+
+	#[inline]
+	async fn start(
+		&mut self,
+		behavior: &mut BehaviorData,
+		children: &mut BehaviorTreeElementList,
+		runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		InlineBehavior::start(self, behavior, children, runtime).await
+	}
+
+	#[inline]
+	async fn tick(
+		&mut self,
+		behavior: &mut BehaviorData,
+		children: &mut BehaviorTreeElementList,
+		runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		InlineBehavior::tick(self, behavior, children, runtime).await
+	}
+
+	#[inline]
+	fn is_inline(&self) -> bool {
+		true
+	}
+
+	#[inline]
+	fn tick_inline(
+		&mut self,
+		behavior: &mut BehaviorData,
+		children: &mut BehaviorTreeElementList,
+		runtime: &SharedRuntime,
+		start: bool,
+	) -> Option<BehaviorResult> {
+		let result = if start {
+			poll_once(InlineBehavior::start(self, behavior, children, runtime))
+		} else {
+			poll_once(InlineBehavior::tick(self, behavior, children, runtime))
+		};
+		Some(result.unwrap_or_else(|| {
+			Err(BehaviorError::Pending {
+				node: behavior.description().path().clone(),
+			})
+		}))
+	}
+
+	#[inline]
+	fn halt(
+		&mut self,
+		behavior: &mut BehaviorData,
+		children: &mut BehaviorTreeElementList,
+		runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		InlineBehavior::halt(self, behavior, children, runtime)
+	}
+
+	#[inline]
+	fn is_reactive(&self) -> bool {
+		InlineBehavior::is_reactive(self)
+	}
+
+	#[inline]
+	fn is_boolean(&self) -> bool {
+		InlineBehavior::is_boolean(self)
+	}
+
+	#[inline]
+	fn provided_ports() -> PortList
+	where
+		Self: Sized,
+	{
+		<Self as InlineBehavior>::provided_ports()
+	}
+}
+// endregion:	--- InlineBehavior
@@ -4,15 +4,13 @@
 #[cfg(feature = "std")]
 extern crate std;
 
-use super::{Behavior, BehaviorCreationFn, BehaviorExecution, BehaviorResult, BehaviorState};
+use super::{BehaviorCreationFn, BehaviorExecution, BehaviorResult, BehaviorState, InlineBehavior};
+use crate::SharedRuntime;
 use crate::{
 	BehaviorError, BehaviorKind, ConstString, behavior::BehaviorData, port::PortList, tree::BehaviorTreeElementList,
 };
 use alloc::{boxed::Box, sync::Arc};
 use core::{any::Any, time::Duration};
-#[cfg(feature = "std")]
-use std::time::Instant;
-use tinyscript::SharedRuntime;
 
 // region:		--- MockBehaviorConfig
 /// Configuration for the [`MockBehavior`].
@@ -72,7 +70,7 @@ pub struct MockBehavior {
 	config: MockBehaviorConfig,
 	port_list: PortList,
 	#[cfg(feature = "std")]
-	start_time: Option<Instant>,
+	start_time: Option<Duration>,
 }
 
 impl BehaviorExecution for MockBehavior {
@@ -96,8 +94,7 @@ impl BehaviorExecution for MockBehavior {
 	}
 }
 
-#[async_trait::async_trait]
-impl Behavior for MockBehavior {
+impl InlineBehavior for MockBehavior {
 	fn on_halt(&mut self) -> Result<(), BehaviorError> {
 		#[cfg(feature = "std")]
 		{
@@ -122,7 +119,7 @@ impl Behavior for MockBehavior {
 			// remember start time
 			#[cfg(feature = "std")]
 			{
-				self.start_time = Some(Instant::now());
+				self.start_time = Some(behavior.now());
 				Ok(BehaviorState::Running)
 			}
 			#[cfg(not(feature = "std"))]
@@ -145,7 +142,7 @@ impl Behavior for MockBehavior {
 		if let Some(delay) = &self.config.async_delay
 			&& let Some(start) = &self.start_time
 		{
-			if Instant::now().duration_since(*start) > *delay {
+			if behavior.now().saturating_sub(*start) > *delay {
 				self.start_time = None;
 				self.completed(behavior, runtime)
 			} else {
@@ -193,6 +190,8 @@ impl MockBehavior {
 
 	#[allow(clippy::unnecessary_wraps)]
 	/// Returns the result state considering all configuration assets.
+	/// Without scripting the scripts are ignored.
+	#[cfg_attr(not(feature = "scripting"), allow(unused_variables))]
 	fn completed(&self, behavior: &mut BehaviorData, runtime: &SharedRuntime) -> BehaviorResult {
 		let state = self
 			.config
@@ -201,6 +200,7 @@ impl MockBehavior {
 			.map_or(self.config.return_state, |func| func());
 
 		// success or failure script set?
+		#[cfg(feature = "scripting")]
 		if state == BehaviorState::Success
 			&& let Some(script) = &self.config.success_script
 		{
@@ -212,6 +212,7 @@ impl MockBehavior {
 		}
 
 		// post script set?
+		#[cfg(feature = "scripting")]
 		if let Some(script) = &self.config.post_script {
 			let _result = runtime.lock().run(script, behavior)?;
 		}
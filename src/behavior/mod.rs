@@ -4,10 +4,13 @@
 pub mod action;
 pub mod behavior_data;
 pub mod behavior_description;
+#[cfg(feature = "scripting")]
+mod compiled_script;
 pub mod condition;
 pub mod control;
 pub mod decorator;
 pub mod error;
+mod inline_behavior;
 #[cfg(feature = "mock_behavior")]
 pub mod mock_behavior;
 pub mod pre_post_conditions;
@@ -17,16 +20,20 @@ mod simple_behavior;
 mod sub_tree;
 
 // flatten
+#[cfg(feature = "scripting")]
+pub(crate) use compiled_script::CompiledScript;
 pub use error::Error as BehaviorError;
+pub use inline_behavior::InlineBehavior;
 #[cfg(feature = "mock_behavior")]
 pub use mock_behavior::{MockBehavior, MockBehaviorConfig};
 use nanoserde::DeJson;
 pub use shared_queue::SharedQueue;
 #[cfg(feature = "simple_behavior")]
-pub use simple_behavior::{ComplexBhvrTickFn, SimpleBehavior, SimpleBhvrTickFn};
+pub use simple_behavior::{AsyncBhvrTickFn, ComplexBhvrTickFn, SimpleBehavior, SimpleBhvrTickFn};
 pub use sub_tree::SubTree;
 
 // region:      --- modules
+use crate::SharedRuntime;
 use crate::{
 	ACTION, CONDITION, CONTROL, DECORATOR, FAILURE, IDLE, RUNNING, SKIPPED, SUBTREE, SUCCESS,
 	behavior::{behavior_data::BehaviorData, behavior_description::BehaviorDescription, pre_post_conditions::Conditions},
@@ -37,7 +44,6 @@ use crate::{
 use alloc::{boxed::Box, string::String};
 use core::any::Any;
 use databoard::{Databoard, Remappings};
-use tinyscript::SharedRuntime;
 // endregion:   --- modules
 
 // region:		--- types
@@ -68,6 +74,8 @@ pub(crate) struct BehaviorDataCollection<'a> {
 	pub remappings: Remappings,
 	pub conditions: Conditions,
 	pub uid: u16,
+	pub stable_id: Option<u64>,
+	pub autoremap: bool,
 	pub registry: &'a BehaviorRegistry,
 }
 // endregion:	--- BehaviorDataCollection
@@ -75,7 +83,7 @@ pub(crate) struct BehaviorDataCollection<'a> {
 // region:      --- BehaviorExecution
 /// Supertrait for execution of a [`Behavior`].
 /// The contained functions are generated by the derive macros.
-pub trait BehaviorExecution: Any + Behavior {
+pub trait BehaviorExecution: Any + Behavior + DynamicKind {
 	/// Dynamic downcasting.
 	fn as_any(&self) -> &dyn Any;
 
@@ -95,11 +103,52 @@ pub trait BehaviorExecution: Any + Behavior {
 	/// Get the `static` list of defined ports.
 	fn static_provided_ports(&self) -> PortList;
 }
+
+/// Compile time check, that `T` is usable as a behavior.
+///
+/// Behaviors are moved between threads, so they must be [`Send`] and [`Sync`].
+/// A call like `const _: () = assert_behavior::<MyBehavior>();` reports a missing bound
+/// directly at the type instead of at registration of the behavior.
+/// The derive macros emit this check for non generic types.
+pub const fn assert_behavior<T: BehaviorExecution + Send + Sync>() {}
+
+/// Access to the [`BehaviorKind`] of a behavior behind a [`BehaviorPtr`].
+/// It is implemented for every [`BehaviorExecution`].
+pub trait DynamicKind {
+	/// Get the [`BehaviorKind`] of the behavior.
+	fn dynamic_kind(&self) -> BehaviorKind;
+}
+
+impl<T: BehaviorExecution> DynamicKind for T {
+	#[inline]
+	fn dynamic_kind(&self) -> BehaviorKind {
+		T::kind()
+	}
+}
 // endregion:   --- BehaviorExecution
 
+// region:		--- FromConfig
+/// Construction of a behavior from a JSON configuration.
+///
+/// A type implementing this trait can be registered with
+/// [`BehaviorTreeFactory::register_configurable_type`](crate::factory::BehaviorTreeFactory::register_configurable_type)
+/// and then be used for any number of behaviors registered with
+/// [`BehaviorTreeFactory::register_behavior_from_json`](crate::factory::BehaviorTreeFactory::register_behavior_from_json).
+pub trait FromConfig: BehaviorExecution + Sized {
+	/// The construction parameters, deserialized from JSON.
+	type Config: DeJson + Clone + Send + Sync + 'static;
+
+	/// Create a new instance of the behavior from its `config`.
+	fn from_config(config: &Self::Config) -> Self;
+}
+// endregion:	--- FromConfig
+
 // region:		--- Behavior
 /// Defines the methods common to all behaviors.
 /// These methods are available when traversing a behavior tree.
+///
+/// The async methods are implemented via `async_trait`, which boxes the returned futures.
+/// To avoid these allocations implement the companion trait [`InlineBehavior`] instead.
 #[async_trait::async_trait]
 pub trait Behavior: Send + Sync {
 	/// Method called during stop/cancel/halt of a behavior,
@@ -116,6 +165,20 @@ pub trait Behavior: Send + Sync {
 		Ok(())
 	}
 
+	/// Method called once, when the behavior has been created as part of a tree,
+	/// intended to check and prepare the configuration of your behavior, e.g. to compile scripts.
+	///
+	/// Only constant port values are available, blackboard entries may not yet exist.
+	/// An error lets the creation of the tree fail.
+	///
+	/// Default implementation does nothing.
+	/// # Errors
+	/// - if the configuration of the behavior is invalid
+	#[inline]
+	fn on_creation(&mut self, _behavior: &mut BehaviorData, _runtime: &SharedRuntime) -> Result<(), BehaviorError> {
+		Ok(())
+	}
+
 	/// Method is called before starting to tick a behavior,
 	/// intended to do preliminary stuff for your behavior.
 	///
@@ -161,6 +224,29 @@ pub trait Behavior: Send + Sync {
 		runtime: &SharedRuntime,
 	) -> BehaviorResult;
 
+	/// Returns whether the behavior is an [`InlineBehavior`], which can be ticked by `tick_inline()`.
+	#[doc(hidden)]
+	#[must_use]
+	#[inline]
+	fn is_inline(&self) -> bool {
+		false
+	}
+
+	/// Starts or ticks the behavior without boxing the future, if the behavior is an [`InlineBehavior`].
+	///
+	/// Default implementation returns `None`, the tree then uses `start()` or `tick()`.
+	#[doc(hidden)]
+	#[inline]
+	fn tick_inline(
+		&mut self,
+		_behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+		_start: bool,
+	) -> Option<BehaviorResult> {
+		None
+	}
+
 	/// Method to halt a behavior.
 	/// # Errors
 	#[inline]
@@ -175,6 +261,28 @@ pub trait Behavior: Send + Sync {
 		Ok(BehaviorState::Idle)
 	}
 
+	/// Returns `true` if the behavior is reactive, ticking its already completed children again on every tick,
+	/// e.g. to re-check conditions while a later child is running.
+	/// Tooling can use this to warn about expensive children of a reactive control.
+	///
+	/// Default implementation returns `false`.
+	#[must_use]
+	#[inline]
+	fn is_reactive(&self) -> bool {
+		false
+	}
+
+	/// Returns `true` if the behavior is a boolean control, which evaluates its children with short-circuit
+	/// and never returns [`BehaviorState::Running`].
+	/// The tree creation rejects children of a boolean control, which are neither conditions nor boolean controls.
+	///
+	/// Default implementation returns `false`.
+	#[must_use]
+	#[inline]
+	fn is_boolean(&self) -> bool {
+		false
+	}
+
 	/// Provide the list of defined ports.
 	/// Default implementation returns an empty list.
 	#[must_use]
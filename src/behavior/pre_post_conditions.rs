@@ -3,7 +3,9 @@
 
 // region		--- modules
 use super::error::Error as BehaviorError;
-use crate::{ConstString, FAILURE_IF, ON_FAILURE, ON_HALTED, ON_SUCCESS, POST, SKIP_IF, SUCCESS_IF, WHILE};
+use crate::{
+	ConstString, FAILURE_IF, ON_ENTER, ON_EXIT, ON_FAILURE, ON_HALTED, ON_SUCCESS, POST, SKIP_IF, SUCCESS_IF, WHILE,
+};
 use core::ops::{Deref, DerefMut};
 // endregion:	--- modules
 
@@ -18,7 +20,8 @@ pub struct Conditions {
 
 // region:      --- PreConditions
 /// Names and order of the `PreConditions`.
-pub const PRE_CONDITIONS: [&str; 4] = [FAILURE_IF, SUCCESS_IF, SKIP_IF, WHILE];
+/// `_on_enter` is only available for subtrees.
+pub const PRE_CONDITIONS: [&str; 5] = [FAILURE_IF, SUCCESS_IF, SKIP_IF, WHILE, ON_ENTER];
 
 /// Array holding the pre conditions.
 #[derive(Default)]
@@ -60,7 +63,7 @@ impl PreConditions {
 	pub fn set(&mut self, name: &str, script: &str) -> Result<(), BehaviorError> {
 		// lazy init
 		if self.0.is_none() {
-			self.0 = Some([None, None, None, None]);
+			self.0 = Some([None, None, None, None, None]);
 		}
 
 		let op = (0..PRE_CONDITIONS.len()).find(|&i| PRE_CONDITIONS[i] == name);
@@ -81,7 +84,8 @@ impl PreConditions {
 
 // region:      --- PostConditions
 /// Names and order of the `PostConditions`.
-pub const POST_CONDITIONS: [&str; 4] = [ON_HALTED, ON_FAILURE, ON_SUCCESS, POST];
+/// `_on_exit` is only available for subtrees.
+pub const POST_CONDITIONS: [&str; 5] = [ON_HALTED, ON_FAILURE, ON_SUCCESS, POST, ON_EXIT];
 
 /// Array holding the post conditions.
 #[derive(Default)]
@@ -123,7 +127,7 @@ impl PostConditions {
 	pub fn set(&mut self, name: &str, script: &str) -> Result<(), BehaviorError> {
 		// lazy init
 		if self.0.is_none() {
-			self.0 = Some([None, None, None, None]);
+			self.0 = Some([None, None, None, None, None]);
 		}
 
 		let op = (0..POST_CONDITIONS.len()).find(|&i| POST_CONDITIONS[i] == name);
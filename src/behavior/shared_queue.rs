@@ -15,6 +15,7 @@ use core::str::FromStr;
 
 // region:		--- SharedQueue
 /// Shared queue implementation for the behaviors
+/// - [`ConvertQueue<F, T>`](crate::behavior::action::ConvertQueue)
 /// - [`Loop<T>`](crate::behavior::decorator::Loop)
 /// - [`PopFromQueue<T>`](crate::behavior::action::PopFromQueue)
 #[derive(Debug, Default)]
@@ -2,10 +2,10 @@
 //! [`SimpleBehavior`]  implementation for registering functions as behavior.
 
 use super::{Behavior, BehaviorCreationFn, BehaviorExecution, BehaviorResult};
+use crate::SharedRuntime;
 use crate::{BehaviorKind, behavior::BehaviorData, port::PortList, tree::BehaviorTreeElementList};
 use alloc::{boxed::Box, sync::Arc};
-use core::any::Any;
-use tinyscript::SharedRuntime;
+use core::{any::Any, future::Future, pin::Pin};
 
 /// Signature of a simple registered behavior function called by `SimpleBehavior`'s tick
 pub type SimpleBhvrTickFn = Arc<dyn Fn() -> BehaviorResult + Send + Sync>;
@@ -13,6 +13,10 @@ pub type SimpleBhvrTickFn = Arc<dyn Fn() -> BehaviorResult + Send + Sync>;
 /// Signature of a registered behavior function called by `SimpleBehavior`'s tick
 pub type ComplexBhvrTickFn = Arc<dyn Fn(&mut BehaviorData) -> BehaviorResult + Send + Sync>;
 
+/// Signature of a registered async behavior function called by `SimpleBehavior`'s tick
+pub type AsyncBhvrTickFn =
+	Arc<dyn for<'a> Fn(&'a mut BehaviorData) -> Pin<Box<dyn Future<Output = BehaviorResult> + Send + 'a>> + Send + Sync>;
+
 /// A simple behavior
 #[derive(Default)]
 pub struct SimpleBehavior {
@@ -20,6 +24,8 @@ pub struct SimpleBehavior {
 	simple_tick_fn: Option<SimpleBhvrTickFn>,
 	/// The function to be called on tick if ports exist
 	complex_tick_fn: Option<ComplexBhvrTickFn>,
+	/// The async function to be called on tick if ports exist
+	async_tick_fn: Option<AsyncBhvrTickFn>,
 	/// List of provided ports
 	provided_ports: PortList,
 }
@@ -53,6 +59,9 @@ impl Behavior for SimpleBehavior {
 		_children: &mut BehaviorTreeElementList,
 		_runtime: &SharedRuntime,
 	) -> BehaviorResult {
+		if let Some(func) = &self.async_tick_fn {
+			return func(behavior).await;
+		}
 		self.complex_tick_fn.as_ref().map_or_else(
 			|| {
 				self.simple_tick_fn.as_ref().map_or_else(
@@ -77,6 +86,7 @@ impl SimpleBehavior {
 			Box::new(Self {
 				simple_tick_fn: Some(tick_fn.clone()),
 				complex_tick_fn: None,
+				async_tick_fn: None,
 				provided_ports: PortList::default(),
 			})
 		})
@@ -88,6 +98,19 @@ impl SimpleBehavior {
 			Box::new(Self {
 				simple_tick_fn: None,
 				complex_tick_fn: Some(tick_fn.clone()),
+				async_tick_fn: None,
+				provided_ports: port_list.clone(),
+			})
+		})
+	}
+
+	/// Create a `SimpleBehavior` with the given async function and list of ports
+	pub fn new_create_async_with_ports(tick_fn: AsyncBhvrTickFn, port_list: PortList) -> Box<BehaviorCreationFn> {
+		Box::new(move || {
+			Box::new(Self {
+				simple_tick_fn: None,
+				complex_tick_fn: None,
+				async_tick_fn: Some(tick_fn.clone()),
 				provided_ports: port_list.clone(),
 			})
 		})
@@ -2,16 +2,16 @@
 //! [`SubTree`]  implementation.
 
 // region:      --- modules
+use crate::SharedRuntime;
 use crate::{
 	behavior::{
-		Behavior, BehaviorData, BehaviorExecution, BehaviorKind, BehaviorResult, BehaviorState,
+		BehaviorData, BehaviorExecution, BehaviorKind, BehaviorResult, BehaviorState, InlineBehavior,
 		error::Error as BehaviorError,
 	},
 	port::PortList,
 	tree::BehaviorTreeElementList,
 };
 use alloc::boxed::Box;
-use tinyscript::SharedRuntime;
 // endregion:   --- modules
 
 // region:      --- SubTree
@@ -41,8 +41,7 @@ impl BehaviorExecution for SubTree {
 	}
 }
 
-#[async_trait::async_trait]
-impl Behavior for SubTree {
+impl InlineBehavior for SubTree {
 	#[inline]
 	fn on_start(
 		&mut self,
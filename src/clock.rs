@@ -0,0 +1,92 @@
+// Copyright © 2025 Stephan Kunz
+
+//! Clocks for the time based behaviors of [`behaviortree`](crate).
+
+#[doc(hidden)]
+#[cfg(feature = "std")]
+extern crate std;
+
+// region:      --- modules
+use crate::Mutex;
+use alloc::sync::Arc;
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::{sync::OnceLock, time::Instant};
+// endregion:   --- modules
+
+// region:		--- types
+/// The [`Clock`] used, if none is set in the factory.
+#[cfg(feature = "std")]
+pub(crate) type DefaultClock = SystemClock;
+/// The [`Clock`] used, if none is set in the factory.
+#[cfg(all(feature = "embassy", not(feature = "std")))]
+pub(crate) type DefaultClock = EmbassyClock;
+// endregion:	--- types
+
+// region:      --- Clock
+/// A monotonic clock consulted by the time based behaviors and [`BehaviorTree::tick_with_budget`](crate::tree::BehaviorTree::tick_with_budget).
+///
+/// The time based behaviors are [`Delay`](crate::behavior::decorator::Delay), [`Timeout`](crate::behavior::decorator::Timeout)
+/// and [`Sleep`](crate::behavior::action::Sleep), they need feature `std`.
+/// A clock is set with [`BehaviorTreeFactory::set_clock`](crate::factory::BehaviorTreeFactory::set_clock),
+/// the default is the `SystemClock`, without feature `std` the `EmbassyClock` of feature `embassy`.
+pub trait Clock: Send + Sync {
+	/// Returns the current time as duration since an arbitrary but fixed point in time.
+	fn now(&self) -> Duration;
+}
+// endregion:   --- Clock
+
+// region:      --- SystemClock
+/// The [`Clock`] using the monotonic system time.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+	fn now(&self) -> Duration {
+		static EPOCH: OnceLock<Instant> = OnceLock::new();
+		EPOCH.get_or_init(Instant::now).elapsed()
+	}
+}
+// endregion:   --- SystemClock
+
+// region:      --- EmbassyClock
+/// The [`Clock`] using the monotonic time of [`embassy_time`], e.g. on microcontrollers without `std`.
+///
+/// The application has to provide an `embassy-time` driver, as usual for `embassy` based firmware.
+/// The clock is gated behind feature `embassy`.
+#[cfg(feature = "embassy")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EmbassyClock;
+
+#[cfg(feature = "embassy")]
+impl Clock for EmbassyClock {
+	fn now(&self) -> Duration {
+		Duration::from_micros(embassy_time::Instant::now().as_micros())
+	}
+}
+// endregion:   --- EmbassyClock
+
+// region:      --- ManualClock
+/// A logical [`Clock`], which only advances when told so.
+///
+/// Clones share the time, so a clone given to the factory can be advanced from outside,
+/// e.g. to test time based behaviors deterministically without real waiting.
+#[derive(Clone, Debug, Default)]
+pub struct ManualClock(Arc<Mutex<Duration>>);
+
+impl ManualClock {
+	/// Advances the clock by `duration`.
+	pub fn advance(&self, duration: Duration) {
+		let mut now = self.0.lock();
+		*now = now.saturating_add(duration);
+	}
+}
+
+impl Clock for ManualClock {
+	fn now(&self) -> Duration {
+		*self.0.lock()
+	}
+}
+// endregion:   --- ManualClock
@@ -76,6 +76,15 @@ impl From<crate::tree::error::Error> for Error {
 	}
 }
 
+impl From<crate::xml::error::Error> for Error {
+	fn from(source: crate::xml::error::Error) -> Self {
+		Self {
+			module: "xml",
+			source: Box::new(source),
+		}
+	}
+}
+
 impl From<databoard::Error> for Error {
 	fn from(source: databoard::Error) -> Self {
 		Self {
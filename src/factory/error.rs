@@ -7,6 +7,7 @@
 extern crate std;
 
 use crate::ConstString;
+use alloc::boxed::Box;
 
 /// `factory` error type
 #[non_exhaustive]
@@ -20,8 +21,10 @@ pub enum Error {
 	Create {
 		/// Name of the tree to create
 		name: ConstString,
+		/// Path of the element, in which the creation failed
+		path: ConstString,
 		/// The error from xml module
-		error: ConstString,
+		source: Box<crate::xml::error::Error>,
 	},
 	/// Invalid file path
 	#[cfg(feature = "std")]
@@ -53,6 +56,14 @@ pub enum Error {
 		/// Returned result code
 		code: u32,
 	},
+	/// Loading libraries from a plugin directory failed
+	#[cfg(feature = "std")]
+	RegisterPlugins {
+		/// The plugin directory
+		path: ConstString,
+		/// The collected errors
+		errors: ConstString,
+	},
 	/// Registration of an XML failed
 	RegisterXml {
 		/// Name of the tree to create
@@ -61,6 +72,7 @@ pub enum Error {
 		error: ConstString,
 	},
 	/// Passthrough for scripting Errors
+	#[cfg(feature = "scripting")]
 	Scripting {
 		/// Original error
 		source: tinyscript::Error,
@@ -77,8 +89,10 @@ pub enum Error {
 impl core::error::Error for Error {
 	fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
 		match self {
+			Self::Create { source, .. } => Some(source.as_ref()),
 			#[cfg(feature = "std")]
 			Self::LibLoading { source } => Some(source),
+			#[cfg(feature = "scripting")]
 			Self::Scripting { source } => Some(source),
 			#[cfg(feature = "std")]
 			Self::StdIo { source } => Some(source),
@@ -97,7 +111,7 @@ impl core::fmt::Debug for Error {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		match self {
 			Self::AlreadyRegistered { name } => write!(f, "AlreadyRegistered(name: {name})"),
-			Self::Create { name, error } => write!(f, "Create(name: {name}, error: {error})"),
+			Self::Create { name, path, source } => write!(f, "Create(name: {name}, path: {path}, error: {source})"),
 			#[cfg(feature = "std")]
 			Self::InvalidPath { path } => write!(f, "InvalidPath(path: {path})"),
 			#[cfg(feature = "std")]
@@ -106,7 +120,10 @@ impl core::fmt::Debug for Error {
 			Self::NotRegistered { name } => write!(f, "NotRegistered(name: {name})"),
 			#[cfg(feature = "std")]
 			Self::RegisterLib { path, code } => write!(f, "RegisterLib(path: {path}, code: {code})"),
+			#[cfg(feature = "std")]
+			Self::RegisterPlugins { path, errors } => write!(f, "RegisterPlugins(path: {path}, errors: {errors})"),
 			Self::RegisterXml { name, error } => write!(f, "RegisterXml(name: {name}, error: {error})"),
+			#[cfg(feature = "scripting")]
 			Self::Scripting { source } => write!(f, "Scripting({source})"),
 			#[cfg(feature = "std")]
 			Self::StdIo { source } => write!(f, "StdIo({source})"),
@@ -118,7 +135,9 @@ impl core::fmt::Display for Error {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		match self {
 			Self::AlreadyRegistered { name } => write!(f, "the item {name} is already registered"),
-			Self::Create { name, error } => write!(f, "creation of tree {name} failed with: {error}"),
+			Self::Create { name, path, source } => {
+				write!(f, "creation of tree {name} failed at {path} with: {source}")
+			}
 			#[cfg(feature = "std")]
 			Self::InvalidPath { path } => write!(f, "the file path {path} is invalid"),
 			#[cfg(feature = "std")]
@@ -127,7 +146,12 @@ impl core::fmt::Display for Error {
 			Self::NotRegistered { name } => write!(f, "the item {name} is not registered"),
 			#[cfg(feature = "std")]
 			Self::RegisterLib { path, code } => write!(f, "registration of the library {path} failed with: {code}"),
+			#[cfg(feature = "std")]
+			Self::RegisterPlugins { path, errors } => {
+				write!(f, "registration of plugins in {path} failed with: {errors}")
+			}
 			Self::RegisterXml { name, error } => write!(f, "registration of XML {name} failed with: {error}"),
+			#[cfg(feature = "scripting")]
 			Self::Scripting { source } => write!(f, "accessing scripting failed with: {source}"),
 			#[cfg(feature = "std")]
 			Self::StdIo { source } => write!(f, "accessing file failed with: {source}"),
@@ -148,6 +172,7 @@ impl From<nanoserde::DeJsonErr> for Error {
 	}
 }
 
+#[cfg(feature = "scripting")]
 impl From<tinyscript::Error> for Error {
 	fn from(source: tinyscript::Error) -> Self {
 		Self::Scripting { source }
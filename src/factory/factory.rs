@@ -9,14 +9,20 @@
 extern crate std;
 
 // region:      --- modules
-use super::{error::Error, registry::BehaviorRegistry};
+use super::{
+	TreeOptions,
+	error::Error,
+	registry::{BehaviorRegistry, MergePolicy, TreeRedefinitionPolicy},
+};
 #[cfg(feature = "simple_behavior")]
-use crate::behavior::{ComplexBhvrTickFn, SimpleBehavior, SimpleBhvrTickFn};
+use crate::behavior::{AsyncBhvrTickFn, ComplexBhvrTickFn, SimpleBehavior, SimpleBhvrTickFn};
+#[cfg(any(feature = "std", feature = "embassy"))]
+use crate::clock::Clock;
 use crate::{
-	ConstString,
-	behavior::{BehaviorExecution, SubTree, behavior_description::BehaviorDescription},
-	tree::BehaviorTree,
-	xml::parser::XmlParser,
+	ConstString, EMPTY_STR,
+	behavior::{BehaviorExecution, FromConfig, SubTree, behavior_description::BehaviorDescription},
+	tree::{BehaviorTree, TreeWarning},
+	xml::{ParserLimits, binary::BinaryXmlDocument, parser::XmlParser},
 };
 #[allow(unused)]
 use crate::{
@@ -31,10 +37,14 @@ use crate::{
 };
 #[allow(unused)]
 use alloc::string::String;
+#[cfg(any(feature = "std", feature = "embassy"))]
+use alloc::sync::Arc;
 use alloc::{boxed::Box, string::ToString, vec::Vec};
-use databoard::Databoard;
+use databoard::{Databoard, Remappings};
 #[cfg(feature = "mock_behavior")]
 use nanoserde::DeJson;
+#[cfg(feature = "scripting")]
+use tinyscript::ScriptEnum;
 // endregion:   --- modules
 
 // region:      --- BehaviorTreeFactory
@@ -51,21 +61,27 @@ use nanoserde::DeJson;
 ///   [`PopString`](crate::behavior::action::PopFromQueue): feature `pop_string`
 ///   [`Script`](crate::behavior::action::Script): feature `script`
 ///   [`SetBlackboard`](crate::behavior::action::SetBlackboard): feature `set_blackboard`
+///   [`SetTypedBlackboard`](crate::behavior::action::SetTypedBlackboard): feature `set_typed_blackboard`
 ///   [`Sleep`](crate::behavior::action::Sleep): feature `sleep`
 ///   [`UnsetBlackboard`](crate::behavior::action::UnsetBlackboard): feature `unset_blackboard`
 /// - Conditions:
+///   [`HasEntry`](crate::behavior::condition::HasEntry): feature `has_entry`
 ///   [`ScriptCondition`](crate::behavior::condition::ScriptCondition): feature `script_condition`
 ///   [`WasEntryUpdated`](crate::behavior::condition::WasEntryUpdated): feature `was_entry_updated`
 /// - Controls:
+///   [`And`](crate::behavior::control::And): feature `and`
 ///   [`AsyncFallback`](crate::behavior::control::Fallback): feature `async_fallback`
 ///   [`AsyncSequence`](crate::behavior::control::Sequence): feature `async_sequence`
 ///   [`Fallback`](crate::behavior::control::Fallback): feature `fallback`
 ///   [`IfThenElse`](crate::behavior::control::IfThenElse): feature `if_then_else`
+///   [`Or`](crate::behavior::control::Or): feature `or`
 ///   [`Sequence`](crate::behavior::control::Sequence): feature `sequence`
 ///   [`Parallel`](crate::behavior::control::Parallel): feature `parallel`
 ///   [`ParallelAll`](crate::behavior::control::ParallelAll): feature `parallel_all`
+///   [`ParallelTimeout`](crate::behavior::control::ParallelTimeout): feature `parallel_timeout`
 ///   [`ReactiveFallback`](crate::behavior::control::ReactiveFallback): feature `reactive_fallback`
 ///   [`ReactiveSequence`](crate::behavior::control::ReactiveSequence): feature `reactive_sequence`
+///   [`RetryFallback`](crate::behavior::control::RetryFallback): feature `retry_fallback`
 ///   [`SequenceWithMemory`](crate::behavior::control::SequenceWithMemory): feature `sequence_with_memory`
 ///   [`Switch2`](crate::behavior::control::Switch): feature `switch2`
 ///   [`Switch3`](crate::behavior::control::Switch): feature `switch3`
@@ -75,6 +91,7 @@ use nanoserde::DeJson;
 ///   [`WhileDoElse`](crate::behavior::control::WhileDoElse): feature `while_do_else`
 /// - Decorators:
 ///   [`Delay`](crate::behavior::decorator::Delay): feature `delay`
+///   [`ForEach`](crate::behavior::decorator::ForEach): feature `for_each`
 ///   [`ForceFailure`](crate::behavior::decorator::ForceState): feature `force_failure`
 ///   [`ForceRunning`](crate::behavior::decorator::ForceState): feature `force_success`
 ///   [`ForceSuccess`](crate::behavior::decorator::ForceState): feature `force_success`
@@ -84,6 +101,7 @@ use nanoserde::DeJson;
 ///   [`LoopDouble`](crate::behavior::decorator::Loop): feature `loop_double`
 ///   [`LoopInt`](crate::behavior::decorator::Loop): feature `loop_int`
 ///   [`LoopString`](crate::behavior::decorator::Loop): feature `loop_string`
+///   [`MapState`](crate::behavior::decorator::MapState): feature `map_state`
 ///   [`Precondition`](crate::behavior::decorator::Precondition): feature `precondition`
 ///   [`Repeat`](crate::behavior::decorator::Repeat): feature `repeat`
 ///   [`RetryUntilSuccessful`](crate::behavior::decorator::RetryUntilSuccessful): feature `retry_until_successful`
@@ -94,6 +112,9 @@ use nanoserde::DeJson;
 /// - For mocking and behavior replacements:
 ///   [`MockBehavior`](crate::behavior::MockBehavior): feature `mock_behavior`
 ///
+/// Pre-/post-conditions, subtree hooks and the script behaviors need the feature `scripting`.
+/// Without it, script attributes in XML are rejected.
+///
 /// Always available is
 /// - [`SubTree`]: to enable (sub) trees including the root tree
 pub struct BehaviorTreeFactory {
@@ -113,6 +134,29 @@ impl BehaviorTreeFactory {
 		&mut self.registry
 	}
 
+	/// Merges the behaviors, tree definitions, enums, substitution rules and libraries
+	/// of `other` into this factory.
+	///
+	/// The builtin behaviors of both factories are no collisions.
+	/// # Errors
+	/// - if any registered name of `other` is already registered in this factory,
+	///   in that case nothing is merged
+	#[inline]
+	pub fn merge(&mut self, other: Self) -> Result<(), Error> {
+		self.registry
+			.merge(other.registry, MergePolicy::Fail)
+	}
+
+	/// Merges `other` into this factory like [`merge`](Self::merge),
+	/// resolving name collisions according to the given [`MergePolicy`].
+	/// # Errors
+	/// - if there is a name collision and `policy` is [`MergePolicy::Fail`]
+	/// - if an enum key has differing values and `policy` is not [`MergePolicy::KeepExisting`]
+	#[inline]
+	pub fn merge_with_policy(&mut self, other: Self, policy: MergePolicy) -> Result<(), Error> {
+		self.registry.merge(other.registry, policy)
+	}
+
 	/// Creates a factory with the configured set of behaviors.
 	///
 	/// # Errors
@@ -154,6 +198,8 @@ impl BehaviorTreeFactory {
 		f.register_groot2_behavior_type::<action::Script>("Script")?;
 		#[cfg(feature = "set_blackboard")]
 		f.register_groot2_behavior_type::<action::SetBlackboard<String>>("SetBlackboard")?;
+		#[cfg(feature = "set_typed_blackboard")]
+		f.register_behavior_type::<action::SetTypedBlackboard>("SetTypedBlackboard")?;
 		#[cfg(feature = "sleep")]
 		f.register_groot2_behavior_type::<action::Sleep>("Sleep")?;
 		#[cfg(feature = "pop_bool")]
@@ -168,12 +214,16 @@ impl BehaviorTreeFactory {
 		f.register_groot2_behavior_type::<action::UnsetBlackboard<String>>("UnsetBlackboard")?;
 
 		// conditions
+		#[cfg(feature = "has_entry")]
+		f.register_behavior_type::<condition::HasEntry>("HasEntry")?;
 		#[cfg(feature = "script_condition")]
 		f.register_groot2_behavior_type::<condition::ScriptCondition>("ScriptCondition")?;
 		#[cfg(feature = "was_entry_updated")]
 		f.register_groot2_behavior_type::<condition::WasEntryUpdated>("WasEntryUpdated")?;
 
 		// controls
+		#[cfg(feature = "and")]
+		f.register_behavior_type::<control::And>("And")?;
 		#[cfg(feature = "async_fallback")]
 		register_groot2_behavior!(f, control::Fallback, "AsyncFallback", true)?;
 		#[cfg(feature = "async_sequence")]
@@ -182,14 +232,20 @@ impl BehaviorTreeFactory {
 		f.register_groot2_behavior_type::<control::Fallback>("Fallback")?;
 		#[cfg(feature = "if_then_else")]
 		f.register_groot2_behavior_type::<control::IfThenElse>("IfThenElse")?;
+		#[cfg(feature = "or")]
+		f.register_behavior_type::<control::Or>("Or")?;
 		#[cfg(feature = "parallel")]
 		f.register_groot2_behavior_type::<control::Parallel>("Parallel")?;
 		#[cfg(feature = "parallel_all")]
 		f.register_groot2_behavior_type::<control::ParallelAll>("ParallelAll")?;
+		#[cfg(feature = "parallel_timeout")]
+		f.register_behavior_type::<control::ParallelTimeout>("ParallelTimeout")?;
 		#[cfg(feature = "reactive_fallback")]
 		f.register_groot2_behavior_type::<control::ReactiveFallback>("ReactiveFallback")?;
 		#[cfg(feature = "reactive_sequence")]
 		f.register_groot2_behavior_type::<control::ReactiveSequence>("ReactiveSequence")?;
+		#[cfg(feature = "retry_fallback")]
+		f.register_behavior_type::<control::RetryFallback>("RetryFallback")?;
 		#[cfg(feature = "sequence")]
 		f.register_groot2_behavior_type::<control::Sequence>("Sequence")?;
 		#[cfg(feature = "sequence_with_memory")]
@@ -210,6 +266,8 @@ impl BehaviorTreeFactory {
 		// decorators
 		#[cfg(feature = "delay")]
 		f.register_groot2_behavior_type::<decorator::Delay>("Delay")?;
+		#[cfg(feature = "for_each")]
+		f.register_behavior_type::<decorator::ForEach>("ForEach")?;
 		#[cfg(feature = "force_failure")]
 		register_groot2_behavior!(f, decorator::ForceState, "ForceFailure", BehaviorState::Failure)?;
 		#[cfg(feature = "force_running")]
@@ -228,6 +286,8 @@ impl BehaviorTreeFactory {
 		f.register_behavior_type::<decorator::Loop<i32>>("LoopInt")?;
 		#[cfg(feature = "loop_string")]
 		f.register_groot2_behavior_type::<decorator::Loop<String>>("LoopString")?;
+		#[cfg(feature = "map_state")]
+		f.register_behavior_type::<decorator::MapState>("MapState")?;
 		#[cfg(feature = "precondition")]
 		f.register_groot2_behavior_type::<decorator::Precondition>("Precondition")?;
 		#[cfg(feature = "repeat")]
@@ -243,16 +303,137 @@ impl BehaviorTreeFactory {
 		#[cfg(feature = "wait_value_updated")]
 		register_groot2_behavior!(f, decorator::EntryUpdated, "WaitValueUpdated", BehaviorState::Running)?;
 
+		f.registry.mark_builtin_behaviors();
 		Ok(f)
 	}
 
 	/// Register an enums key/value pair.
 	/// # Errors
 	/// - if the key is already used
+	#[cfg(feature = "scripting")]
 	pub fn register_enum_tuple(&mut self, key: &str, value: i8) -> Result<(), Error> {
 		self.registry.register_enum_tuple(key, value)
 	}
 
+	/// Register all key/value pairs of an enum deriving [`ScriptEnum`].
+	/// Unlike [`register_scripting_enum!`](crate::register_scripting_enum) this does not
+	/// return early from the calling function and can be used in expression position.
+	/// # Errors
+	/// - if one of the keys is already used
+	#[cfg(feature = "scripting")]
+	pub fn register_enum_type<T: ScriptEnum>(&mut self) -> Result<(), Error> {
+		for (key, value) in T::key_value_tuples() {
+			self.register_enum_tuple(key, value)?;
+		}
+		Ok(())
+	}
+
+	/// Set the limits the XML parser enforces when registering behavior trees.
+	/// The limits apply to all subsequent registrations including `<include/>`d files.
+	#[inline]
+	pub const fn set_parser_limits(&mut self, limits: ParserLimits) {
+		self.registry.set_parser_limits(limits);
+	}
+
+	/// Set the [`TreeOptions`] for the XML parser and the created trees.
+	/// The options apply to all subsequently created trees, default is [`TreeOptions::empty`].
+	#[inline]
+	pub const fn set_options(&mut self, options: TreeOptions) {
+		self.registry.set_options(options);
+	}
+
+	/// Enable or disable [`TreeOptions::PRESERVE_COMMENTS`], keeping the other options.
+	#[inline]
+	pub const fn preserve_comments(&mut self, enabled: bool) {
+		self.set_option(TreeOptions::PRESERVE_COMMENTS, enabled);
+	}
+
+	/// Enable or disable [`TreeOptions::STABLE_IDS`], keeping the other options.
+	#[inline]
+	pub const fn stable_ids(&mut self, enabled: bool) {
+		self.set_option(TreeOptions::STABLE_IDS, enabled);
+	}
+
+	/// Enable or disable [`TreeOptions::STRICT_PORT_DIRECTIONS`], keeping the other options.
+	#[inline]
+	pub const fn set_strict_port_directions(&mut self, enabled: bool) {
+		self.set_option(TreeOptions::STRICT_PORT_DIRECTIONS, enabled);
+	}
+
+	/// Enable or disable [`TreeOptions::LENIENT_BOOLS`], keeping the other options.
+	#[inline]
+	pub const fn set_lenient_bools(&mut self, enabled: bool) {
+		self.set_option(TreeOptions::LENIENT_BOOLS, enabled);
+	}
+
+	/// Enable or disable [`TreeOptions::HALT_ON_ERROR`], keeping the other options.
+	#[inline]
+	pub const fn halt_on_error(&mut self, enabled: bool) {
+		self.set_option(TreeOptions::HALT_ON_ERROR, enabled);
+	}
+
+	/// Enable or disable [`TreeOptions::STRICT_AUTOREMAP`], keeping the other options.
+	#[inline]
+	pub const fn set_strict_autoremap(&mut self, enabled: bool) {
+		self.set_option(TreeOptions::STRICT_AUTOREMAP, enabled);
+	}
+
+	/// Enable or disable [`TreeOptions::IGNORE_UNKNOWN_METADATA`], keeping the other options.
+	#[inline]
+	pub const fn ignore_unknown_metadata(&mut self, enabled: bool) {
+		self.set_option(TreeOptions::IGNORE_UNKNOWN_METADATA, enabled);
+	}
+
+	/// Set or clear a single option, keeping the other options.
+	const fn set_option(&mut self, option: TreeOptions, enabled: bool) {
+		self.set_options(self.registry.options().with(option, enabled));
+	}
+
+	/// Set the maximum depth of the created trees including their subtrees, the root has depth 1.
+	///
+	/// Creating, ticking and halting a tree recurses along its depth, so the stack consumption grows with it.
+	/// On targets with a small stack, e.g. microcontrollers, the practical depth is about 12 levels,
+	/// see the embedded `recursion` test. With a limit a tree exceeding it fails to be created with
+	/// [`TreeTooDeep`](crate::xml::error::Error::TreeTooDeep) instead of overflowing the stack.
+	/// The depth is checked while descending, so this also stops self referencing subtrees early.
+	/// The limit applies to all subsequently created trees, default is no limit.
+	#[inline]
+	pub const fn set_max_tree_depth(&mut self, depth: usize) {
+		self.registry.set_max_tree_depth(Some(depth));
+	}
+
+	/// Set the policy for registering a (sub)tree definition with an ID, which is already registered,
+	/// e.g. when reloading definitions or loading files with overlapping definitions.
+	///
+	/// The policy applies to all subsequent registrations, default is [`TreeRedefinitionPolicy::Fail`].
+	/// With [`TreeRedefinitionPolicy::IgnoreIdentical`] the XML source of both definitions is compared.
+	#[inline]
+	pub const fn set_tree_redefinition_policy(&mut self, policy: TreeRedefinitionPolicy) {
+		self.registry.set_tree_redefinition_policy(policy);
+	}
+
+	/// Set the [`Clock`] consulted by the time based behaviors instead of the system time.
+	///
+	/// With a [`ManualClock`](crate::clock::ManualClock) time based behaviors like
+	/// [`Timeout`](crate::behavior::decorator::Timeout) can be tested deterministically without real waiting.
+	/// The clock applies to all subsequently created trees, default is the `SystemClock`,
+	/// without feature `std` the `EmbassyClock` of feature `embassy`.
+	#[cfg(any(feature = "std", feature = "embassy"))]
+	pub fn set_clock(&mut self, clock: impl Clock + 'static) {
+		self.registry.set_clock(Arc::new(clock));
+	}
+
+	/// Set a seed to make random behaviors reproducible.
+	///
+	/// Each behavior gets its own seed derived from this seed and its uid,
+	/// available via [`BehaviorData::seed()`](crate::behavior::BehaviorData::seed),
+	/// so trees created from the same XML with the same seed make identical random choices.
+	/// The seed applies to all subsequently created trees, without a seed behaviors get `None`.
+	#[inline]
+	pub const fn set_seed(&mut self, seed: u64) {
+		self.registry.set_seed(Some(seed));
+	}
+
 	/// Clear previously registered behavior trees.
 	pub fn clear_registered_behavior_trees(&mut self) {
 		self.registry.clear_registered_trees();
@@ -292,11 +473,66 @@ impl BehaviorTreeFactory {
 		let mut parser = XmlParser::default();
 		match parser.create_tree_from_definition(name, &self.registry, None) {
 			Ok(root) => Ok(BehaviorTree::new(root, &self.registry)),
-			Err(err) => Err(Error::Create {
-				name: name.into(),
-				error: err.to_string().into(),
-			}),
+			Err(err) => Err(create_error(name, &parser, err)),
+		}
+	}
+
+	/// Create the main [`BehaviorTree`] from a document encoded with [`BinaryXmlEncoder`](crate::BinaryXmlEncoder).
+	/// The document is not registered, its subtrees are resolved within the document.
+	///
+	/// Encode the XML in a `build.rs` and embed the result with `include_bytes!`
+	/// to avoid XML parsing at runtime.
+	/// # Errors
+	/// - if the binary XML document is invalid
+	/// - if the binary XML document does not contain the main tree
+	/// - if behaviors or subtrees are missing
+	pub fn create_from_binary_xml(&mut self, bytes: &[u8]) -> Result<BehaviorTree, Error> {
+		let document = match BinaryXmlDocument::decode(bytes, self.registry.parser_limits()) {
+			Ok(document) => document,
+			Err(err) => {
+				return Err(Error::Create {
+					name: EMPTY_STR.into(),
+					path: EMPTY_STR.into(),
+					source: Box::new(err),
+				});
+			}
+		};
+		let name: ConstString = match (document.main_tree_id(), self.registry.main_tree_id()) {
+			(Some(name), _) => name.into(),
+			(None, Some(name)) if !name.is_empty() => name,
+			_ => "MainTree".into(),
+		};
+		let mut parser = XmlParser::default();
+		match parser.create_tree_from_binary_xml(&document, &name, &self.registry, None) {
+			Ok(root) => Ok(BehaviorTree::new(root, &self.registry)),
+			Err(err) => Err(create_error(&name, &parser, err)),
+		}
+	}
+
+	/// Create all the named [`BehaviorTree`]s from registration.
+	/// In contrast to [`create_tree`](Self::create_tree) the creation does not stop at the first failure,
+	/// which is useful to validate all trees of a project at once.
+	/// # Errors
+	/// - the list of names and errors of all trees which could not be created
+	pub fn create_trees(&mut self, names: &[&str]) -> Result<Vec<BehaviorTree>, Vec<(ConstString, Error)>> {
+		let mut trees = Vec::with_capacity(names.len());
+		let mut errors = Vec::new();
+		for name in names {
+			match self.create_tree(name) {
+				Ok(tree) => trees.push(tree),
+				Err(err) => errors.push(((*name).into(), err)),
+			}
 		}
+		if errors.is_empty() { Ok(trees) } else { Err(errors) }
+	}
+
+	/// Analyzes the data flow of the named [`BehaviorTree`], see [`BehaviorTree::analyze`].
+	/// The tree is created for the analysis, but not ticked.
+	/// # Errors
+	/// - if no tree with `name` can be found
+	/// - if behaviors or subtrees are missing
+	pub fn analyze_tree(&mut self, name: &str) -> Result<Vec<TreeWarning>, Error> {
+		Ok(self.create_tree(name)?.analyze())
 	}
 
 	/// Create the named [`BehaviorTree`] from registration using external created blackboard.
@@ -307,10 +543,7 @@ impl BehaviorTreeFactory {
 		let mut parser = XmlParser::default();
 		match parser.create_tree_from_definition(name, &self.registry, Some(blackboard)) {
 			Ok(root) => Ok(BehaviorTree::new(root, &self.registry)),
-			Err(err) => Err(Error::Create {
-				name: name.into(),
-				error: err.to_string().into(),
-			}),
+			Err(err) => Err(create_error(name, &parser, err)),
 		}
 	}
 
@@ -349,6 +582,38 @@ impl BehaviorTreeFactory {
 		}
 	}
 
+	/// Replace the registered (sub)tree definition `id` with the one described by the XML.
+	/// The XML is either a single `<BehaviorTree ID="...">` element or a document containing it.
+	/// Already created trees are not changed, see [`BehaviorTree::reload_subtree()`].
+	/// # Errors
+	/// - if no (sub)tree `id` is registered
+	/// - on incorrect XML
+	/// - if the XML does not contain a definition for `id`
+	pub fn replace_subtree_definition(&mut self, id: &str, xml: &str) -> Result<(), Error> {
+		match XmlParser::replace_tree_definition(&mut self.registry, id, xml) {
+			Ok(()) => Ok(()),
+			Err(err) => Err(Error::RegisterXml {
+				name: id.into(),
+				error: err.to_string().into(),
+			}),
+		}
+	}
+
+	/// Register the behavior (sub)trees described by the XML read from `reader`.
+	/// The caller controls the source and buffering, but the XML is read completely before parsing.
+	/// Relative `<include/>` paths are resolved against the current directory.
+	/// # Errors
+	/// - if reading fails or the content is not valid UTF-8
+	/// - on incorrect XML
+	/// - if tree description is not in BTCPP v4
+	/// - if tree is already registered
+	#[cfg(feature = "std")]
+	pub fn register_behavior_tree_from_reader(&mut self, mut reader: impl std::io::Read) -> Result<(), Error> {
+		let mut xml = String::new();
+		reader.read_to_string(&mut xml)?;
+		self.register_behavior_tree_from_text(&xml)
+	}
+
 	/// Register the behavior (sub)trees described by the XML in the file.
 	/// # Errors
 	/// - on incorrect XML
@@ -384,6 +649,15 @@ impl BehaviorTreeFactory {
 		}
 	}
 
+	/// Get the sorted name list of the trees flagged as entry points,
+	/// either with `main_tree_to_execute` or with the comma separated list `main_trees`
+	/// of a documents `<root>` element.
+	/// Each of them can be created with [`create_tree`](Self::create_tree).
+	#[must_use]
+	pub fn main_trees(&self) -> Vec<ConstString> {
+		self.registry.main_trees()
+	}
+
 	/// Get the name list of registered behavior trees.
 	#[must_use]
 	pub fn registered_behavior_trees(&self) -> Vec<ConstString> {
@@ -396,52 +670,104 @@ impl BehaviorTreeFactory {
 	/// - is compiled with the same `Rust` version.
 	/// # Errors
 	/// - if library is not found
-	/// - if library does not provide the `extern "Rust" register(&mut BehaviorTreeFactory) -> i32` function
-	/// # Panics
-	/// - on OS other than `Windows` and `Linux`,
-	/// - should not panic on supported OS unless some weird constellation is happening.
-	#[cfg(feature = "std")]
-	#[allow(unsafe_code)]
+	/// - if library does not provide the `extern "Rust" register(&mut BehaviorTreeFactory) -> u32` function
+	///
+	/// Only available on `Linux` and `Windows`.
+	#[cfg(all(feature = "std", any(target_os = "linux", target_os = "windows")))]
 	pub fn register_from_plugin(&mut self, name: &str) -> Result<(), Error> {
 		// create path from exe path
 		// in dev environment maybe we have to remove a '/deps'
-		if let Some(path) = std::env::current_exe()?.parent() {
-			if let Some(str_path) = path.to_str() {
-				let path = str_path.trim_end_matches("/deps").to_string();
-
-				#[cfg(not(any(target_os = "linux", target_os = "windows")))]
-				todo!("This plattform is not upported!");
-				#[cfg(target_os = "linux")]
-				let libname = path + "/lib" + name + ".so";
-				#[cfg(target_os = "windows")]
-				let libname = path + "\\" + name + ".dll";
-
-				let lib = unsafe {
-					let lib = libloading::Library::new(libname)?;
-					let registration_fn: libloading::Symbol<unsafe extern "Rust" fn(&mut Self) -> u32> =
-						lib.get(b"register")?;
-					let res = registration_fn(&mut *self);
-					if res != 0 {
-						return Err(Error::RegisterLib {
-							path: name.into(),
-							code: res,
-						});
-					}
-					lib
-				};
-
-				// The Library must be kept in storage until the [`BehaviorTree`] is destroyed.
-				// Therefore the library is handed over to the behavior registry and later referenced by any tree.
-				self.registry.add_library(lib);
-				Ok(())
-			} else {
-				Err(Error::InvalidPath { path: name.into() })
+		let exe = std::env::current_exe()?;
+		let Some(str_path) = exe.parent().and_then(std::path::Path::to_str) else {
+			return Err(Error::InvalidPath { path: name.into() });
+		};
+		let path = str_path.trim_end_matches("/deps").to_string();
+
+		#[cfg(target_os = "linux")]
+		let libname = path + "/lib" + name + ".so";
+		#[cfg(target_os = "windows")]
+		let libname = path + "\\" + name + ".dll";
+
+		self.load_library(std::path::Path::new(&libname), name)
+	}
+
+	/// Register all behavior plugins found in the directory `dir`.
+	/// Every file with the platforms shared library extension (`.so` on `Linux`, `.dll` on `Windows`)
+	/// is loaded and its `register` function is called.
+	/// Loading continues if a plugin fails, the failures are collected into one error.
+	/// Returns the number of successfully registered plugins.
+	/// # Errors
+	/// - if the directory cannot be read
+	/// - if any of the found libraries could not be registered
+	///
+	/// Only available on `Linux` and `Windows`.
+	#[cfg(all(feature = "std", any(target_os = "linux", target_os = "windows")))]
+	pub fn register_plugins_from_dir(&mut self, dir: impl AsRef<std::path::Path>) -> Result<usize, Error> {
+		#[cfg(target_os = "linux")]
+		let extension = "so";
+		#[cfg(target_os = "windows")]
+		let extension = "dll";
+
+		// sort the entries to get a reproducible loading order
+		let mut paths = std::fs::read_dir(dir.as_ref())?
+			.map(|entry| entry.map(|entry| entry.path()))
+			.collect::<Result<Vec<_>, _>>()?;
+		paths.sort();
+
+		let mut count = 0;
+		let mut errors = Vec::new();
+		for path in paths {
+			if !path.is_file()
+				|| path
+					.extension()
+					.is_none_or(|ext| ext != extension)
+			{
+				continue;
+			}
+			let name = path.to_string_lossy();
+			match self.load_library(&path, &name) {
+				Ok(()) => count += 1,
+				Err(err) => errors.push(std::format!("{name}: {err}")),
 			}
+		}
+
+		if errors.is_empty() {
+			Ok(count)
 		} else {
-			Err(Error::InvalidPath { path: name.into() })
+			Err(Error::RegisterPlugins {
+				path: dir.as_ref().to_string_lossy().into(),
+				errors: errors.join("; ").into(),
+			})
 		}
 	}
 
+	/// Load a library and call its `register` function.
+	/// # Errors
+	/// - if library is not found
+	/// - if library does not provide the `extern "Rust" register(&mut BehaviorTreeFactory) -> u32` function
+	/// - if the `register` function returns an error code
+	#[cfg(all(feature = "std", any(target_os = "linux", target_os = "windows")))]
+	#[allow(unsafe_code)]
+	fn load_library(&mut self, path: &std::path::Path, name: &str) -> Result<(), Error> {
+		let lib = unsafe {
+			let lib = libloading::Library::new(path)?;
+			let registration_fn: libloading::Symbol<unsafe extern "Rust" fn(&mut Self) -> u32> = lib.get(b"register")?;
+			let res = registration_fn(&mut *self);
+			if res != 0 {
+				return Err(Error::RegisterLib {
+					path: name.into(),
+					code: res,
+				});
+			}
+			lib
+		};
+
+		// The Library must be kept in storage until the [`BehaviorTree`] is destroyed.
+		// Therefore the library is handed over to the behavior registry and later referenced by any tree.
+		self.registry.add_library(lib);
+		Ok(())
+	}
+
 	/// Register a `Behavior` of type `<T>`.
 	/// # Errors
 	/// - if a behavior with that `name` is already registered
@@ -455,6 +781,35 @@ impl BehaviorTreeFactory {
 			.add_behavior(bhvr_desc, bhvr_creation_fn)
 	}
 
+	/// Register the type `<T>` under `type_tag`, so that behaviors of that type
+	/// can be registered from a JSON configuration with [`BehaviorTreeFactory::register_behavior_from_json`].
+	/// # Errors
+	/// - if a type with that `type_tag` is already registered
+	pub fn register_configurable_type<T>(&mut self, type_tag: &str) -> Result<(), Error>
+	where
+		T: FromConfig,
+	{
+		self.registry.add_configurable_type::<T>(type_tag)
+	}
+
+	/// Register a `Behavior` named `name` of the configurable type registered as `type_tag`.
+	/// The construction parameters of the behavior are deserialized from `config_json`.
+	///
+	/// # Example:
+	///
+	/// ```no-test
+	/// factory.register_configurable_type::<MoveTo>("MoveTo")?;
+	/// factory.register_behavior_from_json("MoveHome", "MoveTo", r#"{"x": 0.0, "y": 0.0}"#)?;
+	/// ```
+	/// # Errors
+	/// - if no type with that `type_tag` is registered
+	/// - if `config_json` is not a valid configuration for the type
+	/// - if a behavior with that `name` is already registered
+	pub fn register_behavior_from_json(&mut self, name: &str, type_tag: &str, config_json: &str) -> Result<(), Error> {
+		self.registry
+			.add_configured_behavior(name, type_tag, config_json)
+	}
+
 	/// Register a `Behavior` of type `<T>` which is also builtin in Groot2.
 	/// # Errors
 	/// - if a behavior with that `name` is already registered
@@ -501,6 +856,25 @@ impl BehaviorTreeFactory {
 			.add_behavior(bhvr_desc, bhvr_creation_fn)
 	}
 
+	/// Registers an async function as [`BehaviorKind::Action`] or [`BehaviorKind::Condition`] which is using ports.
+	/// The function gets access to the ports via [`BehaviorData`](crate::behavior::BehaviorData),
+	/// but neither to children nor to the scripting runtime.
+	/// # Errors
+	/// - if a behavior with that `name` is already registered
+	#[cfg(feature = "simple_behavior")]
+	pub fn register_simple_async_function_with_ports(
+		&mut self,
+		name: &str,
+		tick_fn: AsyncBhvrTickFn,
+		kind: BehaviorKind,
+		port_list: PortList,
+	) -> Result<(), Error> {
+		let bhvr_desc = BehaviorDescription::new(name, name, kind, false, port_list.clone());
+		let bhvr_creation_fn = SimpleBehavior::new_create_async_with_ports(tick_fn, port_list);
+		self.registry
+			.add_behavior(bhvr_desc, bhvr_creation_fn)
+	}
+
 	/// Registers a substitution rule for a pattern.
 	/// # Errors
 	/// - if
@@ -533,3 +907,39 @@ impl BehaviorTreeFactory {
 	}
 }
 // endregion:   --- BehaviorTreeFactory
+
+// region:		--- remappings
+/// Creates [`Remappings`] from pairs of a key and its remapped value, e.g. for a [`Databoard`]
+/// passed to [`BehaviorTreeFactory::create_tree_with`].
+///
+/// The value is either a blackboard pointer like `{key}` resp. `{@key}` for the global scope,
+/// or a constant assignment. The pairs work like the port remapping attributes of a `SubTree` in XML:
+/// ```no-test
+/// let remappings = remappings_from_pairs(&[("target", "{@goal}"), ("speed", "0.5")])?;
+/// let blackboard = Databoard::with(Some(global_blackboard), Some(remappings), false);
+/// let tree = factory.create_tree_with("MainTree", &blackboard)?;
+/// ```
+/// # Errors
+/// - if a key is given more than once
+pub fn remappings_from_pairs(pairs: &[(&str, &str)]) -> Result<Remappings, databoard::Error> {
+	let mut remappings = Remappings::default();
+	for (key, value) in pairs {
+		remappings.add(*key, *value)?;
+	}
+	Ok(remappings)
+}
+// endregion:	--- remappings
+
+// region:		--- helper
+/// Creates the [`Error::Create`] for the tree `name` with the failure path recorded by the `parser`.
+fn create_error(name: &str, parser: &XmlParser, source: crate::xml::error::Error) -> Error {
+	Error::Create {
+		name: name.into(),
+		path: parser
+			.failure_path()
+			.cloned()
+			.unwrap_or_else(|| name.into()),
+		source: Box::new(source),
+	}
+}
+// endregion:	--- helper
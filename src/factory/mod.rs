@@ -7,11 +7,13 @@ pub mod error;
 mod factory;
 #[cfg(feature = "mock_behavior")]
 mod json_config;
+mod options;
 pub mod registry;
 
 // flatten
-pub use factory::BehaviorTreeFactory;
-pub use registry::BehaviorRegistry;
+pub use factory::{BehaviorTreeFactory, remappings_from_pairs};
+pub use options::TreeOptions;
+pub use registry::{BehaviorRegistry, MergePolicy, TreeRedefinitionPolicy};
 
 #[cfg(test)]
 mod tests {
@@ -25,5 +27,6 @@ mod tests {
 		is_normal::<error::Error>();
 		is_normal::<BehaviorTreeFactory>();
 		is_normal::<BehaviorRegistry>();
+		is_normal::<TreeOptions>();
 	}
 }
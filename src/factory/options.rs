@@ -0,0 +1,110 @@
+// Copyright © 2025 Stephan Kunz
+
+//! [`TreeOptions`] for the XML parser and the created trees.
+
+use core::ops::{BitOr, BitOrAssign};
+
+// region:      --- TreeOptions
+/// A set of options for the XML parser and the behavior trees created by the
+/// [`BehaviorTreeFactory`](crate::factory::BehaviorTreeFactory).
+///
+/// The options apply to all subsequently created trees, by default no option is set.
+///
+/// Example:
+///
+/// ```no-test
+/// let mut factory = BehaviorTreeFactory::new()?;
+/// factory.set_options(TreeOptions::STABLE_IDS | TreeOptions::HALT_ON_ERROR);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TreeOptions(u32);
+
+impl TreeOptions {
+	/// The XML parser attaches comments to the following behavior.
+	/// Preserved comments are stored in the [`BehaviorDescription`](crate::behavior::behavior_description::BehaviorDescription)s
+	/// metadata and re-emitted by the [`XmlCreator`](crate::XmlCreator).
+	pub const PRESERVE_COMMENTS: Self = Self(1);
+
+	/// The XML parser assigns a stable id to each behavior.
+	///
+	/// The uid of a behavior follows the creation order and is kept dense, as Groot2 and the
+	/// [`BehaviorTreeObserver`](crate::BehaviorTreeObserver) rely on it. So reordering siblings in the XML shifts the uids.
+	/// The stable id is an additional hash derived from the names along the path to the behavior,
+	/// which survives reordering of siblings with different names.
+	/// Equally named siblings are distinguished by their order, for unnamed behaviors the ID is used as name.
+	pub const STABLE_IDS: Self = Self(1 << 1);
+
+	/// Behaviors may only read input and write output ports.
+	///
+	/// With strict port directions reading an [`output_port!`](crate::output_port!)
+	/// fails with [`NotReadable`](crate::port::error::Error::NotReadable) and writing an
+	/// [`input_port!`](crate::input_port!) fails with [`NotWritable`](crate::port::error::Error::NotWritable),
+	/// so wiring bugs are not masked. Bidirectional ports and keys, which are not declared as port, are not checked.
+	pub const STRICT_PORT_DIRECTIONS: Self = Self(1 << 2);
+
+	/// Ports of type `bool` also accept `1`/`0` and `yes`/`no`, ignoring the case.
+	///
+	/// By default a `bool` is parsed strictly from `true` or `false`, like [`FromStr`](core::str::FromStr) does.
+	/// With lenient bools e.g. `"1"`, `"YES"` or `"False"` are accepted by
+	/// [`BehaviorData::get`](crate::behavior::BehaviorData::get) too,
+	/// which eases the use of XML files and blackboard values written by other tools.
+	pub const LENIENT_BOOLS: Self = Self(1 << 3);
+
+	/// A tree is halted, when a tick fails with an error.
+	///
+	/// Without this option a failing tick leaves the tree in the partially ticked state it had when the error occured.
+	/// With this option all elements, which are not idle, are halted before the error is returned,
+	/// running their `_onHalted` scripts and resetting their state to [`BehaviorState::Idle`](crate::behavior::BehaviorState::Idle),
+	/// so e.g. actuators are not left in an inconsistent state.
+	pub const HALT_ON_ERROR: Self = Self(1 << 4);
+
+	/// The keys read in subtrees with `_autoremap="true"` must be available in the parent scope.
+	///
+	/// Without this option a key, which an autoremapped subtree reads but no parent provides, is silently missing
+	/// until the subtree is ticked. With this option the tree creation fails with
+	/// [`AutoremapEntryMissing`](crate::XmlError::AutoremapEntryMissing), unless the key is remapped explicitly,
+	/// written within the subtree, used by a port or script of a parent scope or already in the blackboard.
+	pub const STRICT_AUTOREMAP: Self = Self(1 << 5);
+
+	/// The XML parser ignores unknown `_` prefixed attributes, like positions or colors added by an editor.
+	/// Ignored attributes are kept in the [`BehaviorDescription`](crate::behavior::behavior_description::BehaviorDescription)s
+	/// metadata attributes. Otherwise an unknown `_` prefixed attribute is an error.
+	pub const IGNORE_UNKNOWN_METADATA: Self = Self(1 << 6);
+
+	/// Create an empty set of options.
+	#[must_use]
+	pub const fn empty() -> Self {
+		Self(0)
+	}
+
+	/// Returns `true` if all of the `options` are set, otherwise `false`.
+	#[must_use]
+	pub const fn contains(self, options: Self) -> bool {
+		self.0 & options.0 == options.0
+	}
+
+	/// Returns a copy with the `options` set or cleared depending on `enabled`.
+	#[must_use]
+	pub const fn with(self, options: Self, enabled: bool) -> Self {
+		if enabled {
+			Self(self.0 | options.0)
+		} else {
+			Self(self.0 & !options.0)
+		}
+	}
+}
+
+impl BitOr for TreeOptions {
+	type Output = Self;
+
+	fn bitor(self, rhs: Self) -> Self {
+		Self(self.0 | rhs.0)
+	}
+}
+
+impl BitOrAssign for TreeOptions {
+	fn bitor_assign(&mut self, rhs: Self) {
+		self.0 |= rhs.0;
+	}
+}
+// endregion:   --- TreeOptions
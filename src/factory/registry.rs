@@ -12,22 +12,45 @@ use core::ops::Range;
 // region:      --- modules
 #[cfg(feature = "mock_behavior")]
 use crate::behavior::{MockBehavior, MockBehaviorConfig};
+#[cfg(any(feature = "std", feature = "embassy"))]
+use crate::clock::Clock;
 use crate::{
 	BehaviorExecution, ConstString,
-	behavior::{BehaviorCreationFn, BehaviorPtr, behavior_description::BehaviorDescription},
+	behavior::{BehaviorCreationFn, BehaviorPtr, FromConfig, behavior_description::BehaviorDescription},
 	port::PortDirection,
+	xml::ParserLimits,
+};
+use alloc::{
+	boxed::Box,
+	collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+	sync::Arc,
+	vec::Vec,
 };
-use alloc::{boxed::Box, collections::btree_map::BTreeMap, sync::Arc, vec::Vec};
 #[cfg(feature = "std")]
 use libloading::Library;
+#[cfg(feature = "scripting")]
 use tinyscript::Runtime;
 
-use super::error::Error;
+use super::{TreeOptions, error::Error};
 
 #[cfg(doc)]
 use super::BehaviorTreeFactory;
 // endregion:   --- modules
 
+// region:		--- ConfigurableTypeFn
+/// Registers a behavior of a configurable type with a JSON configuration,
+/// see [`BehaviorRegistry::add_configurable_type`].
+type ConfigurableTypeFn = fn(&mut BehaviorRegistry, &str, &str) -> Result<(), Error>;
+
+/// Registers the behavior `name` of the configurable type `T`.
+/// The configuration is deserialized once, every created behavior gets its own instance.
+fn configured_behavior<T: FromConfig>(registry: &mut BehaviorRegistry, name: &str, config_json: &str) -> Result<(), Error> {
+	let config: T::Config = nanoserde::DeJson::deserialize_json(config_json)?;
+	let bhvr_desc = BehaviorDescription::new(name, name, T::kind(), false, T::provided_ports());
+	registry.add_behavior(bhvr_desc, move || -> BehaviorPtr { Box::new(T::from_config(&config)) })
+}
+// endregion:	--- ConfigurableTypeFn
+
 // region:		--- SubstitutionRule
 /// Variants of substitution rules
 #[cfg(feature = "mock_behavior")]
@@ -41,6 +64,34 @@ pub enum SubstitutionRule {
 }
 // endregion:	--- SubstitutionRule
 
+// region:		--- MergePolicy
+/// Policy for name collisions when merging a [`BehaviorRegistry`] into another one.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MergePolicy {
+	/// A collision is an error, nothing is merged.
+	#[default]
+	Fail,
+	/// On a collision the existing entry is kept.
+	KeepExisting,
+	/// On a collision the existing entry is replaced.
+	Override,
+}
+// endregion:	--- MergePolicy
+
+// region:		--- TreeRedefinitionPolicy
+/// Policy for registering a (sub)tree definition with an already registered ID.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TreeRedefinitionPolicy {
+	/// A redefinition is an error.
+	#[default]
+	Fail,
+	/// A redefinition is ignored, if its source is identical to the registered one, otherwise it is an error.
+	IgnoreIdentical,
+	/// A redefinition replaces the registered definition.
+	Overwrite,
+}
+// endregion:	--- TreeRedefinitionPolicy
+
 // region:     --- TreeNodesModelEntry
 /// A `TreeNodesModel` entry.
 #[derive(Debug)]
@@ -58,6 +109,9 @@ pub struct BehaviorRegistry {
 	/// [`BTreeMap`] of available behavior creation functions.
 	/// The key is the name stored in the [`BehaviorDescription`].
 	behaviors: BTreeMap<ConstString, (BehaviorDescription, Arc<BehaviorCreationFn>)>,
+	/// [`BTreeMap`] of types constructable from a JSON configuration.
+	/// The key is the type tag used in [`BehaviorRegistry::add_configured_behavior`].
+	configurable_types: BTreeMap<ConstString, ConfigurableTypeFn>,
 	/// [`BTreeMap`] of registered behavior tree definitions.
 	tree_definitions: BTreeMap<ConstString, (ConstString, Range<usize>)>,
 	/// `TreNodesModel` remappings. The key is combined from behaviors type and ID.
@@ -67,7 +121,28 @@ pub struct BehaviorRegistry {
 	substitution_rules: BTreeMap<ConstString, SubstitutionRule>,
 	/// Main tree ID
 	main_tree_id: Option<ConstString>,
+	/// IDs of the trees flagged as entry points
+	main_trees: BTreeSet<ConstString>,
+	/// Limits for the XML parser
+	parser_limits: ParserLimits,
+	/// Options for the XML parser and the created trees
+	options: TreeOptions,
+	/// Maximum depth of a created tree including its subtrees, `None` for no limit
+	max_tree_depth: Option<usize>,
+	/// Seed for reproducible random behaviors
+	seed: Option<u64>,
+	/// Policy for registering a (sub)tree definition with an already registered ID
+	tree_redefinition_policy: TreeRedefinitionPolicy,
+	/// Clock for the time based behaviors, `None` for the default clock
+	#[cfg(any(feature = "std", feature = "embassy"))]
+	clock: Option<Arc<dyn Clock>>,
+	/// Names of the behaviors registered by [`BehaviorTreeFactory::new()`].
+	builtin_behaviors: BTreeSet<ConstString>,
+	/// Registered enum tuples, needed to merge registries.
+	#[cfg(feature = "scripting")]
+	enums: BTreeMap<ConstString, i8>,
 	/// Scripting runtime
+	#[cfg(feature = "scripting")]
 	runtime: Runtime,
 	/// List of loaded libraries.
 	/// Every tree must keep a reference to its needed libraries to keep the libraries in memory
@@ -99,6 +174,60 @@ impl BehaviorRegistry {
 		Ok(())
 	}
 
+	/// Add the type `T` under `type_tag`, so that behaviors of that type can be added
+	/// with a JSON configuration using [`BehaviorRegistry::add_configured_behavior`].
+	/// # Errors
+	/// - if a type with that `type_tag` is already registered
+	pub fn add_configurable_type<T: FromConfig>(&mut self, type_tag: &str) -> Result<(), Error> {
+		if self.configurable_types.contains_key(type_tag) {
+			return Err(Error::AlreadyRegistered { name: type_tag.into() });
+		}
+		self.configurable_types
+			.insert(type_tag.into(), configured_behavior::<T>);
+		Ok(())
+	}
+
+	/// Add a behavior `name` of the configurable type registered as `type_tag`,
+	/// constructed from the configuration in `config_json`.
+	/// # Errors
+	/// - if no type with that `type_tag` is registered
+	/// - if `config_json` is not a valid configuration for the type
+	/// - if a behavior with that `name` is already registered
+	pub fn add_configured_behavior(&mut self, name: &str, type_tag: &str, config_json: &str) -> Result<(), Error> {
+		let Some(add_fn) = self.configurable_types.get(type_tag).copied() else {
+			return Err(Error::NotRegistered { name: type_tag.into() });
+		};
+		add_fn(self, name, config_json)
+	}
+
+	/// Add a behavior `name`, which wraps the behavior registered as `inner`.
+	/// On creation, `wrap_fn` gets a new instance of the inner behavior and returns the wrapping behavior.
+	///
+	/// The new behavior uses the kind and ports of the inner behavior,
+	/// so the wrapper should delegate [`BehaviorExecution::static_provided_ports`] to the inner behavior.
+	/// # Errors
+	/// - if no behavior `inner` is registered
+	/// - if a behavior `name` already exists
+	pub fn register_wrapper<F>(&mut self, name: &str, inner: &str, wrap_fn: F) -> Result<(), Error>
+	where
+		F: Fn(BehaviorPtr) -> BehaviorPtr + Send + Sync + 'static,
+	{
+		let Some((inner_desc, inner_creation_fn)) = self.behaviors.get(inner) else {
+			return Err(Error::NotRegistered { name: inner.into() });
+		};
+		let bhvr_desc = BehaviorDescription::new(name, name, inner_desc.kind(), false, inner_desc.ports().clone());
+		let inner_creation_fn = inner_creation_fn.clone();
+		self.add_behavior(bhvr_desc, move || wrap_fn(inner_creation_fn()))
+	}
+
+	/// Get the [`BehaviorDescription`] of the behavior registered under `name`.
+	#[must_use]
+	pub fn behavior_description(&self, name: &str) -> Option<&BehaviorDescription> {
+		self.behaviors
+			.get(name)
+			.map(|(bhvr_desc, _)| bhvr_desc)
+	}
+
 	pub(crate) const fn behaviors(&self) -> &BTreeMap<ConstString, (BehaviorDescription, Arc<BehaviorCreationFn>)> {
 		&self.behaviors
 	}
@@ -150,6 +279,17 @@ impl BehaviorRegistry {
 		self.main_tree_id = Some(id.into());
 	}
 
+	/// Flag the tree `id` as an entry point.
+	pub fn add_main_tree(&mut self, id: &str) {
+		self.main_trees.insert(id.into());
+	}
+
+	/// Get the sorted IDs of the trees flagged as entry points.
+	#[must_use]
+	pub fn main_trees(&self) -> Vec<ConstString> {
+		self.main_trees.iter().cloned().collect()
+	}
+
 	/// Clear registered behavior trees.
 	///
 	/// Clears only the registered trees, not the registered behaviors.
@@ -157,6 +297,7 @@ impl BehaviorRegistry {
 	pub fn clear_registered_trees(&mut self) {
 		// delete the main tree id
 		self.main_tree_id = None;
+		self.main_trees.clear();
 		// remove tree definitions
 		self.tree_definitions.clear();
 		// @TODO: What about the libraries???
@@ -168,6 +309,74 @@ impl BehaviorRegistry {
 		self.main_tree_id.clone()
 	}
 
+	/// Get the limits for the XML parser.
+	#[must_use]
+	pub const fn parser_limits(&self) -> &ParserLimits {
+		&self.parser_limits
+	}
+
+	/// Set the limits for the XML parser.
+	pub const fn set_parser_limits(&mut self, limits: ParserLimits) {
+		self.parser_limits = limits;
+	}
+
+	/// Get the options for the XML parser and the created trees.
+	#[must_use]
+	pub const fn options(&self) -> TreeOptions {
+		self.options
+	}
+
+	/// Set the options for the XML parser and the created trees.
+	pub const fn set_options(&mut self, options: TreeOptions) {
+		self.options = options;
+	}
+
+	/// Get the maximum depth of a created tree including its subtrees, if one is set.
+	#[must_use]
+	pub const fn max_tree_depth(&self) -> Option<usize> {
+		self.max_tree_depth
+	}
+
+	/// Set the maximum depth of a created tree including its subtrees, `None` for no limit.
+	pub const fn set_max_tree_depth(&mut self, depth: Option<usize>) {
+		self.max_tree_depth = depth;
+	}
+
+	/// Get the seed for random behaviors, if one is set.
+	#[must_use]
+	pub const fn seed(&self) -> Option<u64> {
+		self.seed
+	}
+
+	/// Set the seed for random behaviors.
+	pub const fn set_seed(&mut self, seed: Option<u64>) {
+		self.seed = seed;
+	}
+
+	/// Get the clock for the time based behaviors, `None` for the default clock.
+	#[cfg(any(feature = "std", feature = "embassy"))]
+	#[must_use]
+	pub const fn clock(&self) -> Option<&Arc<dyn Clock>> {
+		self.clock.as_ref()
+	}
+
+	/// Set the clock for the time based behaviors.
+	#[cfg(any(feature = "std", feature = "embassy"))]
+	pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+		self.clock = Some(clock);
+	}
+
+	/// Get the policy for registering a (sub)tree definition with an already registered ID.
+	#[must_use]
+	pub const fn tree_redefinition_policy(&self) -> TreeRedefinitionPolicy {
+		self.tree_redefinition_policy
+	}
+
+	/// Set the policy for registering a (sub)tree definition with an already registered ID.
+	pub const fn set_tree_redefinition_policy(&mut self, policy: TreeRedefinitionPolicy) {
+		self.tree_redefinition_policy = policy;
+	}
+
 	/// Add a behavior tree definition to the registry.
 	/// # Errors
 	/// - if the behavior tree definition is already registered.
@@ -178,14 +387,35 @@ impl BehaviorRegistry {
 		range: Range<usize>,
 	) -> Result<(), Error> {
 		let key: ConstString = id.into();
-		if let alloc::collections::btree_map::Entry::Vacant(e) = self.tree_definitions.entry(key) {
-			e.insert((tree_definition, range));
-			Ok(())
-		} else {
-			Err(Error::AlreadyRegistered { name: id.into() })
+		match self.tree_definitions.entry(key) {
+			alloc::collections::btree_map::Entry::Vacant(e) => {
+				e.insert((tree_definition, range));
+				Ok(())
+			}
+			alloc::collections::btree_map::Entry::Occupied(mut e) => match self.tree_redefinition_policy {
+				TreeRedefinitionPolicy::Fail => Err(Error::AlreadyRegistered { name: id.into() }),
+				TreeRedefinitionPolicy::IgnoreIdentical => {
+					let (source, registered) = e.get();
+					if source[registered.clone()] == tree_definition[range] {
+						Ok(())
+					} else {
+						Err(Error::AlreadyRegistered { name: id.into() })
+					}
+				}
+				TreeRedefinitionPolicy::Overwrite => {
+					e.insert((tree_definition, range));
+					Ok(())
+				}
+			},
 		}
 	}
 
+	/// Replace a behavior tree definition in the registry.
+	pub(crate) fn replace_tree_definition(&mut self, id: &str, tree_definition: ConstString, range: Range<usize>) {
+		self.tree_definitions
+			.insert(id.into(), (tree_definition, range));
+	}
+
 	/// Fetch a behavior creation function from the registry.
 	/// # Errors
 	/// - if the behavior is not found in the registry
@@ -306,19 +536,111 @@ impl BehaviorRegistry {
 	}
 
 	/// Access the runtime.
+	#[cfg(feature = "scripting")]
 	#[must_use]
 	pub const fn runtime(&self) -> &Runtime {
 		&self.runtime
 	}
 
 	/// Access the runtime mutable.
+	#[cfg(feature = "scripting")]
 	pub const fn runtime_mut(&mut self) -> &mut Runtime {
 		&mut self.runtime
 	}
 
+	#[cfg(feature = "scripting")]
 	pub(crate) fn register_enum_tuple(&mut self, key: &str, value: i8) -> Result<(), Error> {
 		self.runtime.register_enum_tuple(key, value)?;
+		self.enums.insert(key.into(), value);
 		Ok(())
 	}
+
+	/// Marks all currently registered behaviors as builtin.
+	pub(crate) fn mark_builtin_behaviors(&mut self) {
+		self.builtin_behaviors = self.behaviors.keys().cloned().collect();
+	}
+
+	/// Merges `other` into this registry.
+	///
+	/// Behaviors which are builtin in both registries are no collisions and kept as they are.
+	/// Enum tuples with identical values are no collisions, differing values can not be overridden.
+	/// The main tree id of `other` is only taken if this registry has none.
+	/// # Errors
+	/// - if there is a collision and `policy` is [`MergePolicy::Fail`]
+	/// - if an enum key has differing values and `policy` is not [`MergePolicy::KeepExisting`]
+	pub(crate) fn merge(&mut self, other: Self, policy: MergePolicy) -> Result<(), Error> {
+		// check all collisions before changing anything
+		#[cfg(feature = "scripting")]
+		if policy != MergePolicy::KeepExisting {
+			for (key, value) in &other.enums {
+				if self
+					.enums
+					.get(key)
+					.is_some_and(|own| own != value)
+				{
+					return Err(Error::AlreadyRegistered { name: key.clone() });
+				}
+			}
+		}
+		if policy == MergePolicy::Fail {
+			let collision = other
+				.behaviors
+				.keys()
+				.filter(|key| !(self.builtin_behaviors.contains(*key) && other.builtin_behaviors.contains(*key)))
+				.find(|key| self.behaviors.contains_key(*key))
+				.or_else(|| find_collision(&self.configurable_types, &other.configurable_types))
+				.or_else(|| find_collision(&self.tree_definitions, &other.tree_definitions))
+				.or_else(|| find_collision(&self.tree_nodes_models, &other.tree_nodes_models));
+			#[cfg(feature = "mock_behavior")]
+			let collision = collision.or_else(|| find_collision(&self.substitution_rules, &other.substitution_rules));
+			if let Some(key) = collision {
+				return Err(Error::AlreadyRegistered { name: key.clone() });
+			}
+		}
+
+		for (key, entry) in other.behaviors {
+			if !(self.builtin_behaviors.contains(&key) && other.builtin_behaviors.contains(&key)) {
+				merge_entry(&mut self.behaviors, key, entry, policy);
+			}
+		}
+		for (key, entry) in other.configurable_types {
+			merge_entry(&mut self.configurable_types, key, entry, policy);
+		}
+		for (key, entry) in other.tree_definitions {
+			merge_entry(&mut self.tree_definitions, key, entry, policy);
+		}
+		for (key, entry) in other.tree_nodes_models {
+			merge_entry(&mut self.tree_nodes_models, key, entry, policy);
+		}
+		#[cfg(feature = "mock_behavior")]
+		for (key, entry) in other.substitution_rules {
+			merge_entry(&mut self.substitution_rules, key, entry, policy);
+		}
+		#[cfg(feature = "scripting")]
+		for (key, value) in other.enums {
+			if !self.enums.contains_key(&key) {
+				self.register_enum_tuple(&key, value)?;
+			}
+		}
+		self.main_trees.extend(other.main_trees);
+		if self.main_tree_id.is_none() {
+			self.main_tree_id = other.main_tree_id;
+		}
+		#[cfg(feature = "std")]
+		self.libraries.extend(other.libraries);
+		Ok(())
+	}
+}
+
+/// Returns the first key of `other` which is also a key of `own`.
+fn find_collision<'a, V>(own: &BTreeMap<ConstString, V>, other: &'a BTreeMap<ConstString, V>) -> Option<&'a ConstString> {
+	other.keys().find(|key| own.contains_key(*key))
+}
+
+/// Inserts an entry into a map according to the [`MergePolicy`].
+fn merge_entry<V>(map: &mut BTreeMap<ConstString, V>, key: ConstString, entry: V, policy: MergePolicy) {
+	if policy == MergePolicy::Override || !map.contains_key(&key) {
+		map.insert(key, entry);
+	}
 }
 // endregion:   --- BehaviorRegistry
@@ -13,6 +13,8 @@ pub mod prelude;
 
 // modules
 pub mod behavior; // due to macros!!
+#[cfg(any(feature = "std", feature = "embassy"))]
+pub mod clock;
 mod error;
 pub mod factory; // due to macros!!
 pub mod port; // due to macros!!
@@ -21,21 +23,30 @@ mod xml;
 
 // flatten:
 pub use behavior::{
-	Behavior, BehaviorError, BehaviorExecution, BehaviorKind, BehaviorResult, BehaviorState, behavior_data::BehaviorData,
-	behavior_description::BehaviorDescription,
+	Behavior, BehaviorError, BehaviorExecution, BehaviorKind, BehaviorResult, BehaviorState, InlineBehavior,
+	behavior_data::BehaviorData, behavior_description::BehaviorDescription,
 };
 //pub use blackboard::{Blackboard, BlackboardData, BlackboardInterface, Remappings, SharedBlackboard};
+#[cfg(feature = "embassy")]
+pub use clock::EmbassyClock;
+#[cfg(feature = "std")]
+pub use clock::SystemClock;
+#[cfg(any(feature = "std", feature = "embassy"))]
+pub use clock::{Clock, ManualClock};
 pub use error::{BehaviorTreeResult, Error};
-pub use factory::BehaviorTreeFactory;
+pub use factory::{BehaviorTreeFactory, TreeOptions};
 pub use port::PortList;
 #[cfg(feature = "std")]
 pub use tree::observer::groot2_connector::Groot2Connector;
+pub use tree::observer::state_streamer::StateStreamer;
 pub use tree::observer::tree_observer::BehaviorTreeObserver;
-pub use tree::{BehaviorTree, BehaviorTreeElement};
-pub use xml::creator::XmlCreator;
+pub use tree::{BehaviorTree, BehaviorTreeElement, TickHook, TreeWarning, error::Error as TreeError};
+pub use xml::{ParserLimits, binary::BinaryXmlEncoder, creator::XmlCreator, error::Error as XmlError};
 
 // re-exports:
 pub use behaviortree_derive::{Action, Condition, Control, Decorator};
+#[cfg(feature = "scripting")]
+pub use tinyscript::{Runtime, SharedRuntime};
 // Mutex from wherever it comes from for register_simple_behavior!() and SharedQueue
 pub use spin::Mutex;
 
@@ -47,6 +58,15 @@ use alloc::sync::Arc;
 /// An immutable thread safe `String` type
 /// see: [Logan Smith](https://www.youtube.com/watch?v=A4cKi7PTJSs).
 type ConstString = Arc<str>;
+
+/// Placeholder for the scripting runtime, if feature `scripting` is disabled.
+#[cfg(not(feature = "scripting"))]
+#[derive(Clone, Debug, Default)]
+pub struct Runtime;
+
+/// Defines a shared [`Runtime`], which is only a placeholder, if feature `scripting` is disabled.
+#[cfg(not(feature = "scripting"))]
+pub type SharedRuntime = Arc<Mutex<Runtime>>;
 // endregion:   --- types
 
 // region:		--- globals
@@ -80,6 +100,10 @@ pub const BEHAVIORTREE: &str = "BehaviorTree";
 /// Literal `"TreeNodesModel"`
 pub const TREENODESMODEL: &str = "TreeNodesModel";
 
+/// Literal "reason" for the optional output port of conditions explaining a failure.
+/// See [`BehaviorData::set_reason`](crate::behavior::behavior_data::BehaviorData::set_reason).
+pub const REASON: &str = "reason";
+
 /// Literal "name" for ports etc.
 const NAME: &str = "name";
 /// Literal "ID" for ports etc.
@@ -89,6 +113,7 @@ const DEFAULT: &str = "default";
 
 /// Literals for scripting ports
 const AUTOREMAP: &str = "_autoremap";
+const PREFIX: &str = "_prefix";
 const FAILURE_IF: &str = "_failureIf";
 const SUCCESS_IF: &str = "_successIf";
 const SKIP_IF: &str = "_skipIf";
@@ -97,6 +122,8 @@ const ON_HALTED: &str = "_onHalted";
 const ON_FAILURE: &str = "_onFailure";
 const ON_SUCCESS: &str = "_onSuccess";
 const POST: &str = "_post";
+const ON_ENTER: &str = "_on_enter";
+const ON_EXIT: &str = "_on_exit";
 // endregion:	--- globals
 
 // region:		---macros
@@ -131,7 +158,8 @@ macro_rules! register_behavior {
 	}};
 	// behavior type struct with arguments for construction
 	($factory:ident, $tp:ty, $name:literal, $($arg:expr),* $(,)?) => {{
-		let bhvr_desc = $crate::behavior::behavior_description::BehaviorDescription::new($name, stringify!($tp), <$tp>::kind(), false, <$tp>::provided_ports());
+		let mut bhvr_desc = $crate::behavior::behavior_description::BehaviorDescription::new($name, $name, <$tp>::kind(), false, <$tp>::provided_ports());
+		bhvr_desc.set_arguments(Some(stringify!($($arg),*).into()));
 		let bhvr_creation_fn = alloc::boxed::Box::new(move || -> alloc::boxed::Box<dyn $crate::behavior::BehaviorExecution> {
 			alloc::boxed::Box::new(<$tp>::new($($arg),*))
 		});
@@ -141,6 +169,43 @@ macro_rules! register_behavior {
 	}};
 }
 
+/// Macro to register a batch of behaviors in one invocation.
+/// Each entry is registered with [`register_behavior!(...)`](crate::register_behavior!),
+/// the registration stops at the first failing entry.
+///
+/// # Usage:
+///
+/// ```no-test
+/// register_behaviors!(<mutable (reference to) behavior factory>,
+///     <behavior to register> => <"identifying name">,
+///     <behavior to register> => <"identifying name"> [<arg1>, <arg2>, ...],
+///     ...
+/// )
+/// ```
+///
+/// # Example:
+///
+/// ```no-test
+/// let mut factory = BehaviorTreeFactory::new()?;
+/// register_behaviors!(factory,
+///     ActionA => "Action_A",
+///     ActionB => "Action_B" [42, "hello world".into()],
+///     Loop<Pose2D> => "LoopPose",
+/// )?;
+/// ```
+#[macro_export]
+macro_rules! register_behaviors {
+	($factory:ident, $($tp:ty => $name:literal $([$($arg:expr),* $(,)?])?),* $(,)?) => {{
+		let mut register = || -> ::core::result::Result<(), $crate::factory::error::Error> {
+			$(
+				$crate::register_behavior!($factory, $tp, $name $($(, $arg)*)?)?;
+			)*
+			Ok(())
+		};
+		register()
+	}};
+}
+
 /// Macro to register groot2 behaviors.
 /// It as the same usage as the macro [`register_behavior!(...)`](crate::register_behavior!),
 /// the difference is, that it marks a behavior as knwon by Groot2.
@@ -152,7 +217,8 @@ macro_rules! register_groot2_behavior {
 	}};
 	// behavior type struct with arguments for construction
 	($factory:ident, $tp:ty, $name:literal, $($arg:expr),* $(,)?) => {{
-		let bhvr_desc = $crate::behavior::behavior_description::BehaviorDescription::new($name, stringify!($tp), <$tp>::kind(), true, <$tp>::provided_ports());
+		let mut bhvr_desc = $crate::behavior::behavior_description::BehaviorDescription::new($name, $name, <$tp>::kind(), true, <$tp>::provided_ports());
+		bhvr_desc.set_arguments(Some(stringify!($($arg),*).into()));
 		let bhvr_creation_fn = alloc::boxed::Box::new(move || -> alloc::boxed::Box<dyn $crate::behavior::BehaviorExecution> {
 			alloc::boxed::Box::new(<$tp>::new($($arg),*))
 		});
@@ -241,13 +307,12 @@ macro_rules! register_simple_behavior {
 /// ```no-test
 /// register_scripting_enum!(factory "THE_ANSWER", 42, "OTHER_ANSWER", 44);
 /// ```
+#[cfg(feature = "scripting")]
 #[macro_export]
 macro_rules! register_scripting_enum {
 	// register an enum type
 	($factory:ident, $tp:ty) => {
-		for (key, value) in <$tp>::key_value_tuples() {
-			$factory.register_enum_tuple(key, value)?;
-		}
+		$factory.register_enum_type::<$tp>()?;
 	};
 	// register a key value pair
 	($factory:ident, $($key:literal, $value:literal),+ $(,)?) => {
@@ -7,6 +7,15 @@ use crate::ConstString;
 /// Port errors.
 #[non_exhaustive]
 pub enum Error {
+	/// Accessing a port by reference failed
+	Access {
+		/// Name of the port
+		port: ConstString,
+		/// Name of the requested type
+		type_name: ConstString,
+		/// The databoard error
+		source: databoard::Error,
+	},
 	/// the port is already defined in list of ports
 	AlreadyInPortList {
 		/// The port name
@@ -34,6 +43,39 @@ pub enum Error {
 		/// The key that could not be found
 		key: ConstString,
 	},
+	/// An output port is read with strict port directions
+	NotReadable {
+		/// Name of the port
+		port: ConstString,
+	},
+	/// An input port is written with strict port directions
+	NotWritable {
+		/// Name of the port
+		port: ConstString,
+	},
+	/// The stored value has a different type than requested
+	TypeMismatch {
+		/// Name of the port
+		key: ConstString,
+		/// Name of the requested type
+		expected: ConstString,
+		/// Name of the stored type
+		actual: ConstString,
+	},
+}
+
+impl Error {
+	/// Returns whether the error is caused by a missing value, e.g. an unset blackboard entry.
+	#[must_use]
+	pub const fn is_not_found(&self) -> bool {
+		match self {
+			Self::NotFound { .. } => true,
+			Self::Access { source, .. } | Self::Databoard { source } => {
+				matches!(source, databoard::Error::NotFound { .. })
+			}
+			_ => false,
+		}
+	}
 }
 
 /// Only default implementation needed.
@@ -52,11 +94,19 @@ impl core::error::Error for Error {
 impl core::fmt::Debug for Error {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		match self {
+			Self::Access { port, type_name, source } => {
+				write!(f, "Access(port: {port}, type_name: {type_name}, source: {source})")
+			}
 			Self::AlreadyInPortList { key } => write!(f, "AlreadyInPortList(key: {key})"),
 			Self::CouldNotConvert { value, port } => write!(f, "CouldNotConvert(value: {value}, value: {port})"),
 			Self::Databoard { source } => write!(f, "Databoard({source})"),
 			Self::NameNotAllowed { port } => write!(f, "NameNotAllowed(port: {port})"),
 			Self::NotFound { key } => write!(f, "NotFound(key: {key})"),
+			Self::NotReadable { port } => write!(f, "NotReadable(port: {port})"),
+			Self::NotWritable { port } => write!(f, "NotWritable(port: {port})"),
+			Self::TypeMismatch { key, expected, actual } => {
+				write!(f, "TypeMismatch(key: {key}, expected: {expected}, actual: {actual})")
+			}
 		}
 	}
 }
@@ -64,11 +114,19 @@ impl core::fmt::Debug for Error {
 impl core::fmt::Display for Error {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		match self {
+			Self::Access { port, type_name, source } => {
+				write!(f, "could not access port {port} as type {type_name}: {source}")
+			}
 			Self::AlreadyInPortList { key } => write!(f, "the port {key} is already in the list of defined ports"),
 			Self::CouldNotConvert { value, port } => write!(f, "could not convert '{value}' into wanted type for {port}"),
 			Self::Databoard { source } => write!(f, "accessing blackboard failed with: {source}"),
 			Self::NameNotAllowed { port } => write!(f, "the name {port} is not allowed for a port"),
 			Self::NotFound { key } => write!(f, "key {key} could not be found"),
+			Self::NotReadable { port } => write!(f, "the output port {port} can not be read"),
+			Self::NotWritable { port } => write!(f, "the input port {port} can not be written"),
+			Self::TypeMismatch { key, expected, actual } => {
+				write!(f, "the value for {key} has type {actual} but {expected} was requested")
+			}
 		}
 	}
 }
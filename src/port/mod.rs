@@ -12,12 +12,16 @@ pub use port_definition::PortDefinition;
 pub use port_direction::PortDirection;
 pub use port_list::PortList;
 
-use crate::{AUTOREMAP, FAILURE_IF, ID, NAME, ON_FAILURE, ON_HALTED, ON_SUCCESS, POST, SKIP_IF, SUCCESS_IF, WHILE};
+use crate::{
+	AUTOREMAP, FAILURE_IF, ID, NAME, ON_ENTER, ON_EXIT, ON_FAILURE, ON_HALTED, ON_SUCCESS, POST, PREFIX, SKIP_IF,
+	SUCCESS_IF, WHILE,
+};
 use error::Error;
 
 // forbidden port names
 const FORBIDDEN_PORT_NAMES: &[&str] = &[
-	NAME, ID, AUTOREMAP, FAILURE_IF, SUCCESS_IF, SKIP_IF, WHILE, ON_HALTED, ON_FAILURE, ON_SUCCESS, POST,
+	NAME, ID, AUTOREMAP, FAILURE_IF, SUCCESS_IF, SKIP_IF, WHILE, ON_HALTED, ON_FAILURE, ON_SUCCESS, POST, ON_ENTER, ON_EXIT,
+	PREFIX,
 ];
 
 // region:   	--- helper
@@ -39,6 +43,8 @@ pub fn create_port<T>(
 }
 
 /// Check a name to be allowed for ports.
+/// A leading `@` declares a port of the global scope, which is bound to the equally named entry of the root blackboard,
+/// unless a default value is given.
 #[must_use]
 pub fn is_allowed_port_name(name: &str) -> bool {
 	if name.is_empty() {
@@ -68,6 +74,56 @@ pub fn is_allowed_port_name(name: &str) -> bool {
 	}
 	true
 }
+
+/// Check a name to be allowed for ports at compile time.
+///
+/// In contrast to [`is_allowed_port_name`] only ASCII letters are accepted as first character.
+#[must_use]
+pub const fn is_allowed_const_port_name(name: &str) -> bool {
+	let bytes = name.as_bytes();
+	let start = match bytes {
+		[b'@', ..] => 1,
+		_ => 0,
+	};
+	if bytes.len() <= start || !bytes[start].is_ascii_alphabetic() {
+		return false;
+	}
+	let mut i = 0;
+	while i < FORBIDDEN_PORT_NAMES.len() {
+		if const_str_eq(FORBIDDEN_PORT_NAMES[i], name) {
+			return false;
+		}
+		i += 1;
+	}
+	true
+}
+
+/// Returns the `name`, if it is allowed for ports.
+/// Used in a const context, e.g. via [`port_name!`](crate::port_name), a forbidden name fails to compile.
+/// # Panics
+/// - if the name is not allowed for ports
+#[must_use]
+pub const fn check_port_name(name: &'static str) -> &'static str {
+	assert!(is_allowed_const_port_name(name), "name is not allowed for ports");
+	name
+}
+
+/// Compares two str in a const context.
+const fn const_str_eq(a: &str, b: &str) -> bool {
+	let a = a.as_bytes();
+	let b = b.as_bytes();
+	if a.len() != b.len() {
+		return false;
+	}
+	let mut i = 0;
+	while i < a.len() {
+		if a[i] != b[i] {
+			return false;
+		}
+		i += 1;
+	}
+	true
+}
 // endregion:   --- helper
 
 // region:		---macros
@@ -190,6 +246,42 @@ macro_rules! output_port {
 macro_rules! port_list {
 	($($e:expr),* $(,)?) => {$crate::port::PortList(alloc::vec![$($e),*])};
 }
+
+/// macro for a compile time check of a port name, evaluates to the name.
+/// A forbidden or empty name fails to compile.
+#[macro_export]
+macro_rules! port_name {
+	($name:expr $(,)?) => {{
+		const NAME: &str = $crate::port::check_port_name($name);
+		NAME
+	}};
+}
+
+/// macro for creation of a port definition with a compile time checked name.
+///
+/// The first element is the direction `In`, `InOut` or `Out`,
+/// the remaining elements are the same as for [`input_port!`], [`inout_port!`] and [`output_port!`].
+#[macro_export]
+macro_rules! const_port {
+	(In, $tp:ty, $name:expr $(,)?) => {
+		$crate::input_port!($tp, $crate::port_name!($name))
+	};
+	(In, $tp:ty, $name:expr, $($rest:tt)+) => {
+		$crate::input_port!($tp, $crate::port_name!($name), $($rest)+)
+	};
+	(InOut, $tp:ty, $name:expr $(,)?) => {
+		$crate::inout_port!($tp, $crate::port_name!($name))
+	};
+	(InOut, $tp:ty, $name:expr, $($rest:tt)+) => {
+		$crate::inout_port!($tp, $crate::port_name!($name), $($rest)+)
+	};
+	(Out, $tp:ty, $name:expr $(,)?) => {
+		$crate::output_port!($tp, $crate::port_name!($name))
+	};
+	(Out, $tp:ty, $name:expr, $($rest:tt)+) => {
+		$crate::output_port!($tp, $crate::port_name!($name), $($rest)+)
+	};
+}
 // endregion:	--- macros
 
 #[cfg(test)]
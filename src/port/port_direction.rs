@@ -14,6 +14,13 @@ const INPUT_TYPE: &str = "input_port";
 const OUTPUT_TYPE: &str = "output_port";
 const INOUT_TYPE: &str = "inout_port";
 
+/// All variants of [`PortDirection`], see function `all()` below.
+const ALL: &[PortDirection] = &[
+	PortDirection::In,
+	PortDirection::Out,
+	PortDirection::InOut,
+];
+
 /// Direction of a [`Port`](crate::port).
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(u8)]
@@ -33,6 +40,12 @@ impl core::fmt::Display for PortDirection {
 }
 
 impl PortDirection {
+	/// Returns all [`PortDirection`]s.
+	#[must_use]
+	pub const fn all() -> &'static [Self] {
+		ALL
+	}
+
 	/// Get the [`PortDirection`] as &str.
 	#[must_use]
 	pub const fn as_str(&self) -> &str {
@@ -61,15 +74,13 @@ impl TryFrom<&str> for PortDirection {
 
 	fn try_from(value: &str) -> Result<Self, Self::Error> {
 		let value = value.to_lowercase();
-		match value.as_ref() {
-			INPUT_TYPE | INPUT => Ok(Self::In),
-			OUTPUT_TYPE | OUTPUT => Ok(Self::Out),
-			INOUT_TYPE | INOUT => Ok(Self::InOut),
-			_ => Err(crate::port::Error::CouldNotConvert {
+		ALL.iter()
+			.find(|direction| direction.type_str() == value || direction.as_str().eq_ignore_ascii_case(&value))
+			.copied()
+			.ok_or_else(|| crate::port::Error::CouldNotConvert {
 				value: value.into(),
 				port: "direction".into(),
-			}),
-		}
+			})
 	}
 }
 // endregion:   --- PortDirection
@@ -8,6 +8,7 @@
 
 // to avoid adding these crates to dependencies
 pub extern crate alloc;
+#[cfg(feature = "scripting")]
 pub extern crate tinyscript;
 
 // re-exports
@@ -21,7 +22,9 @@ pub use behaviortree_derive::{Action, Condition, Control, Decorator};
 // databoard
 pub use databoard::{Databoard, Remappings};
 // tinyscript
-pub use tinyscript::{ScriptEnum, SharedRuntime};
+pub use crate::SharedRuntime;
+#[cfg(feature = "scripting")]
+pub use tinyscript::ScriptEnum;
 // Mutex from wherever it comes from for register_simple_behavior!() and SharedQueue
 pub use spin::Mutex;
 
@@ -31,20 +34,22 @@ pub use crate::EMPTY_STR;
 // error handling
 pub use crate::error::{BehaviorTreeResult, Error};
 // behavior macros
+#[cfg(feature = "scripting")]
+pub use crate::register_scripting_enum;
 #[cfg(feature = "simple_behavior")]
 pub use crate::register_simple_behavior;
-pub use crate::{register_behavior, register_scripting_enum};
+pub use crate::{register_behavior, register_behaviors};
 // port macros
-pub use crate::{inout_port, input_port, output_port, port_list};
+pub use crate::{const_port, inout_port, input_port, output_port, port_list, port_name};
 // behavior
 pub use crate::behavior::{
 	BehaviorKind, BehaviorResult, BehaviorState, behavior_data::BehaviorData, behavior_description::BehaviorDescription,
 	error::Error as BehaviorError,
 };
 // behavior traits
-pub use crate::behavior::{Behavior, BehaviorExecution};
+pub use crate::behavior::{Behavior, BehaviorExecution, FromConfig};
 // factory
-pub use crate::factory::BehaviorTreeFactory;
+pub use crate::factory::{BehaviorTreeFactory, TreeOptions, remappings_from_pairs};
 // port
 pub use crate::port::PortList;
 // tree
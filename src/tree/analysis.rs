@@ -0,0 +1,489 @@
+// Copyright © 2025 Stephan Kunz
+
+//! Data flow analysis of a [`BehaviorTree`](crate::tree::BehaviorTree).
+
+// region:      --- modules
+use alloc::{
+	collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+	string::String,
+	vec::Vec,
+};
+use databoard::strip_board_pointer;
+
+use crate::{ConstString, port::PortDirection};
+
+use super::{BehaviorTreeElement, TreeElementKind};
+// endregion:   --- modules
+
+// region:		--- TreeWarning
+/// A finding of the data flow analysis of a [`BehaviorTree`](crate::tree::BehaviorTree).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TreeWarning {
+	/// A port declares another type for a blackboard entry than a previous port accessing the same entry.
+	InconsistentType {
+		/// Path of the element
+		path: ConstString,
+		/// Name of the port
+		port: ConstString,
+		/// The accessed blackboard key
+		key: ConstString,
+		/// The declared type of the port
+		type_name: ConstString,
+		/// The type declared by the previous port
+		expected: ConstString,
+	},
+	/// An input port reads a blackboard entry, which has no producer within its blackboard scope.
+	InputWithoutProducer {
+		/// Path of the element
+		path: ConstString,
+		/// Name of the port
+		port: ConstString,
+		/// The read blackboard key
+		key: ConstString,
+	},
+	/// An output port writes a blackboard entry, which is never read within its blackboard scope.
+	OutputNeverRead {
+		/// Path of the element
+		path: ConstString,
+		/// Name of the port
+		port: ConstString,
+		/// The written blackboard key
+		key: ConstString,
+	},
+	/// An input port is neither remapped nor has a default value.
+	UnwiredInput {
+		/// Path of the element
+		path: ConstString,
+		/// Name of the port
+		port: ConstString,
+	},
+}
+
+impl core::fmt::Display for TreeWarning {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			Self::InconsistentType {
+				path,
+				port,
+				key,
+				type_name,
+				expected,
+			} => write!(
+				f,
+				"port `{port}` of {path} declares `{type_name}` for `{key}`, which is declared as `{expected}` elsewhere"
+			),
+			Self::InputWithoutProducer { path, port, key } => {
+				write!(f, "input port `{port}` of {path} reads `{key}`, which has no producer")
+			}
+			Self::OutputNeverRead { path, port, key } => {
+				write!(f, "output port `{port}` of {path} writes `{key}`, which is never read")
+			}
+			Self::UnwiredInput { path, port } => write!(f, "input port `{port}` of {path} has no producer or default"),
+		}
+	}
+}
+// endregion:	--- TreeWarning
+
+// region:		--- Scope
+/// An access of a port to a blackboard key.
+struct Access {
+	/// The blackboard key
+	key: ConstString,
+	/// Path of the element
+	path: ConstString,
+	/// Name of the port
+	port: ConstString,
+}
+
+/// The accesses within one blackboard scope, which is the main tree or a subtree.
+#[derive(Default)]
+struct Scope {
+	/// Keys, which are read and written from outside the scope or by bidirectional ports
+	shared: BTreeSet<ConstString>,
+	/// Constant values and scripts, which may access keys of the scope
+	scripts: Vec<ConstString>,
+	/// Accesses of input ports
+	reads: Vec<Access>,
+	/// Accesses of output ports
+	writes: Vec<Access>,
+}
+
+impl Scope {
+	/// Returns `true` if the `key` is shared or mentioned in a script.
+	fn is_shared(&self, key: &str) -> bool {
+		self.shared.contains(key)
+			|| self
+				.scripts
+				.iter()
+				.any(|script| mentions(script, key))
+	}
+
+	/// Returns `true` if the `key` is used anywhere within the scope.
+	fn uses(&self, key: &str) -> bool {
+		self.is_shared(key)
+			|| self
+				.reads
+				.iter()
+				.chain(self.writes.iter())
+				.any(|access| access.key.as_ref() == key)
+	}
+
+	/// Appends the warnings for the keys, which are only read or only written.
+	fn warnings(self, warnings: &mut Vec<TreeWarning>) {
+		let written: BTreeSet<&ConstString> = self
+			.writes
+			.iter()
+			.map(|access| &access.key)
+			.collect();
+		let read: BTreeSet<&ConstString> = self
+			.reads
+			.iter()
+			.map(|access| &access.key)
+			.collect();
+		for access in &self.reads {
+			if !written.contains(&&access.key) && !self.is_shared(&access.key) {
+				warnings.push(TreeWarning::InputWithoutProducer {
+					path: access.path.clone(),
+					port: access.port.clone(),
+					key: access.key.clone(),
+				});
+			}
+		}
+		for access in &self.writes {
+			if !read.contains(&&access.key) && !self.is_shared(&access.key) {
+				warnings.push(TreeWarning::OutputNeverRead {
+					path: access.path.clone(),
+					port: access.port.clone(),
+					key: access.key.clone(),
+				});
+			}
+		}
+	}
+}
+
+/// Returns `true` if the `script` contains `key` as a whole identifier.
+fn mentions(script: &str, key: &str) -> bool {
+	script
+		.split(|c: char| !(c.is_alphanumeric() || c == '_'))
+		.any(|identifier| identifier == key)
+}
+// endregion:	--- Scope
+
+// region:		--- analysis
+/// Analyzes the data flow of the tree with `root` within each blackboard scope.
+///
+/// Global `@` keys and the data flow between a subtree and its parent are not analyzed,
+/// the keys remapped at a subtree element count as read and written in both scopes.
+/// Keys mentioned in scripts or constant values count as read and written, too.
+pub fn analyze(root: &BehaviorTreeElement) -> Vec<TreeWarning> {
+	let mut warnings = Vec::new();
+	analyze_scope(root, &mut warnings);
+	warnings
+}
+
+/// Analyzes the scope of the `subtree` element and recursively the scopes of its nested subtrees.
+fn analyze_scope(subtree: &BehaviorTreeElement, warnings: &mut Vec<TreeWarning>) {
+	let mut nested = Vec::new();
+	let mut scope = collect_scope(subtree, &mut nested, warnings);
+	if let Some(remappings) = subtree.blackboard().remappings() {
+		for (key, _) in remappings.iter() {
+			scope.shared.insert(key.clone());
+		}
+	}
+	scope.warnings(warnings);
+	for subtree in nested {
+		analyze_scope(subtree, warnings);
+	}
+}
+
+/// Collects the accesses within the scope of the `subtree` element,
+/// nested subtrees are collected into `nested`.
+fn collect_scope<'a>(
+	subtree: &'a BehaviorTreeElement,
+	nested: &mut Vec<&'a BehaviorTreeElement>,
+	warnings: &mut Vec<TreeWarning>,
+) -> Scope {
+	let mut scope = Scope::default();
+	for child in subtree.children().iter() {
+		collect(child, &mut scope, nested, warnings);
+	}
+	scope
+}
+
+/// Collects the accesses of `element` and its descendants into `scope`,
+/// nested subtrees are collected into `nested` for a separate analysis.
+fn collect<'a>(
+	element: &'a BehaviorTreeElement,
+	scope: &mut Scope,
+	nested: &mut Vec<&'a BehaviorTreeElement>,
+	warnings: &mut Vec<TreeWarning>,
+) {
+	if matches!(element.kind(), TreeElementKind::SubTree) {
+		if let Some(remappings) = element.blackboard().remappings() {
+			for (_, value) in remappings.iter() {
+				match strip_board_pointer(value) {
+					Some(key) => {
+						scope.shared.insert(key.into());
+					}
+					None => scope.scripts.push(value.clone()),
+				}
+			}
+		}
+		nested.push(element);
+		return;
+	}
+
+	for conditions in [
+		&element.pre_conditions().0,
+		&element.post_conditions().0,
+	]
+	.into_iter()
+	.flatten()
+	{
+		scope
+			.scripts
+			.extend(conditions.iter().flatten().cloned());
+	}
+
+	let remappings = element.remappings();
+	for port in element.data().description().ports().iter() {
+		let value = remappings.find(port.name());
+		let key = value
+			.as_deref()
+			.and_then(strip_board_pointer)
+			.filter(|key| !key.starts_with('@'));
+		match (key, port.direction()) {
+			(Some(key), PortDirection::In) => scope.reads.push(Access {
+				key: key.into(),
+				path: element.groot2_path().clone(),
+				port: port.name().into(),
+			}),
+			(Some(key), PortDirection::Out) => scope.writes.push(Access {
+				key: key.into(),
+				path: element.groot2_path().clone(),
+				port: port.name().into(),
+			}),
+			(Some(key), PortDirection::InOut) => {
+				scope.shared.insert(key.into());
+			}
+			(None, direction) => match &value {
+				Some(value) => {
+					if strip_board_pointer(value).is_none() {
+						scope.scripts.push(value.clone());
+					}
+				}
+				None => {
+					if *direction == PortDirection::In && port.default_value().is_none() {
+						warnings.push(TreeWarning::UnwiredInput {
+							path: element.groot2_path().clone(),
+							port: port.name().into(),
+						});
+					}
+				}
+			},
+		}
+	}
+
+	for child in element.children().iter() {
+		collect(child, scope, nested, warnings);
+	}
+}
+// endregion:	--- analysis
+
+// region:		--- autoremap check
+/// Checks, that the keys read within the autoremapped subtrees below `root` are available,
+/// either remapped explicitly, used in the scope of a parent or already existing in the blackboard.
+///
+/// The `autoremapped` set contains the uids of the autoremapped subtree elements.
+/// Returns the path of the reading element and the key of the first unavailable entry.
+pub fn check_autoremap(root: &BehaviorTreeElement, autoremapped: &BTreeSet<u16>) -> Option<(ConstString, ConstString)> {
+	check_autoremap_scope(root, autoremapped, &[])
+}
+
+/// Checks the scope of the `subtree` element and recursively the scopes of its nested subtrees.
+/// The `outer` scopes are given with the flag, whether they are autoremapped themselves.
+fn check_autoremap_scope(
+	subtree: &BehaviorTreeElement,
+	autoremapped: &BTreeSet<u16>,
+	outer: &[(&Scope, bool)],
+) -> Option<(ConstString, ConstString)> {
+	let mut nested = Vec::new();
+	let mut scope = collect_scope(subtree, &mut nested, &mut Vec::new());
+	// the keys written within a nested autoremapped subtree are used in this scope, too
+	for inner in &nested {
+		if autoremapped.contains(&inner.uid()) {
+			collect_autoremapped_writes(inner, autoremapped, &mut scope.shared);
+		}
+	}
+
+	let is_autoremapped = autoremapped.contains(&subtree.uid());
+	if is_autoremapped {
+		let remappings = subtree.blackboard().remappings();
+		for access in &scope.reads {
+			let key = access.key.as_ref();
+			if scope
+				.writes
+				.iter()
+				.any(|write| write.key.as_ref() == key)
+				|| scope.is_shared(key)
+				|| remappings.is_some_and(|remappings| remappings.find(key).is_some())
+				|| subtree.blackboard().entry(key).is_ok()
+			{
+				continue;
+			}
+			let mut available = false;
+			for (scope, scope_autoremapped) in outer.iter().rev() {
+				if scope.uses(key) {
+					available = true;
+					break;
+				}
+				if !scope_autoremapped {
+					break;
+				}
+			}
+			if !available {
+				return Some((access.path.clone(), access.key.clone()));
+			}
+		}
+	}
+
+	let mut scopes = outer.to_vec();
+	scopes.push((&scope, is_autoremapped));
+	nested
+		.into_iter()
+		.find_map(|subtree| check_autoremap_scope(subtree, autoremapped, &scopes))
+}
+
+/// Collects the keys written or shared within the autoremapped `subtree`
+/// and its nested autoremapped subtrees into `keys`.
+fn collect_autoremapped_writes(
+	subtree: &BehaviorTreeElement,
+	autoremapped: &BTreeSet<u16>,
+	keys: &mut BTreeSet<ConstString>,
+) {
+	let mut nested = Vec::new();
+	let scope = collect_scope(subtree, &mut nested, &mut Vec::new());
+	keys.extend(scope.shared);
+	keys.extend(scope.writes.into_iter().map(|access| access.key));
+	for inner in nested {
+		if autoremapped.contains(&inner.uid()) {
+			collect_autoremapped_writes(inner, autoremapped, keys);
+		}
+	}
+}
+// endregion:	--- autoremap check
+
+// region:		--- type validation
+/// A port declaration accessing a blackboard entry.
+struct TypedAccess {
+	/// The blackboard key as given in the port remapping
+	key: ConstString,
+	/// Path of the element
+	path: ConstString,
+	/// Name of the port
+	port: ConstString,
+	/// The declared type of the port
+	type_name: &'static str,
+}
+
+/// The resolution of blackboard keys to entries within one blackboard scope.
+struct Resolver {
+	/// Identification of the scope
+	scope: ConstString,
+	/// Keys remapped to entries of a parent scope
+	remapped: BTreeMap<ConstString, ConstString>,
+}
+
+impl Resolver {
+	/// Returns the identification of the entry accessed with `key`.
+	fn resolve(&self, key: &str) -> ConstString {
+		if key.starts_with('@') {
+			key.into()
+		} else if let Some(entry) = self.remapped.get(key) {
+			entry.clone()
+		} else {
+			(String::from(self.scope.as_ref()) + "/" + key).into()
+		}
+	}
+}
+
+/// Validates, that all ports accessing the same blackboard entry declare the same type.
+///
+/// The entries are followed through the remappings of subtrees and into the global `@` scope.
+/// Ports of type `String` are not validated, as strings are converted on access.
+pub fn validate_types(root: &BehaviorTreeElement) -> Vec<TreeWarning> {
+	let resolver = Resolver {
+		scope: root.groot2_path().clone(),
+		remapped: BTreeMap::new(),
+	};
+	let mut entries: BTreeMap<ConstString, Vec<TypedAccess>> = BTreeMap::new();
+	for child in root.children().iter() {
+		collect_types(child, &resolver, &mut entries);
+	}
+
+	let mut warnings = Vec::new();
+	for accesses in entries.values() {
+		let mut accesses = accesses
+			.iter()
+			.filter(|access| access.type_name != "String");
+		let Some(first) = accesses.next() else {
+			continue;
+		};
+		for access in accesses {
+			if access.type_name != first.type_name {
+				warnings.push(TreeWarning::InconsistentType {
+					path: access.path.clone(),
+					port: access.port.clone(),
+					key: access.key.clone(),
+					type_name: access.type_name.into(),
+					expected: first.type_name.into(),
+				});
+			}
+		}
+	}
+	warnings
+}
+
+/// Collects the typed accesses of `element` and its descendants into `entries`.
+fn collect_types(element: &BehaviorTreeElement, resolver: &Resolver, entries: &mut BTreeMap<ConstString, Vec<TypedAccess>>) {
+	if matches!(element.kind(), TreeElementKind::SubTree) {
+		let mut remapped = BTreeMap::new();
+		if let Some(remappings) = element.blackboard().remappings() {
+			for (key, value) in remappings.iter() {
+				if let Some(outer) = strip_board_pointer(value) {
+					remapped.insert(key.clone(), resolver.resolve(outer));
+				}
+			}
+		}
+		let resolver = Resolver {
+			scope: element.groot2_path().clone(),
+			remapped,
+		};
+		for child in element.children().iter() {
+			collect_types(child, &resolver, entries);
+		}
+		return;
+	}
+
+	let remappings = element.remappings();
+	for port in element.data().description().ports().iter() {
+		if let Some(value) = remappings.find(port.name())
+			&& let Some(key) = strip_board_pointer(&value)
+		{
+			entries
+				.entry(resolver.resolve(key))
+				.or_default()
+				.push(TypedAccess {
+					key: key.into(),
+					path: element.groot2_path().clone(),
+					port: port.name().into(),
+					type_name: port.type_name(),
+				});
+		}
+	}
+
+	for child in element.children().iter() {
+		collect_types(child, resolver, entries);
+	}
+}
+// endregion:	--- type validation
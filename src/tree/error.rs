@@ -5,7 +5,7 @@
 #[doc(hidden)]
 extern crate alloc;
 
-use crate::ConstString;
+use crate::{BehaviorKind, ConstString};
 
 /// Tree errors
 #[non_exhaustive]
@@ -15,16 +15,66 @@ pub enum Error {
 		/// The affected index
 		index: usize,
 	},
+	/// No element with the path is found
+	ElementNotFound {
+		/// The Groot2 style path
+		path: ConstString,
+	},
 	/// Invalid Groot request type
 	InvalidRequestType {
 		/// The request type value
 		value: u8,
 	},
+	/// The kind of a behavior does not match the kind of the element
+	KindMismatch {
+		/// The Groot2 style path
+		path: ConstString,
+		/// The kind of the element
+		expected: BehaviorKind,
+		/// The kind of the behavior
+		found: BehaviorKind,
+	},
+	/// Halting an element failed
+	Halt {
+		/// The Groot2 style path
+		path: ConstString,
+		/// The reason
+		error: ConstString,
+	},
+	/// Modifying the children of an element failed
+	Mutation {
+		/// The Groot2 style path of the parent element
+		path: ConstString,
+		/// The reason
+		error: ConstString,
+	},
 	/// Recursion limit  of 127 is reached
 	RecursionLimit {
 		/// The affected behavior
 		behavior: ConstString,
 	},
+	/// Reloading a subtree failed
+	Reload {
+		/// The subtrees id
+		id: ConstString,
+		/// The error from xml module
+		error: ConstString,
+	},
+	/// No subtree with the id is found
+	SubtreeIdNotFound {
+		/// The subtrees id
+		id: ConstString,
+	},
+	/// The subtree is not idle
+	SubtreeNotIdle {
+		/// The subtrees id
+		id: ConstString,
+	},
+	/// No element with the uid is found
+	UidNotFound {
+		/// The uid
+		uid: u16,
+	},
 }
 
 /// Only default implementation needed.
@@ -45,8 +95,18 @@ impl core::fmt::Debug for Error {
 		match self {
 			// Self::Behavior { source } => write!(f, "Behavior(source: {source}"),
 			Self::SubtreeNotFound { index } => write!(f, "IndexNotFound({index})"),
+			Self::ElementNotFound { path } => write!(f, "ElementNotFound({path})"),
 			Self::InvalidRequestType { value } => write!(f, "InvalidRequestType({value})"),
+			Self::KindMismatch { path, expected, found } => {
+				write!(f, "KindMismatch(path: {path}, expected: {expected}, found: {found})")
+			}
+			Self::Halt { path, error } => write!(f, "Halt(path: {path}, error: {error})"),
+			Self::Mutation { path, error } => write!(f, "Mutation(path: {path}, error: {error})"),
 			Self::RecursionLimit { behavior } => write!(f, "RecursionLimit({behavior})"),
+			Self::Reload { id, error } => write!(f, "Reload(id: {id}, error: {error})"),
+			Self::SubtreeIdNotFound { id } => write!(f, "SubtreeIdNotFound({id})"),
+			Self::SubtreeNotIdle { id } => write!(f, "SubtreeNotIdle({id})"),
+			Self::UidNotFound { uid } => write!(f, "UidNotFound({uid})"),
 		}
 	}
 }
@@ -56,8 +116,18 @@ impl core::fmt::Display for Error {
 		match self {
 			// Self::Behavior { source } => write!(f, "Behavior(source: {source}"),
 			Self::SubtreeNotFound { index } => write!(f, "the subtree with the index {index} cannot be found"),
+			Self::ElementNotFound { path } => write!(f, "an element with the path {path} cannot be found"),
 			Self::InvalidRequestType { value } => write!(f, "an invalid request type {value} was sent from Groot2"),
+			Self::KindMismatch { path, expected, found } => {
+				write!(f, "the element {path} is a {expected}, but the behavior is a {found}")
+			}
+			Self::Halt { path, error } => write!(f, "halting {path} failed with: {error}"),
+			Self::Mutation { path, error } => write!(f, "modifying the children of {path} failed with: {error}"),
 			Self::RecursionLimit { behavior } => write!(f, "recursion limit of '127' is reached for behavior {behavior}"),
+			Self::Reload { id, error } => write!(f, "reloading the subtree {id} failed with: {error}"),
+			Self::SubtreeIdNotFound { id } => write!(f, "a subtree with the id {id} cannot be found"),
+			Self::SubtreeNotIdle { id } => write!(f, "the subtree {id} is not idle"),
+			Self::UidNotFound { uid } => write!(f, "an element with the uid {uid} cannot be found"),
 		}
 	}
 }
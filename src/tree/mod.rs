@@ -2,6 +2,7 @@
 
 //! [`behaviortree`](crate) tree module.
 
+mod analysis;
 pub mod error;
 pub mod observer;
 #[allow(clippy::module_inception)]
@@ -11,7 +12,8 @@ mod tree_element_list;
 mod tree_iter;
 
 // flatten
-pub use tree::BehaviorTree;
+pub use analysis::{TreeWarning, check_autoremap};
+pub use tree::{BehaviorTree, TickHook};
 pub use tree_element::{BehaviorTreeElement, TreeElementKind};
 pub use tree_element_list::BehaviorTreeElementList;
 
@@ -26,6 +28,7 @@ mod tests {
 	#[test]
 	const fn normal_types() {
 		is_normal::<error::Error>();
+		is_normal::<TreeWarning>();
 		is_normal::<BehaviorTree>();
 		is_normal::<BehaviorTreeElement>();
 		is_normal::<BehaviorTreeElementList>();
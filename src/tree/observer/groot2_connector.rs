@@ -9,16 +9,19 @@ use core::time::Duration;
 use alloc::collections::vec_deque::VecDeque;
 // region:      --- modules
 use crate::{
-	ConstString, Mutex, XmlCreator,
+	ConstString, Error, Mutex, XmlCreator,
 	behavior::{BehaviorState, behavior_data::BehaviorData},
 	tree::{
-		observer::groot2_protocol::{Groot2ReplyHeader, Groot2RequestHeader, Groot2RequestType, Groot2TransitionInfo},
+		observer::{
+			groot2_protocol::{Groot2ReplyHeader, Groot2RequestHeader, Groot2RequestType, Groot2TransitionInfo},
+			state_streamer::{new_state_buffer, update_state_buffer},
+		},
 		tree::{BehaviorTree, BehaviorTreeMessage},
 	},
 };
 use alloc::string::{String, ToString};
 use alloc::sync::Arc;
-use bytes::{Bytes, BytesMut};
+use bytes::{BufMut, Bytes, BytesMut};
 use thingbuf::mpsc;
 use tokio::{task::JoinHandle, time::Instant};
 use zeromq::{Socket, SocketRecv, SocketSend, ZmqMessage};
@@ -31,6 +34,21 @@ const TRANSITION_SIZE: u32 = 100;
 
 /// constants
 pub const GROOT_STATE: &str = "groot_state";
+/// Header of a Groot2 log file.
+const FILE_HEADER: &str = "BTCPP4-FileLogger2";
+/// Protocol version of a Groot2 log file.
+const FILE_PROTOCOL: u8 = 1;
+
+/// Returns the microseconds since 01.01.1970.
+fn timestamp() -> u64 {
+	#[allow(clippy::cast_possible_truncation)]
+	#[allow(clippy::expect_used)]
+	let timestamp = std::time::SystemTime::now()
+		.duration_since(std::time::UNIX_EPOCH)
+		.expect("Time went backwards")
+		.as_micros() as u64;
+	timestamp
+}
 
 // region:      --- GrootCallback
 /// Attach the Groot2 communication callbacks to a [`BehaviorTree`].
@@ -47,17 +65,8 @@ pub fn attach_groot_callback(tree: &mut BehaviorTree, shared: Arc<Mutex<Groot2Co
 			if behavior.state() != *new_state {
 				// Groot does not need a state for root
 				if behavior.uid() != 0 {
-					let state = if *new_state == BehaviorState::Idle {
-						behavior.state() as u8 + 10
-					} else {
-						*new_state as u8
-					};
 					let mut shared_guard = shared_clone.lock();
-					let uid = behavior.uid().to_le_bytes();
-					let index = 3 * ((behavior.uid() - 1) as usize);
-					shared_guard.state_buffer[index] = uid[0];
-					shared_guard.state_buffer[index + 1] = uid[1];
-					shared_guard.state_buffer[index + 2] = state;
+					update_state_buffer(&mut shared_guard.state_buffer, behavior.uid(), behavior.state(), *new_state);
 
 					if shared_guard.recording {
 						#[allow(clippy::cast_possible_truncation)]
@@ -97,6 +106,8 @@ pub struct Groot2Connector {
 	tx: mpsc::Sender<BehaviorTreeMessage>,
 	/// Shared data across multiple tasks (callbacks)
 	shared: Arc<Mutex<Groot2ConnectorData>>,
+	/// The tree in Groot2 XML format
+	xml: Bytes,
 	/// Response server
 	server_handle: JoinHandle<Result<(), zeromq::ZmqError>>,
 	/// watchdog for connection
@@ -109,6 +120,8 @@ pub struct Groot2ConnectorData {
 	connected: bool,
 	/// Flag for recording transitions, accessible from multiple tasks
 	recording: bool,
+	/// Timestamp of the recordings start
+	recording_start: u64,
 	/// Current size of the transition buffer
 	transitions: u32,
 	/// The state buffer for Groot communication
@@ -127,20 +140,13 @@ impl Groot2Connector {
 	pub fn new(tree: &mut BehaviorTree, port: u16) -> Self {
 		// an empty transitions buffer
 		let transitions_buffer = VecDeque::new();
-		// a state buffer
-		let tree_size = tree.size() - 1; // without root
-		let mut state_buffer = BytesMut::zeroed((3 * tree_size) as usize);
-		// initialize state buffer
-		for i in 0..tree_size {
-			let index = (3 * i) as usize;
-			let bytes = (i + 1).to_be_bytes();
-			state_buffer[index] = bytes[0];
-			state_buffer[index] = bytes[1];
-		}
+		// an initialized state buffer
+		let state_buffer = BytesMut::from(new_state_buffer(tree.size()).as_slice());
 
 		let shared = Arc::new(Mutex::new(Groot2ConnectorData {
 			connected: false,
 			recording: false,
+			recording_start: 0,
 			transitions: 0,
 			state_buffer,
 			transitions_buffer,
@@ -179,6 +185,7 @@ impl Groot2Connector {
 		let tree_id = tree.uuid();
 		#[allow(clippy::expect_used)]
 		let xml = XmlCreator::groot_write_tree(tree).expect("usually this should not happen");
+		let xml_clone = xml.clone();
 		let sender = tree.sender();
 
 		let server_handle = tokio::spawn(async move {
@@ -253,6 +260,7 @@ impl Groot2Connector {
 											// activate transition recording
 											let mut shared_guard = shared_clone.lock();
 											shared_guard.recording = true;
+											shared_guard.recording_start = timestamp();
 											// clear transition buffer
 											shared_guard.transitions_buffer.clear();
 											// ensure that we can store at least TRANSITION_SIZE elements
@@ -314,9 +322,53 @@ impl Groot2Connector {
 		Self {
 			tx: tree.sender(),
 			shared,
+			xml: xml_clone,
 			server_handle,
 			watchdog_handle,
 		}
 	}
+
+	/// Starts recording the state transitions without a connection to Groot2,
+	/// e.g. for an [`export_recording`](Self::export_recording).
+	/// The recording begins with the next tick of the tree.
+	pub fn start_recording(&self) {
+		let mut shared_guard = self.shared.lock();
+		shared_guard.recording = true;
+		shared_guard.recording_start = timestamp();
+		shared_guard.transitions_buffer.clear();
+		drop(shared_guard);
+		let _ = self
+			.tx
+			.try_send(BehaviorTreeMessage::AddGrootCallback(self.shared.clone()));
+	}
+
+	/// Stops recording the state transitions.
+	pub fn stop_recording(&self) {
+		self.shared.lock().recording = false;
+	}
+
+	/// Writes the recorded state transitions together with the tree
+	/// in the Groot2 log format (`.btlog`) to the file `path`.
+	///
+	/// Only the latest recorded transitions are available,
+	/// and transitions already sent to Groot2 are no longer in the recording.
+	/// # Errors
+	/// - if the file can not be written
+	pub fn export_recording(&self, path: impl AsRef<std::path::Path>) -> Result<(), Error> {
+		let mut bytes = BytesMut::new();
+		bytes.extend_from_slice(FILE_HEADER.as_bytes());
+		bytes.put_u8(FILE_PROTOCOL);
+		#[allow(clippy::cast_possible_truncation)]
+		bytes.put_u32_le(self.xml.len() as u32);
+		bytes.extend_from_slice(&self.xml);
+		let shared_guard = self.shared.lock();
+		bytes.put_u64_le(shared_guard.recording_start);
+		for info in &shared_guard.transitions_buffer {
+			bytes.extend(Bytes::from(&info.relative_to(shared_guard.recording_start)));
+		}
+		drop(shared_guard);
+		std::fs::write(path, bytes)?;
+		Ok(())
+	}
 }
 // endregion:   --- Groot2Connector
@@ -225,6 +225,16 @@ impl Groot2TransitionInfo {
 			state: state as u8,
 		}
 	}
+
+	/// Returns the transition info with the timestamp relative to `start`.
+	#[must_use]
+	pub const fn relative_to(&self, start: u64) -> Self {
+		Self {
+			timestamp: self.timestamp.saturating_sub(start),
+			uid: self.uid,
+			state: self.state,
+		}
+	}
 }
 // endregion:   --- Groot2TransitionInfo
 
@@ -6,6 +6,7 @@
 pub mod groot2_connector;
 #[cfg(feature = "std")]
 pub mod groot2_protocol;
+pub mod state_streamer;
 pub mod tree_observer;
 
 // flatten
@@ -17,7 +18,8 @@ mod tests {
 		tree::observer::{
 			groot2_connector::Groot2ConnectorData,
 			groot2_protocol::{Groot2Hook, Groot2ReplyHeader, Groot2RequestHeader, Groot2RequestType},
-			tree_observer::Statistics,
+			state_streamer::StateStreamer,
+			tree_observer::{Statistics, Transition},
 		},
 	};
 
@@ -27,6 +29,7 @@ mod tests {
 	#[test]
 	const fn normal_types() {
 		is_normal::<Statistics>();
+		is_normal::<Transition>();
 		is_normal::<BehaviorTreeObserver>();
 		is_normal::<Groot2Connector>();
 		is_normal::<Groot2ConnectorData>();
@@ -34,5 +37,6 @@ mod tests {
 		is_normal::<Groot2ReplyHeader>();
 		is_normal::<Groot2RequestHeader>();
 		is_normal::<Groot2RequestType>();
+		is_normal::<StateStreamer>();
 	}
 }
@@ -0,0 +1,134 @@
+// Copyright © 2025 Stephan Kunz
+
+//! [`StateStreamer`] implementation.
+
+// region:      --- modules
+use crate::{
+	ConstString, Mutex,
+	behavior::{BehaviorState, behavior_data::BehaviorData},
+	tree::tree::BehaviorTree,
+};
+use alloc::{boxed::Box, sync::Arc, vec, vec::Vec};
+// endregion:   --- modules
+
+// region:		--- globals
+/// Name of the callbacks of a [`StateStreamer`].
+const STATE_STREAMER: &str = "state_streamer";
+// endregion:	--- globals
+
+// region:		--- types
+/// The sink of a [`StateStreamer`].
+type Sink = Box<dyn FnMut(&[u8]) + Send>;
+// endregion:	--- types
+
+// region:      --- state buffer
+/// Creates a state buffer for a tree with `tree_size` elements including the root.
+///
+/// The buffer contains 3 bytes per element except the root: the uid in little endian
+/// followed by the state, like Groot2 expects it.
+#[must_use]
+pub fn new_state_buffer(tree_size: u16) -> Vec<u8> {
+	let elements = tree_size.saturating_sub(1);
+	let mut buffer = vec![0; 3 * elements as usize];
+	for uid in 1..=elements {
+		let index = 3 * (uid - 1) as usize;
+		buffer[index..index + 2].copy_from_slice(&uid.to_le_bytes());
+	}
+	buffer
+}
+
+/// Updates the entry of the element `uid` in a state buffer created with [`new_state_buffer`].
+///
+/// A change to [`BehaviorState::Idle`] is encoded as the previous state plus 10.
+/// Changes of the root and changes to the same state are ignored.
+pub fn update_state_buffer(buffer: &mut [u8], uid: u16, old_state: BehaviorState, new_state: BehaviorState) {
+	if uid == 0 || old_state == new_state {
+		return;
+	}
+	let state = if new_state == BehaviorState::Idle {
+		old_state as u8 + 10
+	} else {
+		new_state as u8
+	};
+	let index = 3 * (uid - 1) as usize;
+	if let Some(entry) = buffer.get_mut(index..index + 3) {
+		entry[..2].copy_from_slice(&uid.to_le_bytes());
+		entry[2] = state;
+	}
+}
+// endregion:   --- state buffer
+
+// region:      --- StateStreamer
+/// The shared data of a [`StateStreamer`].
+struct StateStreamerData {
+	/// The state buffer
+	buffer: Vec<u8>,
+	/// Flag whether the buffer changed since the last emit
+	changed: bool,
+}
+
+/// Streams the states of a [`BehaviorTree`]s elements to a user supplied sink,
+/// independent of Groot2 and its transport.
+///
+/// The sink receives the same compact state buffer the [`Groot2Connector`](crate::Groot2Connector)
+/// sends, see [`new_state_buffer`]. The application decides when to emit, e.g. after each tick
+/// or periodically, and how to transport the buffer, e.g. via a serial line or a channel.
+pub struct StateStreamer {
+	/// The shared data
+	data: Arc<Mutex<StateStreamerData>>,
+	/// The sink
+	sink: Mutex<Sink>,
+}
+
+impl StateStreamer {
+	/// Construct a new [`StateStreamer`] for `tree` emitting into `sink`.
+	pub fn new<F>(tree: &mut BehaviorTree, sink: F) -> Self
+	where
+		F: FnMut(&[u8]) + Send + 'static,
+	{
+		let id: ConstString = STATE_STREAMER.into();
+		let data = Arc::new(Mutex::new(StateStreamerData {
+			buffer: new_state_buffer(tree.size()),
+			changed: true,
+		}));
+
+		// add a callback for each tree element
+		for element in tree.iter_mut() {
+			let data_clone = data.clone();
+			// the callback
+			let callback = move |behavior: &BehaviorData, new_state: &mut BehaviorState| {
+				if behavior.uid() != 0 && behavior.state() != *new_state {
+					let mut guard = data_clone.lock();
+					update_state_buffer(&mut guard.buffer, behavior.uid(), behavior.state(), *new_state);
+					guard.changed = true;
+					drop(guard);
+				}
+			};
+			element.add_pre_state_change_callback(id.clone(), callback);
+		}
+
+		Self {
+			data,
+			sink: Mutex::new(Box::new(sink)),
+		}
+	}
+
+	/// Emits the current state buffer into the sink.
+	pub fn emit(&self) {
+		let mut guard = self.data.lock();
+		guard.changed = false;
+		(*self.sink.lock())(&guard.buffer);
+		drop(guard);
+	}
+
+	/// Emits the current state buffer into the sink, if a state changed since the last emit.
+	/// Returns whether the buffer has been emitted.
+	pub fn emit_if_changed(&self) -> bool {
+		let changed = self.data.lock().changed;
+		if changed {
+			self.emit();
+		}
+		changed
+	}
+}
+// endregion:   --- StateStreamer
@@ -11,9 +11,18 @@ use crate::{
 	behavior::{BehaviorState, behavior_data::BehaviorData},
 	tree::tree::BehaviorTree,
 };
-use alloc::{sync::Arc, vec::Vec};
 #[cfg(feature = "std")]
-use std::time::Instant;
+use alloc::string::{String, ToString};
+use alloc::{collections::vec_deque::VecDeque, sync::Arc, vec::Vec};
+#[cfg(feature = "std")]
+use nanoserde::SerJson;
+#[cfg(feature = "std")]
+use std::{
+	fs::File,
+	io::{BufWriter, Write},
+	path::Path,
+	time::Instant,
+};
 // endregion:   --- modules
 
 // region:      --- Statistics
@@ -68,23 +77,179 @@ impl Statistics {
 }
 // endregion:   --- Statistics
 
+// region:      --- Transition
+/// A recorded state transition of a [`BehaviorTreeElement`](crate::tree::tree_element::BehaviorTreeElement).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Transition {
+	/// The uid of the element.
+	pub uid: u16,
+	/// The state the element changed to.
+	pub new_state: BehaviorState,
+	/// Running number of the transition since creation or last reset of the observer.
+	pub index: usize,
+}
+
+/// Bounded log of the most recent [`Transition`]s.
+/// The storage is allocated once on creation and never grows.
+struct TransitionLog {
+	/// Maximum number of stored transitions.
+	capacity: usize,
+	/// Running number for the next transition.
+	next_index: usize,
+	/// The stored transitions, oldest first.
+	entries: VecDeque<Transition>,
+}
+
+impl TransitionLog {
+	fn new(capacity: usize) -> Self {
+		Self {
+			capacity,
+			next_index: 0,
+			entries: VecDeque::with_capacity(capacity),
+		}
+	}
+
+	fn record(&mut self, uid: u16, new_state: BehaviorState) {
+		if self.capacity == 0 {
+			return;
+		}
+		if self.entries.len() == self.capacity {
+			self.entries.pop_front();
+		}
+		self.entries.push_back(Transition {
+			uid,
+			new_state,
+			index: self.next_index,
+		});
+		self.next_index += 1;
+	}
+
+	fn reset(&mut self) {
+		self.next_index = 0;
+		self.entries.clear();
+	}
+}
+// endregion:   --- Transition
+
+// region:      --- JsonlSink
+/// A state transition as written by the [`JsonlSink`].
+#[cfg(feature = "std")]
+#[derive(SerJson)]
+struct TransitionRecord {
+	/// Time in seconds since creation of the sink
+	t: f64,
+	/// The uid of the behavior
+	uid: u16,
+	/// The path of the behavior
+	path: String,
+	/// The state before the transition
+	old: String,
+	/// The state after the transition
+	new: String,
+}
+
+/// Streams the state transitions as JSON lines into a buffered file.
+/// The buffer is flushed on demand and when the sink is dropped.
+#[cfg(feature = "std")]
+struct JsonlSink {
+	/// The buffered file
+	writer: BufWriter<File>,
+	/// Start of the time stamps
+	start: Instant,
+}
+
+#[cfg(feature = "std")]
+impl JsonlSink {
+	fn create(path: &Path) -> std::io::Result<Self> {
+		Ok(Self {
+			writer: BufWriter::new(File::create(path)?),
+			start: Instant::now(),
+		})
+	}
+
+	/// Writes the transition of `behavior` to `new_state`.
+	/// Write errors are ignored, as they can not be reported from within a tick.
+	fn write(&mut self, behavior: &BehaviorData, new_state: BehaviorState) {
+		let record = TransitionRecord {
+			t: self.start.elapsed().as_secs_f64(),
+			uid: behavior.uid(),
+			path: behavior.path().to_string(),
+			old: behavior.state().to_string(),
+			new: new_state.to_string(),
+		};
+		let _ = writeln!(self.writer, "{}", record.serialize_json());
+	}
+
+	/// Writes the buffered transitions into the file.
+	fn flush(&mut self) -> std::io::Result<()> {
+		self.writer.flush()
+	}
+}
+// endregion:   --- JsonlSink
+
 // region:      --- BehaviorTreeObserver
 /// An observer collecting [`BehaviorTree`] statistics
+/// and optionally a log of the most recent state transitions.
 pub struct BehaviorTreeObserver {
 	/// The shared statistics data
 	statistics: Arc<Mutex<Vec<Statistics>>>,
+	/// The shared transition log
+	transitions: Arc<Mutex<TransitionLog>>,
+	/// The shared JSON lines sink
+	#[cfg(feature = "std")]
+	sink: Option<Arc<Mutex<JsonlSink>>>,
 }
 
 impl BehaviorTreeObserver {
-	/// Construct a new [`BehaviorTreeObserver`].
+	/// Construct a new [`BehaviorTreeObserver`] without transition log.
 	pub fn new(root: &mut BehaviorTree) -> Self {
+		Self::with_transition_log(root, 0)
+	}
+
+	/// Construct a new [`BehaviorTreeObserver`] that additionally keeps the last `capacity` state transitions,
+	/// which can be read with [`BehaviorTreeObserver::recent_transitions()`].
+	/// Useful for post-mortem analysis, also in embedded environments.
+	pub fn with_transition_log(root: &mut BehaviorTree, capacity: usize) -> Self {
+		Self::create(
+			root,
+			capacity,
+			#[cfg(feature = "std")]
+			None,
+		)
+	}
+
+	/// Construct a new [`BehaviorTreeObserver`] that additionally streams each state transition
+	/// as a line of JSON into the file at `path`, e.g. for post-hoc analysis of long runs.
+	///
+	/// A line looks like `{"t":0.000042,"uid":1,"path":"MainTree/Sequence","old":"Idle","new":"Running"}`,
+	/// where `t` is the time in seconds since creation of the observer. An existing file is truncated.
+	/// The lines are buffered, use [`BehaviorTreeObserver::flush()`] to write them before
+	/// the observer and the tree are dropped.
+	/// # Errors
+	/// - if the file can not be created
+	#[cfg(feature = "std")]
+	pub fn with_jsonl_sink(root: &mut BehaviorTree, path: impl AsRef<Path>) -> std::io::Result<Self> {
+		let sink = JsonlSink::create(path.as_ref())?;
+		Ok(Self::create(root, 0, Some(Arc::new(Mutex::new(sink)))))
+	}
+
+	/// Construct a new [`BehaviorTreeObserver`] with a transition log of `capacity` and an optional `sink`.
+	fn create(
+		root: &mut BehaviorTree,
+		capacity: usize,
+		#[cfg(feature = "std")] sink: Option<Arc<Mutex<JsonlSink>>>,
+	) -> Self {
 		let id: ConstString = "statistics".into();
 		let statistics: Arc<Mutex<Vec<Statistics>>> = Arc::new(Mutex::new(Vec::new()));
+		let transitions = Arc::new(Mutex::new(TransitionLog::new(capacity)));
 
 		// add a statistics entry and a callback for each tree element
 		for element in root.iter_mut() {
 			statistics.lock().push(Statistics::default());
 			let statistics_clone: Arc<Mutex<Vec<Statistics>>> = statistics.clone();
+			let transitions_clone = transitions.clone();
+			#[cfg(feature = "std")]
+			let sink_clone = sink.clone();
 			// the callback
 			let callback = move |behavior: &BehaviorData, new_state: &mut BehaviorState| {
 				let mut stats = statistics_clone.lock();
@@ -108,10 +273,32 @@ impl BehaviorTreeObserver {
 					entry.timestamp = Instant::now();
 				}
 				drop(stats);
+				transitions_clone
+					.lock()
+					.record(behavior.uid(), *new_state);
+				#[cfg(feature = "std")]
+				if let Some(sink) = &sink_clone {
+					sink.lock().write(behavior, *new_state);
+				}
 			};
 			element.add_pre_state_change_callback(id.clone(), callback);
 		}
-		Self { statistics }
+		Self {
+			statistics,
+			transitions,
+			#[cfg(feature = "std")]
+			sink,
+		}
+	}
+
+	/// Write the buffered lines of a JSON lines sink into its file.
+	/// # Errors
+	/// - if writing to the file fails
+	#[cfg(feature = "std")]
+	pub fn flush(&self) -> std::io::Result<()> {
+		self.sink
+			.as_ref()
+			.map_or(Ok(()), |sink| sink.lock().flush())
 	}
 
 	/// Get the [`Statistics`] for a [`BehaviorTreeElement`](crate::tree::tree_element::BehaviorTreeElement) using its uid.
@@ -123,11 +310,24 @@ impl BehaviorTreeObserver {
 		None
 	}
 
+	/// Get the recorded recent [`Transition`]s, oldest first.
+	/// The list is empty, if the observer was created without transition log.
+	#[must_use]
+	pub fn recent_transitions(&self) -> Vec<Transition> {
+		self.transitions
+			.lock()
+			.entries
+			.iter()
+			.copied()
+			.collect()
+	}
+
 	/// Reset the [`BehaviorTreeObserver`].
 	pub fn reset(&self) {
 		for stats in &mut (*self.statistics.lock()) {
 			stats.reset();
 		}
+		self.transitions.lock().reset();
 	}
 }
 // endregion:   --- BehaviorTreeObserver
@@ -8,31 +8,58 @@ extern crate std;
 
 // region:      --- modules
 use crate::Mutex;
+use crate::SharedRuntime;
+#[cfg(feature = "mock_behavior")]
+use crate::behavior::{MockBehavior, MockBehaviorConfig};
+#[cfg(any(feature = "std", feature = "embassy"))]
+use crate::clock::{Clock, DefaultClock};
 #[cfg(feature = "std")]
 use crate::tree::observer::groot2_connector::{GROOT_STATE, Groot2ConnectorData, attach_groot_callback};
 use crate::{
-	behavior::{BehaviorError, BehaviorResult, BehaviorState},
-	factory::BehaviorRegistry,
+	ConstString,
+	behavior::{BehaviorError, BehaviorKind, BehaviorPtr, BehaviorResult, BehaviorState, behavior_data::BehaviorData},
+	factory::{BehaviorRegistry, BehaviorTreeFactory, TreeOptions},
 	tree::{
 		tree_element::TreeElementKind,
 		tree_iter::{TreeIter, TreeIterMut},
 	},
+	xml::{error::Error as XmlError, parser::XmlParser},
 };
-#[cfg(feature = "std")]
-use alloc::vec::Vec;
-use alloc::{string::String, sync::Arc};
+use alloc::{
+	boxed::Box,
+	format,
+	string::{String, ToString},
+	sync::Arc,
+	vec::Vec,
+};
+use core::any::Any;
+#[cfg(any(feature = "std", feature = "embassy"))]
+use core::time::Duration;
 use databoard::Databoard;
 #[cfg(feature = "std")]
 use libloading::Library;
 #[cfg(feature = "std")]
+use std::time::Instant;
+#[cfg(feature = "std")]
 use thingbuf::mpsc;
-use tinyscript::SharedRuntime;
 #[cfg(feature = "std")]
 use uuid::Uuid;
 
-use super::{error::Error, tree_element::BehaviorTreeElement};
+use super::{
+	analysis::{self, TreeWarning},
+	error::Error,
+	tree_element::BehaviorTreeElement,
+};
 // endregion:   --- modules
 
+// region:		--- types
+/// Tree level hook called once before respectively after each tick of a [`BehaviorTree`]
+/// with the root blackboard of the tree.
+pub type TickHook = dyn FnMut(&Databoard) + Send + Sync;
+/// The full path and the reason of the most recently failed leaf.
+type LastFailure = (ConstString, Option<ConstString>);
+// endregion:	--- types
+
 // region:		--- helper
 /// Recursion function to print a (sub)tree recursively, limit is a tree-depth of 127
 /// # Errors
@@ -57,6 +84,48 @@ fn print_recursively(level: i8, behavior: &BehaviorTreeElement) -> Result<(), Er
 	}
 	Ok(())
 }
+
+/// Recursion function to collect the child index paths of a (sub)tree, indexed by the elements uid.
+fn collect_paths(behavior: &BehaviorTreeElement, path: &mut Vec<usize>, paths: &mut Vec<Option<Box<[usize]>>>) {
+	let uid = usize::from(behavior.uid());
+	if paths.len() <= uid {
+		paths.resize(uid + 1, None);
+	}
+	paths[uid] = Some(path.clone().into_boxed_slice());
+	for (index, child) in behavior.children().iter().enumerate() {
+		path.push(index);
+		collect_paths(child, path, paths);
+		path.pop();
+	}
+}
+
+/// Halts `element` and all its descendants, which are not idle.
+/// Other than [`BehaviorTreeElement::halt`] this also reaches the descendants of an idle element,
+/// e.g. of a parent whose tick has been interrupted by an error.
+fn halt_all(element: &mut BehaviorTreeElement, runtime: &SharedRuntime) -> Result<(), BehaviorError> {
+	if element.state() != BehaviorState::Idle {
+		element.halt(runtime)?;
+	}
+	for child in element.children_iter_mut() {
+		halt_all(child, runtime)?;
+	}
+	Ok(())
+}
+
+/// Creates the error for a missing (sub)tree with `index`.
+fn subtree_not_found(index: usize) -> BehaviorError {
+	BehaviorError::Composition {
+		txt: ("the subtree with the index ".to_string() + &index.to_string() + " cannot be found").into(),
+	}
+}
+
+/// Derives the seed of an element from the trees seed and the elements uid using the `SplitMix64` finalizer.
+fn element_seed(seed: u64, uid: u16) -> u64 {
+	let mut z = seed ^ u64::from(uid).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+	z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+	z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+	z ^ (z >> 31)
+}
 // endregion:	--- helper
 
 // region:      --- BehaviorTreeMessage
@@ -79,6 +148,29 @@ pub struct BehaviorTree {
 	uuid: Uuid,
 	/// The root element
 	root: BehaviorTreeElement,
+	/// The child index paths from root to each element, indexed by the elements uid
+	paths: Vec<Option<Box<[usize]>>>,
+	/// The number of ticks since creation or last reset, shared with all elements
+	tick_count: Arc<Mutex<u64>>,
+	/// Seed for reproducible random behaviors
+	seed: Option<u64>,
+	/// Options of the tree, shared with all elements
+	options: TreeOptions,
+	/// Flag whether the elements were mutably accessed and their inline flags have to be updated before the next tick
+	inline_outdated: bool,
+	/// The clock for the time based behaviors, shared with all elements
+	#[cfg(any(feature = "std", feature = "embassy"))]
+	clock: Arc<dyn Clock>,
+	/// The application context, shared with all elements
+	context: Option<Arc<dyn Any + Send + Sync>>,
+	/// Flag whether failure tracking is enabled, so elements added later are tracked too
+	failure_tracking: bool,
+	/// Path and reason of the most recently failed leaf, if failure tracking is enabled
+	last_failure: Arc<Mutex<Option<LastFailure>>>,
+	/// Hook called before each tick
+	pre_tick_hook: Option<Box<TickHook>>,
+	/// Hook called after each tick
+	post_tick_hook: Option<Box<TickHook>>,
 	/// `runtime` is shared between elements
 	runtime: SharedRuntime,
 	/// `libraries` stores a reference to the used shared libraries aka plugins.
@@ -99,7 +191,10 @@ impl BehaviorTree {
 	pub fn new(root: BehaviorTreeElement, registry: &BehaviorRegistry) -> Self {
 		// create a [`SharedRuntime`](https://docs.rs/tinyscript/latest/tinyscript/runtime/type.SharedRuntime.html)
 		// based on the current state of registriesscripting runtime
+		#[cfg(feature = "scripting")]
 		let runtime = Arc::new(Mutex::new(registry.runtime().clone()));
+		#[cfg(not(feature = "scripting"))]
+		let runtime = SharedRuntime::default();
 		// clone the current state of registered libraries so that they are not deallocated while tree is running
 		#[cfg(feature = "std")]
 		let mut libraries = Vec::with_capacity(registry.libraries().capacity() + 1);
@@ -108,12 +203,31 @@ impl BehaviorTree {
 			libraries.push(lib.clone());
 		}
 
+		// uids are dense, so the paths can be stored in a vector
+		let mut paths = Vec::new();
+		collect_paths(&root, &mut Vec::new(), &mut paths);
+
 		#[cfg(feature = "std")]
 		let (tx, rx) = mpsc::channel::<BehaviorTreeMessage>(5);
-		Self {
+		let mut tree = Self {
 			#[cfg(feature = "std")]
 			uuid: Uuid::new_v4(),
 			root,
+			paths,
+			tick_count: Arc::default(),
+			seed: registry.seed(),
+			options: registry.options(),
+			inline_outdated: false,
+			#[cfg(any(feature = "std", feature = "embassy"))]
+			clock: registry
+				.clock()
+				.cloned()
+				.unwrap_or_else(|| Arc::new(DefaultClock::default())),
+			context: None,
+			failure_tracking: false,
+			last_failure: Arc::default(),
+			pre_tick_hook: None,
+			post_tick_hook: None,
 			runtime,
 			#[cfg(feature = "std")]
 			_libraries: libraries,
@@ -121,9 +235,88 @@ impl BehaviorTree {
 			tx,
 			#[cfg(feature = "std")]
 			rx,
+		};
+		tree.share_tree_data();
+		tree
+	}
+
+	/// Shares the trees tick count, clock, context, options and the derived seeds with all elements.
+	/// This has to be done after each structural change.
+	fn share_tree_data(&mut self) {
+		for element in TreeIterMut::new(&mut self.root) {
+			let seed = self
+				.seed
+				.map(|seed| element_seed(seed, element.uid()));
+			let data = element.data_mut();
+			data.set_tick_count(self.tick_count.clone());
+			data.set_seed(seed);
+			data.set_options(self.options);
+			#[cfg(any(feature = "std", feature = "embassy"))]
+			data.set_clock(self.clock.clone());
+			data.set_context(self.context.clone());
 		}
 	}
 
+	/// Sets an application context, e.g. a handle to a device driver, which
+	/// all behaviors can access with [`BehaviorData::context`] during their tick.
+	/// A previously set context is replaced.
+	pub fn set_context<T: Any + Send + Sync>(&mut self, context: T) {
+		self.context = Some(Arc::new(context));
+		self.share_tree_data();
+	}
+
+	/// Returns the application context of type `T`, if one of that type is set.
+	#[must_use]
+	pub fn context<T: Any + Send + Sync>(&self) -> Option<&T> {
+		self.context
+			.as_deref()
+			.and_then(|context| context.downcast_ref::<T>())
+	}
+
+	/// Sets a hook, which is called once at the start of each tick of the tree with the root blackboard,
+	/// e.g. to snapshot sensor values into the blackboard.
+	/// A previously set hook is replaced.
+	pub fn set_pre_tick_hook<F>(&mut self, hook: F)
+	where
+		F: FnMut(&Databoard) + Send + Sync + 'static,
+	{
+		self.pre_tick_hook = Some(Box::new(hook));
+	}
+
+	/// Sets a hook, which is called once at the end of each tick of the tree with the root blackboard,
+	/// e.g. to flush output values from the blackboard to actuators.
+	/// The hook is also called, if the tick failed.
+	/// A previously set hook is replaced.
+	pub fn set_post_tick_hook<F>(&mut self, hook: F)
+	where
+		F: FnMut(&Databoard) + Send + Sync + 'static,
+	{
+		self.post_tick_hook = Some(Box::new(hook));
+	}
+
+	/// Removes the pre and the post tick hook.
+	pub fn remove_tick_hooks(&mut self) {
+		self.pre_tick_hook = None;
+		self.post_tick_hook = None;
+	}
+
+	/// Get the seed for random behaviors, if one is set.
+	#[must_use]
+	pub const fn seed(&self) -> Option<u64> {
+		self.seed
+	}
+
+	/// Get the number of ticks since creation or the last [`reset`](Self::reset) of the tree.
+	#[must_use]
+	pub fn tick_count(&self) -> u64 {
+		*self.tick_count.lock()
+	}
+
+	/// Increments the tick count before a tick of the tree.
+	fn count_tick(&self) {
+		*self.tick_count.lock() += 1;
+	}
+
 	/// Access the root blackboard of the tree.
 	#[must_use]
 	pub const fn blackboard(&self) -> &Databoard {
@@ -154,6 +347,242 @@ impl BehaviorTree {
 		Err(Error::SubtreeNotFound { index })
 	}
 
+	/// Get the [`BehaviorTreeElement`] with the given `uid`, where uid 0 is the root.
+	/// The lookup follows a path stored at construction and does not scan the tree.
+	#[must_use]
+	pub fn element_by_uid(&self, uid: u16) -> Option<&BehaviorTreeElement> {
+		let path = self.paths.get(usize::from(uid))?.as_ref()?;
+		let mut element = &self.root;
+		for index in path {
+			element = element.children().get(*index)?;
+		}
+		Some(element)
+	}
+
+	/// Get the mutable [`BehaviorTreeElement`] with the given `uid`.
+	fn element_by_uid_mut(&mut self, uid: u16) -> Option<&mut BehaviorTreeElement> {
+		self.inline_outdated = true;
+		let path = self.paths.get(usize::from(uid))?.as_ref()?;
+		let mut element = &mut self.root;
+		for index in path {
+			element = element.children_mut().get_mut(*index)?;
+		}
+		Some(element)
+	}
+
+	/// Replace the behavior of the element with the Groot2 style `path` by `behavior`, keeping the tree structure.
+	/// A running element is halted before the replacement, so the replaced behavior can clean up.
+	/// The state of the element is reset to [`BehaviorState::Idle`], its children are kept as they are.
+	/// # Errors
+	/// - if no element with `path` is found
+	/// - if the kind of `behavior` does not match the kind of the element
+	/// - if halting the element fails
+	pub fn replace_behavior(&mut self, path: &str, behavior: BehaviorPtr) -> Result<(), Error> {
+		let runtime = self.runtime.clone();
+		let Some(element) = self
+			.iter_mut()
+			.find(|element| element.groot2_path().as_ref() == path)
+		else {
+			return Err(Error::ElementNotFound { path: path.into() });
+		};
+		let expected = element.data().description().kind();
+		let found = behavior.dynamic_kind();
+		if expected != found {
+			return Err(Error::KindMismatch {
+				path: path.into(),
+				expected,
+				found,
+			});
+		}
+		element
+			.halt(&runtime)
+			.map_err(|error| Error::Halt {
+				path: path.into(),
+				error: error.to_string().into(),
+			})?;
+		*element.behavior_mut() = behavior;
+		element.reset_state();
+		Ok(())
+	}
+
+	/// Rebuild the children of all subtree elements with the given `id` from the current
+	/// definition in the `factory`, see [`BehaviorTreeFactory::replace_subtree_definition()`].
+	/// The blackboards of the subtrees and the surrounding structure are preserved.
+	/// Returns the number of reloaded subtree elements.
+	///
+	/// The reloaded elements get new uids beyond the existing ones, so existing uids stay valid.
+	/// Observers and Groot2 connectors have to be attached again to see the reloaded elements.
+	/// # Errors
+	/// - if no subtree with `id` is found.
+	/// - if one of the subtrees is not idle.
+	/// - if the subtree definition cannot be parsed or one of the subtrees cannot be created,
+	///   the tree is then left unchanged.
+	/// - if the tree runs out of uids.
+	pub fn reload_subtree(&mut self, factory: &BehaviorTreeFactory, id: &str) -> Result<usize, Error> {
+		let mut targets = Vec::new();
+		for element in self.iter() {
+			if matches!(element.kind(), TreeElementKind::SubTree) && element.name().as_ref() == id {
+				if element.state() != BehaviorState::Idle {
+					return Err(Error::SubtreeNotIdle { id: id.into() });
+				}
+				targets.push(element.uid());
+			}
+		}
+		if targets.is_empty() {
+			return Err(Error::SubtreeIdNotFound { id: id.into() });
+		}
+
+		let start_uid = u16::try_from(self.paths.len()).map_err(|_| Error::Reload {
+			id: id.into(),
+			error: XmlError::TooManyElements.to_string().into(),
+		})?;
+		let mut parser = XmlParser::with_start_uid(start_uid);
+		let mut replacements = Vec::with_capacity(targets.len());
+		for uid in &targets {
+			let element = self
+				.element_by_uid(*uid)
+				.ok_or_else(|| Error::SubtreeIdNotFound { id: id.into() })?;
+			let children = parser
+				.create_subtree_children(
+					id,
+					factory.registry(),
+					element.full_path(),
+					element.data().description().prefix(),
+					element.data().stable_id(),
+					element.blackboard(),
+				)
+				.map_err(|err| Error::Reload {
+					id: id.into(),
+					error: err.to_string().into(),
+				})?;
+			replacements.push((*uid, children));
+		}
+		// all subtrees are rebuilt, so the tree is only changed now
+		for (uid, children) in replacements {
+			if let Some(element) = self.element_by_uid_mut(uid) {
+				*element.children_mut() = children;
+			}
+		}
+
+		self.paths.clear();
+		collect_paths(&self.root, &mut Vec::new(), &mut self.paths);
+		self.share_tree_data();
+		if self.failure_tracking {
+			self.enable_failure_tracking();
+		}
+		Ok(targets.len())
+	}
+
+	/// Inserts the content of the registered (sub)tree definition `id` as child at `index`
+	/// of the element with `parent_uid`, e.g. to grow a tree procedurally between ticks.
+	///
+	/// The new elements get uids beyond the current maximum and use the blackboard of the parent.
+	/// Controls re-read their children on every tick, so the new child is ticked like the others.
+	/// Returns the uid of the inserted child.
+	/// Observers and Groot2 connectors have to be attached again to see the inserted elements.
+	/// # Errors
+	/// - if no element with `parent_uid` is found.
+	/// - if the parent is a leaf or running or one of its children is running.
+	/// - if `index` is out of bounds.
+	/// - if the definition cannot be parsed or is empty.
+	/// - if the tree runs out of uids.
+	pub fn insert_child(
+		&mut self,
+		factory: &BehaviorTreeFactory,
+		parent_uid: u16,
+		index: usize,
+		id: &str,
+	) -> Result<u16, Error> {
+		let start_uid = u16::try_from(self.paths.len());
+		let parent = self.mutable_parent(parent_uid)?;
+		let path = parent.groot2_path().clone();
+		let mutation_error = |error: ConstString| Error::Mutation {
+			path: path.clone(),
+			error,
+		};
+		let mut parser = XmlParser::with_start_uid(
+			start_uid.map_err(|_| mutation_error(XmlError::TooManyElements.to_string().into()))?,
+		);
+		let mut children = parser
+			.create_subtree_children(
+				id,
+				factory.registry(),
+				parent.full_path(),
+				parent.data().description().prefix(),
+				parent.data().stable_id(),
+				parent.blackboard(),
+			)
+			.map_err(|err| mutation_error(err.to_string().into()))?;
+		let Some(child) = children.pop() else {
+			return Err(mutation_error(format!("the definition {id} is empty").into()));
+		};
+		let uid = child.uid();
+		parent
+			.children_mut()
+			.insert(index, child)
+			.map_err(|err| mutation_error(err.to_string().into()))?;
+
+		self.paths.clear();
+		collect_paths(&self.root, &mut Vec::new(), &mut self.paths);
+		self.share_tree_data();
+		if self.failure_tracking {
+			self.enable_failure_tracking();
+		}
+		Ok(uid)
+	}
+
+	/// Removes and returns the child at `index` of the element with `parent_uid`.
+	/// The uids of the remaining elements are not changed.
+	/// # Errors
+	/// - if no element with `parent_uid` is found.
+	/// - if the parent is a leaf or running or one of its children is running.
+	/// - if `index` is out of bounds.
+	pub fn remove_child(&mut self, parent_uid: u16, index: usize) -> Result<BehaviorTreeElement, Error> {
+		let parent = self.mutable_parent(parent_uid)?;
+		let child = parent
+			.children_mut()
+			.remove(index)
+			.map_err(|err| Error::Mutation {
+				path: parent.groot2_path().clone(),
+				error: err.to_string().into(),
+			})?;
+
+		self.paths.clear();
+		collect_paths(&self.root, &mut Vec::new(), &mut self.paths);
+		self.share_tree_data();
+		Ok(child)
+	}
+
+	/// Returns the element with `parent_uid`, if its children may be modified.
+	fn mutable_parent(&mut self, parent_uid: u16) -> Result<&mut BehaviorTreeElement, Error> {
+		let parent = self
+			.element_by_uid_mut(parent_uid)
+			.ok_or(Error::UidNotFound { uid: parent_uid })?;
+		if matches!(parent.kind(), TreeElementKind::Leaf) {
+			return Err(Error::Mutation {
+				path: parent.groot2_path().clone(),
+				error: "a leaf has no children".into(),
+			});
+		}
+		if parent.state() == BehaviorState::Running {
+			return Err(Error::Mutation {
+				path: parent.groot2_path().clone(),
+				error: "the element is running".into(),
+			});
+		}
+		if parent
+			.children()
+			.iter()
+			.any(|child| child.state() == BehaviorState::Running)
+		{
+			return Err(Error::Mutation {
+				path: parent.groot2_path().clone(),
+				error: "a child of the element is running".into(),
+			});
+		}
+		Ok(parent)
+	}
+
 	/// Get the trees uuid.
 	#[cfg(feature = "std")]
 	#[must_use]
@@ -180,7 +609,86 @@ impl BehaviorTree {
 		count
 	}
 
-	/// Handle incoming message    
+	/// Get the trees maximum nesting level, where a tree with only a root element has depth 1.
+	#[must_use]
+	pub fn depth(&self) -> usize {
+		// number of children still to visit for each open level
+		let mut remaining: Vec<usize> = Vec::new();
+		let mut depth = 0;
+		for element in self.iter() {
+			while remaining.last() == Some(&0) {
+				remaining.pop();
+			}
+			if let Some(count) = remaining.last_mut() {
+				*count -= 1;
+			}
+			remaining.push(element.children().len());
+			depth = depth.max(remaining.len());
+		}
+		depth
+	}
+
+	/// Get the maximum number of children of a single element in the tree.
+	#[must_use]
+	pub fn max_branching(&self) -> usize {
+		self.iter()
+			.map(|element| element.children().len())
+			.max()
+			.unwrap_or_default()
+	}
+
+	/// Get the Groot2 style paths of all leafs, the actions and conditions, of the tree.
+	#[must_use]
+	pub fn leaf_paths(&self) -> Vec<ConstString> {
+		self.iter()
+			.filter(|element| matches!(element.kind(), TreeElementKind::Leaf))
+			.map(|element| element.groot2_path().clone())
+			.collect()
+	}
+
+	/// Analyzes the data flow through the blackboard and returns the findings, e.g.
+	/// output ports, which are never read, and input ports without producer or default.
+	///
+	/// The analysis is done within each blackboard scope, the main tree and each subtree.
+	/// Global `@` keys and the data flow between a subtree and its parent are not analyzed,
+	/// keys mentioned in scripts or constant values count as read and written.
+	#[must_use]
+	pub fn analyze(&self) -> Vec<TreeWarning> {
+		analysis::analyze(&self.root)
+	}
+
+	/// Validates the declared port types before the first tick and returns a
+	/// [`TreeWarning::InconsistentType`] for each port, which declares another type
+	/// for a blackboard entry than the first port accessing that entry.
+	///
+	/// The entries are followed through the remappings of subtrees and into the global `@` scope,
+	/// so e.g. a producer writing an `i32` and a consumer reading an `f64` are detected without ticking.
+	/// Ports of type `String` are not validated, as strings are converted on access.
+	#[must_use]
+	pub fn validate_runtime_types(&self) -> Vec<TreeWarning> {
+		analysis::validate_types(&self.root)
+	}
+
+	/// Get the leafs, the actions and conditions, which are currently [`BehaviorState::Running`].
+	/// Within parallel branches several leafs can be running at the same time.
+	#[must_use]
+	pub fn running_leaves(&self) -> Vec<&BehaviorTreeElement> {
+		self.iter()
+			.filter(|element| {
+				matches!(element.kind(), TreeElementKind::Leaf) && element.data().state() == BehaviorState::Running
+			})
+			.collect()
+	}
+
+	/// Handle all pending messages
+	#[cfg(feature = "std")]
+	fn handle_messages(&mut self) {
+		while let Ok(message) = self.rx.try_recv() {
+			self.handle_message(message);
+		}
+	}
+
+	/// Handle incoming message
 	#[cfg(feature = "std")]
 	fn handle_message(&mut self, message: BehaviorTreeMessage) {
 		match message {
@@ -198,6 +706,14 @@ impl BehaviorTree {
 		}
 	}
 
+	/// Updates the inline flags of the elements, if they were mutably accessed since the last tick.
+	fn update_inline(&mut self) {
+		if self.inline_outdated {
+			self.root.update_inline();
+			self.inline_outdated = false;
+		}
+	}
+
 	/// Ticks the tree exactly once.
 	/// # Errors
 	#[inline]
@@ -206,7 +722,47 @@ impl BehaviorTree {
 		if let Ok(message) = self.rx.try_recv() {
 			self.handle_message(message);
 		}
-		self.root.tick(&self.runtime).await
+		self.count_tick();
+		self.tick_root().await
+	}
+
+	/// Ticks the root element once surrounded by the tick hooks.
+	/// If the tick fails and the tree is configured to halt on errors,
+	/// the tree is halted before the error is returned.
+	async fn tick_root(&mut self) -> BehaviorResult {
+		self.tick_wrapped(None).await
+	}
+
+	/// Ticks the root element or the (sub)tree with index `subtree` once surrounded by the tick hooks.
+	/// If the tick fails and the tree is configured to halt on errors,
+	/// the ticked element is halted before the error is returned.
+	async fn tick_wrapped(&mut self, subtree: Option<usize>) -> BehaviorResult {
+		self.update_inline();
+		if let Some(hook) = self.pre_tick_hook.as_mut() {
+			hook(self.root.data().blackboard());
+		}
+		let element = match subtree {
+			None => Ok(&mut self.root),
+			Some(index) => TreeIterMut::new(&mut self.root)
+				.filter(|element| matches!(element.kind(), TreeElementKind::SubTree))
+				.nth(index)
+				.ok_or_else(|| subtree_not_found(index)),
+		};
+		let result = match element {
+			Ok(element) => {
+				let result = element.tick(&self.runtime).await;
+				if result.is_err() && self.options.contains(TreeOptions::HALT_ON_ERROR) {
+					// the error of the tick is more relevant than an eventual error during halting
+					let _ = halt_all(element, &self.runtime);
+				}
+				result
+			}
+			Err(error) => Err(error),
+		};
+		if let Some(hook) = self.post_tick_hook.as_mut() {
+			hook(self.root.data().blackboard());
+		}
+		result
 	}
 
 	/// Ticks the tree once.
@@ -218,19 +774,58 @@ impl BehaviorTree {
 		if let Ok(message) = self.rx.try_recv() {
 			self.handle_message(message);
 		}
-		self.root.tick(&self.runtime).await
+		self.count_tick();
+		self.tick_root().await
+	}
+
+	/// Ticks the tree once like [`tick_once`](Self::tick_once) and
+	/// returns the sorted uids of all elements, which changed their state during the tick.
+	/// # Errors
+	/// - if ticking the tree fails.
+	pub async fn tick_once_with_changes(&mut self) -> Result<(BehaviorState, Vec<u16>), BehaviorError> {
+		let name: ConstString = "tick_once_with_changes".into();
+		let changes: Arc<Mutex<Vec<u16>>> = Arc::default();
+		let recorder = changes.clone();
+		self.add_global_transition_callback(&name, move |behavior, _| {
+			recorder.lock().push(behavior.uid());
+		});
+		let result = self.tick_once().await;
+		self.remove_global_transition_callback(&name);
+		let mut uids = core::mem::take(&mut *changes.lock());
+		uids.sort_unstable();
+		uids.dedup();
+		Ok((result?, uids))
+	}
+
+	/// Ticks only the (sub)tree with `index` once, where index 0 is the root tree.
+	///
+	/// The subtree is ticked with its own blackboard. Entries remapped to the parent
+	/// blackboard are read from and written to the parent like in a tick of the whole tree,
+	/// so values the parent tree normally provides have to be set in advance.
+	/// The parents of the subtree are not ticked and keep their state.
+	/// The tick is counted and surrounded by the tick hooks like a tick of the whole tree.
+	/// # Errors
+	/// - if no subtree with `index` exists.
+	/// - if ticking the subtree fails.
+	pub async fn tick_subtree(&mut self, index: usize) -> BehaviorResult {
+		if self.subtree(index).is_err() {
+			return Err(subtree_not_found(index));
+		}
+		self.count_tick();
+		self.tick_wrapped(Some(index)).await
 	}
 
 	/// Ticks the tree until it finishes either with [`BehaviorState::Success`] or [`BehaviorState::Failure`].
+	/// The ticking also ends, if the tree is [`BehaviorState::Skipped`], e.g. by a `_skipIf` of its top element,
+	/// so only [`BehaviorState::Running`] and [`BehaviorState::Idle`] lead to another tick.
 	/// # Errors
 	pub async fn tick_while_running(&mut self) -> BehaviorResult {
 		let mut state = BehaviorState::Running;
 		while state == BehaviorState::Running || state == BehaviorState::Idle {
 			#[cfg(feature = "std")]
-			while let Ok(message) = self.rx.try_recv() {
-				self.handle_message(message);
-			}
-			state = self.root.tick(&self.runtime).await?;
+			self.handle_messages();
+			self.count_tick();
+			state = self.tick_root().await?;
 
 			// Not implemented: Check for wake-up conditions and tick again if so
 			// Not sure if this is still necessary with real async
@@ -246,30 +841,252 @@ impl BehaviorTree {
 
 		// handle eventually pending messages
 		#[cfg(feature = "std")]
-		while let Ok(message) = self.rx.try_recv() {
-			self.handle_message(message);
+		self.handle_messages();
+		Ok(state)
+	}
+
+	/// Ticks the tree `n` times in a row and returns the result of the last tick,
+	/// e.g. for measuring the ticks per second.
+	///
+	/// With `reset_between` a tree completed with [`BehaviorState::Success`] or [`BehaviorState::Failure`]
+	/// is halted before the next tick, so that it starts over. Unlike [`reset`](Self::reset) this keeps the
+	/// tick count and the state of the scripting runtime, so that no buffers are released and allocated again.
+	/// Pending messages are handled once before the first tick.
+	///
+	/// The ticks themselves do not allocate, except for the boxed futures of the behaviors `start` and `tick`
+	/// methods and whatever the behaviors allocate on their own.
+	/// # Errors
+	/// - if a tick of the tree fails
+	pub async fn tick_bulk(&mut self, n: usize, reset_between: bool) -> BehaviorResult {
+		#[cfg(feature = "std")]
+		self.handle_messages();
+		let mut state = self.root.state();
+		for _ in 0..n {
+			if reset_between && state.is_completed() {
+				self.root.halt(&self.runtime)?;
+			}
+			self.count_tick();
+			state = self.tick_root().await?;
+		}
+		Ok(state)
+	}
+
+	/// Ticks the tree until it finishes either with [`BehaviorState::Success`] or [`BehaviorState::Failure`],
+	/// with a minimum `period` between the starts of two ticks.
+	/// While the tree is running, the rest of the period is slept instead of ticking in a busy loop.
+	/// A tick taking longer than the period is followed immediately by the next tick.
+	///
+	/// Only available with feature `std`, as the rest of the period is slept with the `tokio` timer.
+	/// So the period is measured in real time, independent of the trees [`Clock`].
+	/// # Errors
+	/// - if a tick of the tree fails
+	#[cfg(feature = "std")]
+	pub async fn tick_while_running_at(&mut self, period: Duration) -> BehaviorResult {
+		let mut state = BehaviorState::Running;
+		while state == BehaviorState::Running || state == BehaviorState::Idle {
+			let start = Instant::now();
+			self.handle_messages();
+			self.count_tick();
+			state = self.tick_root().await?;
+
+			if state == BehaviorState::Running || state == BehaviorState::Idle {
+				tokio::time::sleep(period.saturating_sub(start.elapsed())).await;
+			}
+		}
+
+		// be cooperative & allow pending tasks to catch up
+		tokio::task::yield_now().await;
+
+		// handle eventually pending messages
+		self.handle_messages();
+		Ok(state)
+	}
+
+	/// Ticks the tree repeatedly until it finishes with [`BehaviorState::Success`] or [`BehaviorState::Failure`]
+	/// or the time `budget` is exhausted, whichever comes first.
+	/// The budget is checked after each tick, so a single tick is never interrupted.
+	/// Returns the latest state, which is [`BehaviorState::Running`] if the budget was exhausted.
+	///
+	/// The budget is measured with the trees [`Clock`], so with a [`ManualClock`](crate::clock::ManualClock)
+	/// it is exhausted only when the clock is advanced.
+	/// Only available with feature `std` or `embassy`, which provide a default [`Clock`].
+	/// # Errors
+	/// - if a tick of the tree fails
+	#[cfg(any(feature = "std", feature = "embassy"))]
+	pub async fn tick_with_budget(&mut self, budget: Duration) -> BehaviorResult {
+		let start = self.clock.now();
+		let mut state = BehaviorState::Running;
+		while state == BehaviorState::Running || state == BehaviorState::Idle {
+			#[cfg(feature = "std")]
+			self.handle_messages();
+			self.count_tick();
+			state = self.tick_root().await?;
+
+			if self.clock.now().saturating_sub(start) >= budget {
+				break;
+			}
+		}
+
+		// be cooperative & allow pending tasks to catch up
+		#[cfg(feature = "std")]
+		{
+			tokio::task::yield_now().await;
 		}
 		Ok(state)
 	}
 
+	/// Does a single dry run tick of the tree and returns the Groot2 style paths of the visited leafs in order.
+	///
+	/// The actions and conditions are not executed, they are replaced by a mock returning `leaf_state`
+	/// for the duration of the dry run, while the controls and decorators work as usual.
+	/// As conditions must not return [`BehaviorState::Running`], they are mocked with
+	/// [`BehaviorState::Success`] when `leaf_state` is `Running`.
+	/// Afterwards the tree is halted and the original behaviors are restored.
+	/// The tree must be idle, so that the dry run does not interfere with a running tick.
+	///
+	/// Be aware, that pre- and post-conditions of the elements are evaluated as usual,
+	/// so their scripts may modify the blackboard.
+	/// # Errors
+	/// - if the tree is not idle
+	/// - if ticking the tree fails
+	/// - if halting the tree fails
+	#[cfg(feature = "mock_behavior")]
+	pub async fn dry_run(&mut self, leaf_state: BehaviorState) -> Result<Vec<ConstString>, BehaviorError> {
+		if self.root.state() != BehaviorState::Idle {
+			return Err(BehaviorError::State {
+				behavior: self.root.name().clone(),
+				state: self.root.state(),
+			});
+		}
+		let visited: Arc<Mutex<Vec<ConstString>>> = Arc::default();
+		let mut originals: Vec<BehaviorPtr> = Vec::new();
+		for element in self.iter_mut() {
+			if matches!(element.kind(), TreeElementKind::Leaf) {
+				let path = element.groot2_path().clone();
+				let visited = visited.clone();
+				// conditions must not return `Running`
+				let state = if leaf_state == BehaviorState::Running
+					&& element.data().description().kind() == BehaviorKind::Condition
+				{
+					BehaviorState::Success
+				} else {
+					leaf_state
+				};
+				let config = MockBehaviorConfig {
+					return_state: state,
+					complete_func: Some(Arc::new(move || {
+						visited.lock().push(path.clone());
+						state
+					})),
+					..Default::default()
+				};
+				let port_list = element.behavior().static_provided_ports();
+				let mock: BehaviorPtr = Box::new(MockBehavior::new(config, port_list));
+				originals.push(core::mem::replace(element.behavior_mut(), mock));
+			}
+		}
+
+		self.update_inline();
+		let result = self.root.tick(&self.runtime).await;
+		let halted = self.root.halt(&self.runtime);
+
+		let mut originals = originals.into_iter();
+		for element in self.iter_mut() {
+			if matches!(element.kind(), TreeElementKind::Leaf)
+				&& let Some(original) = originals.next()
+			{
+				*element.behavior_mut() = original;
+			}
+		}
+		result?;
+		halted?;
+
+		let visited = core::mem::take(&mut *visited.lock());
+		Ok(visited)
+	}
+
 	/// Get an iterator over the tree.
+	///
+	/// The elements are yielded depth-first, parents before their children and siblings from left to right.
+	/// This is the order in which the elements are created, so for a tree created by the factory
+	/// the uids are ascending and contiguous, starting with 0 for the root.
+	/// External tools may therefore index elements by their position in the iteration.
+	/// After [`insert_child`](Self::insert_child) or [`remove_child`](Self::remove_child)
+	/// the order is still depth-first, but the uids are no longer contiguous.
 	#[inline]
 	pub fn iter(&self) -> impl Iterator<Item = &BehaviorTreeElement> {
 		TreeIter::new(&self.root)
 	}
 
 	/// Get a mutable iterator over the tree.
+	/// The elements are yielded in the same order as with [`iter`](Self::iter).
 	#[inline]
 	pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut BehaviorTreeElement> {
+		self.inline_outdated = true;
 		TreeIterMut::new(&mut self.root)
 	}
 
+	/// Add a transition callback with the given name to every element of the tree.
+	/// The callback is called with the elements [`BehaviorData`] and the new state before a state change.
+	/// The name is not unique, which is important when removing callback.
+	pub fn add_global_transition_callback<T>(&mut self, name: &ConstString, callback: T)
+	where
+		T: Fn(&BehaviorData, BehaviorState) + Send + Sync + 'static,
+	{
+		let callback = Arc::new(callback);
+		for element in self.iter_mut() {
+			let callback = callback.clone();
+			element.add_pre_state_change_callback(name.clone(), move |behavior, new_state| {
+				callback(behavior, *new_state);
+			});
+		}
+	}
+
+	/// Enables tracking of the most recently failed leaf, available via [`last_failure`](Self::last_failure).
+	///
+	/// The tracking is done by a transition callback named `last_failure` on every element.
+	/// Elements added later with [`insert_child`](Self::insert_child) or [`reload_subtree`](Self::reload_subtree)
+	/// are tracked too. Enabling the tracking again has no further effect.
+	pub fn enable_failure_tracking(&mut self) {
+		self.failure_tracking = true;
+		let name: ConstString = "last_failure".into();
+		self.remove_global_transition_callback(&name);
+		let last_failure = self.last_failure.clone();
+		self.add_global_transition_callback(&name, move |behavior, state| {
+			if state == BehaviorState::Failure
+				&& matches!(behavior.description().kind(), BehaviorKind::Action | BehaviorKind::Condition)
+			{
+				*last_failure.lock() = Some((behavior.full_path().clone(), behavior.reason()));
+			}
+		});
+	}
+
+	/// Get the Groot2 style path and the optional reason of the most recently failed leaf,
+	/// if failure tracking is enabled with [`enable_failure_tracking`](Self::enable_failure_tracking).
+	///
+	/// The reason is taken from the port [`REASON`](crate::REASON), if it is connected to a blackboard entry.
+	/// The information is kept until the next failure of a leaf or a [`reset`](Self::reset) of the tree.
+	#[must_use]
+	pub fn last_failure(&self) -> Option<(ConstString, Option<ConstString>)> {
+		self.last_failure.lock().clone()
+	}
+
+	/// Remove any transition callback with the given name from every element of the tree.
+	pub fn remove_global_transition_callback(&mut self, name: &ConstString) {
+		for element in self.iter_mut() {
+			element.remove_pre_state_change_callback(name);
+		}
+	}
+
 	/// Reset tree to initial state.
 	/// # Errors
 	/// - if reset of children failed
 	pub fn reset(&mut self) -> Result<(), BehaviorError> {
 		self.root.halt(&self.runtime)?;
+		#[cfg(feature = "scripting")]
 		self.runtime.lock().clear();
+		*self.tick_count.lock() = 0;
+		*self.last_failure.lock() = None;
 		Ok(())
 	}
 }
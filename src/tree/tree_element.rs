@@ -3,23 +3,32 @@
 //! A [`BehaviorTree`](crate::tree::tree::BehaviorTree) element.
 
 // region:      --- modules
+use crate::SharedRuntime;
 use crate::{
-	ConstString, FAILURE_IF, ON_FAILURE, ON_SUCCESS, POST, SKIP_IF, SUCCESS_IF, WHILE,
+	ConstString,
 	behavior::BehaviorDataCollection,
 	behavior::{
-		BehaviorPtr, BehaviorResult, BehaviorState,
+		BehaviorKind, BehaviorPtr, BehaviorResult, BehaviorState,
 		behavior_data::BehaviorData,
 		error::Error as BehaviorError,
 		pre_post_conditions::{Conditions, PostConditions, PreConditions},
 	},
+	port::{PortDirection, error::Error as PortError},
 	tree::{
 		tree_element_list::BehaviorTreeElementList,
 		tree_iter::{TreeIter, TreeIterMut},
 	},
 };
-use alloc::{boxed::Box, string::ToString};
+#[cfg(feature = "scripting")]
+use crate::{FAILURE_IF, ON_ENTER, ON_EXIT, ON_FAILURE, ON_SUCCESS, POST, SKIP_IF, SUCCESS_IF, WHILE};
+use alloc::{
+	boxed::Box,
+	string::{String, ToString},
+	vec::Vec,
+};
 use databoard::{Databoard, Remappings};
-use tinyscript::{Error, SharedRuntime};
+#[cfg(feature = "scripting")]
+use tinyscript::Error;
 // endregion:   --- modules
 
 // region:		--- TreeElementKind
@@ -49,6 +58,13 @@ pub struct BehaviorTreeElement {
 	children: BehaviorTreeElementList,
 	/// Tuple of pre- and post-conditions, checked before and after a tick.
 	conditions: Conditions,
+	/// Flag whether the element is ticked at all.
+	enabled: bool,
+	/// Flag whether the behaviors of the element and all its descendants are [`InlineBehavior`](crate::behavior::InlineBehavior)s.
+	inline: bool,
+	/// Flag whether the parent of the element is currently halting, used for consistency checks.
+	#[cfg(debug_assertions)]
+	parent_halting: bool,
 }
 
 impl BehaviorTreeElement {
@@ -61,7 +77,26 @@ impl BehaviorTreeElement {
 		children: BehaviorTreeElementList,
 		conditions: Conditions,
 	) -> Self {
-		let groot2_path = match kind {
+		let groot2_path = Self::create_groot2_path(kind, &data);
+		data.description_mut()
+			.set_groot2_path(groot2_path);
+		let inline = behavior.is_inline() && children.iter().all(|child| child.inline);
+		Self {
+			kind,
+			behavior,
+			data,
+			children,
+			conditions,
+			enabled: true,
+			inline,
+			#[cfg(debug_assertions)]
+			parent_halting: false,
+		}
+	}
+
+	/// Create the Groot2 style 'path' for an element.
+	fn create_groot2_path(kind: TreeElementKind, data: &BehaviorData) -> ConstString {
+		match kind {
 			TreeElementKind::Leaf | TreeElementKind::Node => data.description().path().clone(),
 			TreeElementKind::SubTree => {
 				if data.description().path().is_empty() {
@@ -71,15 +106,6 @@ impl BehaviorTreeElement {
 					(data.description().name().to_string() + "::" + &uid).into()
 				}
 			}
-		};
-		data.description_mut()
-			.set_groot2_path(groot2_path);
-		Self {
-			kind,
-			behavior,
-			data,
-			children,
-			conditions,
 		}
 	}
 
@@ -116,6 +142,12 @@ impl BehaviorTreeElement {
 		self.data.uid()
 	}
 
+	/// Get the stable id, if enabled in the [`BehaviorTreeFactory`](crate::factory::BehaviorTreeFactory).
+	#[must_use]
+	pub const fn stable_id(&self) -> Option<u64> {
+		self.data.stable_id()
+	}
+
 	/// Get the id.
 	#[must_use]
 	pub const fn id(&self) -> &ConstString {
@@ -128,6 +160,26 @@ impl BehaviorTreeElement {
 		self.data.description().name()
 	}
 
+	/// Renames the element, updating its path and Groot2 path accordingly.
+	///
+	/// Caution: The paths of the children are not updated, so path based lookups
+	/// and substitution rules made before renaming may no longer match.
+	pub fn set_display_name(&mut self, name: &str) {
+		let path = self
+			.data
+			.description()
+			.path()
+			.rsplit_once('/')
+			.map_or_else(|| String::from(name), |(parent, _)| String::from(parent) + "/" + name);
+		let description = self.data.description_mut();
+		description.set_name(name);
+		description.set_path(&path);
+		let groot2_path = Self::create_groot2_path(self.kind, &self.data);
+		self.data
+			.description_mut()
+			.set_groot2_path(groot2_path);
+	}
+
 	/// Returns a reference to the [`BehaviorData`].
 	#[must_use]
 	pub const fn data(&self) -> &BehaviorData {
@@ -158,6 +210,21 @@ impl BehaviorTreeElement {
 		self.data().blackboard()
 	}
 
+	/// Returns whether the element is enabled.
+	#[must_use]
+	pub const fn is_enabled(&self) -> bool {
+		self.enabled
+	}
+
+	/// Enables or disables the element.
+	///
+	/// A disabled element returns [`BehaviorState::Skipped`] without being ticked,
+	/// neither its pre-conditions nor its children are evaluated.
+	/// If the element is running when it is ticked disabled, it is halted.
+	pub const fn set_enabled(&mut self, enabled: bool) {
+		self.enabled = enabled;
+	}
+
 	/// Returns the children.
 	#[must_use]
 	pub const fn children(&self) -> &BehaviorTreeElementList {
@@ -189,38 +256,98 @@ impl BehaviorTreeElement {
 	}
 
 	/// Halts the element and all its children considering postconditions.
+	/// A running subtree also runs its `_on_exit` hook.
 	/// # Errors
 	pub fn halt(&mut self, runtime: &SharedRuntime) -> Result<(), BehaviorError> {
-		if self.data.state() != BehaviorState::Idle {
-			let state = self
+		let old_state = self.data.state();
+		if old_state != BehaviorState::Idle {
+			#[cfg(debug_assertions)]
+			self.set_children_parent_halting(true);
+			let result = self
 				.behavior
-				.halt(&mut self.data, &mut self.children, runtime)?;
-			self.data.set_state(state);
+				.halt(&mut self.data, &mut self.children, runtime);
+			#[cfg(debug_assertions)]
+			self.set_children_parent_halting(false);
+			self.data.set_state(result?);
+			#[cfg(feature = "scripting")]
 			if let Some(script) = self.conditions.post.get("_onHalted") {
 				let _ = runtime.lock().run(script, &mut self.data)?;
 			}
+			if old_state == BehaviorState::Running {
+				self.run_exit_hook(runtime)?;
+			}
 		}
 		Ok(())
 	}
 
 	/// Ticks the element considering pre- and postconditions.
 	/// # Errors
+	/// # Panics
+	/// - in debug builds, if the element is ticked while its parent is halting
 	pub async fn tick(&mut self, runtime: &SharedRuntime) -> BehaviorResult {
+		// a control must not tick its children while halting
+		#[cfg(debug_assertions)]
+		assert!(
+			!self.parent_halting,
+			"element {} is ticked while its parent is halting",
+			self.data.description().path()
+		);
+		if !self.enabled {
+			if self.data.state() == BehaviorState::Running {
+				self.halt(runtime)?;
+			}
+			return Ok(BehaviorState::Skipped);
+		}
+
 		// A pre-condition may return the next state which will override the current tick().
 		let old_state = self.data.state();
 		let state = if let Some(result) = self.check_pre_conditions(runtime)? {
 			result
-		} else if old_state == BehaviorState::Idle {
-			self.behavior
-				.start(&mut self.data, &mut self.children, runtime)
-				.await?
 		} else {
-			self.behavior
-				.tick(&mut self.data, &mut self.children, runtime)
-				.await?
+			let state = if old_state == BehaviorState::Idle {
+				self.run_enter_hook(runtime)?;
+				if self.inline
+					&& let Some(result) = self
+						.behavior
+						.tick_inline(&mut self.data, &mut self.children, runtime, true)
+				{
+					result?
+				} else {
+					self.behavior
+						.start(&mut self.data, &mut self.children, runtime)
+						.await?
+				}
+			} else if self.inline
+				&& let Some(result) = self
+					.behavior
+					.tick_inline(&mut self.data, &mut self.children, runtime, false)
+			{
+				result?
+			} else {
+				self.behavior
+					.tick(&mut self.data, &mut self.children, runtime)
+					.await?
+			};
+			// a tick must never result in `Idle`
+			if state == BehaviorState::Idle {
+				return Err(BehaviorError::IllegalTickResult {
+					node: self.data.description().path().clone(),
+					state,
+				});
+			}
+			// a condition must be instantaneous
+			if state == BehaviorState::Running && self.data.description().kind() == BehaviorKind::Condition {
+				return Err(BehaviorError::ConditionReturnedRunning {
+					node: self.data.description().path().clone(),
+				});
+			}
+			state
 		};
 
 		self.check_post_conditions(state, runtime)?;
+		if state.is_completed() {
+			self.run_exit_hook(runtime)?;
+		}
 
 		// Preserve the last state if skipped, but communicate `Skipped` to parent
 		if state != BehaviorState::Skipped {
@@ -232,6 +359,14 @@ impl BehaviorTreeElement {
 		Ok(state)
 	}
 
+	/// Marks the children as being halted by this element.
+	#[cfg(debug_assertions)]
+	fn set_children_parent_halting(&mut self, halting: bool) {
+		for child in self.children.iter_mut() {
+			child.parent_halting = halting;
+		}
+	}
+
 	/// Halts child at `index`.
 	/// # Errors
 	/// - if index is out of childrens bounds.
@@ -256,6 +391,24 @@ impl BehaviorTreeElement {
 		self.children.halt(runtime)
 	}
 
+	/// Lets the behavior check and prepare its configuration after the creation of the element.
+	/// # Errors
+	/// - if the behavior rejects its configuration
+	pub(crate) fn on_creation(&mut self, runtime: &SharedRuntime) -> Result<(), BehaviorError> {
+		self.behavior.on_creation(&mut self.data, runtime)
+	}
+
+	/// Updates the inline flags of the element and its descendants after a change of behaviors or children.
+	/// Returns the updated flag.
+	pub(crate) fn update_inline(&mut self) -> bool {
+		let mut inline = self.behavior.is_inline();
+		for child in self.children.iter_mut() {
+			inline &= child.update_inline();
+		}
+		self.inline = inline;
+		inline
+	}
+
 	/// Reset state of element.
 	pub fn reset_state(&mut self) {
 		// let prev_state = self.data.state();
@@ -313,6 +466,7 @@ impl BehaviorTreeElement {
 		self.kind
 	}
 
+	#[cfg(feature = "scripting")]
 	fn check_pre_conditions(&mut self, runtime: &SharedRuntime) -> Result<Option<BehaviorState>, Error> {
 		if self.conditions.pre.is_some() {
 			// Preconditions only applied when the node state is `Idle` or `Skipped`
@@ -357,6 +511,7 @@ impl BehaviorTreeElement {
 		Ok(None)
 	}
 
+	#[cfg(feature = "scripting")]
 	fn check_post_conditions(&mut self, state: BehaviorState, runtime: &SharedRuntime) -> Result<(), Error> {
 		if self.conditions.post.is_some() {
 			match state {
@@ -380,6 +535,56 @@ impl BehaviorTreeElement {
 		Ok(())
 	}
 
+	/// Without scripting there are no preconditions.
+	#[cfg(not(feature = "scripting"))]
+	#[allow(clippy::unnecessary_wraps, clippy::unused_self)]
+	const fn check_pre_conditions(&self, _runtime: &SharedRuntime) -> Result<Option<BehaviorState>, BehaviorError> {
+		Ok(None)
+	}
+
+	/// Without scripting there are no postconditions.
+	#[cfg(not(feature = "scripting"))]
+	#[allow(clippy::unnecessary_wraps, clippy::unused_self)]
+	const fn check_post_conditions(&self, _state: BehaviorState, _runtime: &SharedRuntime) -> Result<(), BehaviorError> {
+		Ok(())
+	}
+
+	/// Runs the `_on_enter` hook of a subtree, when it becomes active.
+	#[cfg(feature = "scripting")]
+	fn run_enter_hook(&mut self, runtime: &SharedRuntime) -> Result<(), Error> {
+		if matches!(self.kind, TreeElementKind::SubTree)
+			&& let Some(script) = self.conditions.pre.get(ON_ENTER)
+		{
+			let _ = runtime.lock().run(script, &mut self.data)?;
+		}
+		Ok(())
+	}
+
+	/// Runs the `_on_exit` hook of a subtree, when it is no longer active.
+	#[cfg(feature = "scripting")]
+	fn run_exit_hook(&mut self, runtime: &SharedRuntime) -> Result<(), Error> {
+		if matches!(self.kind, TreeElementKind::SubTree)
+			&& let Some(script) = self.conditions.post.get(ON_EXIT)
+		{
+			let _ = runtime.lock().run(script, &mut self.data)?;
+		}
+		Ok(())
+	}
+
+	/// Without scripting there are no subtree hooks.
+	#[cfg(not(feature = "scripting"))]
+	#[allow(clippy::unnecessary_wraps, clippy::unused_self)]
+	const fn run_enter_hook(&self, _runtime: &SharedRuntime) -> Result<(), BehaviorError> {
+		Ok(())
+	}
+
+	/// Without scripting there are no subtree hooks.
+	#[cfg(not(feature = "scripting"))]
+	#[allow(clippy::unnecessary_wraps, clippy::unused_self)]
+	const fn run_exit_hook(&self, _runtime: &SharedRuntime) -> Result<(), BehaviorError> {
+		Ok(())
+	}
+
 	/// Returns the full 'path' of the element.
 	#[must_use]
 	pub const fn full_path(&self) -> &ConstString {
@@ -397,5 +602,19 @@ impl BehaviorTreeElement {
 	pub const fn state(&self) -> BehaviorState {
 		self.data.state()
 	}
+
+	/// Returns the current values of all input ports, including the bidirectional ones,
+	/// with the remappings resolved against the blackboard.
+	/// A port without an available value comes with the error of the lookup.
+	#[must_use]
+	pub fn input_port_values(&self) -> Vec<(ConstString, Result<String, PortError>)> {
+		self.data
+			.description()
+			.ports()
+			.iter()
+			.filter(|port| matches!(port.direction(), PortDirection::In | PortDirection::InOut))
+			.map(|port| (port.name().into(), self.data.get::<String>(port.name())))
+			.collect()
+	}
 }
 // endregion:	--- BehaviorTreeElement
@@ -3,10 +3,14 @@
 //! [`BehaviorTree`](crate::tree::tree::BehaviorTree) element list implementation.
 
 use super::tree_element::BehaviorTreeElement;
-use crate::behavior::error::Error as BehaviorError;
-use alloc::{format, vec::Vec};
+use crate::SharedRuntime;
+use crate::behavior::{BehaviorState, error::Error as BehaviorError};
+use alloc::{
+	format,
+	string::{String, ToString},
+	vec::Vec,
+};
 use core::ops::{Deref, DerefMut};
-use tinyscript::SharedRuntime;
 
 /// An immutable list of tree components.
 #[derive(Default)]
@@ -28,6 +32,16 @@ impl DerefMut for BehaviorTreeElementList {
 }
 
 impl BehaviorTreeElementList {
+	/// Returns the current states of all children joined with `;`.
+	#[must_use]
+	pub fn states(&self) -> String {
+		self.0
+			.iter()
+			.map(|child| child.state().to_string())
+			.collect::<Vec<String>>()
+			.join(";")
+	}
+
 	/// Halt all children
 	/// # Errors
 	/// - if a child errors on `halt()`
@@ -63,6 +77,50 @@ impl BehaviorTreeElementList {
 		self.0[index].halt(runtime)
 	}
 
+	/// Inserts the `element` as child at `index`, shifting all children after it.
+	///
+	/// The uids of the `element` and its descendants are not changed, the caller is responsible
+	/// for assigning uids, which are unique within the tree. [`BehaviorTree::insert_child`](crate::tree::BehaviorTree::insert_child)
+	/// creates the element from a registered definition with uids beyond the current maximum.
+	/// # Errors
+	/// - if index is out of bounds
+	/// - if a child is running, as a control keeps the index of its running child
+	pub fn insert(&mut self, index: usize, element: BehaviorTreeElement) -> Result<(), BehaviorError> {
+		if index > self.0.len() {
+			let txt = format!("behavior tries to insert a child at a non-existent index [{index}]");
+			return Err(BehaviorError::Composition { txt: txt.into() });
+		}
+		self.check_not_running()?;
+		self.0.insert(index, element);
+		Ok(())
+	}
+
+	/// Removes and returns the child at `index`, shifting all children after it.
+	/// # Errors
+	/// - if index is out of bounds
+	/// - if a child is running, as a control keeps the index of its running child
+	pub fn remove(&mut self, index: usize) -> Result<BehaviorTreeElement, BehaviorError> {
+		if index >= self.0.len() {
+			let txt = format!("behavior tries to remove a non-existent child at index [{index}]");
+			return Err(BehaviorError::Composition { txt: txt.into() });
+		}
+		self.check_not_running()?;
+		Ok(self.0.remove(index))
+	}
+
+	/// Checks that no child is running.
+	fn check_not_running(&self) -> Result<(), BehaviorError> {
+		if let Some(index) = self
+			.0
+			.iter()
+			.position(|child| child.state() == BehaviorState::Running)
+		{
+			let txt = format!("behavior tries to modify its children while the child at index [{index}] is running");
+			return Err(BehaviorError::Composition { txt: txt.into() });
+		}
+		Ok(())
+	}
+
 	/// Reset all children
 	/// # Errors
 	/// - if a child errors on `halt()`
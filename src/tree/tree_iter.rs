@@ -16,6 +16,7 @@ use super::tree_element::BehaviorTreeElement;
 
 // region:		--- TreeIter
 /// Iterator over the [`BehaviorTree`](crate::tree::tree::BehaviorTree).
+/// Yields the elements depth-first in pre-order, siblings from left to right.
 pub struct TreeIter<'a> {
 	/// stack to do a depth first search
 	stack: Vec<&'a BehaviorTreeElement>,
@@ -52,6 +53,7 @@ impl<'a> Iterator for TreeIter<'a> {
 
 // region:		--- TreeIterMut
 /// Mutable iterator over the [`BehaviorTree`](crate::tree::tree::BehaviorTree).
+/// Yields the elements depth-first in pre-order, siblings from left to right.
 pub struct TreeIterMut<'a> {
 	/// stack to do a depth first search
 	stack: Vec<*mut BehaviorTreeElement>,
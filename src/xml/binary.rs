@@ -0,0 +1,371 @@
+// Copyright © 2025 Stephan Kunz
+
+//! Binary encoding of XML behavior tree definitions.
+//!
+//! The encoding keeps the XML structure, tags and attributes are stored as strings,
+//! so the elements are resolved at tree creation like the parsed XML.
+//! It only saves the XML parsing at runtime.
+//!
+//! The format is a flat byte sequence, all numbers are little endian:
+//! - header: magic `BTCB`, version `u8`
+//! - the id of the main tree as string, empty if not given
+//! - number of tree definitions as `u16`, followed by the `<BehaviorTree>` elements
+//!
+//! An element consists of
+//! - the tag as string
+//! - number of attributes as `u8`, followed by key and value string of each attribute
+//! - number of children as `u16`, followed by the child elements
+//!
+//! A string is stored as its length in bytes as `u16` followed by the UTF-8 bytes.
+
+// region:      --- modules
+use alloc::{borrow::Cow, vec::Vec};
+use roxmltree::{Document, Node, NodeType};
+
+use crate::{
+	BEHAVIORTREE, ConstString, ID,
+	factory::BehaviorRegistry,
+	xml::{
+		ParserLimits,
+		error::Error,
+		parser::{ChildFn, DefinitionSource, SourceElement, SourceNode},
+	},
+};
+// endregion:   --- modules
+
+// region:		--- globals
+/// Magic bytes at the start of an encoded document
+const MAGIC: &[u8; 4] = b"BTCB";
+/// Version of the format
+const VERSION: u8 = 1;
+// endregion:	--- globals
+
+// region:      --- BinaryXmlEncoder
+/// Encodes XML behavior tree definitions into a compact binary format.
+///
+/// The result can be loaded with [`BehaviorTreeFactory::create_from_binary_xml`](crate::factory::BehaviorTreeFactory::create_from_binary_xml)
+/// without parsing XML at runtime.
+///
+/// The encoding is intended to be done at build time, e.g. in a `build.rs`:
+///
+/// ```no-test
+/// let xml = std::fs::read_to_string("tree.xml")?;
+/// let encoded = BinaryXmlEncoder::encode(&xml)?;
+/// std::fs::write(std::path::Path::new(&std::env::var("OUT_DIR")?).join("tree.bin"), encoded)?;
+/// ```
+///
+/// and then embedded into the binary:
+///
+/// ```no-test
+/// static ENCODED: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/tree.bin"));
+/// let tree = factory.create_from_binary_xml(ENCODED)?;
+/// ```
+///
+/// Only `<BehaviorTree>` definitions are encoded, comments are dropped.
+/// `<TreeNodesModel>` and `<include>` are not supported.
+pub struct BinaryXmlEncoder;
+
+impl BinaryXmlEncoder {
+	/// Encodes the behavior tree definitions of the XML document.
+	/// # Errors
+	/// - if the XML document is invalid.
+	/// - if the XML document contains unsupported elements.
+	/// - if a string, the number of attributes or children exceeds the format.
+	pub fn encode(xml: &str) -> Result<Vec<u8>, Error> {
+		let doc = Document::parse(xml)?;
+		let root = doc.root_element();
+		if root.tag_name().name() != "root" {
+			return Err(Error::WrongRootName);
+		}
+		if let Some(format) = root.attribute("BTCPP_format")
+			&& format != "4"
+		{
+			return Err(Error::BtCppFormat);
+		}
+
+		let mut trees = Vec::new();
+		for element in root.children() {
+			match element.node_type() {
+				NodeType::Element => {
+					if element.tag_name().name() == BEHAVIORTREE {
+						trees.push(element);
+					} else {
+						return Err(Error::UnsupportedElement {
+							tag: element.tag_name().name().into(),
+						});
+					}
+				}
+				NodeType::PI | NodeType::Root => {
+					return Err(Error::UnsupportedElement {
+						tag: element.tag_name().name().into(),
+					});
+				}
+				NodeType::Comment | NodeType::Text => {}
+			}
+		}
+
+		let mut bytes = Vec::with_capacity(xml.len() / 2);
+		bytes.extend_from_slice(MAGIC);
+		bytes.push(VERSION);
+		write_str(
+			&mut bytes,
+			root.attribute("main_tree_to_execute")
+				.unwrap_or_default(),
+		)?;
+		write_count(&mut bytes, trees.len())?;
+		for tree in &trees {
+			write_element(&mut bytes, tree)?;
+		}
+		Ok(bytes)
+	}
+}
+
+/// Appends a string.
+fn write_str(bytes: &mut Vec<u8>, value: &str) -> Result<(), Error> {
+	write_count(bytes, value.len())?;
+	bytes.extend_from_slice(value.as_bytes());
+	Ok(())
+}
+
+/// Appends a `u16` count.
+fn write_count(bytes: &mut Vec<u8>, count: usize) -> Result<(), Error> {
+	let Ok(count) = u16::try_from(count) else {
+		return Err(Error::BinaryXml {
+			reason: "a string or list exceeds 65535 entries".into(),
+		});
+	};
+	bytes.extend_from_slice(&count.to_le_bytes());
+	Ok(())
+}
+
+/// Appends an element with its attributes and child elements.
+fn write_element(bytes: &mut Vec<u8>, element: &Node) -> Result<(), Error> {
+	write_str(bytes, element.tag_name().name())?;
+	let Ok(attributes) = u8::try_from(element.attributes().len()) else {
+		return Err(Error::TooManyAttributes {
+			tag: element.tag_name().name().into(),
+			limit: u8::MAX.into(),
+		});
+	};
+	bytes.push(attributes);
+	for attribute in element.attributes() {
+		write_str(bytes, attribute.name())?;
+		write_str(bytes, attribute.value())?;
+	}
+
+	let mut children = Vec::new();
+	for child in element.children() {
+		match child.node_type() {
+			NodeType::Element => children.push(child),
+			NodeType::PI | NodeType::Root => {
+				return Err(Error::UnsupportedElement {
+					tag: child.tag_name().name().into(),
+				});
+			}
+			NodeType::Comment | NodeType::Text => {}
+		}
+	}
+	write_count(bytes, children.len())?;
+	for child in &children {
+		write_element(bytes, child)?;
+	}
+	Ok(())
+}
+// endregion:   --- BinaryXmlEncoder
+
+// region:      --- BinaryXmlDocument
+/// An element of an encoded document.
+struct BinaryXmlElement<'a> {
+	/// Tag and attributes
+	element: SourceElement<'a>,
+	/// The child elements
+	children: Vec<BinaryXmlElement<'a>>,
+}
+
+impl SourceNode for BinaryXmlElement<'_> {
+	fn element(&self) -> Cow<'_, SourceElement<'_>> {
+		Cow::Borrowed(&self.element)
+	}
+
+	fn has_children(&self) -> bool {
+		!self.children.is_empty()
+	}
+
+	fn for_each_child(&self, f: &mut ChildFn<'_>) -> Result<(), Error> {
+		for child in &self.children {
+			f(child, None)?;
+		}
+		Ok(())
+	}
+}
+
+impl Drop for BinaryXmlElement<'_> {
+	fn drop(&mut self) {
+		// the descendants are collected, so dropping a deeply nested element does not recurse
+		let mut descendants = core::mem::take(&mut self.children);
+		while let Some(mut element) = descendants.pop() {
+			descendants.append(&mut element.children);
+		}
+	}
+}
+
+/// A decoded binary XML document, borrowing its strings from the encoded bytes.
+pub struct BinaryXmlDocument<'a> {
+	/// Id of the main tree, may be empty
+	main_tree_id: &'a str,
+	/// The `<BehaviorTree>` definitions
+	definitions: Vec<BinaryXmlElement<'a>>,
+}
+
+impl<'a> BinaryXmlDocument<'a> {
+	/// Decodes the encoded `bytes` respecting the nesting depth and attribute limits.
+	/// # Errors
+	/// - if the bytes are not a valid binary XML document.
+	/// - if an element exceeds the limits.
+	pub fn decode(bytes: &'a [u8], limits: &ParserLimits) -> Result<Self, Error> {
+		let mut reader = Reader { bytes, pos: 0 };
+		if reader.take(MAGIC.len())? != MAGIC {
+			return Err(invalid("missing header"));
+		}
+		if reader.u8()? != VERSION {
+			return Err(invalid("unsupported version"));
+		}
+		let main_tree_id = reader.str()?;
+		let count = reader.u16()?;
+		let mut definitions = Vec::with_capacity(count.into());
+		for _ in 0..count {
+			definitions.push(reader.elements(limits)?);
+		}
+		if reader.pos != bytes.len() {
+			return Err(invalid("trailing bytes"));
+		}
+		Ok(Self {
+			main_tree_id,
+			definitions,
+		})
+	}
+
+	/// Returns the id of the main tree, if one is given.
+	#[must_use]
+	pub const fn main_tree_id(&self) -> Option<&'a str> {
+		if self.main_tree_id.is_empty() {
+			None
+		} else {
+			Some(self.main_tree_id)
+		}
+	}
+
+	/// Returns the `<BehaviorTree>` definition with the `id`.
+	fn definition(&self, id: &str) -> Option<&BinaryXmlElement<'a>> {
+		self.definitions
+			.iter()
+			.find(|definition| definition.element.attribute(ID) == Some(id))
+	}
+}
+
+impl DefinitionSource for BinaryXmlDocument<'_> {
+	fn with_definition<R>(
+		&self,
+		_registry: &BehaviorRegistry,
+		id: &str,
+		build: impl FnOnce(&dyn SourceNode) -> Result<R, Error>,
+	) -> Option<Result<R, Error>> {
+		self.definition(id)
+			.map(|definition| build(definition))
+	}
+}
+
+/// Creates the error for an invalid binary XML document.
+fn invalid(reason: &str) -> Error {
+	Error::BinaryXml {
+		reason: ConstString::from(reason),
+	}
+}
+
+/// Sequential reader over the encoded bytes.
+struct Reader<'a> {
+	bytes: &'a [u8],
+	pos: usize,
+}
+
+impl<'a> Reader<'a> {
+	fn take(&mut self, len: usize) -> Result<&'a [u8], Error> {
+		let end = self.pos.saturating_add(len);
+		let Some(slice) = self.bytes.get(self.pos..end) else {
+			return Err(invalid("unexpected end of data"));
+		};
+		self.pos = end;
+		Ok(slice)
+	}
+
+	fn u8(&mut self) -> Result<u8, Error> {
+		Ok(self.take(1)?[0])
+	}
+
+	fn u16(&mut self) -> Result<u16, Error> {
+		let bytes = self.take(2)?;
+		Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+	}
+
+	fn str(&mut self) -> Result<&'a str, Error> {
+		let len = self.u16()?;
+		core::str::from_utf8(self.take(len.into())?).map_err(|_| invalid("string is not valid UTF-8"))
+	}
+
+	/// Reads an element with all its descendants.
+	///
+	/// The nesting is tracked with an explicit stack instead of recursion,
+	/// so the stack consumption does not depend on the nesting depth, whatever the limits are.
+	fn elements(&mut self, limits: &ParserLimits) -> Result<BinaryXmlElement<'a>, Error> {
+		// the open elements with the number of children still to read
+		let mut open: Vec<(BinaryXmlElement<'a>, u16)> = Vec::new();
+		loop {
+			let element = self.element(open.len() + 1, limits)?;
+			let count = self.u16()?;
+			open.push((
+				BinaryXmlElement {
+					element,
+					children: Vec::with_capacity(count.into()),
+				},
+				count,
+			));
+			// close the completed elements
+			while let Some((_, 0)) = open.last() {
+				let Some((element, _)) = open.pop() else {
+					break;
+				};
+				match open.last_mut() {
+					Some((parent, remaining)) => {
+						parent.children.push(element);
+						*remaining -= 1;
+					}
+					None => return Ok(element),
+				}
+			}
+		}
+	}
+
+	/// Reads tag and attributes of an element at `depth`.
+	fn element(&mut self, depth: usize, limits: &ParserLimits) -> Result<SourceElement<'a>, Error> {
+		let tag = self.str()?;
+		if depth > limits.max_depth {
+			return Err(Error::NestingTooDeep {
+				tag: tag.into(),
+				limit: limits.max_depth,
+			});
+		}
+		let count = usize::from(self.u8()?);
+		if count > limits.max_attributes {
+			return Err(Error::TooManyAttributes {
+				tag: tag.into(),
+				limit: limits.max_attributes,
+			});
+		}
+		let mut attributes = Vec::with_capacity(count);
+		for _ in 0..count {
+			attributes.push((self.str()?, self.str()?));
+		}
+		Ok(SourceElement { tag, attributes })
+	}
+}
+// endregion:   --- BinaryXmlDocument
@@ -68,6 +68,8 @@ impl XmlCreator {
 	}
 
 	/// Create XML from tree including `TreeNodesModel`.
+	///
+	/// Only the port values given as attributes are written.
 	/// # Errors
 	/// - if it cannot create an xml entry
 	pub fn write_tree(
@@ -75,6 +77,32 @@ impl XmlCreator {
 		metadata: bool,
 		builtin_models: bool,
 		pretty: bool,
+	) -> Result<ConstString, woxml::Error> {
+		Self::write_tree_impl(tree, metadata, builtin_models, false, pretty)
+	}
+
+	/// Create XML with the effective configuration from tree including `TreeNodesModel`.
+	///
+	/// The effective configuration includes the port values taken from the default values
+	/// of the port definitions and from a `TreeNodesModel`.
+	/// # Errors
+	/// - if it cannot create an xml entry
+	pub fn write_effective_tree(
+		tree: &BehaviorTree,
+		metadata: bool,
+		builtin_models: bool,
+		pretty: bool,
+	) -> Result<ConstString, woxml::Error> {
+		Self::write_tree_impl(tree, metadata, builtin_models, true, pretty)
+	}
+
+	#[allow(clippy::fn_params_excessive_bools)]
+	fn write_tree_impl(
+		tree: &BehaviorTree,
+		metadata: bool,
+		builtin_models: bool,
+		include_defaults: bool,
+		pretty: bool,
 	) -> Result<ConstString, woxml::Error> {
 		let mut writer = if pretty {
 			XmlWriter::pretty_mode(Vec::new())
@@ -89,7 +117,7 @@ impl XmlCreator {
 		// ensure lifetimes
 		{
 			// create the BehaviorTree's
-			Self::create_behavior_trees(&mut writer, &subtrees, metadata)?;
+			Self::create_behavior_trees(&mut writer, &subtrees, metadata, include_defaults, false)?;
 
 			// create the TreeNodesModel
 			Self::create_tree_nodes_model(&mut writer, &behaviors, builtin_models, pretty, false)?;
@@ -104,15 +132,19 @@ impl XmlCreator {
 		writer: &mut XmlWriter<'a, impl Write>,
 		subtrees: &'a Vec<&BehaviorTreeElement>,
 		metadata: bool,
+		include_defaults: bool,
+		state: bool,
 	) -> Result<(), woxml::Error> {
 		for subtree in subtrees {
 			writer.begin_elem("BehaviorTree")?;
 			writer.attr(ID, subtree.name())?;
-			writer.attr("_fullpath", subtree.groot2_path())?;
+			if metadata {
+				writer.attr("_fullpath", subtree.groot2_path())?;
+			}
 
 			// recursive dive into children
 			for element in subtree.children().iter() {
-				Self::write_subtree(element, writer, metadata)?;
+				Self::write_subtree(element, writer, metadata, include_defaults, state)?;
 			}
 			writer.end_elem()?; // BehaviorTree
 		}
@@ -124,45 +156,60 @@ impl XmlCreator {
 		element: &'a BehaviorTreeElement,
 		writer: &mut XmlWriter<'a, impl Write>,
 		metadata: bool,
+		include_defaults: bool,
+		state: bool,
 	) -> Result<(), woxml::Error> {
+		// preserved comment
+		if let Some(comment) = element.data().description().metadata() {
+			writer.comment(comment)?;
+		}
+
+		// The attributes are written in a canonical order to get stable output:
+		// `ID`, `name`, the ports in alphabetical order, then the `_` attributes in alphabetical order.
+		let mut ports: Vec<(ConstString, ConstString)> = Vec::new();
+		let mut specials: Vec<(ConstString, ConstString)> = Vec::new();
+
 		let is_subtree = match element.kind() {
 			TreeElementKind::Leaf | TreeElementKind::Node => {
 				writer.begin_elem(element.id())?;
 				writer.attr(NAME, element.name())?;
+				// behavior port mappings/values, global `@` ports can not be written as attribute
+				let defaulted = element.data().description().defaulted_ports();
+				for remapping in element.remappings().iter() {
+					if remapping.0.starts_with('@') || (!include_defaults && defaulted.contains(&remapping.0)) {
+						continue;
+					}
+					ports.push((remapping.0.clone(), remapping.1.clone()));
+				}
 				false
 			}
 			TreeElementKind::SubTree => {
 				writer.begin_elem(SUBTREE)?;
 				writer.attr(ID, element.name())?;
 				if metadata {
-					writer.attr("_fullpath", element.groot2_path())?;
+					specials.push(("_fullpath".into(), element.groot2_path().clone()));
+				}
+				// subtree port mappings/values are in blackboard
+				if let Some(remappings) = element.blackboard().remappings() {
+					for remapping in remappings.iter() {
+						ports.push((remapping.0.clone(), remapping.1.clone()));
+					}
 				}
 				true
 			}
 		};
 		if metadata {
-			writer.attr("_uid", &element.uid().to_string())?;
+			specials.push(("_uid".into(), element.uid().to_string().into()));
 		}
-
-		if is_subtree {
-			// subtree port mappings/values are in blackboard
-			if let Some(remappings) = element.blackboard().remappings() {
-				for remapping in remappings.iter() {
-					writer.attr(&remapping.0, &remapping.1)?;
-				}
-			}
-		} else {
-			// behavior port mappings/values
-			for remapping in element.remappings().iter() {
-				writer.attr(&remapping.0, &remapping.1)?;
-			}
+		if state {
+			specials.push(("_state".into(), element.state().as_str().into()));
 		}
 
 		// Pre-conditions
 		if let Some(conditions) = &element.pre_conditions().0 {
 			for i in 0..PRE_CONDITIONS.len() {
 				if let Some(cond) = &conditions[i] {
-					writer.attr(PRE_CONDITIONS[i], cond)?;
+					specials.push((PRE_CONDITIONS[i].into(), cond.clone()));
 				}
 			}
 		}
@@ -171,15 +218,21 @@ impl XmlCreator {
 		if let Some(conditions) = &element.post_conditions().0 {
 			for i in 0..POST_CONDITIONS.len() {
 				if let Some(cond) = &conditions[i] {
-					writer.attr(POST_CONDITIONS[i], cond)?;
+					specials.push((POST_CONDITIONS[i].into(), cond.clone()));
 				}
 			}
 		}
 
+		ports.sort_unstable();
+		specials.sort_unstable();
+		for (key, value) in ports.into_iter().chain(specials) {
+			writer.attr(&key, &value)?;
+		}
+
 		if !is_subtree {
 			// recursive dive into children, ignoring subtrees
 			for element in element.children().iter() {
-				Self::write_subtree(element, writer, metadata)?;
+				Self::write_subtree(element, writer, metadata, include_defaults, state)?;
 			}
 		}
 
@@ -266,7 +319,7 @@ impl XmlCreator {
 		// ensure lifetimes
 		{
 			// create the BehaviorTree's
-			Self::create_behavior_trees(&mut writer, &subtrees, true)?;
+			Self::create_behavior_trees(&mut writer, &subtrees, true, true, false)?;
 
 			// create the TreeNodesModel
 			Self::create_tree_nodes_model(&mut writer, &behaviors, false, false, true)?;
@@ -277,6 +330,33 @@ impl XmlCreator {
 		Ok(String::try_from(writer)?.into())
 	}
 
+	/// Create XML from tree including `TreeNodesModel` with the current [`BehaviorState`](crate::behavior::BehaviorState)
+	/// of each element in attribute `_state`, e.g. as a human readable snapshot of a running tree for debugging.
+	///
+	/// The XML contains the same metadata as [`XmlCreator::groot_write_tree`], i.e. `_fullpath` and `_uid`.
+	/// # Errors
+	/// - if it cannot create an xml entry
+	pub fn write_tree_with_state(tree: &BehaviorTree) -> Result<ConstString, woxml::Error> {
+		let mut writer = XmlWriter::pretty_mode(Vec::new());
+
+		writer.begin_elem("root")?;
+		writer.attr("BTCPP_format", "4")?;
+		// scan the tree
+		let (behaviors, subtrees) = Self::scan_tree(tree, false);
+		// ensure lifetimes
+		{
+			// create the BehaviorTree's
+			Self::create_behavior_trees(&mut writer, &subtrees, true, true, true)?;
+
+			// create the TreeNodesModel
+			Self::create_tree_nodes_model(&mut writer, &behaviors, false, true, false)?;
+		}
+		writer.end_elem()?; // root
+		writer.flush()?;
+
+		Ok(String::try_from(writer)?.into())
+	}
+
 	// @TODO: things like: SharedQueue<T: FromStr + ToString>(pub Arc<Mutex<VecDeque<T>>>);
 	fn groot_map_types(input: &str) -> &str {
 		match input {
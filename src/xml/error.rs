@@ -11,6 +11,18 @@ use crate::ConstString;
 /// `xml` error type
 #[non_exhaustive]
 pub enum Error {
+	/// A port of an autoremapped subtree reads a key, which is not available in the parent scope
+	AutoremapEntryMissing {
+		/// Path of the element
+		path: ConstString,
+		/// The missing blackboard key
+		key: ConstString,
+	},
+	/// An invalid binary XML document
+	BinaryXml {
+		/// What is wrong
+		reason: ConstString,
+	},
 	/// `BtCPP format` is not supported
 	BtCppFormat,
 	/// The behavior does not allow children
@@ -18,6 +30,13 @@ pub enum Error {
 		/// The affected behavior
 		behavior: ConstString,
 	},
+	/// A behavior rejected its configuration, when it was created
+	Creation {
+		/// The affected behavior
+		behavior: ConstString,
+		/// original error
+		source: crate::BehaviorError,
+	},
 	/// A wrong pre- or post-condition
 	Condition {
 		/// The affected condition
@@ -32,11 +51,24 @@ pub enum Error {
 		/// original error
 		source: databoard::Error,
 	},
-	// A behavior definition cannot be found
+	/// A behavior definition cannot be found
 	DefinitionNotFound {
 		/// Id of the wanted behavior
 		id: ConstString,
 	},
+	/// The document exceeds the configured maximum length
+	DocumentTooLarge {
+		/// The length of the document
+		length: usize,
+		/// The configured limit
+		limit: usize,
+	},
+	/// An environment variable used in an attribute value is not defined
+	#[cfg(feature = "std")]
+	EnvVarNotDefined {
+		/// The name of the variable
+		name: ConstString,
+	},
 	// @TODO: This breaks the control flow!!
 	// Factory -> XmlParser -> Factory
 	// maybe I should mov registry into own module.
@@ -65,6 +97,20 @@ pub enum Error {
 		/// The invalid name
 		key: ConstString,
 	},
+	/// The element nesting exceeds the configured maximum depth
+	NestingTooDeep {
+		/// The affected tag
+		tag: ConstString,
+		/// The configured limit
+		limit: usize,
+	},
+	/// A boolean control has a child, which may return Running
+	NonBooleanChild {
+		/// The affected behavior
+		behavior: ConstString,
+		/// The rejected child
+		child: ConstString,
+	},
 	/// Behavior is not registered
 	NotRegistered {
 		/// The affected behavior
@@ -89,9 +135,12 @@ pub enum Error {
 	},
 	/// Invalid port type
 	PortType {
+		/// The affected behavior
+		behavior: ConstString,
 		/// The `PortType` literal that is not known
 		value: ConstString,
 	},
+	/// A file cannot be read
 	#[cfg(feature = "std")]
 	ReadFile {
 		/// Filename
@@ -99,6 +148,27 @@ pub enum Error {
 		/// Stringified original error
 		cause: ConstString,
 	},
+	/// A script attribute is given, but scripting is disabled
+	ScriptingDisabled {
+		/// The attributes name
+		key: ConstString,
+	},
+	/// An element has more attributes than the configured maximum
+	TooManyAttributes {
+		/// The affected tag
+		tag: ConstString,
+		/// The configured limit
+		limit: usize,
+	},
+	/// A tree has more elements than uids are available
+	TooManyElements,
+	/// The created tree including its subtrees exceeds the configured maximum depth
+	TreeTooDeep {
+		/// Path of the element beyond the limit
+		path: ConstString,
+		/// The configured limit
+		limit: usize,
+	},
 	/// Unknown attribute
 	UnknownAttribute {
 		/// The attributes name
@@ -119,7 +189,7 @@ pub enum Error {
 impl core::error::Error for Error {
 	fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
 		match self {
-			Self::Condition { key: _, source } => Some(source),
+			Self::Condition { key: _, source } | Self::Creation { behavior: _, source } => Some(source),
 			Self::Databoard { key: _, source } => Some(source),
 			#[cfg(feature = "std")]
 			Self::Parser { source } => Some(source),
@@ -137,26 +207,38 @@ impl core::error::Error for Error {
 impl core::fmt::Debug for Error {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		match self {
+			Self::AutoremapEntryMissing { path, key } => write!(f, "AutoremapEntryMissing(path: {path}, key: {key})"),
+			Self::BinaryXml { reason } => write!(f, "BinaryXml(reason: {reason})"),
 			Self::BtCppFormat => write!(f, "BtCppFormat"),
 			Self::ChildrenNotAllowed { behavior } => write!(f, "ChildrenNotAllowed(behavior: {behavior})"),
+			Self::Creation { behavior, source } => write!(f, "Creation(behavior: {behavior}, err: {source})"),
 			Self::Condition { key, source } => write!(f, "Condition(key: {key}, err: {source})"),
 			Self::Databoard { key, source } => write!(f, "Databoard(key: {key}, err: {source})"),
 			Self::DefinitionNotFound { id } => write!(f, "DefinitonNotFound(id: {id})"),
+			Self::DocumentTooLarge { length, limit } => write!(f, "DocumentTooLarge(length: {length}, limit: {limit})"),
+			#[cfg(feature = "std")]
+			Self::EnvVarNotDefined { name } => write!(f, "EnvVarNotDefined(name: {name})"),
 			Self::Factory { behavior, source } => write!(f, "Factory(key: {behavior}, err: {source})"),
 			Self::InvalidRootElement => write!(f, "InvalidRootElement"),
 			Self::MissingId { tag } => write!(f, "MissingId(tag: {tag})"),
 			#[cfg(feature = "std")]
 			Self::MissingPath { tag } => write!(f, "MissingPath(tag: {tag})"),
 			Self::NameNotAllowed { key } => write!(f, "NameNotAllowed(key: {key})"),
+			Self::NestingTooDeep { tag, limit } => write!(f, "NestingTooDeep(tag: {tag}, limit: {limit})"),
+			Self::NonBooleanChild { behavior, child } => write!(f, "NonBooleanChild(behavior: {behavior}, child: {child})"),
 			Self::NotRegistered { behavior } => write!(f, "NotRegistered(behavior: {behavior})"),
 			Self::OneChild { behavior } => write!(f, "OneChild(behavior: {behavior})"),
 			Self::Parser { source } => write!(f, "Parser({source})"),
 			Self::PortInvalid { port, behavior } => {
 				write!(f, "PortInvalid(port: {port}, behavior: {behavior})")
 			}
-			Self::PortType { value } => write!(f, "PortType(value: {value})"),
+			Self::PortType { behavior, value } => write!(f, "PortType(behavior: {behavior}, value: {value})"),
 			#[cfg(feature = "std")]
 			Self::ReadFile { name, cause } => write!(f, "ReadFile(name: {name}, cause: {cause}"),
+			Self::ScriptingDisabled { key } => write!(f, "ScriptingDisabled(key: {key})"),
+			Self::TooManyAttributes { tag, limit } => write!(f, "TooManyAttributes(tag: {tag}, limit: {limit})"),
+			Self::TooManyElements => write!(f, "TooManyElements"),
+			Self::TreeTooDeep { path, limit } => write!(f, "TreeTooDeep(path: {path}, limit: {limit})"),
 			Self::UnknownAttribute { key } => write!(f, "UnknownAttribute(key: {key})"),
 			Self::UnsupportedElement { tag } => write!(f, "UnsupportedElement(tag: {tag})"),
 			Self::WrongAutoremap => write!(f, "WrongAutoremap"),
@@ -168,11 +250,24 @@ impl core::fmt::Debug for Error {
 impl core::fmt::Display for Error {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		match self {
+			Self::AutoremapEntryMissing { path, key } => write!(
+				f,
+				"the key {key} read by {path} is neither remapped nor available in the parent of the autoremapped subtree"
+			),
+			Self::BinaryXml { reason } => write!(f, "the binary XML document is invalid: {reason}"),
 			Self::BtCppFormat => write!(f, "the attribute 'BTCPP_format' must have the value '4'"),
 			Self::ChildrenNotAllowed { behavior } => write!(f, "the leaf behavior {behavior} may not have any children"),
+			Self::Creation { behavior, source } => write!(f, "creating the behavior {behavior} failed with: {source}"),
 			Self::Condition { key, source } => write!(f, "the pre-/post-condition key: {key} is erronous: {source}"),
 			Self::Databoard { key, source } => write!(f, "the databoard key {key} caused the error {source}"),
 			Self::DefinitionNotFound { id } => write!(f, "the behavior definition for the id: {id} could not be found"),
+			Self::DocumentTooLarge { length, limit } => {
+				write!(f, "the document length of {length} bytes exceeds the limit of {limit} bytes")
+			}
+			#[cfg(feature = "std")]
+			Self::EnvVarNotDefined { name } => {
+				write!(f, "the environment variable {name} is not defined and has no default")
+			}
 			Self::Factory { behavior, source } => write!(f, "registering the behavior {behavior} caused the error {source}"),
 			Self::InvalidRootElement => write!(f, "a root element is invalid as child"),
 			Self::MissingId { tag } => write!(f, "the tag {tag} is missing an 'ID' attribute"),
@@ -180,14 +275,33 @@ impl core::fmt::Display for Error {
 			#[cfg(feature = "std")]
 			Self::MissingPath { tag } => write!(f, "the tag {tag}) is missing a 'path' attribute"),
 			Self::NameNotAllowed { key } => write!(f, "the name for the key {key} is not allowed"),
+			Self::NestingTooDeep { tag, limit } => write!(f, "the element {tag} exceeds the nesting depth limit of {limit}"),
+			Self::NonBooleanChild { behavior, child } => write!(
+				f,
+				"the boolean behavior {behavior} only accepts conditions, but its child {child} may return Running"
+			),
 			Self::NotRegistered { behavior } => write!(f, "the behavior {behavior} is not registered"),
 			Self::Parser { source } => write!(f, "parsing xml failed with: {source}"),
 			Self::PortInvalid { port, behavior } => {
 				write!(f, "the port {port} is not in {behavior}'s  portlist")
 			}
-			Self::PortType { value } => write!(f, "the value {value} is not valid as PortType"),
+			Self::PortType { behavior, value } => write!(
+				f,
+				"the element {value} in the model of {behavior} is not a port, expected one of input_port, output_port or inout_port"
+			),
 			#[cfg(feature = "std")]
 			Self::ReadFile { name, cause } => write!(f, "file {name} could not be read: {cause}"),
+			Self::ScriptingDisabled { key } => {
+				write!(
+					f,
+					"the attribute {key} needs a script, but the feature 'scripting' is disabled"
+				)
+			}
+			Self::TooManyAttributes { tag, limit } => {
+				write!(f, "the element {tag} exceeds the limit of {limit} attributes")
+			}
+			Self::TooManyElements => write!(f, "a tree cannot have more than 65535 elements"),
+			Self::TreeTooDeep { path, limit } => write!(f, "the element {path} exceeds the tree depth limit of {limit}"),
 			Self::UnknownAttribute { key } => write!(f, "the attribute with key {key} is unknown"),
 			Self::UnsupportedElement { tag } => write!(f, "the element {tag} is not supported"),
 			Self::WrongAutoremap => write!(f, "the value for autoremap must be a boolean 'true' or 'false'"),
@@ -0,0 +1,48 @@
+// Copyright © 2025 Stephan Kunz
+
+//! [`ParserLimits`] for the XML parser.
+
+// region:      --- ParserLimits
+/// Limits enforced by the XML parser when registering behavior tree definitions.
+///
+/// Useful when parsing untrusted input, e.g. from fuzzing or over the network.
+/// The default configuration does not restrict anything.
+///
+/// Example:
+///
+/// ```no-test
+/// let mut factory = BehaviorTreeFactory::new()?;
+/// factory.set_parser_limits(ParserLimits::new(64 * 1024, 32, 16));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParserLimits {
+	/// Maximum length of an XML document in bytes.
+	pub max_document_length: usize,
+	/// Maximum nesting depth of elements, the root element has depth 1.
+	pub max_depth: usize,
+	/// Maximum number of attributes per element.
+	pub max_attributes: usize,
+}
+
+impl Default for ParserLimits {
+	fn default() -> Self {
+		Self {
+			max_document_length: usize::MAX,
+			max_depth: usize::MAX,
+			max_attributes: usize::MAX,
+		}
+	}
+}
+
+impl ParserLimits {
+	/// Create a set of limits.
+	#[must_use]
+	pub const fn new(max_document_length: usize, max_depth: usize, max_attributes: usize) -> Self {
+		Self {
+			max_document_length,
+			max_depth,
+			max_attributes,
+		}
+	}
+}
+// endregion:   --- ParserLimits
@@ -2,13 +2,18 @@
 
 //! [`behaviortree`](crate) xml module.
 
+pub mod binary;
 pub mod creator;
 pub mod error;
+mod limits;
 pub mod parser;
 
+// flatten
+pub use limits::ParserLimits;
+
 #[cfg(test)]
 mod tests {
-	use crate::{XmlCreator, xml::parser::XmlParser};
+	use crate::{BinaryXmlEncoder, XmlCreator, xml::ParserLimits, xml::parser::XmlParser};
 
 	// check, that the auto traits are available
 	const fn is_normal<T: Sized + Send + Sync>() {}
@@ -17,6 +22,8 @@ mod tests {
 	const fn normal_types() {
 		is_normal::<crate::xml::error::Error>();
 		is_normal::<XmlParser>();
+		is_normal::<BinaryXmlEncoder>();
 		is_normal::<XmlCreator>();
+		is_normal::<ParserLimits>();
 	}
 }
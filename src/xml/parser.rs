@@ -6,18 +6,30 @@
 #[cfg(feature = "std")]
 extern crate std;
 
+use alloc::borrow::Cow;
 use alloc::{
 	boxed::Box,
+	collections::{btree_map::BTreeMap, btree_set::BTreeSet},
 	string::{String, ToString},
+	sync::Arc,
+	vec,
+	vec::Vec,
 };
 // region:      --- modules
 use crate::{
-	ACTION, BEHAVIORTREE, CONDITION, CONTROL, ConstString, DECORATOR, DEFAULT, EMPTY_STR, ID, NAME, SUBTREE, TREENODESMODEL,
-	behavior::{BehaviorDataCollection, BehaviorKind, BehaviorPtr, pre_post_conditions::Conditions},
-	factory::registry::{BehaviorRegistry, TreeNodesModelEntry},
+	ACTION, BEHAVIORTREE, CONDITION, CONTROL, ConstString, DECORATOR, DEFAULT, EMPTY_STR, ID, Mutex, NAME, SUBTREE,
+	SharedRuntime, TREENODESMODEL,
+	behavior::{
+		BehaviorDataCollection, BehaviorKind, BehaviorPtr, behavior_description::BehaviorDescription,
+		pre_post_conditions::Conditions,
+	},
+	factory::{
+		TreeOptions,
+		registry::{BehaviorRegistry, TreeNodesModelEntry},
+	},
 	port::{PortDirection, is_allowed_port_name},
-	tree::{BehaviorTreeElement, BehaviorTreeElementList},
-	xml::error::Error,
+	tree::{BehaviorTreeElement, BehaviorTreeElementList, check_autoremap},
+	xml::{ParserLimits, binary::BinaryXmlDocument, error::Error},
 };
 use databoard::{Databoard, Remappings, strip_board_pointer};
 use roxmltree::{Document, Node, NodeType};
@@ -26,16 +38,188 @@ use std::path::PathBuf;
 // endregion:   --- modules
 
 // region:		--- helper
-fn create_data_collection_from_xml<'a>(
+/// Expands `${VAR}` and `${VAR:-default}` in an attribute value with values from the environment.
+/// An unterminated `${` is kept literally.
+/// Special attributes starting with `_` are not expanded, so scripts are used as written.
+/// Without feature `std` attribute values are used as written.
+/// # Errors
+/// - if a variable is not defined and has no default.
+#[cfg(feature = "std")]
+fn expand_env(value: &str) -> Result<Cow<'_, str>, Error> {
+	if !value.contains("${") {
+		return Ok(Cow::Borrowed(value));
+	}
+	let mut result = String::with_capacity(value.len());
+	let mut rest = value;
+	while let Some(start) = rest.find("${") {
+		let after = &rest[start + 2..];
+		let Some(end) = after.find('}') else {
+			break;
+		};
+		result.push_str(&rest[..start]);
+		let expression = &after[..end];
+		let (name, default) = match expression.split_once(":-") {
+			Some((name, default)) => (name, Some(default)),
+			None => (expression, None),
+		};
+		match (std::env::var(name), default) {
+			(Ok(val), _) => result.push_str(&val),
+			(Err(_), Some(default)) => result.push_str(default),
+			(Err(_), None) => return Err(Error::EnvVarNotDefined { name: name.into() }),
+		}
+		rest = &after[end + 1..];
+	}
+	result.push_str(rest);
+	Ok(Cow::Owned(result))
+}
+
+/// Derives a stable id from the parents stable id, the elements name and
+/// the elements occurrence among its equally named siblings using FNV-1a.
+fn stable_id(parent: Option<u64>, name: &str, occurrence: usize) -> u64 {
+	let mut hash = parent.unwrap_or(0xcbf2_9ce4_8422_2325);
+	let bytes = core::iter::once(b'/')
+		.chain(name.bytes())
+		.chain((occurrence as u64).to_le_bytes());
+	for byte in bytes {
+		hash ^= u64::from(byte);
+		hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+	}
+	hash
+}
+
+/// Applies the `prefix` to the key of a blackboard pointer, global `@` keys and constants are kept.
+fn prefixed(prefix: &str, value: &str) -> ConstString {
+	match strip_board_pointer(value) {
+		Some(stripped) if !prefix.is_empty() && !stripped.starts_with('@') => {
+			(String::from("{") + prefix + stripped + "}").into()
+		}
+		_ => value.into(),
+	}
+}
+
+/// Tag and attributes of an element, independent of the source format.
+#[derive(Clone)]
+pub struct SourceElement<'a> {
+	/// The tag name
+	pub tag: &'a str,
+	/// The attributes as key value pairs in order of appearance
+	pub attributes: Vec<(&'a str, &'a str)>,
+}
+
+impl<'a> SourceElement<'a> {
+	/// Collects tag and attributes of an XML node.
+	fn from_node(node: &Node<'a, '_>) -> Self {
+		Self {
+			tag: node.tag_name().name(),
+			attributes: node
+				.attributes()
+				.map(|attribute| (attribute.name(), attribute.value()))
+				.collect(),
+		}
+	}
+
+	/// Returns the value of the attribute `key`.
+	pub fn attribute(&self, key: &str) -> Option<&'a str> {
+		self.attributes
+			.iter()
+			.find(|(name, _)| *name == key)
+			.map(|(_, value)| *value)
+	}
+}
+
+/// Callback for the children of a [`SourceNode`], getting the child and the text of a preceding comment.
+pub type ChildFn<'a> = dyn FnMut(&dyn SourceNode, Option<&str>) -> Result<(), Error> + 'a;
+
+/// An element of a tree definition, independent of the source format.
+pub trait SourceNode {
+	/// Returns tag and attributes of the element.
+	fn element(&self) -> Cow<'_, SourceElement<'_>>;
+
+	/// Returns `true` if the element has any content.
+	fn has_children(&self) -> bool;
+
+	/// Calls `f` for each child element in order of appearance,
+	/// together with the text of a comment directly preceding the child.
+	/// # Errors
+	/// - if the source contains an unsupported node.
+	/// - if `f` fails.
+	fn for_each_child(&self, f: &mut ChildFn<'_>) -> Result<(), Error>;
+}
+
+impl SourceNode for Node<'_, '_> {
+	fn element(&self) -> Cow<'_, SourceElement<'_>> {
+		Cow::Owned(SourceElement::from_node(self))
+	}
+
+	fn has_children(&self) -> bool {
+		Node::has_children(self)
+	}
+
+	fn for_each_child(&self, f: &mut ChildFn<'_>) -> Result<(), Error> {
+		// a comment preceding an element is attached to that element
+		let mut comment = None;
+		for child in self.children() {
+			match child.node_type() {
+				NodeType::Comment => comment = child.text(),
+				NodeType::Text => {} // ignore
+				NodeType::Root => {
+					// this should not happen
+					return Err(Error::InvalidRootElement);
+				}
+				NodeType::Element => f(&child, comment.take())?,
+				NodeType::PI => {
+					return Err(Error::UnsupportedElement {
+						tag: child.tag_name().name().into(),
+					});
+				}
+			}
+		}
+		Ok(())
+	}
+}
+
+/// Provides the (sub)tree definitions for the creation of a tree.
+pub trait DefinitionSource {
+	/// Calls `build` with the definition `id`, returns `None` if there is no such definition.
+	fn with_definition<R>(
+		&self,
+		registry: &BehaviorRegistry,
+		id: &str,
+		build: impl FnOnce(&dyn SourceNode) -> Result<R, Error>,
+	) -> Option<Result<R, Error>>;
+}
+
+/// The XML definitions registered in the [`BehaviorRegistry`].
+pub struct XmlDefinitions;
+
+impl DefinitionSource for XmlDefinitions {
+	fn with_definition<R>(
+		&self,
+		registry: &BehaviorRegistry,
+		id: &str,
+		build: impl FnOnce(&dyn SourceNode) -> Result<R, Error>,
+	) -> Option<Result<R, Error>> {
+		let (definition, range) = registry.find_tree_definition(id)?;
+		Some(
+			Document::parse(&definition[range])
+				.map(Box::new)
+				.map_err(Error::from)
+				.and_then(|doc| build(&doc.root_element())),
+		)
+	}
+}
+
+fn create_data_collection<'a>(
 	registry: &'a BehaviorRegistry,
 	path: &str,
-	element: &'a Node,
+	prefix: &str,
+	element: &SourceElement,
 	uid: u16,
 	blackboard: Option<&Databoard>,
 	is_root: bool,
 ) -> Result<Box<BehaviorDataCollection<'a>>, Error> {
 	let (behavior_id, behavior_kind) = {
-		let tag_name = element.tag_name().name();
+		let tag_name = element.tag;
 		match tag_name {
 			BEHAVIORTREE => {
 				if let Some(id) = element.attribute(ID) {
@@ -87,8 +271,15 @@ fn create_data_collection_from_xml<'a>(
 	};
 	bhvr_desc.set_name(&behavior_name);
 	bhvr_desc.set_path(&path);
+	bhvr_desc.set_prefix(
+		&(String::from(prefix)
+			+ element
+				.attribute(crate::PREFIX)
+				.unwrap_or_default()),
+	);
 
-	let (autoremap, mut remappings, conditions) = handle_attributes(registry, behavior_id, behavior_kind, &bhvr, element)?;
+	let (autoremap, mut remappings, conditions) =
+		handle_attributes(registry, behavior_id, behavior_kind, &bhvr, &mut bhvr_desc, prefix, element)?;
 
 	let blackboard = blackboard.map_or_else(Databoard::new, |blackboard| {
 		if is_subtree && !is_root {
@@ -110,17 +301,24 @@ fn create_data_collection_from_xml<'a>(
 		remappings,
 		conditions,
 		uid,
+		stable_id: None,
+		autoremap,
 		registry,
 	}))
 }
 
+/// The blackboard pointers of ports are prefixed with the prefix in the behaviors description.
+/// For subtrees the remapped keys inside the subtree are prefixed, while the pointers into
+/// the parents blackboard only get the `inherited_prefix` of the enclosing subtrees.
 #[allow(clippy::too_many_lines)]
 fn handle_attributes(
 	registry: &BehaviorRegistry,
 	behavior_id: &str,
 	behavior_kind: &str,
 	bhvr: &BehaviorPtr,
-	node: &Node,
+	bhvr_desc: &mut BehaviorDescription,
+	inherited_prefix: &str,
+	node: &SourceElement,
 ) -> Result<
 	(
 		/*autoremap:*/ bool,
@@ -131,9 +329,14 @@ fn handle_attributes(
 > {
 	let mut autoremap = false;
 	let mut remappings = Remappings::default();
+	let mut defaulted_ports: Vec<ConstString> = Vec::new();
+	#[cfg(feature = "scripting")]
 	let mut conditions = Conditions::default();
+	#[cfg(not(feature = "scripting"))]
+	let conditions = Conditions::default();
 	// let mut preconditions = PreConditions::default();
 	// let mut postconditions = PostConditions::default();
+	let prefix = bhvr_desc.prefix().clone();
 
 	// port list is needed twice:
 	// - for checking port names in given attributes
@@ -141,11 +344,21 @@ fn handle_attributes(
 	let port_list = bhvr.static_provided_ports();
 
 	// first check for default values given in port definition.
+	// ports declared with a global `@` name without a default value point to the equally named global entry.
 	// this value can later be overwritten by default values given by xml attribute
 	for port_definition in port_list.iter() {
-		if let Some(default_value) = port_definition.default_value() {
-			match remappings.add(port_definition.name(), default_value.clone()) {
-				Ok(()) => {}
+		let default_value = port_definition.default_value().map_or_else(
+			|| {
+				port_definition
+					.name()
+					.starts_with('@')
+					.then(|| ConstString::from(String::from("{") + port_definition.name() + "}"))
+			},
+			|default_value| Some(prefixed(&prefix, default_value)),
+		);
+		if let Some(default_value) = default_value {
+			match remappings.add(port_definition.name(), default_value) {
+				Ok(()) => defaulted_ports.push(port_definition.name().into()),
 				Err(err) => {
 					return Err(Error::Databoard {
 						key: port_definition.name().into(),
@@ -159,8 +372,8 @@ fn handle_attributes(
 	// second fill in remappings from available TreeNodesModel's
 	for entry in registry.tree_nodes_models() {
 		if entry.0.contains(behavior_id) {
-			match remappings.add(entry.1.key.clone(), entry.1.remapping.clone()) {
-				Ok(()) => {}
+			match remappings.add(entry.1.key.clone(), prefixed(&prefix, &entry.1.remapping)) {
+				Ok(()) => defaulted_ports.push(entry.1.key.clone()),
 				Err(err) => {
 					return Err(Error::Databoard {
 						key: entry.1.key.clone(),
@@ -172,9 +385,18 @@ fn handle_attributes(
 	}
 
 	// third handle attributes
-	for attribute in node.attributes() {
-		let key = attribute.name();
-		let value = attribute.value();
+	for &(key, raw_value) in &node.attributes {
+		// special attributes like scripts are used as written
+		#[cfg(feature = "std")]
+		let expanded = if key.starts_with('_') {
+			Cow::Borrowed(raw_value)
+		} else {
+			expand_env(raw_value)?
+		};
+		#[cfg(feature = "std")]
+		let value: &str = &expanded;
+		#[cfg(not(feature = "std"))]
+		let value = raw_value;
 		if key == NAME {
 			// port "name" is always available
 		} else if key == ID {
@@ -183,26 +405,58 @@ fn handle_attributes(
 			// these are special attributes
 			match key {
 				crate::AUTOREMAP => {
-					autoremap = match attribute.value().parse::<bool>() {
+					autoremap = match raw_value.parse::<bool>() {
 						Ok(val) => val,
 						Err(_) => return Err(Error::WrongAutoremap),
 					};
 				}
+				// already handled with the behaviors description
+				crate::PREFIX => {}
+				// scripts need the scripting feature
+				#[cfg(not(feature = "scripting"))]
+				crate::FAILURE_IF
+				| crate::SKIP_IF
+				| crate::SUCCESS_IF
+				| crate::WHILE
+				| crate::ON_FAILURE
+				| crate::ON_HALTED
+				| crate::ON_SUCCESS
+				| crate::POST
+				| crate::ON_ENTER
+				| crate::ON_EXIT => {
+					return Err(Error::ScriptingDisabled { key: key.into() });
+				}
 				// preconditions
-				crate::FAILURE_IF | crate::SKIP_IF | crate::SUCCESS_IF | crate::WHILE => {
-					match conditions.pre.set(key, value) {
-						Ok(()) => {}
-						Err(err) => {
-							return Err(Error::Condition {
-								key: key.into(),
-								source: err,
-							});
-						}
+				#[cfg(feature = "scripting")]
+				crate::FAILURE_IF | crate::SKIP_IF | crate::SUCCESS_IF | crate::WHILE => match conditions.pre.set(key, value) {
+					Ok(()) => {}
+					Err(err) => {
+						return Err(Error::Condition {
+							key: key.into(),
+							source: err,
+						});
 					}
-				}
+				},
 				// postconditions
-				crate::ON_FAILURE | crate::ON_HALTED | crate::ON_SUCCESS | crate::POST => {
-					match conditions.post.set(key, value) {
+				#[cfg(feature = "scripting")]
+				crate::ON_FAILURE | crate::ON_HALTED | crate::ON_SUCCESS | crate::POST => match conditions.post.set(key, value) {
+					Ok(()) => {}
+					Err(err) => {
+						return Err(Error::Condition {
+							key: key.into(),
+							source: err,
+						});
+					}
+				},
+				// subtree hooks
+				#[cfg(feature = "scripting")]
+				crate::ON_ENTER | crate::ON_EXIT if behavior_kind == SUBTREE => {
+					let result = if key == crate::ON_ENTER {
+						conditions.pre.set(key, value)
+					} else {
+						conditions.post.set(key, value)
+					};
+					match result {
 						Ok(()) => {}
 						Err(err) => {
 							return Err(Error::Condition {
@@ -212,12 +466,23 @@ fn handle_attributes(
 						}
 					}
 				}
-				_ => return Err(Error::UnknownAttribute { key: key.into() }),
+				// unknown attributes, e.g. editor metadata
+				_ => {
+					if registry
+						.options()
+						.contains(TreeOptions::IGNORE_UNKNOWN_METADATA)
+					{
+						bhvr_desc.add_metadata_attribute(key, value);
+					} else {
+						return Err(Error::UnknownAttribute { key: key.into() });
+					}
+				}
 			}
 		} else {
 			// for a subtree we cannot check against a port list
 			if behavior_kind == SUBTREE {
-				remappings.overwrite(key, value);
+				let inner_key = String::from(prefix.as_ref()) + key;
+				remappings.overwrite(&inner_key, prefixed(inherited_prefix, value));
 			} else {
 				// check key against list of provided ports
 				match port_list.find(key) {
@@ -226,7 +491,7 @@ fn handle_attributes(
 							Some(stripped) => {
 								if stripped == "=" {
 									if is_allowed_port_name(key) {
-										let bb_pointer = String::from("{") + key + "}";
+										let bb_pointer = String::from("{") + &prefix + key + "}";
 										remappings.overwrite(key, bb_pointer);
 									} else {
 										return Err(Error::NameNotAllowed { key: key.into() });
@@ -234,7 +499,7 @@ fn handle_attributes(
 								} else {
 									// check if 'value' contains a valid BB pointer
 									if is_allowed_port_name(stripped) {
-										remappings.overwrite(key, value);
+										remappings.overwrite(key, prefixed(&prefix, value));
 									} else {
 										return Err(Error::NameNotAllowed { key: key.into() });
 									}
@@ -257,17 +522,159 @@ fn handle_attributes(
 		}
 	}
 	remappings.shrink();
+	defaulted_ports.retain(|port| {
+		!node
+			.attributes
+			.iter()
+			.any(|(key, _)| *key == port.as_ref())
+	});
+	defaulted_ports.sort_unstable();
+	defaulted_ports.dedup();
+	bhvr_desc.set_defaulted_ports(defaulted_ports);
 	Ok((autoremap, remappings, conditions))
 }
+
+/// Checks nesting depth and number of attributes of all elements against the [`ParserLimits`].
+/// The traversal is iterative, so the check itself does not consume stack.
+/// # Errors
+/// - if an element is nested deeper than allowed.
+/// - if an element has more attributes than allowed.
+fn check_limits(root: &Node, limits: &ParserLimits) -> Result<(), Error> {
+	let mut stack = vec![(*root, usize::from(root.is_element()))];
+	while let Some((node, depth)) = stack.pop() {
+		if node.is_element() {
+			if depth > limits.max_depth {
+				return Err(Error::NestingTooDeep {
+					tag: node.tag_name().name().into(),
+					limit: limits.max_depth,
+				});
+			}
+			if node.attributes().len() > limits.max_attributes {
+				return Err(Error::TooManyAttributes {
+					tag: node.tag_name().name().into(),
+					limit: limits.max_attributes,
+				});
+			}
+		}
+		for child in node.children().filter(Node::is_element) {
+			stack.push((child, depth + 1));
+		}
+	}
+	Ok(())
+}
+
+/// Creates the element for `data` with its `children` and appends it to the `siblings`.
+/// The creation is kept out of the recursive [`XmlParser::collect_children`] to keep its stack frame small.
+/// # Errors
+/// - if the behavior rejects its configuration
+fn push_element(
+	siblings: &mut BehaviorTreeElementList,
+	data: Box<BehaviorDataCollection>,
+	children: BehaviorTreeElementList,
+	runtime: &SharedRuntime,
+) -> Result<(), Error> {
+	let mut element = match data.bhvr_desc.kind() {
+		BehaviorKind::Action | BehaviorKind::Condition => BehaviorTreeElement::create_leaf(data),
+		BehaviorKind::Control | BehaviorKind::Decorator => BehaviorTreeElement::create_node(data, children),
+		BehaviorKind::SubTree => BehaviorTreeElement::create_subtree(data, children),
+	};
+	on_creation(&mut element, runtime)?;
+	siblings.push(element);
+	Ok(())
+}
+
+/// Lets the behavior of the just created `element` check and prepare its configuration.
+/// # Errors
+/// - if the behavior rejects its configuration
+fn on_creation(element: &mut BehaviorTreeElement, runtime: &SharedRuntime) -> Result<(), Error> {
+	element
+		.on_creation(runtime)
+		.map_err(|source| Error::Creation {
+			behavior: element.data().description().name().clone(),
+			source,
+		})
+}
+
+/// Checks, that the `children` of a boolean control `behavior` can not return Running,
+/// so they have to be conditions or boolean controls.
+fn check_boolean_children(behavior: &str, bhvr: &BehaviorPtr, children: &BehaviorTreeElementList) -> Result<(), Error> {
+	if !bhvr.is_boolean() {
+		return Ok(());
+	}
+	for child in children.iter() {
+		let accepted = match child.data().description().kind() {
+			BehaviorKind::Condition => true,
+			BehaviorKind::Control => child.behavior().is_boolean(),
+			_ => false,
+		};
+		if !accepted {
+			return Err(Error::NonBooleanChild {
+				behavior: behavior.into(),
+				child: child.name().clone(),
+			});
+		}
+	}
+	Ok(())
+}
 // endregion:	--- helper
 
 // region:      --- XmlParser
 #[derive(Default)]
 pub struct XmlParser {
 	uid: u16,
+	/// Whether more uids were requested than available.
+	uids_exhausted: bool,
+	/// Path of the element, in which the creation failed.
+	failure_path: Option<ConstString>,
+	/// The uids of the created subtrees with `_autoremap`.
+	autoremapped: BTreeSet<u16>,
+	/// Depth of the elements currently created, the root has depth 1.
+	depth: usize,
+	/// The runtime for the creation of the behaviors, a clone of the registries runtime created on first use.
+	runtime: Option<SharedRuntime>,
 }
 
 impl XmlParser {
+	/// Creates a parser, which assigns uids starting with `uid`.
+	pub(crate) const fn with_start_uid(uid: u16) -> Self {
+		Self {
+			uid,
+			uids_exhausted: false,
+			failure_path: None,
+			autoremapped: BTreeSet::new(),
+			depth: 0,
+			runtime: None,
+		}
+	}
+
+	/// Returns the runtime for the creation of the behaviors.
+	#[cfg_attr(not(feature = "scripting"), allow(unused_variables))]
+	fn runtime(&mut self, registry: &BehaviorRegistry) -> SharedRuntime {
+		self.runtime
+			.get_or_insert_with(|| {
+				#[cfg(feature = "scripting")]
+				let runtime = registry.runtime().clone();
+				#[cfg(not(feature = "scripting"))]
+				let runtime = crate::Runtime;
+				Arc::new(Mutex::new(runtime))
+			})
+			.clone()
+	}
+
+	/// Returns the path of the element, in which the creation of a child failed.
+	pub(crate) const fn failure_path(&self) -> Option<&ConstString> {
+		self.failure_path.as_ref()
+	}
+
+	/// Records the `path` of the element, in which the creation failed.
+	/// Only the first, deepest path is kept.
+	fn locate<T>(&mut self, result: Result<T, Error>, path: &str) -> Result<T, Error> {
+		if result.is_err() && self.failure_path.is_none() {
+			self.failure_path = Some(path.into());
+		}
+		result
+	}
+
 	/// Returns the root element for a [`BehaviorTree`](crate::tree::BehaviorTree).
 	/// If an external blackboard is given, it will be used as a root blackboard.
 	/// # Errors
@@ -281,28 +688,156 @@ impl XmlParser {
 		registry: &BehaviorRegistry,
 		external_blackboard: Option<&Databoard>,
 	) -> Result<BehaviorTreeElement, Error> {
-		registry.find_tree_definition(name).map_or_else(
-			|| Err(Error::DefinitionNotFound { id: name.into() }),
-			|(definition, range)| {
-				let doc = Box::new(Document::parse(&definition[range])?);
-				let element = Box::new(doc.root_element());
-				let data = create_data_collection_from_xml(
+		self.create_tree(&XmlDefinitions, name, registry, external_blackboard)
+	}
+
+	/// Returns the root element for a [`BehaviorTree`](crate::tree::BehaviorTree) from a binary XML document.
+	/// If an external blackboard is given, it will be used as a root blackboard.
+	/// # Errors
+	/// - if the document contains no definition for `name`.
+	/// - if a needed behavior is not registered.
+	/// - if an [`Action`] or [`Condition`] has children.
+	/// - if a [`Decorator`] or [`SubTree`] has more than one child.
+	pub(crate) fn create_tree_from_binary_xml(
+		&mut self,
+		document: &BinaryXmlDocument,
+		name: &str,
+		registry: &BehaviorRegistry,
+		external_blackboard: Option<&Databoard>,
+	) -> Result<BehaviorTreeElement, Error> {
+		self.create_tree(document, name, registry, external_blackboard)
+	}
+
+	/// Returns the root element for the definition `name` provided by `definitions`.
+	fn create_tree<D: DefinitionSource>(
+		&mut self,
+		definitions: &D,
+		name: &str,
+		registry: &BehaviorRegistry,
+		external_blackboard: Option<&Databoard>,
+	) -> Result<BehaviorTreeElement, Error> {
+		definitions
+			.with_definition(registry, name, |definition| {
+				let mut data = create_data_collection(
 					registry,
 					EMPTY_STR,
-					&element,
+					EMPTY_STR,
+					&definition.element(),
 					self.next_uid(),
 					external_blackboard,
 					true,
 				)?;
+				self.depth = 1;
+				if registry
+					.options()
+					.contains(TreeOptions::STABLE_IDS)
+				{
+					data.stable_id = Some(stable_id(None, &data.behavior_name, 0));
+				}
 				// for tree root "path" is empty
-				let children = self.build_children(&data, &element)?;
+				let children = self.build_children(
+					definitions,
+					data.registry,
+					&data.path,
+					data.bhvr_desc.prefix(),
+					data.stable_id,
+					&data.blackboard,
+					definition,
+				)?;
+				if self.uids_exhausted {
+					return Err(Error::TooManyElements);
+				}
 				if children.len() > 1 {
 					return Err(Error::OneChild { behavior: name.into() });
 				}
-				let behaviortree = BehaviorTreeElement::create_subtree(data, children);
+				let mut behaviortree = BehaviorTreeElement::create_subtree(data, children);
+				on_creation(&mut behaviortree, &self.runtime(registry))?;
+				if registry
+					.options()
+					.contains(TreeOptions::STRICT_AUTOREMAP)
+					&& let Some((path, key)) = check_autoremap(&behaviortree, &self.autoremapped)
+				{
+					return Err(Error::AutoremapEntryMissing { path, key });
+				}
 				Ok(behaviortree)
-			},
-		)
+			})
+			.unwrap_or_else(|| Err(Error::DefinitionNotFound { id: name.into() }))
+	}
+
+	/// Returns the children of the (sub)tree definition `id` for an existing subtree element.
+	/// The children are created below the given `path`, `prefix` and `stable_id` using the subtrees `blackboard`.
+	/// The maximum tree depth is checked relative to the subtree element.
+	/// # Errors
+	/// - if no definition for `id` is registered.
+	/// - if a needed behavior is not registered.
+	/// - if the definition has more than one child.
+	/// - if the tree runs out of uids.
+	pub(crate) fn create_subtree_children(
+		&mut self,
+		id: &str,
+		registry: &BehaviorRegistry,
+		path: &str,
+		prefix: &str,
+		stable_id: Option<u64>,
+		blackboard: &Databoard,
+	) -> Result<BehaviorTreeElementList, Error> {
+		XmlDefinitions
+			.with_definition(registry, id, |definition| {
+				self.depth = 1;
+				let children =
+					self.build_children(&XmlDefinitions, registry, path, prefix, stable_id, blackboard, definition)?;
+				if self.uids_exhausted {
+					return Err(Error::TooManyElements);
+				}
+				if children.len() > 1 {
+					return Err(Error::OneChild { behavior: id.into() });
+				}
+				Ok(children)
+			})
+			.unwrap_or_else(|| Err(Error::DefinitionNotFound { id: id.into() }))
+	}
+
+	/// Replaces the registered (sub)tree definition `id` with the definition contained in the XML.
+	/// The XML is either a single `<BehaviorTree ID="...">` element or a document containing it.
+	/// # Errors
+	/// - if no definition for `id` is registered.
+	/// - if the XML document is invalid.
+	/// - if the XML document exceeds the registries [`ParserLimits`].
+	/// - if the XML does not contain a definition for `id`.
+	pub(crate) fn replace_tree_definition(
+		registry: &mut BehaviorRegistry,
+		id: &str,
+		xml: impl Into<ConstString>,
+	) -> Result<(), Error> {
+		if registry.find_tree_definition(id).is_none() {
+			return Err(Error::DefinitionNotFound { id: id.into() });
+		}
+		let xml = xml.into();
+		let limits = *registry.parser_limits();
+		if xml.len() > limits.max_document_length {
+			return Err(Error::DocumentTooLarge {
+				length: xml.len(),
+				limit: limits.max_document_length,
+			});
+		}
+		let range = {
+			let doc = Box::new(Document::parse(&xml)?);
+			let root = doc.root_element();
+			check_limits(&root, &limits)?;
+			let is_definition = |node: &Node| node.tag_name().name() == BEHAVIORTREE && node.attribute(ID) == Some(id);
+			if is_definition(&root) {
+				Some(root.range())
+			} else {
+				root.children()
+					.find(is_definition)
+					.map(|node| node.range())
+			}
+		};
+		let Some(range) = range else {
+			return Err(Error::DefinitionNotFound { id: id.into() });
+		};
+		registry.replace_tree_definition(id, xml, range);
+		Ok(())
 	}
 
 	/// Registers the behavior (sub)tree definitions contained in the XML description.
@@ -310,6 +845,7 @@ impl XmlParser {
 	/// implementation of the `<include path="..."/>` tags.
 	/// # Errors
 	/// - if the XML document is invalid.
+	/// - if the XML document exceeds the registries [`ParserLimits`].
 	/// - if the XML has nested root elements.
 	/// - if a behavior is already registered.
 	pub(crate) fn register_document(
@@ -318,9 +854,18 @@ impl XmlParser {
 		#[cfg(feature = "std")] path: &ConstString,
 	) -> Result<(), Error> {
 		let xml = xml.into();
+		// check size before parsing to avoid unbounded memory consumption
+		let limits = *registry.parser_limits();
+		if xml.len() > limits.max_document_length {
+			return Err(Error::DocumentTooLarge {
+				length: xml.len(),
+				limit: limits.max_document_length,
+			});
+		}
 		// general checks
 		let doc = Box::new(Document::parse(&xml)?);
 		let root = Box::new(doc.root_element());
+		check_limits(&root, &limits)?;
 		if root.tag_name().name() != "root" {
 			return Err(Error::WrongRootName);
 		}
@@ -333,6 +878,17 @@ impl XmlParser {
 		// handle the attribute 'main_tree_to_execute`
 		if let Some(name) = root.attribute("main_tree_to_execute") {
 			registry.set_main_tree_id(name);
+			registry.add_main_tree(name);
+		}
+		// handle the attribute 'main_trees`, a comma separated list of entry points
+		if let Some(names) = root.attribute("main_trees") {
+			for name in names
+				.split(',')
+				.map(str::trim)
+				.filter(|name| !name.is_empty())
+			{
+				registry.add_main_tree(name);
+			}
 		}
 		#[cfg(feature = "std")]
 		Self::register_document_root(registry, &root, &xml, path)?;
@@ -391,11 +947,12 @@ impl XmlParser {
 						"include" => {
 							let mut file_path: PathBuf;
 							if let Some(path_attr) = element.attribute("path") {
-								file_path = PathBuf::from(path_attr);
+								let path_attr = expand_env(path_attr)?;
+								file_path = PathBuf::from(path_attr.as_ref());
 								if file_path.is_relative() {
 									// use the given path
 									file_path = PathBuf::from(path.as_ref());
-									file_path.push(path_attr);
+									file_path.push(path_attr.as_ref());
 								}
 							} else {
 								return Err(Error::MissingPath {
@@ -469,7 +1026,10 @@ impl XmlParser {
 								{
 									let key = String::from(behavior_id) + port_name;
 									let Ok(port_type) = PortDirection::try_from(port_type) else {
-										return Err(Error::PortType { value: port_type.into() });
+										return Err(Error::PortType {
+											behavior: behavior_id.into(),
+											value: port_type.into(),
+										});
 									};
 									let entry = TreeNodesModelEntry {
 										_port_type: port_type,
@@ -507,98 +1067,169 @@ impl XmlParser {
 		Ok(())
 	}
 
-	/// Returns a list of all child behavior tree elements.
+	/// Returns a list of all child behavior tree elements of the `parent` element.
+	/// Subtrees are resolved with the `definitions`.
+	/// On failure the path of the parent element is recorded.
+	///
+	/// The depth is checked before descending, so that the recursion is limited by the registries maximum tree depth,
+	/// even for self referencing subtrees.
 	/// # Errors
+	/// - if the children exceed the maximum tree depth.
 	/// - if a needed behavior is not registered.
 	/// - if an [`Action`] or [`Condition`] has children.
 	/// - if a [`Decorator`] or [`SubTree`] has more than one child.
 	/// - if a [`SubTree`] has no `ID` attribute given.
-	fn build_children(
+	#[allow(clippy::too_many_arguments)]
+	fn build_children<D: DefinitionSource>(
 		&mut self,
-		parent_data: &BehaviorDataCollection,
-		parent_element: &Node,
+		definitions: &D,
+		registry: &BehaviorRegistry,
+		parent_path: &str,
+		parent_prefix: &str,
+		parent_stable_id: Option<u64>,
+		parent_blackboard: &Databoard,
+		parent: &dyn SourceNode,
 	) -> Result<BehaviorTreeElementList, Error> {
-		// @TODO: improve error messages with parent element & current element
+		self.depth += 1;
+		let result = self.collect_children(
+			definitions,
+			registry,
+			parent_path,
+			parent_prefix,
+			parent_stable_id,
+			parent_blackboard,
+			parent,
+		);
+		self.depth -= 1;
+		self.locate(result, parent_path)
+	}
+
+	/// Collects the child behavior tree elements for [`build_children`](Self::build_children).
+	#[allow(clippy::too_many_arguments)]
+	fn collect_children<D: DefinitionSource>(
+		&mut self,
+		definitions: &D,
+		registry: &BehaviorRegistry,
+		parent_path: &str,
+		parent_prefix: &str,
+		parent_stable_id: Option<u64>,
+		parent_blackboard: &Databoard,
+		parent: &dyn SourceNode,
+	) -> Result<BehaviorTreeElementList, Error> {
+		let runtime = self.runtime(registry);
 		let mut children = BehaviorTreeElementList::default();
-		for child_element in parent_element.children() {
-			match child_element.node_type() {
-				NodeType::Comment | NodeType::Text => {} // ignore
-				NodeType::Root => {
-					// this should not happen
-					return Err(Error::InvalidRootElement);
+		// occurrences of equally named siblings for the stable ids
+		let mut occurrences: BTreeMap<String, usize> = BTreeMap::new();
+		parent.for_each_child(&mut |child, comment| {
+			let element = child.element();
+			let mut child_data = create_data_collection(
+				registry,
+				parent_path,
+				parent_prefix,
+				&element,
+				self.next_uid(),
+				Some(parent_blackboard),
+				false,
+			)?;
+			if let Some(limit) = registry.max_tree_depth()
+				&& self.depth > limit
+			{
+				return Err(Error::TreeTooDeep {
+					path: child_data.path.as_str().into(),
+					limit,
+				});
+			}
+			if registry
+				.options()
+				.contains(TreeOptions::PRESERVE_COMMENTS)
+			{
+				child_data
+					.bhvr_desc
+					.set_metadata(comment.map(|text| text.trim().into()));
+			}
+			if registry
+				.options()
+				.contains(TreeOptions::STABLE_IDS)
+			{
+				let occurrence = occurrences
+					.entry(child_data.behavior_name.clone())
+					.or_default();
+				child_data.stable_id = Some(stable_id(parent_stable_id, &child_data.behavior_name, *occurrence));
+				*occurrence += 1;
+			}
+			match child_data.bhvr_desc.kind() {
+				BehaviorKind::Action | BehaviorKind::Condition => {
+					if child.has_children() {
+						return Err(Error::ChildrenNotAllowed {
+							behavior: child_data.behavior_name.into(),
+						});
+					}
+					push_element(&mut children, child_data, BehaviorTreeElementList::default(), &runtime)?;
 				}
-				NodeType::Element => {
-					let new_child = {
-						let child_data = create_data_collection_from_xml(
-							parent_data.registry,
-							&parent_data.path,
-							&child_element,
-							self.next_uid(),
-							Some(&parent_data.blackboard),
-							false,
-						)?;
-						match child_data.bhvr_desc.kind() {
-							BehaviorKind::Action | BehaviorKind::Condition => {
-								if child_element.has_children() {
-									return Err(Error::ChildrenNotAllowed {
-										behavior: child_data.behavior_name.into(),
-									});
-								}
-								BehaviorTreeElement::create_leaf(child_data)
-							}
-							BehaviorKind::Control | BehaviorKind::Decorator => {
-								let children = self.build_children(&child_data, &child_element)?;
-								if child_data.bhvr_desc.kind() == BehaviorKind::Decorator && children.len() != 1 {
-									return Err(Error::OneChild {
-										behavior: child_element.tag_name().name().into(),
-									});
-								}
-								BehaviorTreeElement::create_node(child_data, children)
-							}
-							BehaviorKind::SubTree => {
-								if let Some(id) = child_element.attribute(ID) {
-									match child_data.registry.find_tree_definition(id) {
-										Some((definition, range)) => {
-											let doc = Box::new(Document::parse(&definition[range])?);
-											let children = self.build_children(&child_data, &doc.root_element())?;
-											if children.len() > 1 {
-												return Err(Error::OneChild { behavior: id.into() });
-											}
-											BehaviorTreeElement::create_subtree(child_data, children)
-										}
-										None => {
-											return Err(Error::DefinitionNotFound {
-												id: child_data.behavior_name.into(),
-											});
-										}
-									}
-								} else {
-									return Err(Error::MissingId {
-										tag: child_element.tag_name().name().into(),
-									});
-								}
-							}
-						}
-					};
-					children.push(new_child);
+				BehaviorKind::Control | BehaviorKind::Decorator => {
+					let grandchildren = self.build_children(
+						definitions,
+						child_data.registry,
+						&child_data.path,
+						child_data.bhvr_desc.prefix(),
+						child_data.stable_id,
+						&child_data.blackboard,
+						child,
+					)?;
+					if child_data.bhvr_desc.kind() == BehaviorKind::Decorator && grandchildren.len() != 1 {
+						return Err(Error::OneChild {
+							behavior: element.tag.into(),
+						});
+					}
+					check_boolean_children(element.tag, &child_data.bhvr, &grandchildren)?;
+					push_element(&mut children, child_data, grandchildren, &runtime)?;
 				}
-				NodeType::PI => {
-					return Err(Error::UnsupportedElement {
-						tag: child_element.tag_name().name().into(),
-					});
+				BehaviorKind::SubTree => {
+					if child_data.autoremap {
+						self.autoremapped.insert(child_data.uid);
+					}
+					let Some(id) = element.attribute(ID) else {
+						return Err(Error::MissingId { tag: element.tag.into() });
+					};
+					let grandchildren = definitions
+						.with_definition(child_data.registry, id, |definition| {
+							self.build_children(
+								definitions,
+								child_data.registry,
+								&child_data.path,
+								child_data.bhvr_desc.prefix(),
+								child_data.stable_id,
+								&child_data.blackboard,
+								definition,
+							)
+						})
+						.unwrap_or_else(|| {
+							Err(Error::DefinitionNotFound {
+								id: child_data.behavior_name.as_str().into(),
+							})
+						})?;
+					if grandchildren.len() > 1 {
+						return Err(Error::OneChild { behavior: id.into() });
+					}
+					push_element(&mut children, child_data, grandchildren, &runtime)?;
 				}
 			}
-		}
+			Ok(())
+		})?;
 		Ok(children)
 	}
 
 	/// Get the next uid for a [`BehaviorTreeElement`].
 	/// The maximum allowed number of behaviors in a tree is 65535!
-	/// # Panics
-	/// - if more than 65535 [`BehaviorTreeElement`]s are created for a [`BehaviorTree`](crate::tree::BehaviorTree)
+	/// Running out of uids is only recorded, as an error here would enlarge the frames of the recursive creation,
+	/// the creation fails with [`Error::TooManyElements`] after the children are built.
 	const fn next_uid(&mut self) -> u16 {
 		let next = self.uid;
-		self.uid += 1;
+		if let Some(uid) = next.checked_add(1) {
+			self.uid = uid;
+		} else {
+			self.uids_exhausted = true;
+		}
 		next
 	}
 }
@@ -1,5 +1,5 @@
 // Copyright © 2025 Stephan Kunz
-//! Tests the [`SetBlackboard`] & [`UnsetBlackboard`] actions.
+//! Tests the [`SetBlackboard`], [`SetTypedBlackboard`] & [`UnsetBlackboard`] actions.
 
 extern crate alloc;
 
@@ -29,6 +29,66 @@ const UNSET_TREE_DEFINITION: &str = r#"
 </root>
 "#;
 
+const SET_TYPED_TREE_DEFINITION: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="SetTypedMainTree">
+		<Sequence>
+			<SetTypedBlackboard output_key="int" type="int" value="42"/>
+			<SetTypedBlackboard output_key="double" type="double" value="3.5"/>
+			<SetTypedBlackboard output_key="bool" type="bool" value="true"/>
+			<SetTypedBlackboard output_key="string" type="string" value="hello"/>
+			<SetTypedBlackboard output_key="default" value="world"/>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+const SET_TYPED_INVALID_VALUE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="SetTypedInvalidValue">
+		<SetTypedBlackboard output_key="int" type="int" value="not a number"/>
+	</BehaviorTree>
+</root>
+"#;
+
+const SET_TYPED_INVALID_TYPE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="SetTypedInvalidType">
+		<SetTypedBlackboard output_key="int" type="float" value="1.0"/>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn set_typed_blackboard() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut tree = factory.create_from_text(SET_TYPED_TREE_DEFINITION)?;
+	drop(factory);
+
+	let result = tree.tick_while_running().await?;
+	assert_eq!(result, BehaviorState::Success);
+	assert_eq!(tree.blackboard().get::<i32>("int")?, 42);
+	assert!((tree.blackboard().get::<f64>("double")? - 3.5).abs() < f64::EPSILON);
+	assert!(tree.blackboard().get::<bool>("bool")?);
+	assert_eq!(tree.blackboard().get::<String>("string")?, "hello");
+	assert_eq!(tree.blackboard().get::<String>("default")?, "world");
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn set_typed_blackboard_errors() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut tree = factory.create_from_text(SET_TYPED_INVALID_VALUE)?;
+	assert!(tree.tick_while_running().await.is_err());
+	assert!(tree.blackboard().get::<i32>("int").is_err());
+
+	let mut tree = factory.create_from_text(SET_TYPED_INVALID_TYPE)?;
+	assert!(tree.tick_while_running().await.is_err());
+
+	Ok(())
+}
+
 #[tokio::test]
 async fn set_unset_blackboard() -> Result<(), Error> {
 	let mut factory = BehaviorTreeFactory::new()?;
@@ -58,3 +118,43 @@ async fn set_unset_blackboard() -> Result<(), Error> {
 
 	Ok(())
 }
+
+const UNSET_GLOBAL_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence>
+			<Script code="@value_sqr := 49"/>
+			<Script code="local := 'main'"/>
+			<SubTree ID="Sub"/>
+			<UnsetBlackboard key="unknown"/>
+		</Sequence>
+	</BehaviorTree>
+	<BehaviorTree ID="Sub">
+		<Sequence>
+			<Script code="value_sqr := 2"/>
+			<UnsetBlackboard key="@value_sqr"/>
+			<ScriptCondition code="value_sqr == 2"/>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn unset_global_blackboard() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut tree = factory.create_from_text(UNSET_GLOBAL_TREE)?;
+	drop(factory);
+
+	// the global entry is gone, the subtrees local entry and other root entries are kept
+	let result = tree.tick_while_running().await?;
+	assert_eq!(result, BehaviorState::Success);
+	assert!(tree.blackboard().get::<i64>("value_sqr").is_err());
+	assert!(
+		tree.blackboard()
+			.get::<i64>("@value_sqr")
+			.is_err()
+	);
+	assert_eq!(tree.blackboard().get::<String>("local")?, "main");
+
+	Ok(())
+}
@@ -0,0 +1,79 @@
+// Copyright © 2025 Stephan Kunz
+//! Tests the [`ConvertQueue`] action.
+
+extern crate alloc;
+
+use behaviortree::{
+	behavior::{SharedQueue, action::ConvertQueue},
+	prelude::*,
+};
+
+const TREE_DEFINITION: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Convert input_queue="{ints}" output_queue="{converted}"/>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+#[allow(clippy::float_cmp)]
+async fn convert_int_to_double_queue() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, ConvertQueue<i32, f64>, "Convert")?;
+	factory.register_behavior_tree_from_text(TREE_DEFINITION)?;
+
+	let ints = SharedQueue::<i32>::default();
+	ints.push_back(1);
+	ints.push_back(-2);
+	ints.push_back(3);
+
+	let root_blackboard = Databoard::new();
+	root_blackboard.set("ints", ints.clone())?;
+	let mut tree = factory.create_tree_with("MainTree", &root_blackboard)?;
+	drop(factory);
+
+	// the output queue is created
+	assert_eq!(tree.tick_once().await?, BehaviorState::Success);
+	assert!(ints.pop_front().is_none());
+	let doubles = root_blackboard.get::<SharedQueue<f64>>("converted")?;
+	assert_eq!(doubles.pop_front(), Some(1.0));
+	assert_eq!(doubles.pop_front(), Some(-2.0));
+	assert_eq!(doubles.pop_front(), Some(3.0));
+	assert_eq!(doubles.pop_front(), None);
+
+	// the existing output queue is appended
+	ints.push_back(4);
+	tree.reset()?;
+	assert_eq!(tree.tick_once().await?, BehaviorState::Success);
+	assert_eq!(doubles.pop_front(), Some(4.0));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn convert_failure() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, ConvertQueue<i32, u8>, "Convert")?;
+	factory.register_behavior_tree_from_text(TREE_DEFINITION)?;
+
+	let ints = SharedQueue::<i32>::default();
+	ints.push_back(1);
+	ints.push_back(300);
+	ints.push_back(3);
+
+	let root_blackboard = Databoard::new();
+	root_blackboard.set("ints", ints.clone())?;
+	let mut tree = factory.create_tree_with("MainTree", &root_blackboard)?;
+	drop(factory);
+
+	// the conversion stops at the failing element
+	assert_eq!(tree.tick_once().await?, BehaviorState::Failure);
+	let bytes = root_blackboard.get::<SharedQueue<u8>>("converted")?;
+	assert_eq!(bytes.pop_front(), Some(1));
+	assert_eq!(bytes.pop_front(), None);
+	assert_eq!(ints.pop_front(), Some(300));
+	assert_eq!(ints.pop_front(), Some(3));
+
+	Ok(())
+}
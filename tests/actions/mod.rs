@@ -2,6 +2,7 @@
 //! Test builtin `Action` behaviors.
 
 mod blackboard; // set & unset
+mod convert_queue;
 //mod sleep;
 mod pop_from_queue;
 mod script;
@@ -4,6 +4,7 @@
 extern crate alloc;
 
 use behaviortree::prelude::*;
+use rstest::rstest;
 
 const XML: &str = r#"
 <root BTCPP_format="4">
@@ -37,3 +38,17 @@ async fn script() -> Result<(), Error> {
 
 	Ok(())
 }
+
+#[rstest]
+#[case(r#"<Script code='message := "hello"'/>"#)]
+#[case(r#"<ScriptCondition code='message == "hello"'/>"#)]
+#[case(r#"<Precondition if='message == "hello"' else="FAILURE"><AlwaysSuccess/></Precondition>"#)]
+#[case(r#"<Precondition if="false" else='message == "hello"'><AlwaysSuccess/></Precondition>"#)]
+fn script_syntax_error(#[case] behavior: &str) -> Result<(), Error> {
+	let xml = String::from(r#"<root BTCPP_format="4"><BehaviorTree ID="MainTree">"#) + behavior + "</BehaviorTree></root>";
+	let mut factory = BehaviorTreeFactory::new()?;
+
+	// the syntax error is reported at creation instead of the first tick
+	assert!(factory.create_from_text(&xml).is_err());
+	Ok(())
+}
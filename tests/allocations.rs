@@ -0,0 +1,154 @@
+// Copyright © 2025 Stephan Kunz
+
+//! Tests the heap allocations on the hot path of ticking a tree.
+//!
+//! The tests live in their own binary, as the counting allocator is global.
+//! The counters are per thread, so that the tests do not disturb each other.
+
+#![allow(unsafe_code)]
+
+use behaviortree::{InlineBehavior, prelude::*};
+use core::cell::Cell;
+use std::alloc::{GlobalAlloc, Layout, System};
+
+thread_local! {
+	/// Number of allocations since start.
+	static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+	/// Currently allocated bytes.
+	static ALLOCATED: Cell<isize> = const { Cell::new(0) };
+}
+
+/// Allocator counting the allocations and the allocated bytes.
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+	unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+		let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+		let _ = ALLOCATED.try_with(|bytes| bytes.set(bytes.get() + layout.size().cast_signed()));
+		unsafe { System.alloc(layout) }
+	}
+
+	unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+		let _ = ALLOCATED.try_with(|bytes| bytes.set(bytes.get() - layout.size().cast_signed()));
+		unsafe { System.dealloc(ptr, layout) }
+	}
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+const TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Fallback>
+			<Sequence>
+				<AlwaysSuccess/>
+				<AlwaysFailure/>
+			</Sequence>
+			<ReactiveSequence>
+				<AlwaysSuccess/>
+				<Inverter>
+					<AlwaysFailure/>
+				</Inverter>
+			</ReactiveSequence>
+		</Fallback>
+	</BehaviorTree>
+</root>
+"#;
+
+const TICKS: usize = 1000;
+
+#[tokio::test(flavor = "current_thread")]
+async fn tick_bulk_allocations() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut tree = factory.create_from_text(TREE)?;
+	drop(factory);
+
+	// warm up
+	assert_eq!(tree.tick_bulk(10, true).await?, BehaviorState::Success);
+
+	let allocations = ALLOCATIONS.get();
+	let allocated = ALLOCATED.get();
+	let result = tree.tick_bulk(TICKS, true).await?;
+	let allocations = ALLOCATIONS.get() - allocations;
+	let allocated = ALLOCATED.get().abs_diff(allocated);
+
+	assert_eq!(result, BehaviorState::Success);
+	assert_eq!(allocations, 0);
+	assert_eq!(allocated, 0);
+	assert_eq!(tree.tick_count(), (TICKS + 10) as u64);
+	Ok(())
+}
+
+/// Action implemented as [`InlineBehavior`].
+#[derive(Action, Debug, Default)]
+struct InlineAction;
+
+impl InlineBehavior for InlineAction {
+	async fn tick(
+		&mut self,
+		_behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		Ok(BehaviorState::Success)
+	}
+}
+
+/// The same action implemented as [`Behavior`].
+#[derive(Action, Debug, Default)]
+struct BoxedAction;
+
+#[async_trait::async_trait]
+impl Behavior for BoxedAction {
+	async fn tick(
+		&mut self,
+		_behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		Ok(BehaviorState::Success)
+	}
+}
+
+const ACTIONS_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence>
+			<Work/>
+			<Work/>
+			<Work/>
+			<Work/>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+/// Number of actions in [`ACTIONS_TREE`].
+const ACTIONS: usize = 4;
+
+/// Returns the number of allocations for [`TICKS`] ticks of [`ACTIONS_TREE`] with `T` registered as `Work`.
+async fn count_allocations<T: BehaviorExecution>() -> Result<usize, Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	factory.register_behavior_type::<T>("Work")?;
+	let mut tree = factory.create_from_text(ACTIONS_TREE)?;
+	drop(factory);
+
+	// warm up
+	assert_eq!(tree.tick_bulk(10, true).await?, BehaviorState::Success);
+
+	let allocations = ALLOCATIONS.get();
+	assert_eq!(tree.tick_bulk(TICKS, true).await?, BehaviorState::Success);
+	Ok(ALLOCATIONS.get() - allocations)
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn inline_behavior_allocations() -> Result<(), Error> {
+	let boxed = count_allocations::<BoxedAction>().await?;
+	let inline = count_allocations::<InlineAction>().await?;
+	assert_eq!(inline, 0);
+	// the boxed action allocates a future for `start()` and one for `tick()` on every tick,
+	// the `Sequence` and the root `SubTree` above it have to box their `start()` future as well
+	assert_eq!(boxed, TICKS * (2 * ACTIONS + 2));
+	Ok(())
+}
@@ -0,0 +1,1088 @@
+// Copyright © 2025 Stephan Kunz
+
+//! Tests the [`BehaviorData`] access methods
+
+#![allow(clippy::panic)]
+
+extern crate alloc;
+
+use alloc::sync::Arc;
+use behaviortree::{port::error::Error as PortError, prelude::*};
+use core::{future::Future, pin::Pin};
+use rstest::rstest;
+
+/// Action reading port `value` by reference
+#[derive(Action, Debug, Default)]
+struct RefReader;
+
+#[async_trait::async_trait]
+impl Behavior for RefReader {
+	async fn tick(
+		&mut self,
+		behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		let _value = behavior.get_ref::<i32>("value")?;
+		Ok(BehaviorState::Success)
+	}
+
+	fn provided_ports() -> PortList {
+		port_list![input_port!(i32, "value")]
+	}
+}
+
+/// Action writing port `value` by mutable reference
+#[derive(Action, Debug, Default)]
+struct RefWriter;
+
+#[async_trait::async_trait]
+impl Behavior for RefWriter {
+	async fn tick(
+		&mut self,
+		behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		let mut value = behavior.get_mut_ref::<i32>("value")?;
+		*value += 1;
+		Ok(BehaviorState::Success)
+	}
+
+	fn provided_ports() -> PortList {
+		port_list![inout_port!(i32, "value")]
+	}
+}
+
+const REF_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence>
+			<RefReader value="{number}"/>
+			<RefWriter value="{number}"/>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn get_ref_errors() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, RefReader, "RefReader")?;
+	register_behavior!(factory, RefWriter, "RefWriter")?;
+	let mut tree = factory.create_from_text(REF_TREE)?;
+	drop(factory);
+
+	// missing key
+	let result = tree.tick_once().await;
+	assert!(result.is_err_and(|err| {
+		let msg = err.to_string();
+		msg.contains("value") && msg.contains("i32")
+	}));
+
+	// type mismatch
+	tree.reset()?;
+	tree.blackboard()
+		.set("number", String::from("text"))?;
+	let result = tree.tick_once().await;
+	assert!(result.is_err_and(|err| {
+		let msg = err.to_string();
+		msg.contains("value") && msg.contains("i32")
+	}));
+
+	// correct type
+	tree.reset()?;
+	tree.blackboard().delete::<String>("number")?;
+	tree.blackboard().set("number", 41_i32)?;
+	let result = tree.tick_once().await?;
+	assert_eq!(result, BehaviorState::Success);
+	assert_eq!(tree.blackboard().get::<i32>("number")?, 42);
+
+	Ok(())
+}
+
+/// Action setting port `value` to 42 only if it changed
+#[derive(Action, Debug, Default)]
+struct ChangeWriter;
+
+#[async_trait::async_trait]
+impl Behavior for ChangeWriter {
+	async fn tick(
+		&mut self,
+		behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		if behavior.set_if_changed("value", 42_i32)? {
+			Ok(BehaviorState::Success)
+		} else {
+			Ok(BehaviorState::Failure)
+		}
+	}
+
+	fn provided_ports() -> PortList {
+		port_list![output_port!(i32, "value")]
+	}
+}
+
+const CHANGE_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<ChangeWriter value="{number}"/>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn set_if_changed() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, ChangeWriter, "ChangeWriter")?;
+	let mut tree = factory.create_from_text(CHANGE_TREE)?;
+	drop(factory);
+
+	// different value is written
+	tree.blackboard().set("number", 41_i32)?;
+	let sequence_id = tree.blackboard().sequence_id("number")?;
+	assert_eq!(tree.tick_once().await?, BehaviorState::Success);
+	assert_eq!(tree.blackboard().get::<i32>("number")?, 42);
+	let changed_id = tree.blackboard().sequence_id("number")?;
+	assert_ne!(changed_id, sequence_id);
+
+	// equal value is not written
+	tree.reset()?;
+	assert_eq!(tree.tick_once().await?, BehaviorState::Failure);
+	assert_eq!(tree.blackboard().sequence_id("number")?, changed_id);
+
+	// missing value is written
+	tree.reset()?;
+	tree.blackboard().delete::<i32>("number")?;
+	assert_eq!(tree.tick_once().await?, BehaviorState::Success);
+	assert_eq!(tree.blackboard().get::<i32>("number")?, 42);
+
+	Ok(())
+}
+
+/// Action reporting the outcome of reading port `value` as `i32` into port `outcome`
+#[derive(Action, Debug, Default)]
+struct TypedReader;
+
+#[async_trait::async_trait]
+impl Behavior for TypedReader {
+	async fn tick(
+		&mut self,
+		behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		let outcome = match behavior.get::<i32>("value") {
+			Ok(_) => "ok",
+			Err(PortError::TypeMismatch { .. }) => "mismatch",
+			Err(PortError::CouldNotConvert { .. }) => "convert",
+			Err(_) => "other",
+		};
+		behavior.set("outcome", String::from(outcome))?;
+		Ok(BehaviorState::Success)
+	}
+
+	fn provided_ports() -> PortList {
+		port_list![
+			input_port!(i32, "value"),
+			output_port!(String, "outcome")
+		]
+	}
+}
+
+const TYPED_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence>
+			<TypedReader value="{int}" outcome="{int_outcome}"/>
+			<TypedReader value="{long}" outcome="{long_outcome}"/>
+			<TypedReader value="{number}" outcome="{number_outcome}"/>
+			<TypedReader value="{text}" outcome="{text_outcome}"/>
+			<TypedReader value="{double}" outcome="{double_outcome}"/>
+			<TypedReader value="{bool}" outcome="{bool_outcome}"/>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn get_type_mismatch() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, TypedReader, "TypedReader")?;
+	let mut tree = factory.create_from_text(TYPED_TREE)?;
+	drop(factory);
+
+	tree.blackboard().set("int", 7_i32)?;
+	tree.blackboard().set("long", 7_i64)?;
+	tree.blackboard()
+		.set("number", String::from("7"))?;
+	tree.blackboard()
+		.set("text", String::from("seven"))?;
+	tree.blackboard().set("double", 7.5_f64)?;
+	tree.blackboard().set("bool", true)?;
+
+	assert_eq!(tree.tick_once().await?, BehaviorState::Success);
+	assert_eq!(tree.blackboard().get::<String>("int_outcome")?, "ok");
+	assert_eq!(tree.blackboard().get::<String>("long_outcome")?, "ok");
+	assert_eq!(
+		tree.blackboard()
+			.get::<String>("number_outcome")?,
+		"ok"
+	);
+	assert_eq!(tree.blackboard().get::<String>("text_outcome")?, "convert");
+	assert_eq!(
+		tree.blackboard()
+			.get::<String>("double_outcome")?,
+		"mismatch"
+	);
+	assert_eq!(tree.blackboard().get::<String>("bool_outcome")?, "mismatch");
+
+	Ok(())
+}
+
+/// A user type unknown to scripting
+#[derive(Clone, Debug, Default)]
+struct Pose;
+
+impl core::str::FromStr for Pose {
+	type Err = core::fmt::Error;
+
+	fn from_str(_s: &str) -> Result<Self, Self::Err> {
+		Ok(Self)
+	}
+}
+
+impl core::fmt::Display for Pose {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "Pose")
+	}
+}
+
+/// Action writing a [`Pose`] into port `pose` and
+/// reporting the stored type of a mismatch when reading it as `i32` into port `actual`
+#[derive(Action, Debug, Default)]
+struct PoseMismatch;
+
+#[async_trait::async_trait]
+impl Behavior for PoseMismatch {
+	async fn tick(
+		&mut self,
+		behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		behavior.set("pose", Pose)?;
+		let actual = match behavior.get::<i32>("pose") {
+			Err(PortError::TypeMismatch { actual, .. }) => actual.to_string(),
+			_ => String::new(),
+		};
+		behavior.set("actual", actual)?;
+		Ok(BehaviorState::Success)
+	}
+
+	fn provided_ports() -> PortList {
+		port_list![
+			inout_port!(Pose, "pose"),
+			output_port!(String, "actual")
+		]
+	}
+}
+
+const POSE_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<PoseMismatch pose="{pose}" actual="{actual}"/>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn type_mismatch_user_type() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, PoseMismatch, "PoseMismatch")?;
+	let mut tree = factory.create_from_text(POSE_TREE)?;
+	drop(factory);
+
+	assert_eq!(tree.tick_once().await?, BehaviorState::Success);
+	let actual = tree.blackboard().get::<String>("actual")?;
+	assert!(actual.ends_with("Pose"), "unexpected stored type {actual}");
+
+	Ok(())
+}
+
+/// Action incrementing port `value`, starting with 1 if it is not set
+#[derive(Action, Debug, Default)]
+struct Increment;
+
+#[async_trait::async_trait]
+impl Behavior for Increment {
+	async fn tick(
+		&mut self,
+		behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		let value = behavior.get::<i32>("value").unwrap_or_default();
+		behavior.set("value", value + 1)?;
+		Ok(BehaviorState::Success)
+	}
+
+	fn provided_ports() -> PortList {
+		port_list![inout_port!(i32, "value")]
+	}
+}
+
+const PREFIX_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence>
+			<SubTree ID="Counter" _prefix="left_" _autoremap="true"/>
+			<SubTree ID="Counter" _prefix="left_" _autoremap="true"/>
+			<SubTree ID="Counter" _prefix="right_" _autoremap="true"/>
+			<SubTree ID="Counter" _autoremap="true"/>
+			<Increment _prefix="node_" value="{@global}"/>
+			<SubTree ID="Counted" _prefix="mapped_" total="{sum}"/>
+		</Sequence>
+	</BehaviorTree>
+
+	<BehaviorTree ID="Counter">
+		<Increment value="{count}"/>
+	</BehaviorTree>
+
+	<BehaviorTree ID="Counted">
+		<Increment value="{total}"/>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn prefixed_subtrees() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, Increment, "Increment")?;
+	let mut tree = factory.create_from_text(PREFIX_TREE)?;
+	drop(factory);
+
+	assert_eq!(tree.tick_while_running().await?, BehaviorState::Success);
+
+	// instances with different prefixes share the board without collision
+	assert_eq!(tree.blackboard().get::<i32>("left_count")?, 2);
+	assert_eq!(tree.blackboard().get::<i32>("right_count")?, 1);
+	assert_eq!(tree.blackboard().get::<i32>("count")?, 1);
+	// global keys are not prefixed
+	assert_eq!(tree.blackboard().get::<i32>("@global")?, 1);
+	// explicit remappings into the parent are not prefixed
+	assert_eq!(tree.blackboard().get::<i32>("sum")?, 1);
+
+	Ok(())
+}
+
+/// Async function reading port `limit`
+fn below_limit(behavior: &mut BehaviorData) -> Pin<Box<dyn Future<Output = BehaviorResult> + Send + '_>> {
+	Box::pin(async move {
+		let limit = behavior.get::<i32>("limit")?;
+		if limit < 10 {
+			Ok(BehaviorState::Success)
+		} else {
+			Ok(BehaviorState::Failure)
+		}
+	})
+}
+
+const ASYNC_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence>
+			<BelowLimit limit="5"/>
+			<Inverter>
+				<BelowLimit limit="{limit}"/>
+			</Inverter>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn simple_async_function() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	factory.register_simple_async_function_with_ports(
+		"BelowLimit",
+		alloc::sync::Arc::new(below_limit),
+		BehaviorKind::Condition,
+		port_list![input_port!(i32, "limit")],
+	)?;
+	let mut tree = factory.create_from_text(ASYNC_TREE)?;
+	drop(factory);
+
+	tree.blackboard().set::<i32>("limit", 42)?;
+	assert_eq!(tree.tick_while_running().await?, BehaviorState::Success);
+
+	tree.reset()?;
+	tree.blackboard().set::<i32>("limit", 7)?;
+	assert_eq!(tree.tick_while_running().await?, BehaviorState::Failure);
+
+	Ok(())
+}
+
+/// Action modifying its inout ports by reference
+#[derive(Action, Debug, Default)]
+struct Accumulate;
+
+#[async_trait::async_trait]
+impl Behavior for Accumulate {
+	async fn tick(
+		&mut self,
+		behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		*behavior.get_mut_ref::<i32>("total")? += 1;
+		if behavior.get_mut_ref::<i32>("plain").is_ok() {
+			return Ok(BehaviorState::Failure);
+		}
+		Ok(BehaviorState::Success)
+	}
+
+	fn provided_ports() -> PortList {
+		port_list![
+			inout_port!(i32, "total", 10),
+			inout_port!(i32, "plain")
+		]
+	}
+}
+
+const DEFAULT_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence>
+			<Accumulate total="{sum}" plain="{other}"/>
+			<Accumulate total="{sum}" plain="{other}"/>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn get_mut_ref_default() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, Accumulate, "Accumulate")?;
+	let mut tree = factory.create_from_text(DEFAULT_TREE)?;
+	drop(factory);
+
+	// the first access creates the entry from the default, the second one uses it
+	assert_eq!(tree.tick_while_running().await?, BehaviorState::Success);
+	assert_eq!(tree.blackboard().get::<i32>("sum")?, 12);
+	// without default no entry is created
+	assert!(tree.blackboard().get::<i32>("other").is_err());
+
+	Ok(())
+}
+
+const SCOPED_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence>
+			<SetBlackboard name="main" output_key="{@value}" value="1"/>
+			<SubTree ID="Sub"/>
+		</Sequence>
+	</BehaviorTree>
+	<BehaviorTree ID="Sub">
+		<Sequence>
+			<SetBlackboard name="sub" output_key="{local}" value="{@value}"/>
+			<SubTree ID="SubSub"/>
+		</Sequence>
+	</BehaviorTree>
+	<BehaviorTree ID="SubSub">
+		<SetBlackboard name="sub_sub" output_key="{@value_sqr}" value="{local}"/>
+	</BehaviorTree>
+</root>
+"#;
+
+#[test]
+fn scoped_keys() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	let tree = factory.create_from_text(SCOPED_TREE)?;
+
+	let keys = |name: &str| {
+		tree.iter()
+			.find(|element| element.name().as_ref() == name)
+			.map(|element| (element.data().local_keys(), element.data().global_keys()))
+	};
+	let as_keys = |keys: &[&str]| {
+		keys.iter()
+			.map(|key| (*key).into())
+			.collect::<Vec<Arc<str>>>()
+	};
+
+	assert_eq!(keys("main"), Some((as_keys(&[]), as_keys(&["@value"]))));
+	assert_eq!(keys("sub"), Some((as_keys(&["local"]), as_keys(&["@value"]))));
+	assert_eq!(keys("sub_sub"), Some((as_keys(&["local"]), as_keys(&["@value_sqr"]))));
+
+	Ok(())
+}
+
+/// Action writing port `value` and its `square` in one batch
+#[derive(Action, Debug, Default)]
+struct PairWriter {
+	value: i64,
+}
+
+#[async_trait::async_trait]
+impl Behavior for PairWriter {
+	async fn tick(
+		&mut self,
+		behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		self.value += 1;
+		let value = self.value;
+		let broken = behavior.get::<bool>("broken").unwrap_or(false);
+		behavior.with_batch(|batch| {
+			batch.set("value", value);
+			if broken {
+				batch.set("square", String::from("not a number"));
+			} else {
+				batch.set("square", value * value);
+			}
+			Ok(())
+		})?;
+		Ok(BehaviorState::Success)
+	}
+
+	fn provided_ports() -> PortList {
+		port_list![
+			output_port!(i64, "value"),
+			output_port!(i64, "square"),
+			input_port!(bool, "broken")
+		]
+	}
+}
+
+const BATCH_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<PairWriter value="{value}" square="{square}" broken="{broken}"/>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn with_batch() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, PairWriter, "PairWriter")?;
+	let mut tree = factory.create_from_text(BATCH_TREE)?;
+	drop(factory);
+	tree.blackboard().set("value", 0_i64)?;
+	tree.blackboard().set("square", 0_i64)?;
+	tree.blackboard().set("broken", false)?;
+
+	let consistent = |tree: &BehaviorTree| -> Result<bool, Error> {
+		let value = tree.blackboard().get::<i64>("value")?;
+		Ok(tree.blackboard().get::<i64>("square")? == value * value)
+	};
+
+	assert_eq!(tree.tick_once().await?, BehaviorState::Success);
+	assert_eq!(tree.blackboard().get::<i64>("value")?, 1);
+	assert!(consistent(&tree)?);
+
+	// a failing write in the batch prevents all writes
+	tree.blackboard().set("broken", true)?;
+	assert!(tree.tick_once().await.is_err());
+	assert_eq!(tree.blackboard().get::<i64>("value")?, 1);
+	assert!(consistent(&tree)?);
+
+	tree.blackboard().set("broken", false)?;
+	assert_eq!(tree.tick_once().await?, BehaviorState::Success);
+	assert_eq!(tree.blackboard().get::<i64>("value")?, 3);
+	assert!(consistent(&tree)?);
+
+	Ok(())
+}
+
+const BATCH_SUBTREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<SubTree ID="Pair" value="{value}" square="42" broken="{broken}"/>
+	</BehaviorTree>
+	<BehaviorTree ID="Pair">
+		<PairWriter value="{value}" square="{square}" broken="{broken}"/>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn with_batch_invalid_key() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, PairWriter, "PairWriter")?;
+	let mut tree = factory.create_from_text(BATCH_SUBTREE)?;
+	drop(factory);
+	tree.blackboard().set("value", 0_i64)?;
+	tree.blackboard().set("broken", false)?;
+
+	// `square` is assigned a constant by the subtree, so the batch is rejected before any write
+	assert!(tree.tick_once().await.is_err());
+	assert_eq!(tree.blackboard().get::<i64>("value")?, 0);
+
+	Ok(())
+}
+
+/// Action picking a random number between 0 and 99 from a stream seeded by the behaviors seed
+#[derive(Action, Debug, Default)]
+struct RandomPick {
+	state: Option<u64>,
+}
+
+#[async_trait::async_trait]
+impl Behavior for RandomPick {
+	async fn tick(
+		&mut self,
+		behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		let Some(seed) = behavior.seed() else {
+			return Ok(BehaviorState::Failure);
+		};
+		// xorshift64, the state must not be zero
+		let mut state = self.state.unwrap_or(seed | 1);
+		state ^= state << 13;
+		state ^= state >> 7;
+		state ^= state << 17;
+		self.state = Some(state);
+		behavior.set("choice", state % 100)?;
+		Ok(BehaviorState::Success)
+	}
+
+	fn provided_ports() -> PortList {
+		port_list![output_port!(u64, "choice")]
+	}
+}
+
+const RANDOM_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence>
+			<RandomPick choice="{first}"/>
+			<RandomPick choice="{second}"/>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+async fn random_choices(seed: Option<u64>) -> Result<Vec<(u64, u64)>, Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, RandomPick, "RandomPick")?;
+	if let Some(seed) = seed {
+		factory.set_seed(seed);
+	}
+	let mut tree = factory.create_from_text(RANDOM_TREE)?;
+	drop(factory);
+	let mut choices = Vec::new();
+	for _ in 0..5 {
+		if tree.tick_once().await? != BehaviorState::Success {
+			return Ok(choices);
+		}
+		choices.push((
+			tree.blackboard().get::<u64>("first")?,
+			tree.blackboard().get::<u64>("second")?,
+		));
+	}
+	Ok(choices)
+}
+
+#[tokio::test]
+async fn seed() -> Result<(), Error> {
+	let choices = random_choices(Some(42)).await?;
+	assert_eq!(choices.len(), 5);
+	// same seed gives identical choices
+	assert_eq!(choices, random_choices(Some(42)).await?);
+	// each behavior has its own stream
+	assert!(
+		choices
+			.iter()
+			.any(|(first, second)| first != second)
+	);
+	// a different seed gives different choices
+	assert_ne!(choices, random_choices(Some(43)).await?);
+	// without a seed there is none
+	assert!(random_choices(None).await?.is_empty());
+
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, RandomPick, "RandomPick")?;
+	factory.set_seed(7);
+	let tree = factory.create_from_text(RANDOM_TREE)?;
+	assert_eq!(tree.seed(), Some(7));
+	Ok(())
+}
+
+/// Action writing to the declared global output port `@result`
+#[derive(Action, Debug, Default)]
+struct GlobalWriter;
+
+#[async_trait::async_trait]
+impl Behavior for GlobalWriter {
+	async fn tick(
+		&mut self,
+		behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		behavior.set("@result", 42_i64)?;
+		Ok(BehaviorState::Success)
+	}
+
+	fn provided_ports() -> PortList {
+		port_list![output_port!(i64, "@result")]
+	}
+}
+
+/// Action copying the declared global input port `@result` into port `copy`
+#[derive(Action, Debug, Default)]
+struct GlobalReader;
+
+#[async_trait::async_trait]
+impl Behavior for GlobalReader {
+	async fn tick(
+		&mut self,
+		behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		let value = behavior.get::<i64>("@result")?;
+		behavior.set("copy", value)?;
+		Ok(BehaviorState::Success)
+	}
+
+	fn provided_ports() -> PortList {
+		port_list![
+			input_port!(i64, "@result"),
+			output_port!(i64, "copy")
+		]
+	}
+}
+
+const GLOBAL_PORT_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence>
+			<SubTree ID="Sub"/>
+			<GlobalReader copy="{copy}"/>
+		</Sequence>
+	</BehaviorTree>
+	<BehaviorTree ID="Sub">
+		<SubTree ID="SubSub"/>
+	</BehaviorTree>
+	<BehaviorTree ID="SubSub">
+		<GlobalWriter _prefix="deep_"/>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn global_output_port() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, GlobalWriter, "GlobalWriter")?;
+	register_behavior!(factory, GlobalReader, "GlobalReader")?;
+	let mut tree = factory.create_from_text(GLOBAL_PORT_TREE)?;
+	drop(factory);
+
+	assert_eq!(tree.tick_once().await?, BehaviorState::Success);
+	assert_eq!(tree.blackboard().get::<i64>("@result")?, 42);
+	assert_eq!(tree.blackboard().get::<i64>("copy")?, 42);
+	Ok(())
+}
+
+/// Action writing its own uid and paths to output ports
+#[derive(Action, Debug, Default)]
+struct SelfIdentifying;
+
+#[async_trait::async_trait]
+impl Behavior for SelfIdentifying {
+	async fn tick(
+		&mut self,
+		behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		let uid = behavior.uid();
+		let path = behavior.path().to_string();
+		let groot2_path = behavior.groot2_path().to_string();
+		behavior.set("uid", uid)?;
+		behavior.set("path", path)?;
+		behavior.set("groot2_path", groot2_path)?;
+		Ok(BehaviorState::Success)
+	}
+
+	fn provided_ports() -> PortList {
+		port_list![
+			output_port!(u16, "uid"),
+			output_port!(String, "path"),
+			output_port!(String, "groot2_path")
+		]
+	}
+}
+
+const SELF_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence name="sequence">
+			<AlwaysSuccess/>
+			<SelfIdentifying name="me" uid="{uid}" path="{path}" groot2_path="{groot2_path}"/>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn own_uid_and_path() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, SelfIdentifying, "SelfIdentifying")?;
+	let mut tree = factory.create_from_text(SELF_TREE)?;
+	drop(factory);
+
+	assert_eq!(tree.tick_once().await?, BehaviorState::Success);
+	let Some(element) = tree
+		.iter()
+		.find(|element| element.name().as_ref() == "me")
+	else {
+		panic!("element 'me' not found");
+	};
+	assert_eq!(tree.blackboard().get::<u16>("uid")?, element.uid());
+	assert_eq!(tree.blackboard().get::<String>("path")?, element.full_path().as_ref());
+	assert_eq!(tree.blackboard().get::<String>("groot2_path")?, "/MainTree::0/sequence/me");
+	Ok(())
+}
+
+/// Action accessing its ports against their declared directions, selected by the `mode` argument
+#[derive(Action, Debug, Default)]
+struct DirectionViolator {
+	mode: u8,
+}
+
+impl DirectionViolator {
+	const fn new(mode: u8) -> Self {
+		Self { mode }
+	}
+}
+
+#[async_trait::async_trait]
+impl Behavior for DirectionViolator {
+	async fn tick(
+		&mut self,
+		behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		match self.mode {
+			// read an output port
+			1 => {
+				behavior.get::<i64>("output")?;
+			}
+			// read an output port by reference
+			2 => {
+				behavior.get_ref::<i64>("output")?;
+			}
+			// write an input port
+			3 => {
+				behavior.set("input", 1_i64)?;
+			}
+			// write an input port by reference
+			4 => {
+				behavior.get_mut_ref::<i64>("input")?;
+			}
+			// write an output and an input port in a batch
+			5 => {
+				behavior.with_batch(|batch| {
+					batch.set("output", 42_i64);
+					batch.set("input", 1_i64);
+					Ok(())
+				})?;
+			}
+			// read and write the bidirectional port
+			_ => {
+				let value = behavior.get::<i64>("inout")?;
+				behavior.set("inout", value + 1)?;
+				behavior.set_if_changed("output", value)?;
+			}
+		}
+		Ok(BehaviorState::Success)
+	}
+
+	fn provided_ports() -> PortList {
+		port_list![
+			input_port!(i64, "input"),
+			output_port!(i64, "output"),
+			inout_port!(i64, "inout")
+		]
+	}
+}
+
+const DIRECTION_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Violator input="{value}" output="{value}" inout="{value}"/>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+#[rstest]
+#[case(1, false)]
+#[case(2, false)]
+#[case(3, false)]
+#[case(4, false)]
+#[case(5, false)]
+#[case(0, true)]
+async fn strict_port_directions(#[case] mode: u8, #[case] allowed: bool) -> Result<(), Error> {
+	for strict in [false, true] {
+		let mut factory = BehaviorTreeFactory::new()?;
+		factory.set_options(TreeOptions::empty().with(TreeOptions::STRICT_PORT_DIRECTIONS, strict));
+		register_behavior!(factory, DirectionViolator, "Violator", mode)?;
+		let mut tree = factory.create_from_text(DIRECTION_TREE)?;
+		drop(factory);
+		tree.blackboard().set("value", 41_i64)?;
+
+		let result = tree.tick_once().await;
+		if allowed || !strict {
+			assert_eq!(result?, BehaviorState::Success);
+		} else {
+			let Err(error) = result else {
+				panic!("direction violation with mode {mode} not detected");
+			};
+			assert!(error.to_string().contains("can not be"));
+			// a rejected batch writes nothing
+			assert_eq!(tree.blackboard().get::<i64>("value")?, 41);
+		}
+	}
+	Ok(())
+}
+
+/// Action copying port `value` to port `copy`
+#[derive(Action, Debug, Default)]
+struct CopyValue;
+
+#[async_trait::async_trait]
+impl Behavior for CopyValue {
+	async fn tick(
+		&mut self,
+		behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		let value = behavior.get::<i64>("value")?;
+		behavior.set("copy", value)?;
+		Ok(BehaviorState::Success)
+	}
+
+	fn provided_ports() -> PortList {
+		port_list![
+			input_port!(i64, "value"),
+			output_port!(i64, "copy")
+		]
+	}
+}
+
+const REMAPPING_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<CopyValue name="copy" value="{a}" copy="{copy}"/>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn set_remapping() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, CopyValue, "CopyValue")?;
+	let mut tree = factory.create_from_text(REMAPPING_TREE)?;
+	drop(factory);
+	tree.blackboard().set("a", 1_i64)?;
+	tree.blackboard().set("b", 2_i64)?;
+
+	// repeated reads of the same port
+	for value in [1_i64, 3] {
+		tree.blackboard().set("a", value)?;
+		tree.reset()?;
+		assert_eq!(tree.tick_once().await?, BehaviorState::Success);
+		assert_eq!(tree.blackboard().get::<i64>("copy")?, value);
+	}
+
+	// a changed remapping target is used immediately
+	for target in ["{b}", "42", "{a}"] {
+		let Some(element) = tree
+			.iter_mut()
+			.find(|element| element.name().as_ref() == "copy")
+		else {
+			panic!("element 'copy' not found");
+		};
+		element.data_mut().set_remapping("value", target);
+		tree.reset()?;
+		assert_eq!(tree.tick_once().await?, BehaviorState::Success);
+		let expected = match target {
+			"{b}" => 2,
+			"42" => 42,
+			_ => 3,
+		};
+		assert_eq!(tree.blackboard().get::<i64>("copy")?, expected);
+	}
+	Ok(())
+}
+
+/// Action reading the bool ports `literal` and `remapped` into port `both`
+#[derive(Action, Debug, Default)]
+struct ReadFlags;
+
+#[async_trait::async_trait]
+impl Behavior for ReadFlags {
+	async fn tick(
+		&mut self,
+		behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		let literal = behavior.get::<bool>("literal")?;
+		let remapped = behavior.get::<bool>("remapped")?;
+		behavior.set("both", literal && remapped)?;
+		Ok(BehaviorState::Success)
+	}
+
+	fn provided_ports() -> PortList {
+		port_list![
+			input_port!(bool, "literal"),
+			input_port!(bool, "remapped"),
+			output_port!(bool, "both")
+		]
+	}
+}
+
+const FLAGS_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<ReadFlags literal="1" remapped="{flag}" both="{both}"/>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn lenient_bools() -> Result<(), Error> {
+	for lenient in [false, true] {
+		let mut factory = BehaviorTreeFactory::new()?;
+		factory.set_lenient_bools(lenient);
+		register_behavior!(factory, ReadFlags, "ReadFlags")?;
+		let mut tree = factory.create_from_text(FLAGS_TREE)?;
+		drop(factory);
+		tree.blackboard()
+			.set("flag", String::from("YES"))?;
+
+		let result = tree.tick_once().await;
+		if lenient {
+			assert_eq!(result?, BehaviorState::Success);
+			assert!(tree.blackboard().get::<bool>("both")?);
+		} else {
+			assert!(result.is_err());
+		}
+	}
+	Ok(())
+}
@@ -1,6 +1,8 @@
 // Copyright © 2025 Stephan Kunz
 //! Behavior tests
 
+#![allow(clippy::panic)]
+
 /// Test of the built-in `Action` behaviors
 mod actions;
 /// Test of the built-in `Condition` behaviors
@@ -0,0 +1,12 @@
+// Copyright © 2025 Stephan Kunz
+
+//! Tests compile time checks
+
+#[test]
+fn compile_time_checks() {
+	let t = trybuild::TestCases::new();
+	t.pass("tests/compile/01-port-name.rs");
+	t.compile_fail("tests/compile/02-wrong-port-name.rs");
+	t.compile_fail("tests/compile/03-not-send.rs");
+	t.pass("tests/compile/04-register-behaviors.rs");
+}
@@ -0,0 +1,21 @@
+// Copyright © 2025 Stephan Kunz
+
+//! Test correct usage of compile time checked port names
+
+#[doc(hidden)]
+extern crate alloc;
+
+use behaviortree::prelude::*;
+
+const VALUE: &str = "value";
+
+fn main() {
+	let ports = port_list![
+		const_port!(In, i32, VALUE),
+		const_port!(In, i32, "@global", 42),
+		const_port!(InOut, String, "text", "hello", "A text."),
+		const_port!(Out, bool, "flag"),
+	];
+	assert_eq!(ports.len(), 4);
+	assert_eq!(port_name!("other"), "other");
+}
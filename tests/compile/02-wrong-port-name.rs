@@ -0,0 +1,12 @@
+// Copyright © 2025 Stephan Kunz
+
+//! Test compile time rejection of forbidden port names
+
+#[doc(hidden)]
+extern crate alloc;
+
+use behaviortree::prelude::*;
+
+fn main() {
+	let _ports = port_list![const_port!(In, i32, "name")];
+}
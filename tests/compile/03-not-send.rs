@@ -0,0 +1,28 @@
+// Copyright © 2025 Stephan Kunz
+
+//! Test compile time rejection of behaviors, which are not `Send`
+
+#[doc(hidden)]
+extern crate alloc;
+
+use behaviortree::prelude::*;
+use std::rc::Rc;
+
+#[derive(Action, Debug, Default)]
+struct NotSend {
+	counter: Rc<u32>,
+}
+
+#[async_trait::async_trait]
+impl Behavior for NotSend {
+	async fn tick(
+		&mut self,
+		_behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		Ok(BehaviorState::Success)
+	}
+}
+
+fn main() {}
@@ -0,0 +1,54 @@
+// Copyright © 2025 Stephan Kunz
+
+//! Test registering a batch of behaviors with one macro invocation
+
+#[doc(hidden)]
+extern crate alloc;
+
+use behaviortree::prelude::*;
+
+#[derive(Action, Debug, Default)]
+struct Succeed {
+	value: i32,
+}
+
+impl Succeed {
+	const fn new(value: i32) -> Self {
+		Self { value }
+	}
+}
+
+#[async_trait::async_trait]
+impl Behavior for Succeed {
+	async fn tick(
+		&mut self,
+		_behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		if self.value >= 0 {
+			Ok(BehaviorState::Success)
+		} else {
+			Ok(BehaviorState::Failure)
+		}
+	}
+}
+
+fn main() -> Result<(), behaviortree::factory::error::Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behaviors!(factory,
+		Succeed => "First",
+		Succeed => "Second",
+		Succeed => "Third" [3],
+		Succeed => "Fourth" [-4],
+		Succeed => "Fifth",
+	)?;
+
+	let registry = factory.registry();
+	for name in ["First", "Second", "Third", "Fourth", "Fifth"] {
+		assert!(registry.behavior_description(name).is_some());
+	}
+	assert!(register_behaviors!(factory, Succeed => "First").is_err());
+
+	Ok(())
+}
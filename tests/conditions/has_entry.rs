@@ -0,0 +1,120 @@
+// Copyright © 2025 Stephan Kunz
+
+//! Tests the [`HasEntry`] condition
+
+extern crate alloc;
+
+use behaviortree::prelude::*;
+
+const TREE_DEFINITION: &str = r#"
+<root BTCPP_format="4"
+		main_tree_to_execute="MainTree">
+	<BehaviorTree ID="MainTree">
+		<HasEntry name="has_entry" entry="test"/>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn has_entry() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+
+	let mut tree = factory.create_from_text(TREE_DEFINITION)?;
+	drop(factory);
+
+	let mut result = tree.tick_once().await?;
+	assert_eq!(result, BehaviorState::Failure);
+	tree.blackboard().set("test", 1)?;
+	result = tree.tick_once().await?;
+	assert_eq!(result, BehaviorState::Success);
+	tree.blackboard().delete::<i32>("test")?;
+	result = tree.tick_once().await?;
+	assert_eq!(result, BehaviorState::Failure);
+
+	Ok(())
+}
+
+const TYPED_TREE_DEFINITION: &str = r#"
+<root BTCPP_format="4"
+		main_tree_to_execute="MainTree">
+	<BehaviorTree ID="MainTree">
+		<Sequence>
+			<HasEntry entry="number" type="int"/>
+			<HasEntry entry="text" type="string"/>
+			<Inverter>
+				<HasEntry entry="number" type="double"/>
+			</Inverter>
+			<Inverter>
+				<HasEntry entry="missing" type="bool"/>
+			</Inverter>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+const INVALID_TYPE_TREE_DEFINITION: &str = r#"
+<root BTCPP_format="4"
+		main_tree_to_execute="MainTree">
+	<BehaviorTree ID="MainTree">
+		<HasEntry entry="number" type="float"/>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn has_typed_entry() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+
+	let mut tree = factory.create_from_text(TYPED_TREE_DEFINITION)?;
+	tree.blackboard().set("number", 42_i32)?;
+	let mut result = tree.tick_once().await?;
+	assert_eq!(result, BehaviorState::Failure);
+	tree.blackboard()
+		.set("text", String::from("hello"))?;
+	result = tree.tick_once().await?;
+	assert_eq!(result, BehaviorState::Success);
+
+	factory.clear_registered_behavior_trees();
+	let mut tree = factory.create_from_text(INVALID_TYPE_TREE_DEFINITION)?;
+	tree.blackboard().set("number", 42_i32)?;
+	assert!(tree.tick_once().await.is_err());
+
+	Ok(())
+}
+
+const GLOBAL_TREE_DEFINITION: &str = r#"
+<root BTCPP_format="4"
+		main_tree_to_execute="MainTree">
+	<BehaviorTree ID="MainTree">
+		<SubTree ID="SubTree"/>
+	</BehaviorTree>
+
+	<BehaviorTree ID="SubTree">
+		<Sequence>
+			<HasEntry entry="@global"/>
+			<HasEntry entry="@global" type="bool"/>
+			<Inverter>
+				<HasEntry entry="@missing"/>
+			</Inverter>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn has_global_entry() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	factory.register_behavior_tree_from_text(GLOBAL_TREE_DEFINITION)?;
+
+	let root_blackboard = Databoard::new();
+	let mut tree = factory.create_tree_with("MainTree", &root_blackboard)?;
+	drop(factory);
+
+	let mut result = tree.tick_once().await?;
+	assert_eq!(result, BehaviorState::Failure);
+	root_blackboard.set("global", true)?;
+	result = tree.tick_once().await?;
+	assert_eq!(result, BehaviorState::Success);
+
+	Ok(())
+}
@@ -1,5 +1,6 @@
 // Copyright © 2025 Stephan Kunz
 //! Test builtin `Condition` behaviors.
 
+mod has_entry;
 mod script_condition;
 mod was_entry_updated;
@@ -4,6 +4,7 @@
 extern crate alloc;
 
 use behaviortree::prelude::*;
+use tinyscript::{DefaultEnvironment, Environment, Runtime, ScriptingValue};
 
 const XML: &str = r#"
 <root BTCPP_format="4">
@@ -48,3 +49,79 @@ async fn script_condition() -> Result<(), Error> {
 
 	Ok(())
 }
+
+const REASON_XML: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<ScriptCondition code="value == 42" reason="{reason}"/>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn script_condition_reason() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut tree = factory.create_from_text(REASON_XML)?;
+	drop(factory);
+
+	tree.blackboard().set::<i32>("value", 41)?;
+	let result = tree.tick_while_running().await?;
+	assert_eq!(result, BehaviorState::Failure);
+	assert_eq!(
+		tree.blackboard().get::<String>("reason")?,
+		String::from("script 'value == 42' returned false")
+	);
+
+	Ok(())
+}
+
+/// Evaluates `code` by parsing and executing it in one step.
+fn evaluate(runtime: &mut Runtime, code: &str, env: &mut DefaultEnvironment) -> Result<bool, BehaviorError> {
+	Ok(bool::try_from(runtime.run(code, env)?)?)
+}
+
+const COMPILED_XML: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<ScriptCondition code="{code}"/>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn script_condition_compiled() -> Result<(), Error> {
+	const CODES: [&str; 4] = [
+		"value == 42",
+		"value > 40 && value < 42",
+		"value * 2 == 84",
+		"(value - 2) / 2 == 20",
+	];
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut tree = factory.create_from_text(COMPILED_XML)?;
+	drop(factory);
+
+	// the compiled script gives the same results as the re-parsed script,
+	// also when the values or the code change between ticks
+	let mut reference = Runtime::default();
+	for code in CODES {
+		tree.blackboard()
+			.set::<String>("code", code.into())?;
+		for value in [41_i64, 42] {
+			tree.blackboard().set::<i64>("value", value)?;
+			let mut env = DefaultEnvironment::default();
+			assert!(
+				env.define_env("value", ScriptingValue::Int64(value))
+					.is_ok()
+			);
+			let expected = if evaluate(&mut reference, code, &mut env)? {
+				BehaviorState::Success
+			} else {
+				BehaviorState::Failure
+			};
+			tree.reset()?;
+			assert_eq!(tree.tick_once().await?, expected);
+		}
+	}
+
+	Ok(())
+}
@@ -66,3 +66,33 @@ async fn was_entry_updated_error() -> Result<(), Error> {
 
 	Ok(())
 }
+
+const REASON_TREE_DEFINITION: &str = r#"
+<root BTCPP_format="4"
+		main_tree_to_execute="MainTree">
+	<BehaviorTree ID="MainTree">
+		<WasEntryUpdated name="was_entry_updated" entry="test" reason="{reason}"/>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn was_entry_updated_reason() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+
+	let mut tree = factory.create_from_text(REASON_TREE_DEFINITION)?;
+	drop(factory);
+
+	tree.blackboard().set("test", 1)?;
+	let mut result = tree.tick_once().await?;
+	assert_eq!(result, BehaviorState::Success);
+	assert!(!tree.blackboard().contains_key("reason"));
+	result = tree.tick_once().await?;
+	assert_eq!(result, BehaviorState::Failure);
+	assert_eq!(
+		tree.blackboard().get::<String>("reason")?,
+		String::from("entry 'test' was not updated")
+	);
+
+	Ok(())
+}
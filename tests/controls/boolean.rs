@@ -0,0 +1,125 @@
+// Copyright © 2025 Stephan Kunz
+
+//! Tests the [`And`](behaviortree::behavior::control::And) and [`Or`](behaviortree::behavior::control::Or) behaviors
+
+extern crate alloc;
+
+use behaviortree::{XmlError, factory::error::Error as FactoryError, prelude::*};
+use rstest::rstest;
+
+/// Creates a tree evaluating `a && (b || c)`.
+fn boolean_tree(a: bool, b: bool, c: bool) -> String {
+	format!(
+		r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<And>
+			<ScriptCondition code="{a}"/>
+			<Or>
+				<ScriptCondition code="{b}"/>
+				<ScriptCondition code="{c}"/>
+			</Or>
+		</And>
+	</BehaviorTree>
+</root>
+"#
+	)
+}
+
+#[tokio::test]
+#[rstest]
+#[case(false, false, false)]
+#[case(false, true, true)]
+#[case(true, false, false)]
+#[case(true, false, true)]
+#[case(true, true, false)]
+#[case(true, true, true)]
+async fn and_or(#[case] a: bool, #[case] b: bool, #[case] c: bool) -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut tree = factory.create_from_text(&boolean_tree(a, b, c))?;
+	drop(factory);
+
+	let expected = if a && (b || c) {
+		BehaviorState::Success
+	} else {
+		BehaviorState::Failure
+	};
+	// the result is available within a single tick
+	assert_eq!(tree.tick_once().await?, expected);
+	// the children of `And` are reset after each evaluation
+	assert!(
+		tree.iter()
+			.filter(|element| element.uid() > 1)
+			.all(|element| element.state() == BehaviorState::Idle)
+	);
+	assert_eq!(tree.tick_once().await?, expected);
+	Ok(())
+}
+
+const RUNNING_CHILD: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Or>
+			<ScriptCondition code="false"/>
+			<AlwaysRunning name="running"/>
+		</Or>
+	</BehaviorTree>
+</root>
+"#;
+
+const NESTED_SEQUENCE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<And>
+			<Sequence name="sequence">
+				<ScriptCondition code="true"/>
+			</Sequence>
+		</And>
+	</BehaviorTree>
+</root>
+"#;
+
+#[rstest]
+#[case(RUNNING_CHILD, "running")]
+#[case(NESTED_SEQUENCE, "sequence")]
+fn running_child_rejected(#[case] xml: &str, #[case] rejected: &str) -> Result<(), FactoryError> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	let Err(FactoryError::Create { source, .. }) = factory.create_from_text(xml) else {
+		panic!("a child of a boolean control, which may return Running, must be rejected");
+	};
+	let XmlError::NonBooleanChild { child, .. } = *source else {
+		panic!("unexpected error {source}");
+	};
+	assert_eq!(child.as_ref(), rejected);
+	Ok(())
+}
+
+const FAILING_CHILD: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<And>
+			<ScriptCondition code="true"/>
+			<Or>
+				<ScriptCondition code="false"/>
+				<ScriptCondition code="missing == 1"/>
+			</Or>
+		</And>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn failing_child_resets() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut tree = factory.create_from_text(FAILING_CHILD)?;
+	drop(factory);
+
+	// the error is propagated after all children are reset
+	assert!(tree.tick_once().await.is_err());
+	assert!(
+		tree.iter()
+			.filter(|element| element.uid() > 1)
+			.all(|element| element.state() == BehaviorState::Idle)
+	);
+	Ok(())
+}
@@ -4,7 +4,7 @@
 
 extern crate alloc;
 
-use crate::controls::utilities::ChangeStateAfter;
+use crate::controls::utilities::{ChangeStateAfter, StateList};
 use behaviortree::{
 	behavior::{BehaviorState::*, MockBehavior, MockBehaviorConfig},
 	prelude::*,
@@ -108,6 +108,33 @@ async fn simple_fallback_raw() -> Result<(), Error> {
 	Ok(())
 }
 
+const HALT_DEFINITION: &str = r#"
+<root BTCPP_format="4"
+		main_tree_to_execute="MainTree">
+	<BehaviorTree ID="MainTree">
+		<Fallback name="halted_fallback">
+			<Check name="check"/>
+			<AlwaysRunning name="action"/>
+		</Fallback>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn fallback_restarts_after_halt() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, StateList, "Check", &[Failure, Success])?;
+	let mut tree = factory.create_from_text(HALT_DEFINITION)?;
+	drop(factory);
+
+	assert_eq!(tree.tick_once().await?, Running);
+	tree.reset()?;
+	// after halting, the fallback starts again with the first child
+	assert_eq!(tree.tick_once().await?, Success);
+
+	Ok(())
+}
+
 const TREE_DEFINITION: &str = r#"
 <root BTCPP_format="4"
 		main_tree_to_execute="MainTree">
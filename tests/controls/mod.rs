@@ -7,12 +7,15 @@ mod utilities;
 
 mod async_fallback;
 mod async_sequence;
+mod boolean;
 mod fallback;
 mod if_then_else;
 mod parallel;
 mod parallel_all;
+mod parallel_timeout;
 mod reactive_fallback;
 mod reactive_sequence;
+mod retry_fallback;
 mod sequence;
 mod sequence_with_memory;
 mod switch;
@@ -295,3 +295,90 @@ async fn simple_parallel_reactiveness2(
 
 	Ok(())
 }
+
+const CHILD_STATES_DEFINITION: &str = r#"
+<root BTCPP_format="4"
+		main_tree_to_execute="MainTree">
+	<BehaviorTree ID="MainTree">
+		<Parallel child_states="{states}">
+			<Behavior1	name="step1"/>
+			<Behavior2	name="step2"/>
+			<Behavior3	name="step3"/>
+		</Parallel>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+#[rstest]
+#[case(Success, Running, Failure, "Success;Running;Failure")]
+#[case(Failure, Success, Success, "Failure;Success;Success")]
+#[case(Success, Success, Running, "Success;Success;Running")]
+async fn parallel_child_states(
+	#[case] input1: BehaviorState,
+	#[case] input2: BehaviorState,
+	#[case] input3: BehaviorState,
+	#[case] expected: &str,
+) -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, ChangeStateAfter, "Behavior1", BehaviorState::Running, input1, 0)?;
+	register_behavior!(factory, ChangeStateAfter, "Behavior2", BehaviorState::Running, input2, 0)?;
+	register_behavior!(factory, ChangeStateAfter, "Behavior3", BehaviorState::Running, input3, 0)?;
+
+	let mut tree = factory.create_from_text(CHILD_STATES_DEFINITION)?;
+	drop(factory);
+
+	tree.tick_once().await?;
+	assert_eq!(tree.blackboard().get::<String>("states")?, expected);
+
+	Ok(())
+}
+
+const THRESHOLD_DEFINITION: &str = r#"
+<root BTCPP_format="4"
+		main_tree_to_execute="MainTree">
+	<BehaviorTree ID="MainTree">
+		<Parallel {THRESHOLDS}>
+			<Behavior1	name="step1"/>
+			<Behavior2	name="step2"/>
+			<Behavior3	name="step3"/>
+		</Parallel>
+	</BehaviorTree>
+</root>
+"#;
+
+/// `Skipped` children count neither as success nor as failure
+#[tokio::test]
+#[rstest]
+#[case("success_count=\"2\"", Skipped, Success, Success, Success)]
+#[case("success_count=\"2\"", Skipped, Success, Failure, Failure)]
+#[case("success_count=\"2\"", Skipped, Skipped, Success, Failure)]
+#[case("success_count=\"2\"", Skipped, Skipped, Skipped, Skipped)]
+#[case("failure_count=\"1\"", Skipped, Failure, Success, Success)]
+#[case("failure_count=\"1\"", Skipped, Failure, Failure, Failure)]
+#[case("failure_count=\"1\"", Skipped, Skipped, Failure, Success)]
+#[case("failure_count=\"1\"", Skipped, Skipped, Skipped, Skipped)]
+#[case("success_count=\"1\" failure_count=\"1\"", Skipped, Failure, Success, Success)]
+#[case("success_count=\"1\" failure_count=\"1\"", Skipped, Skipped, Failure, Failure)]
+#[case("success_count=\"1\" failure_count=\"1\"", Skipped, Running, Success, Running)]
+async fn parallel_skipped_thresholds(
+	#[case] thresholds: &str,
+	#[case] input1: BehaviorState,
+	#[case] input2: BehaviorState,
+	#[case] input3: BehaviorState,
+	#[case] expected: BehaviorState,
+) -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, ChangeStateAfter, "Behavior1", BehaviorState::Running, input1, 0)?;
+	register_behavior!(factory, ChangeStateAfter, "Behavior2", BehaviorState::Running, input2, 0)?;
+	register_behavior!(factory, ChangeStateAfter, "Behavior3", BehaviorState::Running, input3, 0)?;
+
+	let mut tree = factory.create_from_text(&THRESHOLD_DEFINITION.replace("{THRESHOLDS}", thresholds))?;
+	drop(factory);
+
+	let mut result = tree.tick_once().await?;
+	assert_eq!(result, expected);
+	result = tree.tick_once().await?;
+	assert_eq!(result, expected);
+	Ok(())
+}
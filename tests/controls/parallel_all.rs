@@ -297,3 +297,83 @@ async fn parallel_all_reactiveness2(
 
 	Ok(())
 }
+
+const CHILD_STATES_DEFINITION: &str = r#"
+<root BTCPP_format="4"
+		main_tree_to_execute="MainTree">
+	<BehaviorTree ID="MainTree">
+		<ParallelAll child_states="{states}">
+			<Behavior1	name="step1"/>
+			<Behavior2	name="step2"/>
+			<Behavior3	name="step3"/>
+		</ParallelAll>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+#[rstest]
+#[case(Success, Running, Failure, "Success;Running;Failure")]
+#[case(Failure, Success, Success, "Failure;Success;Success")]
+#[case(Success, Success, Running, "Success;Success;Running")]
+async fn parallel_all_child_states(
+	#[case] input1: BehaviorState,
+	#[case] input2: BehaviorState,
+	#[case] input3: BehaviorState,
+	#[case] expected: &str,
+) -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, ChangeStateAfter, "Behavior1", BehaviorState::Running, input1, 0)?;
+	register_behavior!(factory, ChangeStateAfter, "Behavior2", BehaviorState::Running, input2, 0)?;
+	register_behavior!(factory, ChangeStateAfter, "Behavior3", BehaviorState::Running, input3, 0)?;
+
+	let mut tree = factory.create_from_text(CHILD_STATES_DEFINITION)?;
+	drop(factory);
+
+	tree.tick_once().await?;
+	assert_eq!(tree.blackboard().get::<String>("states")?, expected);
+
+	Ok(())
+}
+
+const MAX_FAILURES_DEFINITION: &str = r#"
+<root BTCPP_format="4"
+		main_tree_to_execute="MainTree">
+	<BehaviorTree ID="MainTree">
+		<ParallelAll max_failures="1">
+			<Behavior1	name="step1"/>
+			<Behavior2	name="step2"/>
+			<Behavior3	name="step3"/>
+		</ParallelAll>
+	</BehaviorTree>
+</root>
+"#;
+
+/// `Skipped` children do not count as failures
+#[tokio::test]
+#[rstest]
+#[case(Skipped, Failure, Success, Success)]
+#[case(Skipped, Failure, Failure, Failure)]
+#[case(Skipped, Skipped, Failure, Success)]
+#[case(Skipped, Skipped, Skipped, Skipped)]
+#[case(Skipped, Running, Failure, Running)]
+async fn parallel_all_skipped_max_failures(
+	#[case] input1: BehaviorState,
+	#[case] input2: BehaviorState,
+	#[case] input3: BehaviorState,
+	#[case] expected: BehaviorState,
+) -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, ChangeStateAfter, "Behavior1", BehaviorState::Running, input1, 0)?;
+	register_behavior!(factory, ChangeStateAfter, "Behavior2", BehaviorState::Running, input2, 0)?;
+	register_behavior!(factory, ChangeStateAfter, "Behavior3", BehaviorState::Running, input3, 0)?;
+
+	let mut tree = factory.create_from_text(MAX_FAILURES_DEFINITION)?;
+	drop(factory);
+
+	let mut result = tree.tick_once().await?;
+	assert_eq!(result, expected);
+	result = tree.tick_once().await?;
+	assert_eq!(result, expected);
+	Ok(())
+}
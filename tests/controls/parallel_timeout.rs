@@ -0,0 +1,105 @@
+// Copyright © 2025 Stephan Kunz
+
+//! Tests the [`ParallelTimeout`] behavior
+
+extern crate alloc;
+
+use core::time::Duration;
+
+use crate::controls::utilities::ChangeStateAfter;
+use behaviortree::{behavior::BehaviorState::*, prelude::*};
+use rstest::rstest;
+
+const PARALLEL_TIMEOUT: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<ParallelTimeout success_count="{=}" failure_count="1" timeouts="{=}">
+			<AlwaysRunning/>
+			<Finishing/>
+		</ParallelTimeout>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+#[rstest]
+#[case::one_success("10;", 1, Success)]
+#[case::two_successes("10;", 2, Failure)]
+#[case::tolerant_second("10;10000", 1, Success)]
+async fn parallel_timeout(
+	#[case] timeouts: &str,
+	#[case] success_count: i32,
+	#[case] expected: BehaviorState,
+) -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, ChangeStateAfter, "Finishing", Running, Success, 3)?;
+	let mut tree = factory.create_from_text(PARALLEL_TIMEOUT)?;
+	drop(factory);
+	tree.blackboard()
+		.set("timeouts", String::from(timeouts))?;
+	tree.blackboard()
+		.set("success_count", success_count)?;
+
+	// the first child times out, the second one finishes after its timeout
+	let mut result = tree.tick_once().await?;
+	for _ in 0..10 {
+		if result != Running {
+			break;
+		}
+		tokio::time::sleep(Duration::from_millis(10)).await;
+		result = tree.tick_once().await?;
+	}
+	assert_eq!(result, expected);
+	Ok(())
+}
+
+#[tokio::test]
+async fn parallel_timeout_default() -> Result<(), Error> {
+	let xml = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<ParallelTimeout failure_count="1" timeout="10">
+			<AlwaysRunning/>
+			<AlwaysRunning/>
+		</ParallelTimeout>
+	</BehaviorTree>
+</root>
+"#;
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut tree = factory.create_from_text(xml)?;
+	drop(factory);
+
+	assert_eq!(tree.tick_once().await?, Running);
+	tokio::time::sleep(Duration::from_millis(20)).await;
+	// both children time out
+	assert_eq!(tree.tick_once().await?, Failure);
+	Ok(())
+}
+
+#[tokio::test]
+async fn parallel_timeout_errors() -> Result<(), Error> {
+	let xml = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<ParallelTimeout timeouts="{=}">
+			<AlwaysRunning/>
+		</ParallelTimeout>
+	</BehaviorTree>
+</root>
+"#;
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut tree = factory.create_from_text(xml)?;
+	drop(factory);
+
+	// more timeouts than children
+	tree.blackboard()
+		.set("timeouts", String::from("10;10"))?;
+	assert!(tree.tick_once().await.is_err());
+
+	// not a number
+	tree.reset()?;
+	tree.blackboard()
+		.set("timeouts", String::from("ten"))?;
+	assert!(tree.tick_once().await.is_err());
+	Ok(())
+}
@@ -4,8 +4,9 @@
 
 extern crate alloc;
 
-use crate::controls::utilities::ChangeStateAfter;
+use crate::controls::utilities::{ChangeStateAfter, StateList};
 use behaviortree::{
+	BehaviorTreeElement,
 	behavior::{BehaviorState::*, MockBehavior, MockBehaviorConfig},
 	prelude::*,
 };
@@ -290,3 +291,135 @@ async fn reactive_sequence_reactiveness2(
 
 	Ok(())
 }
+
+const PRESERVE_TREE_DEFINITION: &str = r#"
+<root BTCPP_format="4"
+		main_tree_to_execute="MainTree">
+	<BehaviorTree ID="MainTree">
+		<ReactiveSequence name="reactive_sequence" preserve_running_tail="{preserve}">
+			<Behavior1	name="step1"/>
+			<Behavior2	name="step2"/>
+			<Behavior3	name="step3"/>
+		</ReactiveSequence>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+#[rstest]
+// the running 'step2' is halted and restarted
+#[case(false, Running, Running, Running, Running)]
+// the running 'step2' continues
+#[case(true, Running, Running, Running, Success)]
+async fn reactive_sequence_preserve_running_tail(
+	#[case] preserve: bool,
+	#[case] expected1: BehaviorState,
+	#[case] expected2: BehaviorState,
+	#[case] expected3: BehaviorState,
+	#[case] expected4: BehaviorState,
+) -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, StateList, "Behavior1", &[Success, Running, Success])?;
+	register_behavior!(factory, ChangeStateAfter, "Behavior2", Running, Success, 2)?;
+	register_behavior!(factory, ChangeStateAfter, "Behavior3", Running, Success, 0)?;
+
+	let mut tree = factory.create_from_text(PRESERVE_TREE_DEFINITION)?;
+	drop(factory);
+	tree.blackboard().set("preserve", preserve)?;
+
+	let mut result = tree.tick_once().await?;
+	assert_eq!(result, expected1);
+	result = tree.tick_once().await?;
+	assert_eq!(result, expected2);
+	result = tree.tick_once().await?;
+	assert_eq!(result, expected3);
+	result = tree.tick_once().await?;
+	assert_eq!(result, expected4);
+
+	Ok(())
+}
+
+const HALT_TREE_DEFINITION: &str = r#"
+<root BTCPP_format="4"
+		main_tree_to_execute="MainTree">
+	<BehaviorTree ID="MainTree">
+		<ReactiveSequence name="reactive_sequence">
+			<Behavior1	name="step1"/>
+			<Behavior2	name="step2"/>
+		</ReactiveSequence>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn reactive_sequence_halts_running_child() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, StateList, "Behavior1", &[Success, Running])?;
+	register_behavior!(factory, ChangeStateAfter, "Behavior2", Running, Success, 5)?;
+
+	let mut tree = factory.create_from_text(HALT_TREE_DEFINITION)?;
+	drop(factory);
+	let step2_state = |tree: &BehaviorTree| {
+		tree.iter()
+			.find(|element| element.name().as_ref() == "step2")
+			.map(BehaviorTreeElement::state)
+	};
+
+	assert_eq!(tree.tick_once().await?, Running);
+	assert_eq!(step2_state(&tree), Some(Running));
+
+	// the running 'step2' itself is halted, not only its children
+	assert_eq!(tree.tick_once().await?, Running);
+	assert_eq!(step2_state(&tree), Some(Idle));
+
+	Ok(())
+}
+
+const CONTROLS: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence name="sequence">
+			<ReactiveSequence name="reactive_sequence">
+				<AlwaysSuccess/>
+			</ReactiveSequence>
+			<ReactiveFallback name="reactive_fallback">
+				<AlwaysSuccess/>
+			</ReactiveFallback>
+			<WhileDoElse name="while_do_else">
+				<AlwaysSuccess/>
+				<AlwaysSuccess/>
+			</WhileDoElse>
+			<Fallback name="fallback">
+				<AlwaysSuccess/>
+			</Fallback>
+			<SequenceWithMemory name="sequence_with_memory">
+				<AlwaysSuccess/>
+			</SequenceWithMemory>
+			<Parallel name="parallel">
+				<AlwaysSuccess/>
+			</Parallel>
+			<AlwaysSuccess name="action"/>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+#[rstest]
+#[case("reactive_sequence", true)]
+#[case("reactive_fallback", true)]
+#[case("while_do_else", true)]
+#[case("sequence", false)]
+#[case("fallback", false)]
+#[case("sequence_with_memory", false)]
+#[case("parallel", false)]
+#[case("action", false)]
+fn is_reactive(#[case] name: &str, #[case] expected: bool) -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	let tree = factory.create_from_text(CONTROLS)?;
+	let reactive = tree
+		.iter()
+		.find(|element| element.name().as_ref() == name)
+		.map(|element| element.behavior().is_reactive());
+	assert_eq!(reactive, Some(expected));
+	Ok(())
+}
@@ -0,0 +1,72 @@
+// Copyright © 2025 Stephan Kunz
+
+//! Tests the [`RetryFallback`] behavior
+
+extern crate alloc;
+
+use core::time::Duration;
+
+use crate::controls::utilities::StateList;
+use behaviortree::{behavior::BehaviorState::*, prelude::*};
+use rstest::rstest;
+
+const RETRY_FALLBACK: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<RetryFallback num_retries="{retries}">
+			<AlwaysFailure/>
+			<SucceedThird/>
+		</RetryFallback>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+#[rstest]
+#[case::no_retry(0, Failure)]
+#[case::too_few_retries(1, Failure)]
+#[case::enough_retries(2, Success)]
+#[case::infinite_retries(-1, Success)]
+async fn retry_fallback(#[case] retries: i32, #[case] expected: BehaviorState) -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	// fails until the third tick
+	register_behavior!(factory, StateList, "SucceedThird", &[Failure, Failure, Success])?;
+	let mut tree = factory.create_from_text(RETRY_FALLBACK)?;
+	drop(factory);
+	tree.blackboard().set("retries", retries)?;
+
+	// without delay all passes are done within one tick
+	assert_eq!(tree.tick_once().await?, expected);
+	Ok(())
+}
+
+#[tokio::test]
+async fn retry_fallback_delay() -> Result<(), Error> {
+	let xml = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<RetryFallback num_retries="2" retry_delay="10">
+			<AlwaysFailure/>
+			<SucceedThird/>
+		</RetryFallback>
+	</BehaviorTree>
+</root>
+"#;
+	let mut factory = BehaviorTreeFactory::new()?;
+	// fails until the third tick
+	register_behavior!(factory, StateList, "SucceedThird", &[Failure, Failure, Success])?;
+	let mut tree = factory.create_from_text(xml)?;
+	drop(factory);
+
+	// first pass fails, waiting for the retry
+	assert_eq!(tree.tick_once().await?, Running);
+	// still waiting
+	assert_eq!(tree.tick_once().await?, Running);
+	tokio::time::sleep(Duration::from_millis(20)).await;
+	// second pass fails, waiting again
+	assert_eq!(tree.tick_once().await?, Running);
+	tokio::time::sleep(Duration::from_millis(20)).await;
+	// third pass succeeds
+	assert_eq!(tree.tick_once().await?, Success);
+	Ok(())
+}
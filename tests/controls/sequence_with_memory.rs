@@ -290,3 +290,135 @@ async fn sequence_with_memory_reactiveness2(
 
 	Ok(())
 }
+
+const REENTRY_DEFINITION: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<{CONTROL}>
+			<Script code="first_ticks = first_ticks + 1"/>
+			<ScriptCondition code="gate > 0"/>
+		</{CONTROL}>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+#[rstest]
+#[case("Sequence", false, 2)]
+#[case("Sequence", true, 2)]
+#[case("SequenceWithMemory", false, 1)]
+#[case("SequenceWithMemory", true, 2)]
+#[case("SequenceWithMemory reset_on_reentry=\"true\"", false, 1)]
+#[case("SequenceWithMemory reset_on_reentry=\"true\"", true, 2)]
+#[case("SequenceWithMemory reset_on_reentry=\"false\"", false, 1)]
+#[case("SequenceWithMemory reset_on_reentry=\"false\"", true, 1)]
+async fn sequence_reentry(#[case] control: &str, #[case] halt: bool, #[case] expected: i64) -> Result<(), Error> {
+	let name = control.split(' ').next().unwrap_or(control);
+	let xml = REENTRY_DEFINITION
+		.replacen("{CONTROL}", control, 1)
+		.replacen("{CONTROL}", name, 1);
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut tree = factory.create_from_text(&xml)?;
+	drop(factory);
+	tree.blackboard().set::<i64>("first_ticks", 0)?;
+	tree.blackboard().set::<i64>("gate", 0)?;
+
+	// first child succeeds, second child fails
+	assert_eq!(tree.tick_once().await?, Failure);
+	assert_eq!(tree.blackboard().get::<i64>("first_ticks")?, 1);
+
+	if halt {
+		tree.reset()?;
+	}
+
+	// re-entering decides whether the first child is ticked again
+	tree.blackboard().set::<i64>("gate", 1)?;
+	assert_eq!(tree.tick_once().await?, Success);
+	assert_eq!(tree.blackboard().get::<i64>("first_ticks")?, expected);
+	Ok(())
+}
+
+const PERSISTENT_DEFINITION: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<SequenceWithMemory persistent="true"{REENTRY}>
+			<Script code="phase1 = phase1 + 1"/>
+			<ScriptCondition code="ready > 0"/>
+			<Script code="phase2 = phase2 + 1"/>
+		</SequenceWithMemory>
+	</BehaviorTree>
+</root>
+"#;
+
+/// `persistent` takes precedence over `reset_on_reentry`
+#[tokio::test]
+#[rstest]
+#[case("")]
+#[case(" reset_on_reentry=\"true\"")]
+#[case(" reset_on_reentry=\"false\"")]
+async fn sequence_with_memory_persistent(#[case] reentry: &str) -> Result<(), Error> {
+	let xml = PERSISTENT_DEFINITION.replace("{REENTRY}", reentry);
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut tree = factory.create_from_text(&xml)?;
+	drop(factory);
+	tree.blackboard().set::<i64>("phase1", 0)?;
+	tree.blackboard().set::<i64>("phase2", 0)?;
+	tree.blackboard().set::<i64>("ready", 0)?;
+
+	// first phase done, the gate fails
+	assert_eq!(tree.tick_once().await?, Failure);
+	assert_eq!(tree.blackboard().get::<i64>("phase1")?, 1);
+
+	// after a reset the sequence resumes at the gate
+	tree.reset()?;
+	tree.blackboard().set::<i64>("ready", 1)?;
+	assert_eq!(tree.tick_once().await?, Success);
+	assert_eq!(tree.blackboard().get::<i64>("phase1")?, 1);
+	assert_eq!(tree.blackboard().get::<i64>("phase2")?, 1);
+
+	// a completed sequence does not restart, even after a reset
+	tree.reset()?;
+	assert_eq!(tree.tick_once().await?, Success);
+	assert_eq!(tree.blackboard().get::<i64>("phase1")?, 1);
+	assert_eq!(tree.blackboard().get::<i64>("phase2")?, 1);
+	Ok(())
+}
+
+const SKIPPED_DEFINITION: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<SequenceWithMemory persistent="{PERSISTENT}">
+			<Script code="ticks = ticks + 1" _skipIf="skip"/>
+			<Script code="ticks = ticks + 1" _skipIf="skip"/>
+		</SequenceWithMemory>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+#[rstest]
+#[case("false")]
+#[case("true")]
+async fn sequence_with_memory_all_skipped(#[case] persistent: &str) -> Result<(), Error> {
+	let xml = SKIPPED_DEFINITION.replace("{PERSISTENT}", persistent);
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut tree = factory.create_from_text(&xml)?;
+	drop(factory);
+	tree.blackboard().set::<i64>("ticks", 0)?;
+	tree.blackboard().set("skip", true)?;
+
+	assert_eq!(tree.tick_once().await?, Skipped);
+	assert_eq!(tree.blackboard().get::<i64>("ticks")?, 0);
+
+	// a persistent sequence keeps the result of its completion
+	tree.reset()?;
+	tree.blackboard().set("skip", false)?;
+	if persistent == "true" {
+		assert_eq!(tree.tick_once().await?, Skipped);
+		assert_eq!(tree.blackboard().get::<i64>("ticks")?, 0);
+	} else {
+		assert_eq!(tree.tick_once().await?, Success);
+		assert_eq!(tree.blackboard().get::<i64>("ticks")?, 2);
+	}
+	Ok(())
+}
@@ -271,6 +271,109 @@ async fn switch5(
 	Ok(())
 }
 
+const SWITCH_EPSILON_TREE: &str = r#"
+<root BTCPP_format="4"
+		main_tree_to_execute="MainTree">
+	<BehaviorTree ID="MainTree">
+		<Switch2 name="switch2" variable="{var}" case_1="3.14" case_2="-1.5" epsilon="0.01">
+			<Behavior1	name="case1"/>
+			<Behavior2	name="case2"/>
+			<Default	name="default"/>
+		</Switch2>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+#[rstest]
+#[case("3.14", Success)]
+#[case("3.145", Success)]
+#[case("3.1305", Success)]
+#[case("3.16", Failure)]
+#[case("-1.499", Running)]
+#[case("-1.52", Failure)]
+async fn switch_epsilon(#[case] case: &str, #[case] expected: BehaviorState) -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, ChangeStateAfter, "Behavior1", Running, Success, 0)?;
+	register_behavior!(factory, ChangeStateAfter, "Behavior2", Running, Running, 0)?;
+	register_behavior!(factory, ChangeStateAfter, "Default", Running, Failure, 0)?;
+
+	let mut tree = factory.create_from_text(SWITCH_EPSILON_TREE)?;
+	drop(factory);
+
+	tree.blackboard().set("var", String::from(case))?;
+
+	let result = tree.tick_once().await?;
+	assert_eq!(result, expected);
+
+	Ok(())
+}
+
+const SWITCH_PATTERN_TREE: &str = r#"
+<root BTCPP_format="4"
+		main_tree_to_execute="MainTree">
+	<BehaviorTree ID="MainTree">
+		<Switch3 name="switch3" variable="{var}" case_1="1..5" case_2="a|b|c" case_3="42">
+			<Behavior1	name="case1"/>
+			<Behavior2	name="case2"/>
+			<Behavior3	name="case3"/>
+			<Default	name="default"/>
+		</Switch3>
+	</BehaviorTree>
+</root>
+"#;
+
+const SWITCH_INCLUSIVE_TREE: &str = r#"
+<root BTCPP_format="4"
+		main_tree_to_execute="MainTree">
+	<BehaviorTree ID="MainTree">
+		<Switch3 name="switch3" variable="{var}" case_1="-2.5..=0" case_2="x | y" case_3="a..b">
+			<Behavior1	name="case1"/>
+			<Behavior2	name="case2"/>
+			<Behavior3	name="case3"/>
+			<Default	name="default"/>
+		</Switch3>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+#[rstest]
+#[case(SWITCH_PATTERN_TREE, "1", Success)]
+#[case(SWITCH_PATTERN_TREE, "4", Success)]
+#[case(SWITCH_PATTERN_TREE, "4.9", Success)]
+#[case(SWITCH_PATTERN_TREE, "5", Failure)]
+#[case(SWITCH_PATTERN_TREE, "0", Failure)]
+#[case(SWITCH_PATTERN_TREE, "a", Running)]
+#[case(SWITCH_PATTERN_TREE, "c", Running)]
+#[case(SWITCH_PATTERN_TREE, "d", Failure)]
+#[case(SWITCH_PATTERN_TREE, "a|b", Failure)]
+#[case(SWITCH_PATTERN_TREE, "42", Skipped)]
+#[case(SWITCH_PATTERN_TREE, "43", Failure)]
+#[case(SWITCH_INCLUSIVE_TREE, "-2.5", Success)]
+#[case(SWITCH_INCLUSIVE_TREE, "0", Success)]
+#[case(SWITCH_INCLUSIVE_TREE, "0.1", Failure)]
+#[case(SWITCH_INCLUSIVE_TREE, "y", Running)]
+#[case(SWITCH_INCLUSIVE_TREE, "a", Failure)]
+#[case(SWITCH_INCLUSIVE_TREE, "a..b", Skipped)]
+async fn switch_patterns(#[case] xml: &str, #[case] case: &str, #[case] expected: BehaviorState) -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, ChangeStateAfter, "Behavior1", Running, Success, 0)?;
+	register_behavior!(factory, ChangeStateAfter, "Behavior2", Running, Running, 0)?;
+	register_behavior!(factory, ChangeStateAfter, "Behavior3", Running, Skipped, 0)?;
+	register_behavior!(factory, ChangeStateAfter, "Default", Running, Failure, 0)?;
+
+	let mut tree = factory.create_from_text(xml)?;
+	drop(factory);
+
+	tree.blackboard().set("var", String::from(case))?;
+
+	let result = tree.tick_once().await?;
+	assert_eq!(result, expected);
+
+	Ok(())
+}
+
 const WRONG_TREE: &str = r#"
 <root BTCPP_format="4"
 		main_tree_to_execute="MainTree">
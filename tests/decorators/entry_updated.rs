@@ -52,13 +52,18 @@ async fn entry_updated_raw() -> Result<(), Error> {
 	tree.blackboard().set("test", 1)?;
 	let mut result = tree.tick_once().await?;
 	assert_eq!(result, BehaviorState::Success);
-	result = tree.tick_once().await?;
-	assert_eq!(result, BehaviorState::Idle);
+	// the default state `Idle` is no legal tick result
+	assert!(matches!(
+		tree.tick_once().await,
+		Err(BehaviorError::IllegalTickResult { state: Idle, .. })
+	));
 	tree.blackboard().set("test", 2)?;
 	result = tree.tick_once().await?;
 	assert_eq!(result, BehaviorState::Success);
-	result = tree.tick_once().await?;
-	assert_eq!(result, BehaviorState::Idle);
+	assert!(matches!(
+		tree.tick_once().await,
+		Err(BehaviorError::IllegalTickResult { state: Idle, .. })
+	));
 	for behavior in tree.iter_mut() {
 		if behavior.name().as_ref() == "entry_updated" {
 			if let Some(behavior) = behavior
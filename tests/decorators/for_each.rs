@@ -0,0 +1,158 @@
+// Copyright © 2025 Stephan Kunz
+
+//! Tests the [`ForEach`] decorator
+
+extern crate alloc;
+
+use behaviortree::{behavior::BehaviorState::*, prelude::*};
+use rstest::rstest;
+
+const FOR_EACH: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<ForEach from="{=}" to="{=}" step="{=}" value="{index}">
+			<Sequence>
+				<ScriptCondition code="index != stop"/>
+				<Script code="count = count + 1"/>
+				<Script code="sum = sum + index"/>
+			</Sequence>
+		</ForEach>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+#[rstest]
+#[case::forward(0, 3, 1, Success, 3, 3)]
+#[case::forward_step(0, 7, 3, Success, 3, 9)]
+#[case::reverse(3, 0, -1, Success, 3, 6)]
+#[case::reverse_step(10, 0, -4, Success, 3, 18)]
+#[case::empty(2, 2, 1, Success, 0, 0)]
+#[case::empty_forward(3, 0, 1, Success, 0, 0)]
+#[case::empty_reverse(0, 3, -1, Success, 0, 0)]
+#[case::failure(0, 3, 1, Failure, 1, 0)]
+async fn for_each(
+	#[case] from: i32,
+	#[case] to: i32,
+	#[case] step: i32,
+	#[case] expected: BehaviorState,
+	#[case] count: i64,
+	#[case] sum: i64,
+) -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut tree = factory.create_from_text(FOR_EACH)?;
+	drop(factory);
+
+	tree.blackboard().set("from", from)?;
+	tree.blackboard().set("to", to)?;
+	tree.blackboard().set("step", step)?;
+	tree.blackboard().set("index", 0_i32)?;
+	// the failure case stops at index 1
+	tree.blackboard()
+		.set("stop", if expected == Failure { 1_i32 } else { -1_i32 })?;
+	tree.blackboard().set("count", 0_i64)?;
+	tree.blackboard().set("sum", 0_i64)?;
+
+	let result = tree.tick_while_running().await?;
+	assert_eq!(result, expected);
+	assert_eq!(tree.blackboard().get::<i64>("count")?, count);
+	assert_eq!(tree.blackboard().get::<i64>("sum")?, sum);
+	Ok(())
+}
+
+#[tokio::test]
+async fn for_each_keeps_position() -> Result<(), Error> {
+	let xml = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<ForEach from="0" to="3" value="{index}">
+			<Sequence>
+				<Script code="count = count + 1"/>
+				<AlwaysRunning/>
+			</Sequence>
+		</ForEach>
+	</BehaviorTree>
+</root>
+"#;
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut tree = factory.create_from_text(xml)?;
+	drop(factory);
+	tree.blackboard().set("count", 0_i64)?;
+
+	// a running child stays at the current index
+	assert_eq!(tree.tick_once().await?, Running);
+	assert_eq!(tree.tick_once().await?, Running);
+	assert_eq!(tree.blackboard().get::<i32>("index")?, 0);
+	assert_eq!(tree.blackboard().get::<i64>("count")?, 1);
+	Ok(())
+}
+
+#[tokio::test]
+async fn for_each_zero_step() -> Result<(), Error> {
+	let xml = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<ForEach from="0" to="3" step="0" value="{index}">
+			<AlwaysSuccess/>
+		</ForEach>
+	</BehaviorTree>
+</root>
+"#;
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut tree = factory.create_from_text(xml)?;
+	drop(factory);
+	assert!(tree.tick_once().await.is_err());
+	Ok(())
+}
+
+#[tokio::test]
+async fn for_each_step() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut tree = factory.create_from_text(FOR_EACH)?;
+	drop(factory);
+	tree.blackboard().set("from", 0_i32)?;
+	tree.blackboard().set("to", 3_i32)?;
+	tree.blackboard().set("stop", -1_i32)?;
+	tree.blackboard().set("count", 0_i64)?;
+	tree.blackboard().set("sum", 0_i64)?;
+
+	// a missing step defaults to 1
+	assert_eq!(tree.tick_while_running().await?, Success);
+	assert_eq!(tree.blackboard().get::<i64>("count")?, 3);
+
+	// an invalid step is an error
+	tree.reset()?;
+	tree.blackboard()
+		.set("step", String::from("two"))?;
+	assert!(tree.tick_once().await.is_err());
+	Ok(())
+}
+
+const FOR_EACH_SKIPPED: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<ForEach from="0" to="3" value="{index}">
+			<Script code="count = count + 1" _skipIf="index >= skip"/>
+		</ForEach>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+#[rstest]
+#[case::none(3, Success, 3)]
+#[case::some(1, Success, 1)]
+#[case::all(0, Skipped, 0)]
+async fn for_each_skipped(#[case] skip: i32, #[case] expected: BehaviorState, #[case] count: i64) -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut tree = factory.create_from_text(FOR_EACH_SKIPPED)?;
+	drop(factory);
+	tree.blackboard().set("skip", skip)?;
+	tree.blackboard().set("count", 0_i64)?;
+
+	// a skipped child advances the index
+	assert_eq!(tree.tick_while_running().await?, expected);
+	assert_eq!(tree.blackboard().get::<i32>("index")?, 2);
+	assert_eq!(tree.blackboard().get::<i64>("count")?, count);
+	Ok(())
+}
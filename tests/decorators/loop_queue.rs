@@ -41,3 +41,48 @@ async fn loop_over_string_queue() -> Result<(), Error> {
 
 	Ok(())
 }
+
+const EAGER_TREE_DEFINITION: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence>
+			<Script code="result:=''" />
+			<LoopString queue="{queue}" value="{text}" eager="{eager}">
+				<Script code="result += text + ' '" />
+			</LoopString>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn eager_loop() -> Result<(), Error> {
+	async fn ticks_until_done(factory: &mut BehaviorTreeFactory, eager: bool) -> Result<usize, Error> {
+		let queue = SharedQueue::<String>::default();
+		queue.push_back(String::from("Hello"));
+		queue.push_back(String::from("World"));
+		queue.push_back(String::from("!"));
+
+		let root_blackboard = Databoard::new();
+		root_blackboard.set("queue", queue)?;
+		root_blackboard.set("eager", eager)?;
+		let mut tree = factory.create_tree_with("MainTree", &root_blackboard)?;
+
+		let mut ticks = 1;
+		while tree.tick_once().await? == BehaviorState::Running {
+			ticks += 1;
+		}
+		assert_eq!(root_blackboard.get::<String>("result")?, String::from("Hello World ! "));
+		Ok(ticks)
+	}
+
+	let mut factory = BehaviorTreeFactory::new()?;
+	factory.register_behavior_tree_from_text(EAGER_TREE_DEFINITION)?;
+
+	// one tick per item plus one for the empty queue
+	assert_eq!(ticks_until_done(&mut factory, false).await?, 4);
+	// whole queue within one tick
+	assert_eq!(ticks_until_done(&mut factory, true).await?, 1);
+
+	Ok(())
+}
@@ -0,0 +1,131 @@
+// Copyright © 2025 Stephan Kunz
+
+//! Tests the [`MapState`] decorator
+
+extern crate alloc;
+
+use crate::decorators::utilities::ChangeStateAfter;
+use behaviortree::{BehaviorTreeElement, BehaviorTreeObserver, behavior::BehaviorState::*, prelude::*};
+use rstest::rstest;
+
+const TREE_DEFINITION: &str = r#"
+<root BTCPP_format="4"
+		main_tree_to_execute="MainTree">
+	<BehaviorTree ID="MainTree">
+		<MapState name="map_state" mapping="{MAPPING}">
+			<Behavior1	name="child"/>
+		</MapState>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+#[rstest]
+#[case("", Failure, Failure)]
+#[case("", Running, Running)]
+#[case("", Skipped, Skipped)]
+#[case("", Success, Success)]
+#[case("success=failure, failure=success", Failure, Success)]
+#[case("success=failure, failure=success", Success, Failure)]
+#[case("success=failure, failure=success", Running, Running)]
+#[case("failure=success", Failure, Success)]
+#[case("failure=success", Success, Success)]
+#[case("Skipped=Failure", Skipped, Failure)]
+#[case("success=running", Success, Running)]
+async fn map_state(
+	#[case] mapping: &str,
+	#[case] input: BehaviorState,
+	#[case] expected: BehaviorState,
+) -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, ChangeStateAfter, "Behavior1", BehaviorState::Running, input, 0)?;
+
+	let xml = TREE_DEFINITION.replace("{MAPPING}", mapping);
+	let mut tree = factory.create_from_text(&xml)?;
+	drop(factory);
+
+	let mut result = tree.tick_once().await?;
+	assert_eq!(result, expected);
+	result = tree.tick_once().await?;
+	assert_eq!(result, expected);
+
+	tree.reset()?;
+
+	result = tree.tick_once().await?;
+	assert_eq!(result, expected);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn map_running_to_terminal() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, ChangeStateAfter, "Behavior1", BehaviorState::Running, Success, 2)?;
+
+	// unmapped the child succeeds with the third tick
+	let xml = TREE_DEFINITION.replace("{MAPPING}", "");
+	let mut tree = factory.create_from_text(&xml)?;
+	assert_eq!(tree.tick_once().await?, Running);
+	assert_eq!(tree.tick_once().await?, Running);
+	assert_eq!(tree.tick_once().await?, Success);
+
+	// mapped the running child is halted and restarts with every tick
+	let xml = TREE_DEFINITION
+		.replace("MainTree", "MappedTree")
+		.replace("{MAPPING}", "running=failure");
+	let mut tree = factory.create_from_text(&xml)?;
+	drop(factory);
+	for _ in 0..3 {
+		assert_eq!(tree.tick_once().await?, Failure);
+	}
+
+	Ok(())
+}
+
+#[tokio::test]
+#[rstest]
+#[case("idle=success", Success)]
+#[case("success=idle", Success)]
+#[case("success", Success)]
+#[case("success=unknown", Success)]
+#[case("", Idle)]
+async fn map_state_errors(#[case] mapping: &str, #[case] input: BehaviorState) -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, ChangeStateAfter, "Behavior1", BehaviorState::Running, input, 0)?;
+
+	let xml = TREE_DEFINITION.replace("{MAPPING}", mapping);
+	let mut tree = factory.create_from_text(&xml)?;
+	drop(factory);
+
+	let result = tree.tick_once().await;
+	assert!(result.is_err());
+	Ok(())
+}
+
+#[tokio::test]
+async fn map_state_starts_running() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, ChangeStateAfter, "Behavior1", BehaviorState::Running, Failure, 0)?;
+	let xml = TREE_DEFINITION.replace("{MAPPING}", "failure=success");
+	let mut tree = factory.create_from_text(&xml)?;
+	drop(factory);
+	let observer = BehaviorTreeObserver::with_transition_log(&mut tree, 16);
+	let Some(uid) = tree
+		.iter()
+		.find(|element| element.name().as_ref() == "map_state")
+		.map(BehaviorTreeElement::uid)
+	else {
+		panic!("element 'map_state' not found");
+	};
+
+	assert_eq!(tree.tick_once().await?, Success);
+	// like the other decorators the behavior is running while its child is ticked
+	let states: Vec<BehaviorState> = observer
+		.recent_transitions()
+		.iter()
+		.filter(|transition| transition.uid == uid)
+		.map(|transition| transition.new_state)
+		.collect();
+	assert_eq!(states, [Running, Success]);
+	Ok(())
+}
@@ -6,11 +6,14 @@
 mod utilities;
 
 mod entry_updated;
+mod for_each;
 mod force_state;
 mod inverter;
 mod keep_running_until_failure;
 mod loop_queue;
+mod map_state;
 mod precondition;
 mod repeat;
 mod retry_until_successful;
 mod run_once;
+mod timeout;
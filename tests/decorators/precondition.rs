@@ -44,3 +44,67 @@ async fn precondition() -> Result<(), Error> {
 
 	Ok(())
 }
+
+/// Action with ports, which are referenced by the scripts of a [`Precondition`]
+#[derive(Action, Debug, Default)]
+struct Target;
+
+#[async_trait::async_trait]
+impl Behavior for Target {
+	async fn tick(
+		&mut self,
+		_behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		Ok(BehaviorState::Success)
+	}
+
+	fn provided_ports() -> PortList {
+		port_list![
+			input_port!(i64, "target"),
+			input_port!(i64, "limit")
+		]
+	}
+}
+
+const REMAPPED_XML: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence>
+			<Precondition if="target == 42" else="FAILURE">
+				<Target target="{goal}" limit="3"/>
+			</Precondition>
+			<Precondition if="limit == 3" else="FAILURE">
+				<Target target="{goal}" limit="3"/>
+			</Precondition>
+			<Precondition if="target == 0" else="SUCCESS">
+				<Target target="{goal}" limit="3"/>
+			</Precondition>
+			<Precondition if="other == 7" else="FAILURE">
+				<Target target="{goal}" limit="3"/>
+			</Precondition>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn precondition_remapped_ports() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, Target, "Target")?;
+	let mut tree = factory.create_from_text(REMAPPED_XML)?;
+	drop(factory);
+
+	// `target` is resolved through the remapping of the child to `goal`, not to the equally named entry
+	tree.blackboard().set::<i64>("goal", 42)?;
+	tree.blackboard().set::<i64>("target", 0)?;
+	tree.blackboard().set::<i64>("other", 7)?;
+	assert_eq!(tree.tick_while_running().await?, BehaviorState::Success);
+
+	tree.reset()?;
+	tree.blackboard().set::<i64>("goal", 41)?;
+	assert_eq!(tree.tick_while_running().await?, BehaviorState::Failure);
+
+	Ok(())
+}
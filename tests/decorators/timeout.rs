@@ -0,0 +1,78 @@
+// Copyright © 2025 Stephan Kunz
+//! Tests the [`Timeout`] decorator with a logical clock.
+
+extern crate alloc;
+
+use behaviortree::{ManualClock, prelude::*};
+use core::time::Duration;
+
+const TIMEOUT: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Timeout msec="100">
+			<AlwaysRunning/>
+		</Timeout>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn timeout_with_logical_clock() -> Result<(), Error> {
+	let clock = ManualClock::default();
+	let mut factory = BehaviorTreeFactory::new()?;
+	factory.set_clock(clock.clone());
+	let mut tree = factory.create_from_text(TIMEOUT)?;
+	drop(factory);
+
+	// the time only advances when told so
+	for _ in 0..10 {
+		assert_eq!(tree.tick_once().await?, BehaviorState::Running);
+	}
+	clock.advance(Duration::from_millis(100));
+	assert_eq!(tree.tick_once().await?, BehaviorState::Running);
+	clock.advance(Duration::from_millis(1));
+	assert_eq!(tree.tick_once().await?, BehaviorState::Failure);
+
+	// a restart measures from the new start time
+	tree.reset()?;
+	assert_eq!(tree.tick_once().await?, BehaviorState::Running);
+	clock.advance(Duration::from_millis(50));
+	assert_eq!(tree.tick_once().await?, BehaviorState::Running);
+	clock.advance(Duration::from_millis(51));
+	assert_eq!(tree.tick_once().await?, BehaviorState::Failure);
+
+	Ok(())
+}
+
+const DELAY: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence>
+			<Delay delay_msec="20">
+				<AlwaysSuccess/>
+			</Delay>
+			<Sleep msec="30"/>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn delay_and_sleep_with_logical_clock() -> Result<(), Error> {
+	let clock = ManualClock::default();
+	let mut factory = BehaviorTreeFactory::new()?;
+	factory.set_clock(clock.clone());
+	let mut tree = factory.create_from_text(DELAY)?;
+	drop(factory);
+
+	assert_eq!(tree.tick_once().await?, BehaviorState::Running);
+	clock.advance(Duration::from_millis(21));
+	// delay is over, sleep starts
+	assert_eq!(tree.tick_once().await?, BehaviorState::Running);
+	clock.advance(Duration::from_millis(30));
+	assert_eq!(tree.tick_once().await?, BehaviorState::Running);
+	clock.advance(Duration::from_millis(1));
+	assert_eq!(tree.tick_once().await?, BehaviorState::Success);
+
+	Ok(())
+}
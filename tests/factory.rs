@@ -2,7 +2,30 @@
 
 //! Tests the factory
 
-use behaviortree::factory::{BehaviorTreeFactory, error::Error};
+#![allow(clippy::panic)]
+
+extern crate alloc;
+
+use alloc::sync::Arc;
+use behaviortree::{
+	BehaviorDescription, BinaryXmlEncoder, ParserLimits, TreeWarning, XmlError,
+	behavior::{BehaviorCreationFn, BehaviorPtr},
+	factory::{BehaviorTreeFactory, MergePolicy, TreeRedefinitionPolicy, error::Error},
+	input_port, output_port,
+	port::{PortDirection, PortList},
+	port_list,
+	prelude::{
+		Action, Behavior, BehaviorData, BehaviorExecution, BehaviorKind, BehaviorResult, BehaviorState,
+		BehaviorTreeElementList, Box, Databoard, FromConfig, ScriptEnum, SharedRuntime, remappings_from_pairs,
+	},
+	register_behavior,
+};
+use core::{
+	any::Any,
+	sync::atomic::{AtomicUsize, Ordering},
+};
+use nanoserde::DeJson;
+use rstest::rstest;
 
 #[test]
 fn factory_creation() -> Result<(), Error> {
@@ -12,3 +35,1049 @@ fn factory_creation() -> Result<(), Error> {
 
 	Ok(())
 }
+
+const LIMITS_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence>
+			<Sequence>
+				<AlwaysSuccess name="leaf" _skipIf="false" _while="true"/>
+			</Sequence>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+#[test]
+fn parser_limits() -> Result<(), Error> {
+	// default limits accept the document
+	let mut factory = BehaviorTreeFactory::new()?;
+	factory.register_behavior_tree_from_text(LIMITS_TREE)?;
+
+	// too large
+	let mut factory = BehaviorTreeFactory::new()?;
+	factory.set_parser_limits(ParserLimits {
+		max_document_length: 64,
+		..Default::default()
+	});
+	let result = factory.register_behavior_tree_from_text(LIMITS_TREE);
+	assert!(matches!(result, Err(Error::RegisterXml { .. })));
+	assert!(result.is_err_and(|err| {
+		err.to_string()
+			.contains("exceeds the limit of 64 bytes")
+	}));
+
+	// too deep: root/BehaviorTree/Sequence/Sequence/AlwaysSuccess has depth 5
+	let mut factory = BehaviorTreeFactory::new()?;
+	factory.set_parser_limits(ParserLimits {
+		max_depth: 4,
+		..Default::default()
+	});
+	let result = factory.register_behavior_tree_from_text(LIMITS_TREE);
+	assert!(result.is_err_and(|err| {
+		err.to_string()
+			.contains("AlwaysSuccess exceeds the nesting depth limit of 4")
+	}));
+	factory.set_parser_limits(ParserLimits {
+		max_depth: 5,
+		..Default::default()
+	});
+	factory.register_behavior_tree_from_text(LIMITS_TREE)?;
+
+	// too many attributes
+	let mut factory = BehaviorTreeFactory::new()?;
+	factory.set_parser_limits(ParserLimits::new(usize::MAX, usize::MAX, 2));
+	let result = factory.register_behavior_tree_from_text(LIMITS_TREE);
+	assert!(result.is_err_and(|err| {
+		err.to_string()
+			.contains("AlwaysSuccess exceeds the limit of 2 attributes")
+	}));
+
+	Ok(())
+}
+
+#[derive(ScriptEnum)]
+#[allow(unused, clippy::upper_case_acronyms)]
+enum Color {
+	RED = 1,
+	BLUE,
+	GREEN = 4,
+}
+
+#[test]
+fn register_enum_type() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	factory.register_enum_type::<Color>()?;
+
+	// the keys are already used
+	assert!(factory.register_enum_type::<Color>().is_err());
+	assert!(factory.register_enum_tuple("GREEN", 4).is_err());
+
+	// usable in expression position
+	let mut factory = BehaviorTreeFactory::new()?;
+	let registered = factory
+		.register_enum_type::<Color>()
+		.and_then(|()| factory.register_enum_tuple("YELLOW", 8));
+	assert!(registered.is_ok());
+
+	Ok(())
+}
+
+const LIBRARY_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="LibraryTree">
+		<Sequence>
+			<Left/>
+			<Right/>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+fn library(names: &[&str]) -> Result<Box<BehaviorTreeFactory>, Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	for name in names {
+		factory.register_simple_function(name, Arc::new(|| Ok(BehaviorState::Success)), BehaviorKind::Action)?;
+	}
+	Ok(factory)
+}
+
+#[tokio::test]
+async fn merge() -> Result<(), behaviortree::Error> {
+	// disjoint behaviors, the builtin ones are no collisions
+	let mut factory = library(&["Left"])?;
+	factory.merge(*library(&["Right"])?)?;
+	factory.register_behavior_tree_from_text(LIBRARY_TREE)?;
+	let mut tree = factory.create_tree("LibraryTree")?;
+	assert_eq!(tree.tick_while_running().await?, BehaviorState::Success);
+
+	// colliding tree definitions
+	let mut other = library(&[])?;
+	other.register_behavior_tree_from_text(LIBRARY_TREE)?;
+	assert!(factory.merge(*other).is_err());
+
+	// colliding behaviors fail without merging anything
+	let mut factory = library(&["Left"])?;
+	assert!(
+		factory
+			.merge(*library(&["Left", "Right"])?)
+			.is_err()
+	);
+	factory.register_behavior_tree_from_text(LIBRARY_TREE)?;
+	assert!(factory.create_tree("LibraryTree").is_err());
+
+	// colliding behaviors with a policy
+	let mut factory = library(&["Left"])?;
+	factory.merge_with_policy(*library(&["Left", "Right"])?, MergePolicy::KeepExisting)?;
+	let mut other = library(&[])?;
+	other.register_behavior_tree_from_text(LIBRARY_TREE)?;
+	factory.merge_with_policy(*other, MergePolicy::Override)?;
+	let mut tree = factory.create_tree("LibraryTree")?;
+	assert_eq!(tree.tick_while_running().await?, BehaviorState::Success);
+
+	// enums with identical values are no collision, differing values are
+	let mut factory = library(&[])?;
+	factory.register_enum_tuple("ONE", 1)?;
+	let mut other = library(&[])?;
+	other.register_enum_tuple("ONE", 1)?;
+	other.register_enum_tuple("TWO", 2)?;
+	factory.merge(*other)?;
+	assert!(factory.register_enum_tuple("TWO", 2).is_err());
+	let mut other = library(&[])?;
+	other.register_enum_tuple("ONE", 11)?;
+	assert!(
+		factory
+			.merge_with_policy(*other, MergePolicy::Override)
+			.is_err()
+	);
+
+	Ok(())
+}
+
+const TREES: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<SubTree ID="SubA"/>
+	</BehaviorTree>
+
+	<BehaviorTree ID="SubA">
+		<AlwaysSuccess/>
+	</BehaviorTree>
+
+	<BehaviorTree ID="Broken">
+		<UnknownBehavior/>
+	</BehaviorTree>
+</root>
+"#;
+
+#[test]
+fn create_trees() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	factory.register_behavior_tree_from_text(TREES)?;
+
+	let trees = factory.create_trees(&["MainTree", "SubA"]);
+	assert!(trees.is_ok_and(|trees| trees.len() == 2));
+
+	// all failures are reported, not only the first one
+	let errors = factory
+		.create_trees(&["Missing", "MainTree", "Broken"])
+		.err()
+		.unwrap_or_default();
+	assert_eq!(errors.len(), 2);
+	assert_eq!(errors[0].0.as_ref(), "Missing");
+	assert_eq!(errors[1].0.as_ref(), "Broken");
+	assert!(
+		errors[1]
+			.1
+			.to_string()
+			.contains("UnknownBehavior")
+	);
+
+	Ok(())
+}
+
+#[test]
+fn register_from_reader() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	factory.register_behavior_tree_from_reader(std::io::Cursor::new(TREES.as_bytes()))?;
+	assert!(factory.create_tree("MainTree").is_ok());
+
+	// invalid UTF-8
+	let mut factory = BehaviorTreeFactory::new()?;
+	let result = factory.register_behavior_tree_from_reader(std::io::Cursor::new([0xff_u8, 0xfe]));
+	assert!(result.is_err());
+
+	Ok(())
+}
+
+#[test]
+fn port_direction_round_trip() {
+	assert_eq!(PortDirection::all().len(), 3);
+	for direction in PortDirection::all() {
+		assert_eq!(PortDirection::try_from(direction.type_str()).ok(), Some(*direction));
+		assert_eq!(PortDirection::try_from(direction.as_str()).ok(), Some(*direction));
+	}
+	assert!(PortDirection::try_from("unknown_port").is_err());
+}
+
+const UNKNOWN_PORT_ELEMENT: &str = r#"
+<root BTCPP_format="4">
+	<TreeNodesModel>
+		<Action ID="Test">
+			<sideways_port name="value" default="{value}"/>
+		</Action>
+	</TreeNodesModel>
+</root>
+"#;
+
+#[test]
+fn unknown_port_element() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	let result = factory.register_behavior_tree_from_text(UNKNOWN_PORT_ELEMENT);
+	let Err(err) = result else {
+		panic!("unknown port element must be rejected");
+	};
+	let msg = err.to_string();
+	assert!(msg.contains("sideways_port"));
+	assert!(msg.contains("Test"));
+
+	Ok(())
+}
+
+/// Wrapper counting the ticks of the inner behavior
+#[derive(Default)]
+struct TickCounter {
+	inner: Option<BehaviorPtr>,
+	ticks: Arc<AtomicUsize>,
+}
+
+impl BehaviorExecution for TickCounter {
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+
+	fn as_any_mut(&mut self) -> &mut dyn Any {
+		self
+	}
+
+	fn creation_fn() -> Box<BehaviorCreationFn> {
+		Box::new(|| Box::new(Self::default()))
+	}
+
+	fn kind() -> BehaviorKind {
+		BehaviorKind::Action
+	}
+
+	fn static_provided_ports(&self) -> PortList {
+		self.inner
+			.as_ref()
+			.map(|inner| inner.static_provided_ports())
+			.unwrap_or_default()
+	}
+}
+
+#[async_trait::async_trait]
+impl Behavior for TickCounter {
+	async fn start(
+		&mut self,
+		behavior: &mut BehaviorData,
+		children: &mut BehaviorTreeElementList,
+		runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		self.ticks.fetch_add(1, Ordering::Relaxed);
+		match self.inner.as_mut() {
+			Some(inner) => inner.start(behavior, children, runtime).await,
+			None => Ok(BehaviorState::Failure),
+		}
+	}
+
+	async fn tick(
+		&mut self,
+		behavior: &mut BehaviorData,
+		children: &mut BehaviorTreeElementList,
+		runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		self.ticks.fetch_add(1, Ordering::Relaxed);
+		match self.inner.as_mut() {
+			Some(inner) => inner.tick(behavior, children, runtime).await,
+			None => Ok(BehaviorState::Failure),
+		}
+	}
+
+	fn halt(
+		&mut self,
+		behavior: &mut BehaviorData,
+		children: &mut BehaviorTreeElementList,
+		runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		self.inner
+			.as_mut()
+			.map_or(Ok(BehaviorState::Idle), |inner| inner.halt(behavior, children, runtime))
+	}
+}
+
+const WRAPPER_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence>
+			<CountedSuccess/>
+			<CountedSuccess/>
+			<CountedSuccess/>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn register_wrapper() -> Result<(), behaviortree::Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	let ticks = Arc::new(AtomicUsize::new(0));
+	let counter = ticks.clone();
+	factory
+		.registry_mut()
+		.register_wrapper("CountedSuccess", "AlwaysSuccess", move |inner| {
+			Box::new(TickCounter {
+				inner: Some(inner),
+				ticks: counter.clone(),
+			})
+		})?;
+
+	// unknown inner behavior and duplicate name
+	let result = factory
+		.registry_mut()
+		.register_wrapper("Counted", "Unknown", |inner| inner);
+	assert!(matches!(result, Err(Error::NotRegistered { .. })));
+	let result = factory
+		.registry_mut()
+		.register_wrapper("CountedSuccess", "AlwaysFailure", |inner| inner);
+	assert!(matches!(result, Err(Error::AlreadyRegistered { .. })));
+
+	let mut tree = factory.create_from_text(WRAPPER_TREE)?;
+	assert_eq!(tree.tick_while_running().await?, BehaviorState::Success);
+	assert_eq!(ticks.load(Ordering::Relaxed), 3);
+
+	Ok(())
+}
+
+/// Action constructed with a timeout
+#[derive(Action, Debug, Default)]
+struct MoveBase {
+	timeout: u32,
+}
+
+impl MoveBase {
+	const fn new(timeout: u32) -> Self {
+		Self { timeout }
+	}
+}
+
+#[async_trait::async_trait]
+impl Behavior for MoveBase {
+	async fn tick(
+		&mut self,
+		_behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		if self.timeout > 0 {
+			Ok(BehaviorState::Success)
+		} else {
+			Ok(BehaviorState::Failure)
+		}
+	}
+}
+
+#[test]
+fn registration_arguments() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, MoveBase, "MoveBase")?;
+	register_behavior!(factory, MoveBase, "MoveBaseWithTimeout", 5)?;
+
+	let registry = factory.registry();
+	let plain = registry
+		.behavior_description("MoveBase")
+		.map(|bhvr_desc| bhvr_desc.arguments().cloned());
+	assert_eq!(plain, Some(None));
+	let with_args = registry
+		.behavior_description("MoveBaseWithTimeout")
+		.and_then(|bhvr_desc| bhvr_desc.arguments().cloned());
+	assert_eq!(with_args.as_deref(), Some("5"));
+	assert!(registry.behavior_description("Unknown").is_none());
+
+	Ok(())
+}
+
+const NESTED_TREES: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<SubTree ID="Level1" name="level1"/>
+	</BehaviorTree>
+
+	<BehaviorTree ID="Level1">
+		<Sequence>
+			<SubTree ID="Level2" name="level2"/>
+		</Sequence>
+	</BehaviorTree>
+
+	<BehaviorTree ID="Level2">
+		<Sequence name="inner">
+			<AlwaysSuccess/>
+			<MissingBehavior/>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+#[test]
+fn create_error_context() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	factory.register_behavior_tree_from_text(NESTED_TREES)?;
+
+	let Err(Error::Create { name, path, source }) = factory.create_tree("MainTree") else {
+		panic!("creation with a missing behavior must fail");
+	};
+	assert_eq!(name.as_ref(), "MainTree");
+	// the path names the element containing the missing behavior
+	assert!(path.contains("level1"));
+	assert!(path.contains("level2"));
+	assert!(path.ends_with("inner"));
+	// the structured error is kept
+	assert!(matches!(*source, XmlError::NotRegistered { .. }));
+	assert!(source.to_string().contains("MissingBehavior"));
+
+	Ok(())
+}
+
+const DEFINITION_A: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="Redefined">
+		<AlwaysSuccess name="a"/>
+	</BehaviorTree>
+</root>
+"#;
+
+const DEFINITION_A_OTHER_DOCUMENT: &str = r#"<root BTCPP_format="4"><BehaviorTree ID="Redefined">
+		<AlwaysSuccess name="a"/>
+	</BehaviorTree></root>"#;
+
+const DEFINITION_B: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="Redefined">
+		<AlwaysSuccess name="b"/>
+	</BehaviorTree>
+</root>
+"#;
+
+/// Returns the name of the leaf in the registered definition `Redefined`.
+fn redefined_leaf(factory: &mut BehaviorTreeFactory) -> Result<String, Error> {
+	let tree = factory.create_tree("Redefined")?;
+	Ok(tree
+		.iter()
+		.last()
+		.map(|element| element.name().to_string())
+		.unwrap_or_default())
+}
+
+#[rstest]
+#[case(TreeRedefinitionPolicy::Fail, false, false, "a")]
+#[case(TreeRedefinitionPolicy::IgnoreIdentical, true, false, "a")]
+#[case(TreeRedefinitionPolicy::Overwrite, true, true, "b")]
+fn tree_redefinition_policy(
+	#[case] policy: TreeRedefinitionPolicy,
+	#[case] identical_ok: bool,
+	#[case] different_ok: bool,
+	#[case] leaf: &str,
+) -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	assert_eq!(factory.registry().tree_redefinition_policy(), TreeRedefinitionPolicy::Fail);
+	factory.set_tree_redefinition_policy(policy);
+	factory.register_behavior_tree_from_text(DEFINITION_A)?;
+
+	// the definition is compared, not the surrounding document
+	assert_eq!(
+		factory
+			.register_behavior_tree_from_text(DEFINITION_A_OTHER_DOCUMENT)
+			.is_ok(),
+		identical_ok
+	);
+	assert_eq!(redefined_leaf(&mut factory)?, "a");
+
+	assert_eq!(
+		factory
+			.register_behavior_tree_from_text(DEFINITION_B)
+			.is_ok(),
+		different_ok
+	);
+	assert_eq!(redefined_leaf(&mut factory)?, leaf);
+
+	Ok(())
+}
+
+/// Construction parameters of [`CheckLevel`]
+#[derive(Clone, Debug, Default, DeJson)]
+struct LevelConfig {
+	min: i64,
+	max: i64,
+}
+
+/// Action checking whether the input `level` is within a configured range
+#[derive(Action, Debug, Default)]
+struct CheckLevel {
+	config: LevelConfig,
+}
+
+impl FromConfig for CheckLevel {
+	type Config = LevelConfig;
+
+	fn from_config(config: &Self::Config) -> Self {
+		Self { config: config.clone() }
+	}
+}
+
+#[async_trait::async_trait]
+impl Behavior for CheckLevel {
+	async fn tick(
+		&mut self,
+		behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		let level = behavior.get::<i64>("level")?;
+		if (self.config.min..=self.config.max).contains(&level) {
+			Ok(BehaviorState::Success)
+		} else {
+			Ok(BehaviorState::Failure)
+		}
+	}
+
+	fn provided_ports() -> PortList {
+		port_list![input_port!(i64, "level")]
+	}
+}
+
+const LEVEL_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence>
+			<IsLow level="3"/>
+			<Inverter>
+				<IsHigh level="3"/>
+			</Inverter>
+			<IsHigh level="42"/>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn register_behavior_from_json() -> Result<(), behaviortree::Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+
+	// the type has to be registered first
+	assert!(
+		factory
+			.register_behavior_from_json("IsLow", "CheckLevel", r#"{"min": 0, "max": 10}"#)
+			.is_err()
+	);
+	factory.register_configurable_type::<CheckLevel>("CheckLevel")?;
+	assert!(
+		factory
+			.register_configurable_type::<CheckLevel>("CheckLevel")
+			.is_err()
+	);
+
+	// invalid configurations are rejected
+	assert!(
+		factory
+			.register_behavior_from_json("IsLow", "CheckLevel", r#"{"min": 0}"#)
+			.is_err()
+	);
+
+	factory.register_behavior_from_json("IsLow", "CheckLevel", r#"{"min": 0, "max": 10}"#)?;
+	factory.register_behavior_from_json("IsHigh", "CheckLevel", r#"{"min": 11, "max": 100}"#)?;
+	assert!(
+		factory
+			.register_behavior_from_json("IsHigh", "CheckLevel", r#"{"min": 0, "max": 0}"#)
+			.is_err()
+	);
+	let kind = factory
+		.registry()
+		.behavior_description("IsHigh")
+		.map(BehaviorDescription::kind);
+	assert_eq!(kind, Some(BehaviorKind::Action));
+
+	let mut tree = factory.create_from_text(LEVEL_TREE)?;
+	assert_eq!(tree.tick_while_running().await?, BehaviorState::Success);
+	Ok(())
+}
+
+const MISSIONS: &str = r#"
+<root BTCPP_format="4" main_tree_to_execute="Patrol" main_trees="Dock, Explore,">
+	<BehaviorTree ID="Patrol">
+		<SubTree ID="Step"/>
+	</BehaviorTree>
+	<BehaviorTree ID="Explore">
+		<SubTree ID="Step"/>
+	</BehaviorTree>
+	<BehaviorTree ID="Dock">
+		<AlwaysSuccess/>
+	</BehaviorTree>
+	<BehaviorTree ID="Step">
+		<AlwaysSuccess/>
+	</BehaviorTree>
+</root>
+"#;
+
+const MORE_MISSIONS: &str = r#"
+<root BTCPP_format="4" main_trees="Charge">
+	<BehaviorTree ID="Charge">
+		<AlwaysSuccess/>
+	</BehaviorTree>
+</root>
+"#;
+
+#[test]
+fn main_trees() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	assert!(factory.main_trees().is_empty());
+
+	factory.register_behavior_tree_from_text(MISSIONS)?;
+	factory.register_behavior_tree_from_text(MORE_MISSIONS)?;
+	let main_trees = factory.main_trees();
+	let names: Vec<&str> = main_trees.iter().map(AsRef::as_ref).collect();
+	assert_eq!(names, ["Charge", "Dock", "Explore", "Patrol"]);
+
+	// the default main tree is not changed by the list
+	assert_eq!(factory.registry().main_tree_id().as_deref(), Some("Patrol"));
+	// every entry point can be created
+	for name in &names {
+		assert!(factory.create_tree(name).is_ok());
+	}
+
+	factory.registry_mut().clear_registered_trees();
+	assert!(factory.main_trees().is_empty());
+	Ok(())
+}
+
+/// Returns a tree with a chain of `depth` nested `Inverter`s around an `AlwaysSuccess`.
+fn inverter_chain(depth: usize) -> String {
+	let mut xml = String::from(r#"<root BTCPP_format="4"><BehaviorTree ID="MainTree">"#);
+	xml.push_str(&"<Inverter>".repeat(depth));
+	xml.push_str("<AlwaysSuccess/>");
+	xml.push_str(&"</Inverter>".repeat(depth));
+	xml.push_str("</BehaviorTree></root>");
+	xml
+}
+
+#[test]
+fn max_tree_depth() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	assert!(factory.registry().max_tree_depth().is_none());
+	// the root, 10 inverters and the leaf
+	factory.register_behavior_tree_from_text(&inverter_chain(10))?;
+
+	factory.set_max_tree_depth(12);
+	assert!(factory.create_tree("MainTree").is_ok());
+
+	factory.set_max_tree_depth(11);
+	let Err(Error::Create { source, .. }) = factory.create_tree("MainTree") else {
+		panic!("creation of a tree beyond the depth limit must fail");
+	};
+	assert!(matches!(*source, XmlError::TreeTooDeep { limit: 11, .. }));
+
+	// a self referencing subtree is stopped at the limit
+	factory.register_behavior_tree_from_text(SELF_REFERENCING)?;
+	let Err(Error::Create { source, .. }) = factory.create_tree("Recursive") else {
+		panic!("creation of a self referencing tree must fail");
+	};
+	assert!(matches!(*source, XmlError::TreeTooDeep { limit: 11, .. }));
+
+	Ok(())
+}
+
+const SELF_REFERENCING: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="Recursive">
+		<Inverter>
+			<SubTree ID="Recursive"/>
+		</Inverter>
+	</BehaviorTree>
+</root>
+"#;
+
+/// Stack size for ticking deep trees, small compared to the default of 2 MiB for test threads.
+const SMALL_STACK: usize = 256 * 1024;
+
+#[test]
+fn deep_decorator_chain() -> Result<(), behaviortree::Error> {
+	// encoded beforehand, as parsing the XML recurses along the nesting
+	// an even number of inverters results in success
+	let chain = BinaryXmlEncoder::encode(&inverter_chain(32))?;
+	let too_deep = BinaryXmlEncoder::encode(&inverter_chain(64))?;
+	let handle = std::thread::Builder::new()
+		.stack_size(SMALL_STACK)
+		.spawn(move || -> Result<(BehaviorState, bool), String> {
+			let run = || -> Result<(BehaviorState, bool), behaviortree::Error> {
+				let runtime = tokio::runtime::Builder::new_current_thread().build()?;
+				let mut factory = BehaviorTreeFactory::new()?;
+				factory.set_max_tree_depth(40);
+				let mut tree = factory.create_from_binary_xml(&chain)?;
+				let state = runtime.block_on(tree.tick_while_running())?;
+				// the depth is checked while creating, before the stack overflows
+				let rejected = matches!(
+					factory.create_from_binary_xml(&too_deep),
+					Err(Error::Create { source, .. }) if matches!(*source, XmlError::TreeTooDeep { limit: 40, .. })
+				);
+				Ok((state, rejected))
+			};
+			run().map_err(|error| error.to_string())
+		})?;
+	let Ok(result) = handle.join() else {
+		panic!("a deep decorator chain must not overflow a small stack");
+	};
+	assert_eq!(result, Ok((BehaviorState::Success, true)));
+	Ok(())
+}
+
+/// Returns the binary XML encoding of an [`inverter_chain`],
+/// built directly as the XML parser rejects deeply nested documents.
+fn binary_inverter_chain(depth: usize) -> Vec<u8> {
+	let push_str = |bytes: &mut Vec<u8>, value: &str| {
+		bytes.extend_from_slice(
+			&u16::try_from(value.len())
+				.unwrap_or_default()
+				.to_le_bytes(),
+		);
+		bytes.extend_from_slice(value.as_bytes());
+	};
+	let mut bytes = b"BTCB\x01".to_vec();
+	push_str(&mut bytes, "");
+	bytes.extend_from_slice(&1_u16.to_le_bytes());
+	push_str(&mut bytes, "BehaviorTree");
+	bytes.push(1);
+	push_str(&mut bytes, "ID");
+	push_str(&mut bytes, "MainTree");
+	bytes.extend_from_slice(&1_u16.to_le_bytes());
+	for _ in 0..depth {
+		push_str(&mut bytes, "Inverter");
+		bytes.push(0);
+		bytes.extend_from_slice(&1_u16.to_le_bytes());
+	}
+	push_str(&mut bytes, "AlwaysSuccess");
+	bytes.push(0);
+	bytes.extend_from_slice(&0_u16.to_le_bytes());
+	bytes
+}
+
+#[test]
+fn deeply_nested_binary_xml() -> Result<(), behaviortree::Error> {
+	assert_eq!(binary_inverter_chain(3), BinaryXmlEncoder::encode(&inverter_chain(3))?);
+	let nested = binary_inverter_chain(100_000);
+	let handle = std::thread::Builder::new()
+		.stack_size(SMALL_STACK)
+		.spawn(move || -> bool {
+			let Ok(mut factory) = BehaviorTreeFactory::new() else {
+				return false;
+			};
+			factory.set_max_tree_depth(40);
+			// the default parser limits do not restrict the nesting,
+			// the document is decoded without recursion and the creation stops at the tree depth limit
+			matches!(
+				factory.create_from_binary_xml(&nested),
+				Err(Error::Create { source, .. }) if matches!(*source, XmlError::TreeTooDeep { limit: 40, .. })
+			)
+		})?;
+	let Ok(rejected) = handle.join() else {
+		panic!("a deeply nested binary XML document must not overflow a small stack");
+	};
+	assert!(rejected);
+	Ok(())
+}
+
+/// Action writing its output port
+#[derive(Action, Debug, Default)]
+struct Produce;
+
+#[async_trait::async_trait]
+impl Behavior for Produce {
+	async fn tick(
+		&mut self,
+		behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		behavior.set("out", 42_i32)?;
+		Ok(BehaviorState::Success)
+	}
+
+	fn provided_ports() -> PortList {
+		port_list![output_port!(i32, "out")]
+	}
+}
+
+/// Action reading its input port
+#[derive(Action, Debug, Default)]
+struct Consume;
+
+#[async_trait::async_trait]
+impl Behavior for Consume {
+	async fn tick(
+		&mut self,
+		behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		let _ = behavior.get::<i32>("in")?;
+		Ok(BehaviorState::Success)
+	}
+
+	fn provided_ports() -> PortList {
+		port_list![input_port!(i32, "in")]
+	}
+}
+
+const DATA_FLOW_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence name="steps">
+			<Produce out="{wired}"/>
+			<Consume in="{wired}"/>
+			<Produce name="dangling" out="{dangling}"/>
+			<Consume name="missing" in="{missing}"/>
+			<Consume name="unwired"/>
+			<Consume in="42"/>
+			<Script code="scripted := 1"/>
+			<Consume in="{scripted}"/>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+#[test]
+fn analyze_tree() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, Produce, "Produce")?;
+	register_behavior!(factory, Consume, "Consume")?;
+	factory.register_behavior_tree_from_text(DATA_FLOW_TREE)?;
+
+	let warnings = factory.analyze_tree("MainTree")?;
+	assert_eq!(warnings.len(), 3);
+	assert!(warnings.iter().any(|warning| matches!(
+		warning,
+		TreeWarning::OutputNeverRead { path, port, key }
+			if path.ends_with("dangling") && port.as_ref() == "out" && key.as_ref() == "dangling"
+	)));
+	assert!(warnings.iter().any(|warning| matches!(
+		warning,
+		TreeWarning::InputWithoutProducer { path, port, key }
+			if path.ends_with("missing") && port.as_ref() == "in" && key.as_ref() == "missing"
+	)));
+	assert!(warnings.iter().any(|warning| matches!(
+		warning,
+		TreeWarning::UnwiredInput { path, port } if path.ends_with("unwired") && port.as_ref() == "in"
+	)));
+	assert!(
+		warnings
+			.iter()
+			.any(|warning| warning.to_string() == "input port `in` of /MainTree::0/steps/unwired has no producer or default")
+	);
+
+	assert!(factory.analyze_tree("Unknown").is_err());
+	Ok(())
+}
+
+const REMAPPED_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence>
+			<Consume in="{number}"/>
+			<Produce out="{result}"/>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn programmatic_remappings() -> Result<(), behaviortree::Error> {
+	let global_blackboard = Databoard::new();
+	let remappings = remappings_from_pairs(&[("number", "{@number}"), ("result", "{@result}")])?;
+	let root_blackboard = Databoard::with(Some(global_blackboard.clone()), Some(remappings), false);
+
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, Produce, "Produce")?;
+	register_behavior!(factory, Consume, "Consume")?;
+	factory.register_behavior_tree_from_text(REMAPPED_TREE)?;
+	let mut tree = factory.create_tree_with("MainTree", &root_blackboard)?;
+	drop(factory);
+
+	// without the global entry the read fails
+	assert!(tree.tick_once().await.is_err());
+
+	global_blackboard.set("number", 7_i32)?;
+	tree.reset()?;
+	assert_eq!(tree.tick_once().await?, BehaviorState::Success);
+	assert_eq!(global_blackboard.get::<i32>("result")?, 42);
+
+	// a key given twice is rejected
+	assert!(remappings_from_pairs(&[("number", "{a}"), ("number", "{b}")]).is_err());
+	Ok(())
+}
+
+/// Action reading its input port as double
+#[derive(Action, Debug, Default)]
+struct ConsumeDouble;
+
+#[async_trait::async_trait]
+impl Behavior for ConsumeDouble {
+	async fn tick(
+		&mut self,
+		behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		let _ = behavior.get::<f64>("in")?;
+		Ok(BehaviorState::Success)
+	}
+
+	fn provided_ports() -> PortList {
+		port_list![input_port!(f64, "in")]
+	}
+}
+
+const MIXED_TYPES_TREE: &str = r#"
+<root BTCPP_format="4" main_tree_to_execute="MainTree">
+	<BehaviorTree ID="MainTree">
+		<Sequence>
+			<Produce out="{value}"/>
+			<Consume in="{value}"/>
+			<SubTree ID="Sub" number="{value}"/>
+		</Sequence>
+	</BehaviorTree>
+	<BehaviorTree ID="Sub">
+		<Sequence>
+			<Consume in="{number}"/>
+			<ConsumeDouble in="{number}"/>
+			<ConsumeDouble in="{other}"/>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+#[test]
+fn validate_runtime_types() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, Produce, "Produce")?;
+	register_behavior!(factory, Consume, "Consume")?;
+	register_behavior!(factory, ConsumeDouble, "ConsumeDouble")?;
+	let tree = factory.create_from_text(MIXED_TYPES_TREE)?;
+
+	let warnings = tree.validate_runtime_types();
+	assert_eq!(warnings.len(), 1);
+	let TreeWarning::InconsistentType {
+		port,
+		key,
+		type_name,
+		expected,
+		..
+	} = &warnings[0]
+	else {
+		panic!("unexpected warning {}", warnings[0]);
+	};
+	assert_eq!(port.as_ref(), "in");
+	assert_eq!(key.as_ref(), "number");
+	assert_eq!(type_name.as_ref(), "f64");
+	assert_eq!(expected.as_ref(), "i32");
+	assert!(warnings[0].to_string().contains("`f64`"));
+	Ok(())
+}
+
+/// Creates a document with a `MainTree` containing `main` and an autoremapped subtree `Reader`.
+fn autoremap_document(main: &str) -> String {
+	format!(
+		r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence>
+			{main}
+		</Sequence>
+	</BehaviorTree>
+	<BehaviorTree ID="Reader">
+		<Consume in="{{value}}"/>
+	</BehaviorTree>
+</root>
+"#
+	)
+}
+
+#[rstest]
+#[case(r#"<Produce out="{value}"/><SubTree ID="Reader" _autoremap="true"/>"#, true)]
+#[case(r#"<Produce out="{other}"/><SubTree ID="Reader" _autoremap="true" value="{other}"/>"#, true)]
+#[case(r#"<Script code="value := 42"/><SubTree ID="Reader" _autoremap="true"/>"#, true)]
+#[case(r#"<SubTree ID="Reader" _autoremap="true" value="42"/>"#, true)]
+#[case(r#"<SubTree ID="Reader" _autoremap="true"/>"#, false)]
+#[case(r#"<Produce out="{other}"/><SubTree ID="Reader" _autoremap="true"/>"#, false)]
+// a script mentioning `value` only as part of another identifier does not provide it
+#[case(r#"<Script code="other_value := 42"/><SubTree ID="Reader" _autoremap="true"/>"#, false)]
+fn strict_autoremap(#[case] main: &str, #[case] valid: bool) -> Result<(), Error> {
+	for strict in [false, true] {
+		let mut factory = BehaviorTreeFactory::new()?;
+		factory.set_strict_autoremap(strict);
+		register_behavior!(factory, Produce, "Produce")?;
+		register_behavior!(factory, Consume, "Consume")?;
+		factory.register_behavior_tree_from_text(&autoremap_document(main))?;
+
+		let result = factory.create_tree("MainTree");
+		if valid || !strict {
+			assert!(result.is_ok());
+		} else {
+			let Err(Error::Create { source, .. }) = result else {
+				panic!("a missing autoremap entry must be detected");
+			};
+			let XmlError::AutoremapEntryMissing { key, .. } = *source else {
+				panic!("unexpected error {source}");
+			};
+			assert_eq!(key.as_ref(), "value");
+		}
+	}
+	Ok(())
+}
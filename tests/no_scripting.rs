@@ -0,0 +1,45 @@
+// Copyright © 2025 Stephan Kunz
+
+//! Tests a build without the `scripting` feature, run by the `no-default-features` CI job with
+//! `cargo test --no-default-features --features std,sequence,always_success --test no_scripting`
+
+#![cfg(not(feature = "scripting"))]
+
+use behaviortree::prelude::*;
+
+const TREE: &str = r#"
+<root BTCPP_format="4"
+		main_tree_to_execute="MainTree">
+	<BehaviorTree ID="MainTree">
+		<Sequence>
+			<AlwaysSuccess/>
+			<AlwaysSuccess/>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+const SCRIPT_TREE: &str = r#"
+<root BTCPP_format="4"
+		main_tree_to_execute="ScriptTree">
+	<BehaviorTree ID="ScriptTree">
+		<Sequence>
+			<AlwaysSuccess _skipIf="true"/>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn without_scripting() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut tree = factory.create_from_text(TREE)?;
+	assert_eq!(tree.tick_while_running().await?, BehaviorState::Success);
+
+	let Err(err) = factory.create_from_text(SCRIPT_TREE) else {
+		panic!("script attributes must be rejected");
+	};
+	assert!(err.to_string().contains("_skipIf"));
+
+	Ok(())
+}
@@ -6,7 +6,8 @@
 
 extern crate alloc;
 
-use behaviortree::{BehaviorTreeObserver, prelude::*};
+use alloc::sync::Arc;
+use behaviortree::{BehaviorTreeObserver, Groot2Connector, StateStreamer, prelude::*};
 
 const TREE: &str = r#"
 <root BTCPP_format="4"
@@ -54,3 +55,166 @@ async fn tree_observer() -> Result<(), Error> {
 	);
 	Ok(())
 }
+
+#[tokio::test]
+async fn recent_transitions() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+
+	let mut tree = factory.create_from_text(TREE)?;
+	let observer = BehaviorTreeObserver::with_transition_log(&mut tree, 4);
+	drop(factory);
+
+	assert!(observer.recent_transitions().is_empty());
+	let result = tree.tick_while_running().await?;
+	assert_eq!(result, BehaviorState::Success);
+
+	let total: usize = (0..tree.size())
+		.map(|uid| {
+			observer
+				.get_statistics(uid)
+				.unwrap()
+				.transitions_count
+		})
+		.sum();
+	let transitions = observer.recent_transitions();
+	assert_eq!(transitions.len(), total.min(4));
+	// the running numbers are consecutive and end with the last transition
+	for (i, transition) in transitions.iter().enumerate() {
+		assert_eq!(transition.index, total - transitions.len() + i);
+	}
+	// the last transition is the root finishing
+	let last = transitions.last().unwrap();
+	assert_eq!(last.uid, 0);
+	assert_eq!(last.new_state, BehaviorState::Success);
+
+	observer.reset();
+	assert!(observer.recent_transitions().is_empty());
+
+	// without transition log nothing is recorded
+	let observer = BehaviorTreeObserver::new(&mut tree);
+	tree.reset()?;
+	tree.tick_while_running().await?;
+	assert!(observer.recent_transitions().is_empty());
+	Ok(())
+}
+
+#[tokio::test]
+async fn jsonl_sink() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut tree = factory.create_from_text(TREE)?;
+	drop(factory);
+
+	let path = std::env::temp_dir().join("behaviortree_jsonl_sink.jsonl");
+	let observer = BehaviorTreeObserver::with_jsonl_sink(&mut tree, &path)?;
+	let result = tree.tick_while_running().await?;
+	assert_eq!(result, BehaviorState::Success);
+
+	let total: usize = (0..tree.size())
+		.map(|uid| {
+			observer
+				.get_statistics(uid)
+				.unwrap()
+				.transitions_count
+		})
+		.sum();
+	// the lines are buffered until flushed
+	observer.flush()?;
+	let content = std::fs::read_to_string(&path)?;
+	drop(observer);
+	drop(tree);
+	std::fs::remove_file(&path)?;
+
+	let lines: Vec<&str> = content.lines().collect();
+	assert_eq!(lines.len(), total);
+	for line in &lines {
+		assert!(line.starts_with(r#"{"t":"#));
+		assert!(line.contains(r#""uid":"#));
+		assert!(line.contains(r#""old":"#));
+		assert!(line.ends_with('}'));
+	}
+	// the last transition is the root finishing
+	assert!(lines[lines.len() - 1].contains(r#""uid":0,"#));
+	assert!(lines[lines.len() - 1].ends_with(r#""new":"Success"}"#));
+	Ok(())
+}
+
+#[tokio::test]
+async fn export_recording() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut tree = factory.create_from_text(TREE)?;
+	drop(factory);
+	let connector = Groot2Connector::new(&mut tree, 15667);
+
+	connector.start_recording();
+	let result = tree.tick_while_running().await?;
+	assert_eq!(result, BehaviorState::Success);
+	connector.stop_recording();
+
+	let path = std::env::temp_dir().join("behaviortree_export_recording.btlog");
+	connector.export_recording(&path)?;
+	let bytes = std::fs::read(&path)?;
+	std::fs::remove_file(&path)?;
+
+	// header, protocol version and tree
+	let header = b"BTCPP4-FileLogger2";
+	assert!(bytes.starts_with(header));
+	assert_eq!(bytes[header.len()], 1);
+	let xml_start = header.len() + 5;
+	let xml_len = u32::from_le_bytes(
+		bytes[header.len() + 1..xml_start]
+			.try_into()
+			.unwrap(),
+	) as usize;
+	let xml = core::str::from_utf8(&bytes[xml_start..xml_start + xml_len]).unwrap();
+	assert!(xml.starts_with("<root"));
+	assert!(xml.contains("MainTree"));
+	// first timestamp and transitions with 9 bytes each
+	let transitions = &bytes[xml_start + xml_len + 8..];
+	assert!(!transitions.is_empty());
+	assert_eq!(transitions.len() % 9, 0);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn state_streamer() -> Result<(), Error> {
+	/// Decodes a state buffer into uids and states.
+	fn decode(buffer: &[u8]) -> Vec<(u16, u8)> {
+		buffer
+			.chunks_exact(3)
+			.map(|entry| (u16::from_le_bytes([entry[0], entry[1]]), entry[2]))
+			.collect()
+	}
+
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut tree = factory.create_from_text(TREE)?;
+	drop(factory);
+
+	let emitted: Arc<Mutex<Vec<Vec<u8>>>> = Arc::default();
+	let emitted_clone = emitted.clone();
+	let streamer = StateStreamer::new(&mut tree, move |buffer: &[u8]| {
+		emitted_clone.lock().push(buffer.to_vec());
+	});
+
+	// initially all elements except the root are idle
+	streamer.emit();
+	assert!(!streamer.emit_if_changed());
+	assert_eq!(decode(&emitted.lock()[0]), vec![(1, 0), (2, 0), (3, 0), (4, 0)]);
+
+	let result = tree.tick_while_running().await?;
+	assert_eq!(result, BehaviorState::Success);
+	assert!(streamer.emit_if_changed());
+	assert!(!streamer.emit_if_changed());
+	assert_eq!(emitted.lock().len(), 2);
+
+	// the steps failed, failed and succeeded, maybe already halted, which is encoded as last state + 10
+	let states = decode(&emitted.lock()[1]);
+	let uids: Vec<u16> = states.iter().map(|(uid, _)| *uid).collect();
+	assert_eq!(uids, vec![1, 2, 3, 4]);
+	let failure = BehaviorState::Failure as u8;
+	let success = BehaviorState::Success as u8;
+	assert!([failure, failure + 10].contains(&states[1].1));
+	assert!([failure, failure + 10].contains(&states[2].1));
+	assert!([success, success + 10].contains(&states[3].1));
+	Ok(())
+}
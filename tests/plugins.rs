@@ -0,0 +1,92 @@
+// Copyright © 2025 Stephan Kunz
+
+//! Tests loading behavior plugins from a directory.
+//!
+//! The plugins are the `cdylib` examples `plugin_a` and `plugin_b`.
+
+#![cfg(target_os = "linux")]
+
+use behaviortree::prelude::*;
+use std::path::PathBuf;
+
+const TREE: &str = r#"
+<root BTCPP_format="4"
+		main_tree_to_execute="MainTree">
+	<BehaviorTree ID="MainTree">
+		<Sequence>
+			<PluginActionA/>
+			<PluginActionB/>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+/// Temporary directory with copies of the plugin fixtures, removed on drop.
+struct PluginDir(PathBuf);
+
+impl Drop for PluginDir {
+	fn drop(&mut self) {
+		let _ = std::fs::remove_dir_all(&self.0);
+	}
+}
+
+/// Copies the plugin fixtures into a separate directory.
+///
+/// `cargo test` builds the examples of the package into 'target/<profile>/examples',
+/// next to the 'target/<profile>/deps' directory of the test executable.
+fn prepare_plugin_dir() -> Result<PluginDir, Error> {
+	let exe = std::env::current_exe()?;
+	let mut examples = exe
+		.parent()
+		.and_then(|deps| deps.parent())
+		.map(PathBuf::from)
+		.unwrap_or_default();
+	examples.push("examples");
+
+	let dir = PluginDir(std::env::temp_dir().join(format!("behaviortree_plugins_{}", std::process::id())));
+	std::fs::create_dir_all(&dir.0)?;
+	for name in ["libplugin_a.so", "libplugin_b.so"] {
+		std::fs::copy(examples.join(name), dir.0.join(name)).map_err(|err| {
+			std::io::Error::new(
+				err.kind(),
+				format!("plugin fixture {name} not found, build it with `cargo build --examples`: {err}"),
+			)
+		})?;
+	}
+	// a file without library extension is ignored
+	std::fs::write(dir.0.join("README.txt"), "no plugin")?;
+	Ok(dir)
+}
+
+#[tokio::test]
+async fn register_plugins_from_dir() -> Result<(), Error> {
+	let plugins = prepare_plugin_dir()?;
+	let dir = &plugins.0;
+
+	let mut factory = BehaviorTreeFactory::new()?;
+	let count = factory.register_plugins_from_dir(dir)?;
+	assert_eq!(count, 2);
+
+	let mut tree = factory.create_from_text(TREE)?;
+	drop(factory);
+	let result = tree.tick_while_running().await?;
+	assert_eq!(result, BehaviorState::Success);
+
+	// registering the same plugins again fails for both
+	let mut factory = BehaviorTreeFactory::new()?;
+	factory.register_plugins_from_dir(dir)?;
+	let result = factory.register_plugins_from_dir(dir);
+	assert!(result.is_err_and(|err| {
+		let msg = err.to_string();
+		msg.contains("libplugin_a.so") && msg.contains("libplugin_b.so")
+	}));
+
+	// a missing directory is an error
+	assert!(
+		factory
+			.register_plugins_from_dir(dir.join("missing"))
+			.is_err()
+	);
+
+	Ok(())
+}
@@ -0,0 +1,31 @@
+// Copyright © 2025 Stephan Kunz
+
+//! Plugin fixture `B` for the `plugins` test.
+
+use behaviortree::prelude::*;
+
+/// Action provided by plugin `B`.
+#[derive(Action, Debug, Default)]
+pub struct PluginActionB;
+
+#[async_trait::async_trait]
+impl Behavior for PluginActionB {
+	async fn tick(
+		&mut self,
+		_behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		Ok(BehaviorState::Success)
+	}
+}
+
+/// Registration function of the plugin.
+#[allow(unsafe_code)]
+#[unsafe(no_mangle)]
+pub extern "Rust" fn register(factory: &mut BehaviorTreeFactory) -> u32 {
+	match factory.register_behavior_type::<PluginActionB>("PluginActionB") {
+		Ok(()) => 0,
+		Err(_) => 1,
+	}
+}
@@ -0,0 +1,2174 @@
+// Copyright © 2025 Stephan Kunz
+
+//! Tests the [`BehaviorTree`] and its elements
+
+#![allow(clippy::panic)]
+
+extern crate alloc;
+
+use alloc::sync::Arc;
+use behaviortree::{
+	BehaviorTreeElement, BinaryXmlEncoder, ManualClock, TreeError, XmlCreator,
+	behavior::{MockBehavior, MockBehaviorConfig},
+	prelude::*,
+};
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+const TREE: &str = r#"
+<root BTCPP_format="4"
+		main_tree_to_execute="MainTree">
+	<BehaviorTree ID="MainTree">
+		<Sequence name="sequence">
+			<AlwaysSuccess name="step1"/>
+			<AlwaysSuccess/>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn set_display_name() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut tree = factory.create_from_text(TREE)?;
+	drop(factory);
+
+	for element in tree.iter_mut() {
+		if element.name().as_ref() == "step1" {
+			assert_eq!(element.groot2_path().as_ref(), "/MainTree::0/sequence/step1");
+			element.set_display_name("renamed");
+			assert_eq!(element.name().as_ref(), "renamed");
+			assert_eq!(element.groot2_path().as_ref(), "/MainTree::0/sequence/renamed");
+		}
+		if element.uid() == 3 {
+			// unnamed elements get their uid appended
+			assert_eq!(element.groot2_path().as_ref(), "/MainTree::0/sequence/AlwaysSuccess::3");
+			element.set_display_name("step2");
+			assert_eq!(element.groot2_path().as_ref(), "/MainTree::0/sequence/step2");
+		}
+	}
+	assert!(
+		tree.iter()
+			.any(|element| element.name().as_ref() == "renamed")
+	);
+
+	let result = tree.tick_while_running().await?;
+	assert_eq!(result, BehaviorState::Success);
+
+	Ok(())
+}
+
+#[test]
+fn attribute_order_round_trip() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	let tree = factory.create_from_text(REORDERED_ORDER_TREE)?;
+	let xml = XmlCreator::write_tree(&tree, false, false, false)?;
+
+	// re-emitting the emitted XML gives the identical XML
+	let mut factory = BehaviorTreeFactory::new()?;
+	let tree = factory.create_from_text(xml.as_ref())?;
+	assert_eq!(XmlCreator::write_tree(&tree, false, false, false)?, xml);
+
+	Ok(())
+}
+
+const SNAPSHOT_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence name="sequence">
+			<AlwaysSuccess name="done"/>
+			<AlwaysRunning name="busy"/>
+			<AlwaysSuccess name="pending"/>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn write_tree_with_state() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut tree = factory.create_from_text(SNAPSHOT_TREE)?;
+	drop(factory);
+
+	// the attributes of the element with `name`
+	let element = |xml: &str, name: &str| -> String {
+		xml.find(&format!(r#"name="{name}""#))
+			.and_then(|start| {
+				let element = &xml[start..];
+				element
+					.find('>')
+					.map(|end| element[..end].to_string())
+			})
+			.unwrap_or_default()
+	};
+
+	let xml = XmlCreator::write_tree_with_state(&tree)?;
+	for name in ["sequence", "done", "busy", "pending"] {
+		assert!(element(&xml, name).contains(r#"_state="Idle""#));
+	}
+
+	assert_eq!(tree.tick_once().await?, BehaviorState::Running);
+	let xml = XmlCreator::write_tree_with_state(&tree)?;
+	assert!(xml.contains("_fullpath="));
+	assert!(element(&xml, "sequence").contains(r#"_state="Running""#));
+	assert!(element(&xml, "done").contains(r#"_state="Success""#));
+	assert!(element(&xml, "busy").contains(r#"_state="Running""#));
+	assert!(element(&xml, "pending").contains(r#"_state="Idle""#));
+
+	// the snapshot without states is unchanged
+	assert!(!XmlCreator::write_effective_tree(&tree, true, false, true)?.contains("_state"));
+	Ok(())
+}
+
+/// Action with a defaulted port
+#[derive(Action, Debug, Default)]
+struct Drive;
+
+#[async_trait::async_trait]
+impl Behavior for Drive {
+	async fn tick(
+		&mut self,
+		_behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		Ok(BehaviorState::Success)
+	}
+
+	fn provided_ports() -> PortList {
+		port_list![
+			input_port!(i64, "speed", 5),
+			input_port!(i64, "target")
+		]
+	}
+}
+
+const DEFAULTS_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence>
+			<Drive name="default" target="{goal}"/>
+			<Drive name="explicit" speed="7" target="{goal}"/>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+#[test]
+fn write_tree_with_defaults() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, Drive, "Drive")?;
+	let tree = factory.create_from_text(DEFAULTS_TREE)?;
+
+	// the attributes of the element with `name`
+	let element = |xml: &str, name: &str| -> String {
+		xml.find(&format!(r#"name="{name}""#))
+			.and_then(|start| {
+				let element = &xml[start..];
+				element
+					.find('>')
+					.map(|end| element[..end].to_string())
+			})
+			.unwrap_or_default()
+	};
+
+	let authored = XmlCreator::write_tree(&tree, false, false, false)?;
+	assert!(!element(&authored, "default").contains("speed="));
+	assert!(element(&authored, "default").contains(r#"target="{goal}""#));
+	assert!(element(&authored, "explicit").contains(r#"speed="7""#));
+
+	let effective = XmlCreator::write_effective_tree(&tree, false, false, false)?;
+	assert!(element(&effective, "default").contains(r#"speed="5""#));
+	assert!(element(&effective, "default").contains(r#"target="{goal}""#));
+	assert!(element(&effective, "explicit").contains(r#"speed="7""#));
+
+	// the effective configuration creates an equivalent tree
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, Drive, "Drive")?;
+	let tree = factory.create_from_text(effective.as_ref())?;
+	assert_eq!(XmlCreator::write_effective_tree(&tree, false, false, false)?, effective);
+
+	Ok(())
+}
+
+const COMMENTED_TREE: &str = r#"
+<root BTCPP_format="4"
+		main_tree_to_execute="MainTree">
+	<BehaviorTree ID="MainTree">
+		<Sequence name="sequence">
+			<!-- first we check something -->
+			<AlwaysSuccess name="step1"/>
+			<AlwaysSuccess name="step2"/>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn preserve_comments() -> Result<(), Error> {
+	// comments are dropped by default
+	let mut factory = BehaviorTreeFactory::new()?;
+	let tree = factory.create_from_text(COMMENTED_TREE)?;
+	let xml = XmlCreator::write_tree(&tree, false, false, false)?;
+	assert!(!xml.contains("first we check something"));
+
+	// comments survive a round-trip when preserved
+	let mut factory = BehaviorTreeFactory::new()?;
+	factory.preserve_comments(true);
+	let tree = factory.create_from_text(COMMENTED_TREE)?;
+	for element in tree.iter() {
+		let metadata = element.data().description().metadata();
+		if element.name().as_ref() == "step1" {
+			assert_eq!(metadata.map(AsRef::as_ref), Some("first we check something"));
+		} else {
+			assert!(metadata.is_none());
+		}
+	}
+	let xml = XmlCreator::write_tree(&tree, false, false, false)?;
+	assert!(xml.contains("first we check something"));
+
+	factory.clear_registered_behavior_trees();
+	let tree = factory.create_from_text(xml.as_ref())?;
+	let element = tree
+		.iter()
+		.find(|element| element.name().as_ref() == "step1");
+	assert!(element.is_some_and(|element| {
+		element
+			.data()
+			.description()
+			.metadata()
+			.is_some_and(|comment| comment.as_ref() == "first we check something")
+	}));
+
+	Ok(())
+}
+
+const RUNNING_TREE: &str = r#"
+<root BTCPP_format="4"
+		main_tree_to_execute="MainTree">
+	<BehaviorTree ID="MainTree">
+		<AlwaysRunning/>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn tick_with_budget() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut tree = factory.create_from_text(RUNNING_TREE)?;
+
+	let start = std::time::Instant::now();
+	let result = tree
+		.tick_with_budget(core::time::Duration::from_millis(5))
+		.await?;
+	assert_eq!(result, BehaviorState::Running);
+	assert!(start.elapsed() < core::time::Duration::from_secs(1));
+
+	// a finishing tree returns before the budget is exhausted
+	factory.clear_registered_behavior_trees();
+	let mut tree = factory.create_from_text(TREE)?;
+	let start = std::time::Instant::now();
+	let result = tree
+		.tick_with_budget(core::time::Duration::from_secs(10))
+		.await?;
+	assert_eq!(result, BehaviorState::Success);
+	assert!(start.elapsed() < core::time::Duration::from_secs(10));
+
+	// the budget is measured with the trees clock
+	factory.clear_registered_behavior_trees();
+	let clock = ManualClock::default();
+	factory.set_clock(clock.clone());
+	let mut tree = factory.create_from_text(RUNNING_TREE)?;
+	tree.set_pre_tick_hook(move |_| clock.advance(core::time::Duration::from_millis(1)));
+	let result = tree
+		.tick_with_budget(core::time::Duration::from_millis(5))
+		.await?;
+	assert_eq!(result, BehaviorState::Running);
+	assert_eq!(tree.tick_count(), 5);
+
+	Ok(())
+}
+
+#[cfg(feature = "embassy")]
+#[tokio::test]
+async fn tick_with_budget_embassy_clock() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	factory.set_clock(behaviortree::EmbassyClock);
+	let mut tree = factory.create_from_text(RUNNING_TREE)?;
+	// the mock driver only advances when told to
+	tree.set_pre_tick_hook(|_| embassy_time::MockDriver::get().advance(embassy_time::Duration::from_millis(1)));
+	let result = tree
+		.tick_with_budget(core::time::Duration::from_millis(5))
+		.await?;
+	assert_eq!(result, BehaviorState::Running);
+	assert_eq!(tree.tick_count(), 5);
+
+	Ok(())
+}
+
+const HOOKED_TREE: &str = r#"
+<root BTCPP_format="4"
+		main_tree_to_execute="MainTree">
+	<BehaviorTree ID="MainTree">
+		<SubTree ID="Guarded" flag="{flag}" done="{done}" _on_enter="flag = true" _on_exit="flag = false"/>
+	</BehaviorTree>
+
+	<BehaviorTree ID="Guarded">
+		<Sequence>
+			<ScriptCondition code="flag == true"/>
+			<Fallback>
+				<ScriptCondition code="done == true"/>
+				<AlwaysRunning/>
+			</Fallback>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn subtree_hooks() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut tree = factory.create_from_text(HOOKED_TREE)?;
+	drop(factory);
+	tree.blackboard().set("flag", false)?;
+	tree.blackboard().set("done", false)?;
+
+	// entering sets the flag
+	assert_eq!(tree.tick_once().await?, BehaviorState::Running);
+	assert!(tree.blackboard().get::<bool>("flag")?);
+
+	// halting a running subtree clears the flag
+	tree.reset()?;
+	assert!(!tree.blackboard().get::<bool>("flag")?);
+
+	// completing the subtree clears the flag
+	tree.blackboard().set("done", true)?;
+	assert_eq!(tree.tick_once().await?, BehaviorState::Success);
+	assert!(!tree.blackboard().get::<bool>("flag")?);
+
+	Ok(())
+}
+
+#[test]
+fn subtree_hooks_only_on_subtrees() -> Result<(), Error> {
+	let xml = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<AlwaysSuccess _on_enter="flag = true"/>
+	</BehaviorTree>
+</root>
+"#;
+	let mut factory = BehaviorTreeFactory::new()?;
+	assert!(factory.create_from_text(xml).is_err());
+	Ok(())
+}
+
+#[tokio::test]
+async fn element_by_uid() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	let tree = factory.create_from_text(HOOKED_TREE)?;
+	drop(factory);
+
+	// uid 0 is the root
+	let root = tree.element_by_uid(0);
+	let first = tree.iter().next();
+	assert!(root.is_some_and(|root| first.is_some_and(|first| root.uid() == 0 && core::ptr::eq(root, first))));
+
+	for element in tree.iter() {
+		let found = tree.element_by_uid(element.uid());
+		assert!(found.is_some_and(|found| found.uid() == element.uid() && found.groot2_path() == element.groot2_path()));
+	}
+
+	assert!(tree.element_by_uid(tree.size()).is_none());
+	Ok(())
+}
+
+const RELOAD_TREE: &str = r#"
+<root BTCPP_format="4"
+		main_tree_to_execute="MainTree">
+	<BehaviorTree ID="MainTree">
+		<Sequence>
+			<SubTree ID="Body" value="{value}"/>
+			<Script code="after = value"/>
+		</Sequence>
+	</BehaviorTree>
+
+	<BehaviorTree ID="Body">
+		<Script code="value = 1"/>
+	</BehaviorTree>
+</root>
+"#;
+
+const RELOADED_BODY: &str = r#"
+<BehaviorTree ID="Body">
+	<Sequence>
+		<Script code="value = 2"/>
+		<AlwaysSuccess name="added"/>
+	</Sequence>
+</BehaviorTree>
+"#;
+
+#[tokio::test]
+async fn reload_subtree() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut tree = factory.create_from_text(RELOAD_TREE)?;
+	tree.blackboard().set("value", 0_i64)?;
+	tree.blackboard().set("after", 0_i64)?;
+	let size = tree.size();
+
+	assert_eq!(tree.tick_while_running().await?, BehaviorState::Success);
+	assert_eq!(tree.blackboard().get::<i64>("after")?, 1);
+
+	// unknown definitions and subtrees are rejected
+	assert!(
+		factory
+			.replace_subtree_definition("Unknown", RELOADED_BODY)
+			.is_err()
+	);
+	assert!(tree.reload_subtree(&factory, "Unknown").is_err());
+
+	factory.replace_subtree_definition("Body", RELOADED_BODY)?;
+	tree.reset()?;
+	assert_eq!(tree.reload_subtree(&factory, "Body")?, 1);
+	assert_eq!(tree.size(), size + 2);
+
+	// the new elements get new uids
+	let added = tree
+		.iter()
+		.find(|element| element.name().as_ref() == "added")
+		.map(BehaviorTreeElement::uid);
+	assert!(added.is_some_and(|uid| uid >= size && tree.element_by_uid(uid).is_some()));
+
+	// the blackboard is preserved
+	assert_eq!(tree.blackboard().get::<i64>("after")?, 1);
+	assert_eq!(tree.tick_while_running().await?, BehaviorState::Success);
+	assert_eq!(tree.blackboard().get::<i64>("after")?, 2);
+
+	Ok(())
+}
+
+/// Action refusing to be created, if port `fail` is true
+#[derive(Action, Debug, Default)]
+struct Refuse;
+
+#[async_trait::async_trait]
+impl Behavior for Refuse {
+	fn on_creation(&mut self, behavior: &mut BehaviorData, _runtime: &SharedRuntime) -> Result<(), BehaviorError> {
+		if behavior.get::<bool>("fail").unwrap_or_default() {
+			return Err(BehaviorError::Composition {
+				txt: "refused to be created".into(),
+			});
+		}
+		Ok(())
+	}
+
+	async fn tick(
+		&mut self,
+		_behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		Ok(BehaviorState::Success)
+	}
+
+	fn provided_ports() -> PortList {
+		port_list![input_port!(bool, "fail")]
+	}
+}
+
+const PARTIAL_RELOAD_TREE: &str = r#"
+<root BTCPP_format="4"
+		main_tree_to_execute="MainTree">
+	<BehaviorTree ID="MainTree">
+		<Sequence>
+			<SubTree ID="Body" fail="{first}"/>
+			<SubTree ID="Body" fail="{second}"/>
+		</Sequence>
+	</BehaviorTree>
+
+	<BehaviorTree ID="Body">
+		<AlwaysSuccess/>
+	</BehaviorTree>
+</root>
+"#;
+
+const REFUSING_BODY: &str = r#"
+<BehaviorTree ID="Body">
+	<Refuse name="refuse" fail="{fail}"/>
+</BehaviorTree>
+"#;
+
+#[tokio::test]
+async fn reload_subtree_failing() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, Refuse, "Refuse")?;
+	let mut tree = factory.create_from_text(PARTIAL_RELOAD_TREE)?;
+	tree.blackboard().set("first", false)?;
+	tree.blackboard().set("second", true)?;
+	let size = tree.size();
+
+	// the second subtree can not be created, so the first one is not replaced either
+	factory.replace_subtree_definition("Body", REFUSING_BODY)?;
+	assert!(tree.reload_subtree(&factory, "Body").is_err());
+	assert_eq!(tree.size(), size);
+	assert!(
+		!tree
+			.iter()
+			.any(|element| element.name().as_ref() == "refuse")
+	);
+	assert_eq!(tree.tick_while_running().await?, BehaviorState::Success);
+
+	// after fixing the cause both are replaced
+	tree.reset()?;
+	tree.blackboard().set("second", false)?;
+	assert_eq!(tree.reload_subtree(&factory, "Body")?, 2);
+	assert_eq!(
+		tree.iter()
+			.filter(|element| element.name().as_ref() == "refuse")
+			.count(),
+		2
+	);
+	assert_eq!(tree.tick_while_running().await?, BehaviorState::Success);
+
+	Ok(())
+}
+
+const GROWING_TREE: &str = r#"
+<root BTCPP_format="4" main_tree_to_execute="MainTree">
+	<BehaviorTree ID="MainTree">
+		<Sequence name="sequence">
+			<SucceedAtTick tick="2"/>
+		</Sequence>
+	</BehaviorTree>
+	<BehaviorTree ID="Waypoint">
+		<SetBlackboard name="waypoint" output_key="reached" value="42"/>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn insert_and_remove_child() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, SucceedAtTick, "SucceedAtTick")?;
+	let mut tree = factory.create_from_text(GROWING_TREE)?;
+	let size = tree.size();
+	let sequence = tree
+		.iter()
+		.find(|element| element.name().as_ref() == "sequence")
+		.map(BehaviorTreeElement::uid)
+		.ok_or_else(|| TreeError::ElementNotFound { path: "sequence".into() })?;
+
+	// no modification while running
+	assert_eq!(tree.tick_once().await?, BehaviorState::Running);
+	assert!(
+		tree.insert_child(&factory, sequence, 1, "Waypoint")
+			.is_err()
+	);
+	assert!(tree.remove_child(sequence, 0).is_err());
+	assert_eq!(tree.tick_once().await?, BehaviorState::Success);
+
+	// no modification of an element with a running child
+	for state in [BehaviorState::Running, BehaviorState::Success] {
+		let Some(child) = tree
+			.iter_mut()
+			.find(|element| element.uid() == sequence + 1)
+		else {
+			panic!("child of 'sequence' not found");
+		};
+		child.data_mut().set_state(state);
+		if state == BehaviorState::Running {
+			assert!(
+				tree.insert_child(&factory, sequence, 1, "Waypoint")
+					.is_err()
+			);
+			assert!(tree.remove_child(sequence, 0).is_err());
+		}
+	}
+
+	// invalid parents, indices and definitions are rejected
+	assert!(
+		tree.insert_child(&factory, 1000, 0, "Waypoint")
+			.is_err()
+	);
+	assert!(
+		tree.insert_child(&factory, sequence, 2, "Waypoint")
+			.is_err()
+	);
+	assert!(
+		tree.insert_child(&factory, sequence, 1, "Unknown")
+			.is_err()
+	);
+
+	// the inserted child gets a new uid and is ticked
+	let uid = tree.insert_child(&factory, sequence, 1, "Waypoint")?;
+	assert!(uid >= size);
+	assert_eq!(tree.size(), size + 1);
+	assert!(
+		tree.element_by_uid(uid)
+			.is_some_and(|element| element.name().as_ref() == "waypoint")
+	);
+	assert_eq!(tree.tick_once().await?, BehaviorState::Success);
+	assert_eq!(tree.blackboard().get::<String>("reached")?, "42");
+
+	// the removed child is returned
+	let removed = tree.remove_child(sequence, 1)?;
+	assert_eq!(removed.uid(), uid);
+	assert_eq!(tree.size(), size);
+	assert!(tree.element_by_uid(uid).is_none());
+
+	Ok(())
+}
+
+const ENV_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence>
+			<SetBlackboard output_key="package" value="crate ${CARGO_PKG_NAME}"/>
+			<SetBlackboard output_key="fallback" value="${BEHAVIORTREE_UNDEFINED_VARIABLE:-default}"/>
+			<SetBlackboard output_key="literal" value="${unterminated"/>
+			<AlwaysSuccess _onSuccess="script := '${CARGO_PKG_NAME}'"/>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+const UNDEFINED_ENV_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<SetBlackboard output_key="value" value="${BEHAVIORTREE_UNDEFINED_VARIABLE}"/>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn environment_interpolation() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut tree = factory.create_from_text(ENV_TREE)?;
+
+	assert_eq!(tree.tick_once().await?, BehaviorState::Success);
+	assert_eq!(
+		tree.blackboard().get::<String>("package")?,
+		concat!("crate ", env!("CARGO_PKG_NAME"))
+	);
+	assert_eq!(tree.blackboard().get::<String>("fallback")?, "default");
+	assert_eq!(tree.blackboard().get::<String>("literal")?, "${unterminated");
+	// scripts are not expanded
+	assert_eq!(tree.blackboard().get::<String>("script")?, "${CARGO_PKG_NAME}");
+
+	// undefined variable without default
+	let mut factory = BehaviorTreeFactory::new()?;
+	assert!(
+		factory
+			.create_from_text(UNDEFINED_ENV_TREE)
+			.is_err()
+	);
+
+	Ok(())
+}
+
+/// The tree of tutorial t10
+const T10_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence>
+			<Fallback>
+				<AlwaysFailure name="failing_action"/>
+				<SubTree ID="SubTreeA" name="mysub"/>
+			</Fallback>
+			<AlwaysSuccess name="last_action"/>
+		</Sequence>
+	</BehaviorTree>
+
+	<BehaviorTree ID="SubTreeA">
+		<Sequence>
+			<AlwaysSuccess name="action_subA"/>
+			<SubTree ID="SubTreeB" name="sub_nested"/>
+			<SubTree ID="SubTreeB" />
+		</Sequence>
+	</BehaviorTree>
+
+	<BehaviorTree ID="SubTreeB">
+		<AlwaysSuccess name="action_subB"/>
+	</BehaviorTree>
+</root>
+"#;
+
+#[test]
+fn tree_metrics() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	factory.register_behavior_tree_from_text(T10_TREE)?;
+	let tree = factory.create_tree("MainTree")?;
+
+	assert_eq!(tree.size(), 12);
+	// MainTree -> Sequence -> Fallback -> mysub -> Sequence -> sub_nested -> action_subB
+	assert_eq!(tree.depth(), 7);
+	assert_eq!(tree.max_branching(), 3);
+
+	let tree = factory.create_tree("SubTreeB")?;
+	assert_eq!(tree.size(), 2);
+	assert_eq!(tree.depth(), 2);
+	assert_eq!(tree.max_branching(), 1);
+
+	Ok(())
+}
+
+#[test]
+fn leaf_paths() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	factory.register_behavior_tree_from_text(T10_TREE)?;
+	let tree = factory.create_tree("MainTree")?;
+
+	let paths = tree.leaf_paths();
+	let names = [
+		"/failing_action",
+		"/action_subA",
+		"/action_subB",
+		"/action_subB",
+		"/last_action",
+	];
+	assert_eq!(paths.len(), names.len());
+	for (path, name) in paths.iter().zip(names) {
+		assert!(path.ends_with(name));
+	}
+	// the leafs within the subtree instances are distinguishable
+	assert_ne!(paths[2], paths[3]);
+
+	let tree = factory.create_tree("SubTreeB")?;
+	assert_eq!(tree.leaf_paths().len(), 1);
+
+	Ok(())
+}
+
+const STABLE_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence name="sequence">
+			<AlwaysSuccess name="first"/>
+			<SubTree ID="Sub" name="sub"/>
+			<AlwaysSuccess/>
+			<AlwaysFailure/>
+		</Sequence>
+	</BehaviorTree>
+	<BehaviorTree ID="Sub">
+		<AlwaysSuccess name="inner"/>
+	</BehaviorTree>
+</root>
+"#;
+
+const REORDERED_STABLE_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence name="sequence">
+			<AlwaysFailure/>
+			<SubTree ID="Sub" name="sub"/>
+			<AlwaysSuccess/>
+			<AlwaysSuccess name="first"/>
+		</Sequence>
+	</BehaviorTree>
+	<BehaviorTree ID="Sub">
+		<AlwaysSuccess name="inner"/>
+	</BehaviorTree>
+</root>
+"#;
+
+/// Collects the stable ids of a tree by element name.
+fn stable_ids(tree: &BehaviorTree) -> Vec<(String, Option<u64>)> {
+	let mut ids: Vec<(String, Option<u64>)> = tree
+		.iter()
+		.map(|element| (element.name().to_string(), element.stable_id()))
+		.collect();
+	ids.sort();
+	ids
+}
+
+#[test]
+fn stable_ids_survive_reordering() -> Result<(), Error> {
+	// disabled by default
+	let mut factory = BehaviorTreeFactory::new()?;
+	let tree = factory.create_from_text(STABLE_TREE)?;
+	assert!(
+		tree.iter()
+			.all(|element| element.stable_id().is_none())
+	);
+
+	let mut factory = BehaviorTreeFactory::new()?;
+	factory.stable_ids(true);
+	let tree = factory.create_from_text(STABLE_TREE)?;
+	let original = stable_ids(&tree);
+	assert!(original.iter().all(|(_, id)| id.is_some()));
+	// stable ids are unique
+	let mut ids: Vec<Option<u64>> = original.iter().map(|(_, id)| *id).collect();
+	ids.sort_unstable();
+	ids.dedup();
+	assert_eq!(ids.len(), original.len());
+
+	let mut factory = BehaviorTreeFactory::new()?;
+	factory.stable_ids(true);
+	let reordered = factory.create_from_text(REORDERED_STABLE_TREE)?;
+	assert_eq!(stable_ids(&reordered), original);
+
+	// the uids follow the order
+	let uid_of = |tree: &BehaviorTree, name: &str| {
+		tree.iter()
+			.find(|element| element.name().as_ref() == name)
+			.map(BehaviorTreeElement::uid)
+	};
+	assert_ne!(uid_of(&tree, "first"), uid_of(&reordered, "first"));
+
+	Ok(())
+}
+
+/// Action simulating `MoveBase` of tutorial t06, running once before succeeding
+#[derive(Action, Debug, Default)]
+struct MoveBase {
+	ticks: usize,
+}
+
+#[async_trait::async_trait]
+impl Behavior for MoveBase {
+	fn on_start(
+		&mut self,
+		behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> Result<(), BehaviorError> {
+		let _goal = behavior.get::<String>("goal")?;
+		self.ticks = 0;
+		Ok(())
+	}
+
+	async fn tick(
+		&mut self,
+		_behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		self.ticks += 1;
+		if self.ticks > 1 {
+			Ok(BehaviorState::Success)
+		} else {
+			Ok(BehaviorState::Running)
+		}
+	}
+
+	fn provided_ports() -> PortList {
+		port_list![input_port!(String, "goal")]
+	}
+}
+
+/// The tree of tutorial t06
+const T06_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence>
+			<Script code=" move_goal:='1;2;3' " />
+			<SubTree ID="MoveRobot" target="{move_goal}" result="{move_result}" />
+			<Script code=" main_done:=true " />
+		</Sequence>
+	</BehaviorTree>
+
+	<BehaviorTree ID="MoveRobot">
+		<Fallback>
+			<Sequence>
+				<MoveBase  goal="{target}"/>
+				<Script code=" result:='goal reached' " />
+			</Sequence>
+			<ForceFailure>
+				<Script code=" result:='error' " />
+			</ForceFailure>
+		</Fallback>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn tick_subtree() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, MoveBase, "MoveBase")?;
+	factory.register_behavior_tree_from_text(T06_TREE)?;
+	let mut tree = factory.create_tree("MainTree")?;
+	drop(factory);
+
+	// the remapped input is provided by the parent blackboard
+	tree.blackboard()
+		.set("move_goal", String::from("4;5;6"))?;
+	let hooks = Arc::new(AtomicUsize::new(0));
+	let pre = hooks.clone();
+	tree.set_pre_tick_hook(move |_| {
+		pre.fetch_add(1, Ordering::Relaxed);
+	});
+	let post = hooks.clone();
+	tree.set_post_tick_hook(move |_| {
+		post.fetch_add(1, Ordering::Relaxed);
+	});
+
+	assert_eq!(tree.tick_subtree(1).await?, BehaviorState::Running);
+	assert_eq!(tree.tick_subtree(1).await?, BehaviorState::Success);
+	// the ticks are counted and surrounded by the hooks
+	assert_eq!(tree.tick_count(), 2);
+	assert_eq!(hooks.load(Ordering::Relaxed), 4);
+
+	// the remapped output is written to the parent blackboard
+	assert_eq!(tree.blackboard().get::<String>("move_result")?, "goal reached");
+	assert_eq!(tree.blackboard().get::<String>("move_goal")?, "4;5;6");
+	// the main tree was not ticked
+	assert!(!tree.blackboard().contains_key("main_done"));
+
+	assert!(tree.tick_subtree(2).await.is_err());
+	assert_eq!(tree.tick_count(), 2);
+
+	Ok(())
+}
+
+/// A tree with attributes added by an editor
+const EDITOR_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree" _fullpath="">
+		<Sequence name="sequence" _uid="1" _position="120,40">
+			<AlwaysSuccess name="first" _uid="2" _color="red"/>
+			<SubTree ID="Sub" _fullpath="Sub::3" _uid="3" _collapsed="true"/>
+		</Sequence>
+	</BehaviorTree>
+	<BehaviorTree ID="Sub">
+		<AlwaysSuccess name="inner" _uid="4"/>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn ignore_unknown_metadata() -> Result<(), Error> {
+	// strict by default
+	let mut factory = BehaviorTreeFactory::new()?;
+	assert!(factory.create_from_text(EDITOR_TREE).is_err());
+
+	let mut factory = BehaviorTreeFactory::new()?;
+	factory.ignore_unknown_metadata(true);
+	let mut tree = factory.create_from_text(EDITOR_TREE)?;
+	drop(factory);
+
+	let attributes_of = |tree: &BehaviorTree, name: &str| {
+		tree.iter()
+			.find(|element| element.name().as_ref() == name)
+			.map(|element| {
+				element
+					.data()
+					.description()
+					.metadata_attributes()
+					.iter()
+					.map(|(key, value)| String::from(key.as_ref()) + "=" + value)
+					.collect::<Vec<String>>()
+			})
+			.unwrap_or_default()
+	};
+	assert_eq!(attributes_of(&tree, "sequence"), ["_uid=1", "_position=120,40"]);
+	assert_eq!(attributes_of(&tree, "first"), ["_uid=2", "_color=red"]);
+	assert_eq!(attributes_of(&tree, "Sub").len(), 3);
+
+	// known special attributes are still handled
+	assert_eq!(tree.tick_while_running().await?, BehaviorState::Success);
+
+	Ok(())
+}
+
+/// Action succeeding in the tick with the index given by port `tick`
+#[derive(Action, Debug, Default)]
+struct SucceedAtTick;
+
+#[async_trait::async_trait]
+impl Behavior for SucceedAtTick {
+	async fn tick(
+		&mut self,
+		behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		if behavior.tick_index() >= behavior.get::<u64>("tick")? {
+			Ok(BehaviorState::Success)
+		} else {
+			Ok(BehaviorState::Running)
+		}
+	}
+
+	fn provided_ports() -> PortList {
+		port_list![input_port!(u64, "tick")]
+	}
+}
+
+const TICK_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<SucceedAtTick tick="3"/>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn tick_count() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, SucceedAtTick, "SucceedAtTick")?;
+	let mut tree = factory.create_from_text(TICK_TREE)?;
+	drop(factory);
+
+	assert_eq!(tree.tick_count(), 0);
+	assert_eq!(tree.tick_once().await?, BehaviorState::Running);
+	assert_eq!(tree.tick_count(), 1);
+	assert_eq!(tree.tick_while_running().await?, BehaviorState::Success);
+	assert_eq!(tree.tick_count(), 3);
+
+	tree.reset()?;
+	assert_eq!(tree.tick_count(), 0);
+	assert_eq!(tree.tick_once().await?, BehaviorState::Running);
+	assert_eq!(tree.tick_count(), 1);
+
+	Ok(())
+}
+
+const RUNNING_LEAVES_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence name="sequence">
+			<SucceedAtTick name="first" tick="2"/>
+			<SucceedAtTick name="second" tick="4"/>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn running_leaves() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, SucceedAtTick, "SucceedAtTick")?;
+	let mut tree = factory.create_from_text(RUNNING_LEAVES_TREE)?;
+	drop(factory);
+
+	let running = |tree: &BehaviorTree| -> Vec<String> {
+		tree.running_leaves()
+			.iter()
+			.map(|element| element.name().to_string())
+			.collect()
+	};
+
+	assert!(running(&tree).is_empty());
+	assert_eq!(tree.tick_once().await?, BehaviorState::Running);
+	assert_eq!(running(&tree), ["first"]);
+	assert_eq!(tree.tick_once().await?, BehaviorState::Running);
+	assert_eq!(running(&tree), ["second"]);
+	assert_eq!(tree.tick_while_running().await?, BehaviorState::Success);
+	assert!(running(&tree).is_empty());
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn tick_while_running_at() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, SucceedAtTick, "SucceedAtTick")?;
+	let mut tree = factory.create_from_text(TICK_TREE)?;
+	drop(factory);
+
+	// 3 ticks with 2 periods in between
+	let period = core::time::Duration::from_millis(20);
+	let start = std::time::Instant::now();
+	assert_eq!(tree.tick_while_running_at(period).await?, BehaviorState::Success);
+	let elapsed = start.elapsed();
+	assert_eq!(tree.tick_count(), 3);
+	assert!(elapsed >= 2 * period);
+	assert!(elapsed < 50 * period);
+
+	Ok(())
+}
+
+const ORDER_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence name="sequence" _while="true" _skipIf="false">
+			<SetBlackboard value="42" name="set" output_key="answer" _onSuccess="done := true"/>
+			<SubTree ID="Sub" name="sub" target="{answer}" result="{result}"/>
+		</Sequence>
+	</BehaviorTree>
+	<BehaviorTree ID="Sub">
+		<AlwaysSuccess name="inner"/>
+	</BehaviorTree>
+</root>
+"#;
+
+const REORDERED_ORDER_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence _skipIf="false" _while="true" name="sequence">
+			<SetBlackboard _onSuccess="done := true" output_key="answer" name="set" value="42"/>
+			<SubTree result="{result}" target="{answer}" name="sub" ID="Sub"/>
+		</Sequence>
+	</BehaviorTree>
+	<BehaviorTree ID="Sub">
+		<AlwaysSuccess name="inner"/>
+	</BehaviorTree>
+</root>
+"#;
+
+#[test]
+fn canonical_attribute_order() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	let tree = factory.create_from_text(ORDER_TREE)?;
+	let xml = XmlCreator::write_tree(&tree, true, false, false)?;
+
+	// output is stable
+	assert_eq!(XmlCreator::write_tree(&tree, true, false, false)?, xml);
+	let mut factory = BehaviorTreeFactory::new()?;
+	let reordered = factory.create_from_text(REORDERED_ORDER_TREE)?;
+	assert_eq!(XmlCreator::write_tree(&reordered, true, false, false)?, xml);
+
+	// `ID`, `name`, ports alphabetically, `_` attributes alphabetically
+	let in_order = |tag: &str, attributes: &[&str]| {
+		xml.find(tag)
+			.and_then(|start| {
+				let element = &xml[start..];
+				element.find('>').map(|end| &element[..end])
+			})
+			.is_some_and(|element| {
+				let positions: Vec<Option<usize>> = attributes
+					.iter()
+					.map(|attribute| element.find(attribute))
+					.collect();
+				positions.iter().all(Option::is_some) && positions.is_sorted()
+			})
+	};
+	assert!(in_order("<Sequence", &[" name=", " _skipIf=", " _uid=", " _while="]));
+	assert!(in_order(
+		"<SetBlackboard",
+		&[
+			" name=",
+			" output_key=",
+			" value=",
+			" _onSuccess=",
+			" _uid="
+		]
+	));
+	assert!(in_order(
+		"<SubTree",
+		&[
+			" ID=",
+			" result=",
+			" target=",
+			" _fullpath=",
+			" _uid="
+		]
+	));
+
+	Ok(())
+}
+
+/// Action illegally returning `Idle` from tick
+#[derive(Action, Debug, Default)]
+struct ReturnIdle;
+
+#[async_trait::async_trait]
+impl Behavior for ReturnIdle {
+	async fn tick(
+		&mut self,
+		_behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		Ok(BehaviorState::Idle)
+	}
+}
+
+const IDLE_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence name="sequence">
+			<AlwaysSuccess/>
+			<ReturnIdle name="idler"/>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn illegal_tick_result() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, ReturnIdle, "ReturnIdle")?;
+	let mut tree = factory.create_from_text(IDLE_TREE)?;
+	drop(factory);
+
+	let result = tree.tick_once().await;
+	assert!(matches!(
+		result,
+		Err(BehaviorError::IllegalTickResult { node, state: BehaviorState::Idle }) if node.ends_with("/sequence/idler")
+	));
+
+	Ok(())
+}
+
+/// Condition awaiting before it returns the state from port `state`
+#[derive(Condition, Debug, Default)]
+struct AwaitingCondition;
+
+#[async_trait::async_trait]
+impl Behavior for AwaitingCondition {
+	async fn tick(
+		&mut self,
+		behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		// e.g. awaiting a sensor query
+		tokio::task::yield_now().await;
+		Ok(behavior.get::<BehaviorState>("state")?)
+	}
+
+	fn provided_ports() -> PortList {
+		port_list![input_port!(BehaviorState, "state")]
+	}
+}
+
+const AWAITING_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<ReactiveSequence name="sequence">
+			<AwaitingCondition name="check" state="{state}"/>
+			<AlwaysSuccess/>
+		</ReactiveSequence>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn condition_returned_running() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, AwaitingCondition, "AwaitingCondition")?;
+	let mut tree = factory.create_from_text(AWAITING_TREE)?;
+	drop(factory);
+
+	// awaiting is fine, as long as the condition completes
+	tree.blackboard()
+		.set("state", BehaviorState::Success)?;
+	assert_eq!(tree.tick_once().await?, BehaviorState::Success);
+
+	tree.blackboard()
+		.set("state", BehaviorState::Running)?;
+	let result = tree.tick_once().await;
+	assert!(matches!(
+		result,
+		Err(BehaviorError::ConditionReturnedRunning { node }) if node.ends_with("/sequence/check")
+	));
+
+	Ok(())
+}
+
+/// Action running forever
+#[derive(Action, Debug, Default)]
+struct Approach;
+
+#[async_trait::async_trait]
+impl Behavior for Approach {
+	async fn tick(
+		&mut self,
+		_behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		Ok(BehaviorState::Running)
+	}
+
+	fn provided_ports() -> PortList {
+		port_list![
+			input_port!(String, "target"),
+			input_port!(i32, "speed"),
+			input_port!(String, "unset"),
+			output_port!(i32, "done")
+		]
+	}
+}
+
+const INSPECT_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Approach name="approach" target="{goal}" speed="5" unset="{nothing}" done="{result}"/>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn input_port_values() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, Approach, "Approach")?;
+	let mut tree = factory.create_from_text(INSPECT_TREE)?;
+	drop(factory);
+
+	tree.blackboard()
+		.set("goal", String::from("kitchen"))?;
+	assert_eq!(tree.tick_once().await?, BehaviorState::Running);
+
+	let values = |tree: &BehaviorTree| -> Vec<String> {
+		tree.iter()
+			.filter(|element| element.name().as_ref() == "approach")
+			.flat_map(BehaviorTreeElement::input_port_values)
+			.map(|(port, value)| value.map_or_else(|_| format!("{port}: -"), |value| format!("{port}: {value}")))
+			.collect()
+	};
+	// output ports are not listed, not yet set values are errors
+	assert_eq!(values(&tree), ["target: kitchen", "speed: 5", "unset: -"]);
+
+	// values are read from the live blackboard
+	tree.blackboard()
+		.set("goal", String::from("garden"))?;
+	tree.blackboard()
+		.set("nothing", String::from("something"))?;
+	assert_eq!(tree.tick_once().await?, BehaviorState::Running);
+	assert_eq!(values(&tree), ["target: garden", "speed: 5", "unset: something"]);
+
+	Ok(())
+}
+
+const ENABLE_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence>
+			<Sequence name="optional">
+				<Script code="first:=true"/>
+				<Script code="second:=true"/>
+			</Sequence>
+			<AlwaysSuccess/>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn disabled_element() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut tree = factory.create_from_text(ENABLE_TREE)?;
+	drop(factory);
+
+	let set_enabled = |tree: &mut BehaviorTree, enabled: bool| {
+		for element in tree.iter_mut() {
+			if element.name().as_ref() == "optional" {
+				element.set_enabled(enabled);
+			}
+		}
+	};
+
+	// the whole subtree of the disabled element is skipped
+	set_enabled(&mut tree, false);
+	assert_eq!(tree.tick_while_running().await?, BehaviorState::Success);
+	assert!(tree.blackboard().get::<bool>("first").is_err());
+	assert!(tree.blackboard().get::<bool>("second").is_err());
+
+	// after re-enabling the subtree is ticked again
+	tree.reset()?;
+	set_enabled(&mut tree, true);
+	assert_eq!(tree.tick_while_running().await?, BehaviorState::Success);
+	assert!(tree.blackboard().get::<bool>("first")?);
+	assert!(tree.blackboard().get::<bool>("second")?);
+
+	Ok(())
+}
+
+/// Buggy control ticking its child while halting
+#[derive(Control, Debug, Default)]
+struct TickOnHalt;
+
+#[async_trait::async_trait]
+impl Behavior for TickOnHalt {
+	async fn tick(
+		&mut self,
+		_behavior: &mut BehaviorData,
+		children: &mut BehaviorTreeElementList,
+		runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		children[0].tick(runtime).await?;
+		Ok(BehaviorState::Running)
+	}
+
+	fn halt(
+		&mut self,
+		_behavior: &mut BehaviorData,
+		children: &mut BehaviorTreeElementList,
+		runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		{
+			let mut tick = core::pin::pin!(children[0].tick(runtime));
+			let _ = tick
+				.as_mut()
+				.poll(&mut core::task::Context::from_waker(core::task::Waker::noop()));
+		}
+		children.halt(runtime)?;
+		Ok(BehaviorState::Idle)
+	}
+}
+
+const TICK_ON_HALT_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<TickOnHalt>
+			<AlwaysRunning/>
+		</TickOnHalt>
+	</BehaviorTree>
+</root>
+"#;
+
+async fn tick_and_halt() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, TickOnHalt, "TickOnHalt")?;
+	let mut tree = factory.create_from_text(TICK_ON_HALT_TREE)?;
+	drop(factory);
+
+	assert_eq!(tree.tick_once().await?, BehaviorState::Running);
+	tree.reset()?;
+	Ok(())
+}
+
+#[cfg(debug_assertions)]
+#[tokio::test]
+#[should_panic(expected = "is ticked while its parent is halting")]
+async fn tick_while_halting() {
+	let _ = tick_and_halt().await;
+}
+
+#[tokio::test]
+async fn global_transition_callback() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut tree = factory.create_from_text(TREE)?;
+
+	let successes = Arc::new(AtomicUsize::new(0));
+	let counter = successes.clone();
+	tree.add_global_transition_callback(&"count".into(), move |_behavior, state| {
+		if state == BehaviorState::Success {
+			counter.fetch_add(1, Ordering::Relaxed);
+		}
+	});
+
+	// the root subtree, the sequence and both children succeed
+	assert_eq!(tree.tick_while_running().await?, BehaviorState::Success);
+	assert_eq!(successes.load(Ordering::Relaxed), 4);
+
+	tree.remove_global_transition_callback(&"count".into());
+	tree.reset()?;
+	assert_eq!(tree.tick_while_running().await?, BehaviorState::Success);
+	assert_eq!(successes.load(Ordering::Relaxed), 4);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn global_transition_callback_twice() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut tree = factory.create_from_text(TREE)?;
+
+	let successes = Arc::new(AtomicUsize::new(0));
+	for _ in 0..2 {
+		let counter = successes.clone();
+		tree.add_global_transition_callback(&"count".into(), move |_behavior, state| {
+			if state == BehaviorState::Success {
+				counter.fetch_add(1, Ordering::Relaxed);
+			}
+		});
+	}
+	let other = successes.clone();
+	tree.add_global_transition_callback(&"other".into(), move |_behavior, state| {
+		if state == BehaviorState::Success {
+			other.fetch_add(10, Ordering::Relaxed);
+		}
+	});
+
+	// both callbacks with the same name are called
+	assert_eq!(tree.tick_while_running().await?, BehaviorState::Success);
+	assert_eq!(successes.load(Ordering::Relaxed), 48);
+
+	// removing the name removes both and keeps the other callback
+	tree.remove_global_transition_callback(&"count".into());
+	tree.reset()?;
+	assert_eq!(tree.tick_while_running().await?, BehaviorState::Success);
+	assert_eq!(successes.load(Ordering::Relaxed), 88);
+
+	Ok(())
+}
+
+const CHANGES_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence>
+			<AlwaysSuccess name="a"/>
+			<AlwaysRunning name="b"/>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+const FAILING_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Fallback name="fallback">
+			<Sequence name="sequence">
+				<AlwaysSuccess name="first"/>
+				<ScriptCondition name="check" code="value == 1" reason="{why}"/>
+				<AlwaysSuccess name="never"/>
+			</Sequence>
+			<AlwaysFailure name="last"/>
+		</Fallback>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn last_failure() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut tree = factory.create_from_text(FAILING_TREE)?;
+	drop(factory);
+	tree.blackboard().set("value", 0_i64)?;
+
+	// without tracking nothing is recorded
+	assert_eq!(tree.tick_while_running().await?, BehaviorState::Failure);
+	assert!(tree.last_failure().is_none());
+
+	// the most recently failed leaf is reported, not the failed condition before
+	tree.reset()?;
+	tree.enable_failure_tracking();
+	assert_eq!(tree.tick_while_running().await?, BehaviorState::Failure);
+	let Some((path, reason)) = tree.last_failure() else {
+		panic!("no failure recorded");
+	};
+	assert!(path.ends_with("last"));
+	assert!(reason.is_none());
+
+	// reset clears the failure, tracking stays enabled
+	tree.reset()?;
+	assert!(tree.last_failure().is_none());
+	assert_eq!(tree.tick_once().await?, BehaviorState::Failure);
+	assert!(
+		tree.last_failure()
+			.is_some_and(|(path, _)| path.ends_with("last"))
+	);
+
+	Ok(())
+}
+
+const CHECK_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence name="sequence">
+			<AlwaysSuccess name="first"/>
+			<ScriptCondition name="check" code="value == 1" reason="{why}"/>
+			<AlwaysSuccess name="never"/>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn last_failure_reason() -> Result<(), Error> {
+	// the reason is an output port, so it has to be found with strict port directions too
+	for strict in [false, true] {
+		let mut factory = BehaviorTreeFactory::new()?;
+		factory.set_options(TreeOptions::empty().with(TreeOptions::STRICT_PORT_DIRECTIONS, strict));
+		let mut tree = factory.create_from_text(CHECK_TREE)?;
+		drop(factory);
+		tree.blackboard().set("value", 0_i64)?;
+		tree.enable_failure_tracking();
+
+		assert_eq!(tree.tick_while_running().await?, BehaviorState::Failure);
+		let Some((path, reason)) = tree.last_failure() else {
+			panic!("no failure recorded");
+		};
+		assert!(path.ends_with("check"));
+		assert_eq!(reason.as_deref(), Some("script 'value == 1' returned false"));
+	}
+
+	Ok(())
+}
+
+const TRACKED_TREE: &str = r#"
+<root BTCPP_format="4" main_tree_to_execute="MainTree">
+	<BehaviorTree ID="MainTree">
+		<Sequence name="sequence">
+			<AlwaysSuccess name="first"/>
+		</Sequence>
+	</BehaviorTree>
+	<BehaviorTree ID="Failing">
+		<AlwaysFailure name="inserted"/>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn last_failure_of_inserted_child() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	factory.register_behavior_tree_from_text(TRACKED_TREE)?;
+	let mut tree = factory.create_tree("MainTree")?;
+	let sequence = tree
+		.iter()
+		.find(|element| element.name().as_ref() == "sequence")
+		.map(BehaviorTreeElement::uid)
+		.unwrap_or_default();
+
+	// enabling twice registers the tracking only once
+	tree.enable_failure_tracking();
+	tree.enable_failure_tracking();
+	tree.insert_child(&factory, sequence, 1, "Failing")?;
+
+	// the inserted child is tracked without enabling again
+	assert_eq!(tree.tick_while_running().await?, BehaviorState::Failure);
+	assert!(
+		tree.last_failure()
+			.is_some_and(|(path, _)| path.ends_with("inserted"))
+	);
+
+	tree.remove_global_transition_callback(&"last_failure".into());
+	tree.reset()?;
+	assert_eq!(tree.tick_while_running().await?, BehaviorState::Failure);
+	assert!(tree.last_failure().is_none());
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn tick_once_with_changes() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut tree = factory.create_from_text(CHANGES_TREE)?;
+	let uid = |tree: &BehaviorTree, name: &str| {
+		tree.iter()
+			.find(|element| element.name().as_ref() == name)
+			.map(BehaviorTreeElement::uid)
+	};
+	let (a, b) = (uid(&tree, "a"), uid(&tree, "b"));
+
+	// the first tick starts every element
+	let (state, changes) = tree.tick_once_with_changes().await?;
+	assert_eq!(state, BehaviorState::Running);
+	assert_eq!(changes.len(), usize::from(tree.size()));
+	assert!(a.is_some_and(|uid| changes.contains(&uid)));
+	assert!(b.is_some_and(|uid| changes.contains(&uid)));
+
+	// a further tick only keeps `b` running
+	let (state, changes) = tree.tick_once_with_changes().await?;
+	assert_eq!(state, BehaviorState::Running);
+	assert!(changes.is_empty());
+
+	// the recording callback is removed again
+	let (_, changes) = tree.tick_once_with_changes().await?;
+	assert!(changes.is_empty());
+
+	Ok(())
+}
+
+const DRY_RUN_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Fallback>
+			<Sequence>
+				<AlwaysSuccess name="a"/>
+				<AlwaysFailure name="b"/>
+			</Sequence>
+			<AlwaysSuccess name="c"/>
+		</Fallback>
+	</BehaviorTree>
+</root>
+"#;
+
+const DRY_RUN_CONDITION_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence>
+			<ScriptCondition name="a" code="false"/>
+			<AlwaysSuccess name="b"/>
+			<AlwaysSuccess name="c"/>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+const DRY_RUN_RUNNING_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<AlwaysRunning/>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn dry_run() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut tree = factory.create_from_text(DRY_RUN_TREE)?;
+	let leafs = tree.leaf_paths();
+	assert_eq!(leafs.len(), 3);
+
+	// failing leafs let the fallback try the next child
+	let visited = tree.dry_run(BehaviorState::Failure).await?;
+	assert_eq!(visited, [leafs[0].clone(), leafs[2].clone()]);
+
+	// succeeding leafs complete the sequence
+	let visited = tree.dry_run(BehaviorState::Success).await?;
+	assert_eq!(visited, [leafs[0].clone(), leafs[1].clone()]);
+
+	// nothing was executed and the original behaviors are back in place
+	assert_eq!(tree.tick_count(), 0);
+	assert!(
+		tree.iter()
+			.all(|element| element.state() == BehaviorState::Idle)
+	);
+	assert_eq!(tree.tick_while_running().await?, BehaviorState::Success);
+
+	// running leafs stop the sequence, but conditions succeed instead of running
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut tree = factory.create_from_text(DRY_RUN_CONDITION_TREE)?;
+	let leafs = tree.leaf_paths();
+	let visited = tree.dry_run(BehaviorState::Running).await?;
+	assert_eq!(visited, [leafs[0].clone(), leafs[1].clone()]);
+
+	// a running tree is rejected
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut tree = factory.create_from_text(DRY_RUN_RUNNING_TREE)?;
+	assert_eq!(tree.tick_once().await?, BehaviorState::Running);
+	let result = tree.dry_run(BehaviorState::Success).await;
+	assert!(matches!(
+		result,
+		Err(BehaviorError::State {
+			state: BehaviorState::Running,
+			..
+		})
+	));
+
+	Ok(())
+}
+
+/// Number of halts of [`Halting`] actions
+static HALTS: AtomicUsize = AtomicUsize::new(0);
+
+/// Action, which keeps running and counts its halts
+#[derive(Action, Debug, Default)]
+struct Halting;
+
+#[async_trait::async_trait]
+impl Behavior for Halting {
+	fn on_halt(&mut self) -> Result<(), BehaviorError> {
+		HALTS.fetch_add(1, Ordering::Relaxed);
+		Ok(())
+	}
+
+	async fn tick(
+		&mut self,
+		_behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		Ok(BehaviorState::Running)
+	}
+}
+
+const REPLACE_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence name="sequence">
+			<AlwaysSuccess name="first"/>
+			<Halting name="busy"/>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn replace_behavior() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, Halting, "Halting")?;
+	let mut tree = factory.create_from_text(REPLACE_TREE)?;
+	let path_of = |tree: &BehaviorTree, name: &str| {
+		tree.iter()
+			.find(|element| element.name().as_ref() == name)
+			.map(|element| element.groot2_path().to_string())
+			.unwrap_or_default()
+	};
+	let first = path_of(&tree, "first");
+	let busy = path_of(&tree, "busy");
+	let sequence = path_of(&tree, "sequence");
+
+	assert_eq!(tree.tick_once().await?, BehaviorState::Running);
+	assert_eq!(tree.tick_once().await?, BehaviorState::Running);
+
+	// swap the running action for a succeeding mock, the running action is halted
+	let mock = MockBehavior::new(MockBehaviorConfig::new(BehaviorState::Success), PortList::default());
+	tree.replace_behavior(&busy, Box::new(mock))?;
+	assert_eq!(HALTS.load(Ordering::Relaxed), 1);
+	assert!(
+		tree.iter()
+			.filter(|element| element.name().as_ref() == "busy")
+			.all(|element| element.state() == BehaviorState::Idle)
+	);
+	assert_eq!(tree.tick_once().await?, BehaviorState::Success);
+
+	// a control can not be replaced by an action
+	let mock = MockBehavior::new(MockBehaviorConfig::new(BehaviorState::Success), PortList::default());
+	let result = tree.replace_behavior(&sequence, Box::new(mock));
+	assert!(matches!(result, Err(TreeError::KindMismatch { .. })));
+
+	// unknown path
+	let mock = MockBehavior::new(MockBehaviorConfig::new(BehaviorState::Success), PortList::default());
+	let result = tree.replace_behavior("unknown", Box::new(mock));
+	assert!(matches!(result, Err(TreeError::ElementNotFound { .. })));
+
+	// an action can not be replaced by a condition either
+	let result = tree.replace_behavior(&first, Box::new(AwaitingCondition));
+	assert!(matches!(
+		result,
+		Err(TreeError::KindMismatch {
+			expected: BehaviorKind::Action,
+			found: BehaviorKind::Condition,
+			..
+		})
+	));
+	assert!(
+		tree.iter()
+			.filter(|element| element.name().as_ref() == "first")
+			.all(|element| element.data().description().kind() == BehaviorKind::Action)
+	);
+
+	Ok(())
+}
+
+const BINARY_XML_TREE: &str = r#"
+<root BTCPP_format="4"
+		main_tree_to_execute="MainTree">
+	<!-- comments are dropped -->
+	<BehaviorTree ID="MainTree">
+		<Sequence>
+			<Script code="value := 1"/>
+			<SubTree ID="Body" value="{value}" result="{result}"/>
+			<ScriptCondition code="result == 2"/>
+		</Sequence>
+	</BehaviorTree>
+
+	<BehaviorTree ID="Body">
+		<Script code="result := value + 1"/>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn create_from_binary_xml() -> Result<(), Error> {
+	let encoded = BinaryXmlEncoder::encode(BINARY_XML_TREE)?;
+	assert!(encoded.len() < BINARY_XML_TREE.len());
+
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut xml_tree = factory.create_from_text(BINARY_XML_TREE)?;
+	factory.clear_registered_behavior_trees();
+	let mut binary_tree = factory.create_from_binary_xml(&encoded)?;
+
+	assert_eq!(binary_tree.size(), xml_tree.size());
+	let xml_paths: Vec<_> = xml_tree
+		.iter()
+		.map(|element| element.groot2_path().clone())
+		.collect();
+	let binary_paths: Vec<_> = binary_tree
+		.iter()
+		.map(|element| element.groot2_path().clone())
+		.collect();
+	assert_eq!(binary_paths, xml_paths);
+
+	assert_eq!(xml_tree.tick_while_running().await?, BehaviorState::Success);
+	assert_eq!(binary_tree.tick_while_running().await?, BehaviorState::Success);
+	assert_eq!(binary_tree.blackboard().get::<i64>("result")?, 2);
+
+	// invalid documents are rejected
+	assert!(factory.create_from_binary_xml(b"").is_err());
+	assert!(
+		factory
+			.create_from_binary_xml(&encoded[..encoded.len() - 1])
+			.is_err()
+	);
+	assert!(BinaryXmlEncoder::encode("<root BTCPP_format=\"4\"><TreeNodesModel/></root>").is_err());
+
+	Ok(())
+}
+
+/// Condition checking the `u32` context against port `expected`
+#[derive(Condition, Debug, Default)]
+struct ContextCheck;
+
+#[async_trait::async_trait]
+impl Behavior for ContextCheck {
+	async fn tick(
+		&mut self,
+		behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		let expected = behavior.get::<u32>("expected")?;
+		if behavior.context::<u32>() == Some(&expected) {
+			Ok(BehaviorState::Success)
+		} else {
+			Ok(BehaviorState::Failure)
+		}
+	}
+
+	fn provided_ports() -> PortList {
+		port_list![input_port!(u32, "expected")]
+	}
+}
+
+const CONTEXT_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence>
+			<ContextCheck expected="42"/>
+			<SubTree ID="Sub"/>
+		</Sequence>
+	</BehaviorTree>
+	<BehaviorTree ID="Sub">
+		<ContextCheck expected="42"/>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn context() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, ContextCheck, "ContextCheck")?;
+	let mut tree = factory.create_from_text(CONTEXT_TREE)?;
+	drop(factory);
+
+	// no context
+	assert!(tree.context::<u32>().is_none());
+	assert_eq!(tree.tick_while_running().await?, BehaviorState::Failure);
+
+	// context of a different type
+	tree.reset()?;
+	tree.set_context(String::from("42"));
+	assert!(tree.context::<u32>().is_none());
+	assert_eq!(tree.tick_while_running().await?, BehaviorState::Failure);
+
+	// matching context replaces the previous one
+	tree.reset()?;
+	tree.set_context(42_u32);
+	assert_eq!(tree.context::<u32>(), Some(&42));
+	assert!(tree.context::<String>().is_none());
+	assert_eq!(tree.tick_while_running().await?, BehaviorState::Success);
+
+	Ok(())
+}
+
+const BULK_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence>
+			<AlwaysSuccess/>
+			<SucceedAtTick tick="3"/>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn tick_bulk() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, SucceedAtTick, "SucceedAtTick")?;
+	let mut tree = factory.create_from_text(BULK_TREE)?;
+	drop(factory);
+
+	// count the completions of the root
+	let completions = Arc::new(AtomicUsize::new(0));
+	let counter = completions.clone();
+	tree.add_global_transition_callback(&"completions".into(), move |behavior, new_state| {
+		if behavior.uid() == 0 && behavior.state() != new_state && new_state == BehaviorState::Success {
+			counter.fetch_add(1, Ordering::Relaxed);
+		}
+	});
+
+	// no tick at all
+	assert_eq!(tree.tick_bulk(0, true).await?, BehaviorState::Idle);
+	assert_eq!(tree.tick_count(), 0);
+
+	assert_eq!(tree.tick_bulk(2, true).await?, BehaviorState::Running);
+	assert_eq!(completions.load(Ordering::Relaxed), 0);
+
+	// after completion at the third tick the tree is started over with each tick
+	assert_eq!(tree.tick_bulk(3, true).await?, BehaviorState::Success);
+	assert_eq!(tree.tick_count(), 5);
+	assert_eq!(completions.load(Ordering::Relaxed), 3);
+	Ok(())
+}
+
+const NESTED_TREE: &str = r#"
+<root BTCPP_format="4" main_tree_to_execute="MainTree">
+	<BehaviorTree ID="MainTree">
+		<Sequence>
+			<Fallback>
+				<AlwaysFailure/>
+				<SubTree ID="Inner"/>
+			</Fallback>
+			<Inverter>
+				<AlwaysFailure/>
+			</Inverter>
+			<SubTree ID="Inner"/>
+			<AlwaysSuccess/>
+		</Sequence>
+	</BehaviorTree>
+	<BehaviorTree ID="Inner">
+		<Sequence>
+			<AlwaysSuccess/>
+			<ForceSuccess>
+				<AlwaysFailure/>
+			</ForceSuccess>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+#[test]
+fn iteration_in_uid_order() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut tree = factory.create_from_text(NESTED_TREE)?;
+	drop(factory);
+
+	let expected: Vec<u16> = (0..tree.size()).collect();
+	let uids: Vec<u16> = tree
+		.iter()
+		.map(BehaviorTreeElement::uid)
+		.collect();
+	assert_eq!(uids, expected);
+	let uids: Vec<u16> = tree
+		.iter_mut()
+		.map(|element| element.uid())
+		.collect();
+	assert_eq!(uids, expected);
+
+	// the position in the iteration addresses the same element as the uid
+	for (uid, element) in (0_u16..).zip(tree.iter()) {
+		assert!(
+			tree.element_by_uid(uid)
+				.is_some_and(|found| found.groot2_path() == element.groot2_path())
+		);
+	}
+	Ok(())
+}
+
+/// Action running on start and failing with an error on the next tick
+#[derive(Action, Debug, Default)]
+struct ErrorOnTick;
+
+#[async_trait::async_trait]
+impl Behavior for ErrorOnTick {
+	async fn start(
+		&mut self,
+		_behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		Ok(BehaviorState::Running)
+	}
+
+	async fn tick(
+		&mut self,
+		_behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		Err(BehaviorError::Composition {
+			txt: "actuator fault".into(),
+		})
+	}
+}
+
+const ERROR_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence>
+			<AlwaysSuccess/>
+			<ErrorOnTick _onHalted="halted := true"/>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn halt_on_error() -> Result<(), Error> {
+	for halt in [false, true] {
+		let mut factory = BehaviorTreeFactory::new()?;
+		register_behavior!(factory, ErrorOnTick, "ErrorOnTick")?;
+		assert!(
+			!factory
+				.registry()
+				.options()
+				.contains(TreeOptions::HALT_ON_ERROR)
+		);
+		factory.halt_on_error(halt);
+		let mut tree = factory.create_from_text(ERROR_TREE)?;
+		drop(factory);
+
+		assert_eq!(tree.tick_once().await?, BehaviorState::Running);
+		assert!(tree.tick_once().await.is_err());
+
+		let idle = tree
+			.iter()
+			.all(|element| element.state() == BehaviorState::Idle);
+		assert_eq!(idle, halt);
+		assert_eq!(tree.blackboard().get::<bool>("halted").is_ok(), halt);
+	}
+	Ok(())
+}
+
+const SKIPPED_TREE: &str = r#"
+<root BTCPP_format="4">
+	<BehaviorTree ID="MainTree">
+		<Sequence _skipIf="true">
+			<AlwaysRunning/>
+		</Sequence>
+	</BehaviorTree>
+</root>
+"#;
+
+#[tokio::test]
+async fn tick_while_running_skipped() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	let mut tree = factory.create_from_text(SKIPPED_TREE)?;
+	drop(factory);
+
+	assert_eq!(tree.tick_while_running().await?, BehaviorState::Skipped);
+	assert_eq!(tree.tick_count(), 1);
+	assert_eq!(
+		tree.tick_with_budget(core::time::Duration::from_secs(1))
+			.await?,
+		BehaviorState::Skipped
+	);
+	assert_eq!(tree.tick_count(), 2);
+	Ok(())
+}
+
+#[tokio::test]
+async fn tick_hooks() -> Result<(), Error> {
+	let mut factory = BehaviorTreeFactory::new()?;
+	register_behavior!(factory, SucceedAtTick, "SucceedAtTick")?;
+	let mut tree = factory.create_from_text(TICK_TREE)?;
+	drop(factory);
+
+	// the pre tick hook provides a value for the tick
+	let pre_ticks = Arc::new(AtomicUsize::new(0));
+	let counter = pre_ticks.clone();
+	tree.set_pre_tick_hook(move |blackboard| {
+		let ticks = counter.fetch_add(1, Ordering::Relaxed) + 1;
+		let _ = blackboard.set("ticks", ticks);
+	});
+	// the post tick hook sees the value of the same tick
+	let post_ticks = Arc::new(AtomicUsize::new(0));
+	let counter = post_ticks.clone();
+	tree.set_post_tick_hook(move |blackboard| {
+		let ticks = counter.fetch_add(1, Ordering::Relaxed) + 1;
+		assert_eq!(blackboard.get::<usize>("ticks").ok(), Some(ticks));
+	});
+
+	assert_eq!(tree.tick_once().await?, BehaviorState::Running);
+	assert_eq!(pre_ticks.load(Ordering::Relaxed), 1);
+	assert_eq!(post_ticks.load(Ordering::Relaxed), 1);
+
+	tree.tick_while_running().await?;
+	let ticks = usize::try_from(tree.tick_count()).unwrap_or_default();
+	assert_eq!(pre_ticks.load(Ordering::Relaxed), ticks);
+	assert_eq!(post_ticks.load(Ordering::Relaxed), ticks);
+
+	// removed hooks are no longer called
+	tree.remove_tick_hooks();
+	tree.reset()?;
+	tree.tick_once().await?;
+	assert_eq!(pre_ticks.load(Ordering::Relaxed), ticks);
+	assert_eq!(post_ticks.load(Ordering::Relaxed), ticks);
+	Ok(())
+}
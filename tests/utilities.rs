@@ -1,12 +1,12 @@
 // Copyright © 2025 Stephan Kunz
-//! [`ChangeStateAfter`] is a helper  [`Action`] for writing tests.
+//! [`ChangeStateAfter`] and [`StateList`] are helper [`Action`]s for writing tests.
 
 #![allow(unused)]
 
 #[doc(hidden)]
 extern crate alloc;
 
-use alloc::boxed::Box;
+use alloc::{boxed::Box, vec::Vec};
 use behaviortree::prelude::*;
 
 /// The `ChangeStateAfter` behavior returns
@@ -119,3 +119,43 @@ impl ChangeStateAfter {
 		self.final_state = state;
 	}
 }
+
+/// Returns the given states one after the other, repeating the last one.
+#[derive(Action, Debug, Default)]
+pub struct StateList {
+	/// The [`BehaviorState`]s to return.
+	states: Vec<BehaviorState>,
+	/// The current tick count.
+	tick_count: usize,
+}
+
+#[async_trait::async_trait]
+impl Behavior for StateList {
+	async fn tick(
+		&mut self,
+		_behavior: &mut BehaviorData,
+		_children: &mut BehaviorTreeElementList,
+		_runtime: &SharedRuntime,
+	) -> BehaviorResult {
+		let idx = self
+			.tick_count
+			.min(self.states.len().saturating_sub(1));
+		self.tick_count += 1;
+		Ok(self
+			.states
+			.get(idx)
+			.copied()
+			.unwrap_or(BehaviorState::Success))
+	}
+}
+
+impl StateList {
+	/// Returns a [`StateList`] behavior returning the given states.
+	#[must_use]
+	pub fn new(states: &[BehaviorState]) -> Self {
+		Self {
+			states: states.to_vec(),
+			tick_count: 0,
+		}
+	}
+}